@@ -88,6 +88,69 @@ extern "C" {
                                                                            const char*   cred_revoc_id,
                                                                            const char*   revoc_reg_delta_json)
                                                       );
+
+    extern indy_error_t indy_issuer_create_credentials_batch(indy_handle_t command_handle,
+                                                             indy_handle_t wallet_handle,
+                                                             const char *  cred_requests_json,
+                                                             const char *  rev_reg_id,
+                                                             indy_handle_t blob_storage_reader_handle,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   creds_json,
+                                                                                  const char*   revoc_reg_delta_json)
+                                                             );
+
+    extern indy_error_t indy_issuer_create_revocation_registry_pool(indy_handle_t command_handle,
+                                                                    indy_handle_t wallet_handle,
+                                                                    const char *  issuer_did,
+                                                                    const char *  cred_def_id,
+                                                                    const char *  config_json,
+
+                                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   pool_json)
+                                                                    );
+
+    extern indy_error_t indy_issuer_create_credential_from_revocation_registry_pool(indy_handle_t command_handle,
+                                                                                    indy_handle_t wallet_handle,
+                                                                                    const char *  cred_offer_json,
+                                                                                    const char *  cred_req_json,
+                                                                                    const char *  cred_values_json,
+
+                                                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                                                         indy_error_t  err,
+                                                                                                         const char*   cred_json,
+                                                                                                         const char*   rev_reg_id,
+                                                                                                         const char*   cred_revoc_id,
+                                                                                                         const char*   revoc_reg_delta_json)
+                                                                                    );
+
+    extern indy_error_t indy_issuer_get_revocation_registry_pool_pending_requests(indy_handle_t command_handle,
+                                                                                  indy_handle_t wallet_handle,
+                                                                                  const char *  cred_def_id,
+
+                                                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                                                       indy_error_t  err,
+                                                                                                       const char*   requests_json)
+                                                                                  );
+
+    extern indy_error_t indy_issuer_add_revocation_registry_to_pool(indy_handle_t command_handle,
+                                                                    indy_handle_t wallet_handle,
+                                                                    const char *  cred_def_id,
+
+                                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   rev_reg_id)
+                                                                    );
+
+    extern indy_error_t indy_issuer_confirm_revocation_registry_published(indy_handle_t command_handle,
+                                                                          indy_handle_t wallet_handle,
+                                                                          const char *  rev_reg_id,
+
+                                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                                               indy_error_t  err)
+                                                                          );
     
     extern indy_error_t indy_issuer_revoke_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
@@ -100,6 +163,26 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
 
+    extern indy_error_t indy_issuer_revoke_credentials(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       indy_handle_t blob_storage_reader_handle,
+                                                       const char *  rev_reg_id,
+                                                       const char *  cred_revoc_ids_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   revoc_reg_delta_json)
+                                                       );
+
+    extern indy_error_t indy_issuer_get_revocation_status_list(indy_handle_t command_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               const char *  rev_reg_id,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   status_list_json)
+                                                               );
+
 /*    extern indy_error_t indy_issuer_recover_credential(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        indy_handle_t blob_storage_reader_handle,
@@ -274,6 +357,19 @@ extern "C" {
                                                                         indy_bool_t   valid )
                                                    );
 
+    extern indy_error_t indy_verifier_verify_proof_with_details(indy_handle_t command_handle,
+                                                                const char *  proof_request_json,
+                                                                const char *  proof_json,
+                                                                const char *  schemas_json,
+                                                                const char *  credential_defs_jsons,
+                                                                const char *  rev_reg_defs_json,
+                                                                const char *  rev_regs_json,
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   details_json)
+                                                                );
+
 
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
@@ -301,6 +397,17 @@ extern "C" {
                                                                           const char*   updated_rev_state_json)
                                                      );
 
+    extern indy_error_t indy_prover_refresh_revocation_states(indy_handle_t command_handle,
+                                                              indy_handle_t pool_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              indy_handle_t blob_storage_reader_handle,
+                                                              const char *  cred_ids_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   refreshed_json)
+                                                              );
+
 
     extern indy_error_t indy_generate_nonce(indy_handle_t command_handle,
                                             void           (*cb)(indy_handle_t command_handle_,
@@ -315,6 +422,13 @@ extern "C" {
                                                                  const char*   res)
                                             );
 
+    extern indy_error_t indy_encode_credential_attributes(indy_handle_t command_handle,
+                                                          const char *  attributes_json,
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   cred_values_json)
+                                                          );
+
 #ifdef __cplusplus
 }
 #endif
@@ -5,7 +5,70 @@
 extern "C" {
 #endif
 
+    /// Register external key backend (secure enclave, HSM, KMS and etc.) that keeps non-exportable keys.
+    ///
+    /// Keys created with "crypto_type" equal to registered type are stored in the wallet without secret part.
+    /// indy_create_key, indy_create_and_store_my_did, indy_crypto_sign, indy_crypto_verify, indy_sign_request and
+    /// authcrypt part of indy_pack_message delegate to the backend handlers for verkeys with ":<crypto_type>" suffix.
+    ///
+    /// Buffers returned by create_key, sign and crypto_box handlers are identified by handle
+    /// and released by libindy with free_buffer handler.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// crypto_type: Crypto type name that identifies keys of the backend. Must not be equal to built-in crypto types.
+    /// create_key: "create_key" operation handler. Returns base58 encoded verkey without crypto type suffix.
+    /// sign: "sign" operation handler. Receives full verkey with crypto type suffix.
+    /// verify: "verify" operation handler. Receives full verkey with crypto type suffix.
+    /// crypto_box: (optional) "crypto_box" operation handler. If not set authenticated encryption with backend keys isn't supported.
+    /// free_buffer: Handler that allows to de-allocate buffers allocated in backend code
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
 
+    extern indy_error_t indy_register_key_backend(indy_handle_t  command_handle,
+                                                  const char*    crypto_type,
+
+                                                  indy_error_t (*createKeyFn)(const char*    key_info_json,
+                                                                              const char**   verkey_p,
+                                                                              indy_handle_t* verkey_handle_p),
+
+                                                  indy_error_t (*signFn)(const char*           verkey,
+                                                                         const indy_u8_t*      message_raw,
+                                                                         indy_u32_t            message_len,
+                                                                         const indy_u8_t**     signature_raw_p,
+                                                                         indy_u32_t*           signature_len_p,
+                                                                         indy_handle_t*        signature_handle_p),
+
+                                                  indy_error_t (*verifyFn)(const char*         verkey,
+                                                                           const indy_u8_t*    message_raw,
+                                                                           indy_u32_t          message_len,
+                                                                           const indy_u8_t*    signature_raw,
+                                                                           indy_u32_t          signature_len,
+                                                                           indy_bool_t*        valid_p),
+
+                                                  indy_error_t (*cryptoBoxFn)(const char*        my_verkey,
+                                                                              const char*        their_verkey,
+                                                                              const indy_u8_t*   message_raw,
+                                                                              indy_u32_t         message_len,
+                                                                              const indy_u8_t*   nonce_raw,
+                                                                              indy_u32_t         nonce_len,
+                                                                              const indy_u8_t**  encrypted_raw_p,
+                                                                              indy_u32_t*        encrypted_len_p,
+                                                                              indy_handle_t*     encrypted_handle_p),
+
+                                                  indy_error_t (*freeBufferFn)(indy_handle_t buffer_handle),
+
+                                                  void         (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                  );
 
     /// Creates keys pair and stores in the wallet.
     ///
@@ -16,7 +79,9 @@ extern "C" {
     /// {
     ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
     ///                                Can be UTF-8, base64 or hex string.
-    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently 'ed25519', 'secp256k1', 'p256' values
+    ///                            // or crypto type registered with indy_register_key_backend are supported for this field.
+    ///                            // 'secp256k1' and 'p256' keys can be used only for signing and verification.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -165,6 +230,89 @@ extern "C" {
                                                                 indy_bool_t   valid )
                                           );
 
+    /// Signs a payload with a key and produces JSON Web Signature (RFC 7515).
+    ///
+    /// Algorithm of signature is defined by crypto type of the key:
+    /// "EdDSA" for ed25519, "ES256K" for secp256k1 and "ES256" for p256.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// signer_vk: id (verkey) of payload signer. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// payload_raw: a pointer to first byte of payload to be signed
+    /// payload_len: a payload length
+    /// options_json: (optional) signing options as json:
+    /// {
+    ///     "serialization": (optional) "compact" or "json", "compact" by default,
+    ///     "detached": (optional) if true payload isn't included into JWS (RFC 7515 Appendix F), false by default,
+    ///     "did": (optional) DID of the signer used to build "kid" header. Unqualified DID is qualified with "sov" method
+    ///            and "#key-1" fragment is appended if DID has no fragment.
+    ///            If not set "kid" is did:key DID URL of signer_vk.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// JWS string:
+    /// for compact serialization:
+    ///     b64URLencode({"alg": <alg>, "kid": <kid>}).b64URLencode(payload).b64URLencode(signature)
+    /// for json serialization:
+    /// {
+    ///     "payload": b64URLencode(payload),
+    ///     "signatures": [
+    ///         {
+    ///             "protected": b64URLencode({"alg": <alg>}),
+    ///             "header": {"kid": <kid>},
+    ///             "signature": b64URLencode(signature)
+    ///         }
+    ///     ]
+    /// }
+    /// Encoded payload is empty (compact) or omitted (json) for detached signature.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_jws(indy_handle_t      command_handle,
+                                             indy_handle_t      wallet_handle,
+                                             const char *       signer_vk,
+                                             const indy_u8_t *  payload_raw,
+                                             indy_u32_t         payload_len,
+                                             const char *       options_json,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char *  jws)
+                                            );
+
+    /// Verify JSON Web Signature (RFC 7515) with a verkey.
+    ///
+    /// Both compact and json serializations are accepted. Json serialization must contain a single signature.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// signer_vk: verkey of the payload signer
+    /// jws: JWS string to be verified
+    /// detached_payload_raw: (optional) a pointer to first byte of detached payload. Must be set only if JWS has no payload.
+    /// detached_payload_len: a detached payload length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if signature is valid, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_jws(indy_handle_t      command_handle,
+                                               const char *       signer_vk,
+                                               const char *       jws,
+                                               const indy_u8_t *  detached_payload_raw,
+                                               indy_u32_t         detached_payload_len,
+
+                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err,
+                                                                    indy_bool_t   valid )
+                                              );
+
     /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_pack_message() INSTEAD ****
     /// Encrypt a message by authenticated-encryption scheme.
     ///
@@ -445,6 +593,119 @@ extern "C" {
                                                                  const indy_u8_t*  res_json_raw,
                                                                  indy_u32_t        res_json_len)
                                             );
+
+    /// Packs a message into DIDComm v2 format (Experimental)
+    ///
+    /// Plaintext can be wrapped into JWS signed envelope and/or encrypted as JWE in general JSON serialization.
+    /// Anoncrypt uses X25519 ECDH-ES+A256KW key management, authcrypt uses X25519 ECDH-1PU+A256KW.
+    /// Content is encrypted with A256CBC-HS512. Only ed25519 keys can be used for encryption,
+    /// secp256k1 and p256 keys can be used for signing.
+    ///
+    /// Key ids can be either verkeys or did:key DID URLs. Verkeys are converted to did:key DID URLs
+    /// in the message headers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// message: a pointer to the first byte of the message to be packed
+    /// message_len: the length of the message
+    /// receiver_kids: a json list of receiver's key ids the message is being encrypted for.
+    ///                Can be empty if only signed message is needed.
+    ///                Example:
+    ///                "["did:key:z6Mk...#z6LS...", <receiver verkey>]"
+    /// sender_kid: the sender's key id. When null pointer is used in this parameter, anoncrypt is used
+    /// signer_kid: the signer's key id. When set the message is signed before encryption
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// an encrypted message:
+    /// {
+    ///     "protected": b64URLencode({
+    ///         "typ": "application/didcomm-encrypted+json",
+    ///         "alg": "ECDH-1PU+A256KW" or "ECDH-ES+A256KW",
+    ///         "enc": "A256CBC-HS512",
+    ///         "epk": {"kty": "OKP", "crv": "X25519", "x": <b64URLencode(ephemeral public key)>},
+    ///         "apv": b64URLencode(sha256(<sorted recipient kids joined with ".">)),
+    ///         "skid": <sender kid>, // authcrypt only
+    ///         "apu": b64URLencode(<sender kid>) // authcrypt only
+    ///     }),
+    ///     "recipients": [
+    ///         {
+    ///             "header": {"kid": <recipient kid>},
+    ///             "encrypted_key": b64URLencode(A256KW(kek, cek))
+    ///         }
+    ///     ],
+    ///     "iv": b64URLencode(iv),
+    ///     "ciphertext": b64URLencode(ciphertext),
+    ///     "tag": b64URLencode(tag)
+    /// }
+    ///
+    /// OR signed message if no receivers passed:
+    /// {
+    ///     "payload": b64URLencode(message),
+    ///     "signatures": [
+    ///         {
+    ///             "protected": b64URLencode({"typ": "application/didcomm-signed+json", "alg": "EdDSA" or "ES256K" or "ES256"}),
+    ///             "header": {"kid": <signer kid>},
+    ///             "signature": b64URLencode(signature)
+    ///         }
+    ///     ]
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_pack_message_v2(indy_handle_t      command_handle,
+                                             indy_handle_t      wallet_handle,
+                                             const indy_u8_t*   message,
+                                             indy_u32_t         message_len,
+                                             const char *       receiver_kids,
+                                             const char *       sender_kid,
+                                             const char *       signer_kid,
+
+                                             void           (*cb)(indy_handle_t     command_handle_,
+                                                                  indy_error_t      err,
+                                                                  const indy_u8_t*  packed_msg_raw,
+                                                                  indy_u32_t        packed_msg_len)
+                                             );
+
+    /// Unpacks a message packed by indy_pack_message_v2 or indy_pack_message (Experimental)
+    ///
+    /// Format of the message is detected automatically: DIDComm v2 encrypted message (JWE),
+    /// DIDComm v2 signed message (JWS) or JWE-like message produced by indy_pack_message.
+    /// Signed envelope inside of encrypted message is verified and unwrapped.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// packed_data: a pointer to the first byte of the message to be unpacked
+    /// packed_len: the length of the message in bytes
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// {
+    ///     message: <decrypted message>,
+    ///     recipient_kid: <recipient kid>, // if message was encrypted
+    ///     sender_kid: <sender kid>, // if message was authcrypted
+    ///     signer_kid: <signer kid> // if message was signed
+    /// }
+    /// For messages produced by indy_pack_message kids are equal to verkeys.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_unpack_message_v2(indy_handle_t      command_handle,
+                                               indy_handle_t      wallet_handle,
+                                               const indy_u8_t*   packed_msg,
+                                               indy_u32_t         packed_len,
+
+                                               void           (*cb)(indy_handle_t     command_handle_,
+                                                                    indy_error_t      err,
+                                                                    const indy_u8_t*  res_json_raw,
+                                                                    indy_u32_t        res_json_len)
+                                               );
 #ifdef __cplusplus
 }
 #endif
@@ -14,6 +14,10 @@ extern "C" {
     /// sign key (see wallet_sign), and sends signed request message
     /// to validator pool (see write_request).
     ///
+    /// If transaction author agreement acceptance is registered on the pool handle
+    /// (see indy_set_txn_author_agreement_acceptance_for_pool) and request is a write to DOMAIN ledger
+    /// without taaAcceptance field, the acceptance is appended to the request before signing.
+    ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
     /// pool_handle: pool handle (created by open_pool_ledger).
@@ -68,6 +72,94 @@ extern "C" {
                                                                  const char*   request_result_json)
                                            );
 
+    /// Publishes request message to validator pool (no signing, like submit_request)
+    /// and returns the reply together with per node results of consensus.
+    ///
+    /// Unlike submit_request, the callback is called with the details also
+    /// in case pool couldn't reach consensus, so a caller can find out which nodes
+    /// rejected the request and why.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result with consensus details as json.
+    /// {
+    ///     "reply": <json> - reply agreed by f+1 nodes, null if consensus is not reached,
+    ///     "consensus": { - null for read requests which are sent to a single node
+    ///         "reached": bool - whether f+1 nodes agreed on the same reply,
+    ///         "f": int - number of tolerated faulty nodes,
+    ///         "nodes": {
+    ///             <node alias>: {
+    ///                 "acked": bool - whether node acknowledged the request (REQACK),
+    ///                 "status": string - one of "REPLY", "REQNACK", "REJECT", "TIMEOUT", "NO_REPLY",
+    ///                 "reason": Option<string> - reason of REQNACK or REJECT returned by node,
+    ///                 "errorKind": Option<string> - classified reason:
+    ///                     "AuthRule", "TaaMismatch", "InvalidSignature", "InvalidRequest", "Other"
+    ///             },
+    ///             ...
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_submit_request_with_details(indy_handle_t command_handle,
+                                                         indy_handle_t pool_handle,
+                                                         const char *  request_json,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   response_with_details_json)
+                                                        );
+
+    /// Publishes batch of request messages to validator pool (no signing, like submit_request).
+    ///
+    /// Requests are sent to the validator pool as is. It's assumed that they are already prepared.
+    /// Requests are pipelined: up to `max_in_flight` of them are sent without waiting for replies.
+    /// Pool sends up to `conn_limit` (pool open option) of them over each connection, so if `max_in_flight`
+    /// exceeds it requests in flight are spread across several pool connections.
+    /// Consensus and state proof verification are performed for each request independently,
+    /// so failure of one request doesn't affect the others.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// requests_json: Array of request data jsons.
+    ///     [{request_1}, {request_2}, ...]
+    /// options_json: (Optional) batch options.
+    ///     {
+    ///         "max_in_flight": int - (Optional) maximum number of requests sent to the pool
+    ///                                without reply received. `conn_limit` pool open option
+    ///                                by default, so requests in flight share single pool connection.
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Array of per-request results in the same order as requests:
+    ///     [
+    ///         {"reply": {reply json received from the pool}},
+    ///         {"error": {"code": int - error code, "message": string - error description}},
+    ///         ...
+    ///     ]
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_submit_requests_batch(indy_handle_t command_handle,
+                                                   indy_handle_t pool_handle,
+                                                   const char *  requests_json,
+                                                   const char *  options_json,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   results_json)
+                                                  );
+
     /// Send action to particular nodes of validator pool.
     ///
     /// The list of requests can be send:
@@ -972,7 +1064,8 @@ extern "C" {
     ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
-    /// response: response of write or get request.
+    /// response: response of write or get request
+    ///     or result of indy_submit_request_with_details.
     /// cb: Callback that takes command result as parameter.
     ///
     /// #Returns
@@ -981,7 +1074,9 @@ extern "C" {
     ///     "seqNo": Option<u64> - transaction sequence number,
     ///     "txnTime": Option<u64> - transaction ordering time,
     ///     "lastSeqNo": Option<u64> - the latest transaction seqNo for particular Node,
-    ///     "lastTxnTime": Option<u64> - the latest transaction ordering time for particular Node
+    ///     "lastTxnTime": Option<u64> - the latest transaction ordering time for particular Node,
+    ///     "consensus": Option<json> - per node results of consensus,
+    ///         only for result of indy_submit_request_with_details (see its description)
     /// }
     ///
     /// #Errors
@@ -995,6 +1090,37 @@ extern "C" {
                                                                         const char*   response_metadata)
                                                   );
 
+    /// Verifies that transaction returned in GET_TXN response is included into the ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// get_txn_response: response of GET_TXN request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// verified inclusion info.
+    /// {
+    ///     "seqNo": u64 - sequence number of the transaction,
+    ///     "txnTime": Option<u64> - transaction ordering time,
+    ///     "ledgerId": Option<u64> - id of the ledger signed by nodes,
+    ///     "ledgerSize": u64 - size of the ledger the audit path is built for,
+    ///     "rootHash": string - verified ledger root hash (base58),
+    ///     "multiSignatureTimestamp": Option<u64> - time when the root hash was signed by nodes
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_verify_txn_inclusion(indy_handle_t command_handle,
+                                                  indy_handle_t pool_handle,
+                                                  const char *  get_txn_response,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   txn_inclusion_json)
+                                                 );
+
     /// Builds a AUTH_RULE request. Request to change authentication rules for a ledger transaction.
     ///
     /// #Params
@@ -1117,6 +1243,184 @@ extern "C" {
                                                                               const char*   request_json)
                                                         );
 
+    /// Checks locally whether a set of signers satisfies the auth rule constraint for a ledger action.
+    ///
+    /// The rule is selected from `auth_rules_json` in the same way as the ledger does it:
+    /// rules with exact `field`, `old_value` and `new_value` take precedence over rules with `*` values.
+    /// Nothing is sent to the ledger, so the result can be used to predict whether a write will be rejected.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// auth_rules_json: list of auth rules in the format of `data` field of GET_AUTH_RULE reply:
+    /// [
+    ///     {
+    ///         auth_type: ledger transaction alias or associated value,
+    ///         auth_action: type of an action ("ADD" or "EDIT"),
+    ///         field: transaction field,
+    ///         old_value: (Optional) old value of a field,
+    ///         new_value: (Optional) new value of a field,
+    ///         constraint: set of constraints (see `indy_build_auth_rule_request`)
+    ///     }
+    /// ]
+    /// txn_type: ledger transaction alias or associated value.
+    /// action: type of an action. Can be either "ADD" or "EDIT".
+    /// field: transaction field.
+    /// old_value: (Optional) old value of a field (mandatory for EDIT action).
+    /// new_value: (Optional) new value of a field.
+    /// signers_json: list of transaction signers:
+    /// [
+    ///     {
+    ///         role: (Optional) role of the signer on the ledger (alias or associated value, null for common USER),
+    ///         isOwner: (Optional) whether the signer is an owner of the transaction (false by default),
+    ///         offLedger: (Optional) whether the signer DID isn't present on the ledger (false by default)
+    ///     }
+    /// ]
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Check result json:
+    /// {
+    ///     allowed: bool - whether the signers satisfy the constraint,
+    ///     explanation: string - human readable explanation of the constraint evaluation,
+    ///     fees: [string] - fees aliases set in the metadata of satisfied constraints
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_check_auth_rule(indy_handle_t command_handle,
+                                             const char *  auth_rules_json,
+                                             const char *  txn_type,
+                                             const char *  action,
+                                             const char *  field,
+                                             const char *  old_value,
+                                             const char *  new_value,
+                                             const char *  signers_json,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char*   result_json)
+                                             );
+
+    /// Builds a Rich Schema object request (JSON_LD_CONTEXT, RICH_SCHEMA, RICH_SCHEMA_ENCODING,
+    /// RICH_SCHEMA_MAPPING, RICH_SCHEMA_CRED_DEF or RICH_SCHEMA_PRES_DEF depending on `rs_type`).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// rs_id: identifier of the Rich Schema object.
+    /// rs_content: JSON-LD content of the object as a string.
+    ///     Must contain `@context` for `ctx` objects and `@id` equal to `rs_id` for others.
+    /// rs_name: name of the Rich Schema object.
+    /// rs_version: version of the Rich Schema object.
+    /// rs_type: type of the Rich Schema object. One of:
+    ///     "ctx" - JSON-LD context
+    ///     "sch" - Rich Schema
+    ///     "enc" - Encoding
+    ///     "map" - Mapping
+    ///     "cdf" - Credential Definition
+    ///     "pdf" - Presentation Definition
+    /// ver: version of the Rich Schema object json format.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_rich_schema_request(indy_handle_t command_handle,
+                                                       const char *  submitter_did,
+                                                       const char *  rs_id,
+                                                       const char *  rs_content,
+                                                       const char *  rs_name,
+                                                       const char *  rs_version,
+                                                       const char *  rs_type,
+                                                       const char *  ver,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   request_json)
+                                                       );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get a Rich Schema object by its identifier.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// rs_id: identifier of the Rich Schema object.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_rich_schema_object_by_id_request(indy_handle_t command_handle,
+                                                                        const char *  submitter_did,
+                                                                        const char *  rs_id,
+
+                                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                                             indy_error_t  err,
+                                                                                             const char*   request_json)
+                                                                        );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+    /// Request to get a Rich Schema object by its type, name and version.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// rs_type: type of the Rich Schema object (one of "ctx", "sch", "enc", "map", "cdf", "pdf").
+    /// rs_name: name of the Rich Schema object.
+    /// rs_version: version of the Rich Schema object.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_rich_schema_object_by_metadata_request(indy_handle_t command_handle,
+                                                                              const char *  submitter_did,
+                                                                              const char *  rs_type,
+                                                                              const char *  rs_name,
+                                                                              const char *  rs_version,
+
+                                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                                   indy_error_t  err,
+                                                                                                   const char*   request_json)
+                                                                              );
+
+    /// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_rich_schema_object_response: response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Rich Schema object json.
+    /// {
+    ///     id: identifier of the object
+    ///     content: JSON-LD content of the object as a string
+    ///     rsName: name of the object
+    ///     rsVersion: version of the object
+    ///     rsType: type of the object
+    ///     ver: version of the object json format
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_parse_get_rich_schema_object_response(indy_handle_t command_handle,
+                                                                   const char *  get_rich_schema_object_response,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   rich_schema_json)
+                                                                   );
+
     /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
     ///
     /// EXPERIMENTAL
@@ -1377,6 +1681,46 @@ extern "C" {
                                                                                                     const char*   request_with_meta_json)
                                                                                );
 
+    /// Register transaction author agreement acceptance on the pool handle.
+    /// indy_sign_and_submit_request will append it to writes sent through the pool
+    /// which require transaction author agreement (NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF, REVOC_REG_ENTRY)
+    /// and don't contain acceptance data yet.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// Before the first write the latest agreement is read from the ledger (GET_TXN_AUTHR_AGRMT) and cached for the pool handle.
+    /// The cached agreement is read again after 10 minutes or when the pool rejects a write because of the acceptance.
+    /// Writes fail without sending to the pool if the accepted agreement isn't the latest one or has been retired.
+    /// Registered acceptance is dropped when the pool is closed. Repeated call replaces the acceptance.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// text and version - (optional) raw data about TAA from ledger.
+    ///     These parameters should be passed together.
+    ///     These parameters are required if taa_digest parameter is omitted.
+    /// taa_digest - (optional) digest on text and version.
+    ///     Digest is sha256 hash calculated on concatenated strings: version || text.
+    ///     This parameter is required if text and version parameters are omitted.
+    /// mechanism - mechanism how user has accepted the TAA
+    /// time - UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_set_txn_author_agreement_acceptance_for_pool(indy_handle_t command_handle,
+                                                                          indy_handle_t pool_handle,
+                                                                          const char *  text,
+                                                                          const char *  version,
+                                                                          const char *  taa_digest,
+                                                                          const char *  mechanism,
+                                                                          indy_u64_t    time,
+
+                                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                                               indy_error_t  err)
+                                                                          );
+
     /// Append Endorser to an existing request.
     ///
     /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1405,6 +1749,77 @@ extern "C" {
                                                                           const char*   out_request_json)
                                                      );
 
+    /// Prepares a request for an Endorser.
+    ///
+    /// Appends Endorser to the request, signs it by the Transaction Author and wraps it
+    /// into a pending transaction which can be passed to the Endorser (see `indy_endorse_pending_transaction`).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the Transaction Author. Must be the same as an identifier of the request.
+    /// request_json: original request.
+    /// endorser_did: DID of the Endorser that will submit the transaction.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Pending transaction json
+    /// {
+    ///     ver: "1.0",
+    ///     txnType: type of the request,
+    ///     author: short DID of the Transaction Author,
+    ///     endorser: short DID of the Endorser,
+    ///     request: request with Endorser field appended and signed by the Transaction Author
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_prepare_request_for_endorser(indy_handle_t command_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  submitter_did,
+                                                          const char *  request_json,
+                                                          const char *  endorser_did,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   pending_txn_json)
+                                                          );
+
+    /// Endorses a pending transaction prepared by `indy_prepare_request_for_endorser` and submits it to validator pool.
+    ///
+    /// Before co-signing the request roles of the Endorser and the Transaction Author are read from the ledger (GET_NYM)
+    /// and their signatures are checked against auth rules set on the ledger (GET_AUTH_RULE) for adding the transaction
+    /// (see `indy_check_auth_rule`). Author not written to the ledger yet is checked as an off-ledger signer.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// endorser_did: DID of the Endorser. Must be the same as the Endorser of the pending transaction.
+    /// pending_txn_json: pending transaction json (see `indy_prepare_request_for_endorser`).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+    extern indy_error_t indy_endorse_pending_transaction(indy_handle_t command_handle,
+                                                         indy_handle_t pool_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  endorser_did,
+                                                         const char *  pending_txn_json,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_result_json)
+                                                         );
+
 #ifdef __cplusplus
 }
 #endif
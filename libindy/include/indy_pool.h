@@ -43,6 +43,78 @@ extern "C" {
                                                   indy_u64_t    protocol_version,
                                                   void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                   );
+
+    extern indy_error_t indy_get_local_txn(indy_handle_t command_handle,
+                                           const char *  config_name,
+                                           indy_u64_t    ledger_id,
+                                           indy_u64_t    seq_no,
+                                           void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char* txn_json)
+                                           );
+
+    extern indy_error_t indy_get_local_txns_by_type(indy_handle_t command_handle,
+                                                    const char *  config_name,
+                                                    indy_u64_t    ledger_id,
+                                                    const char *  txn_type,
+                                                    indy_u64_t    from_seq_no,
+                                                    indy_u64_t    count,
+                                                    void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char* txns_json)
+                                                    );
+
+    extern indy_error_t indy_diagnose_pool(indy_handle_t command_handle,
+                                           const char *  config_name,
+                                           const char *  config,
+                                           void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char* diagnostics_json)
+                                           );
+
+    /// Register custom pool transport that delivers messages to the pool nodes instead of ZMQ connections.
+    ///
+    /// Pool opened with "transport" config value equal to registered name sends messages through the transport handlers.
+    /// Libindy still verifies state proofs, BLS multi-signatures and catchup replies received through the transport.
+    ///
+    /// send and recv handlers must not block. recv sets node_alias_p and msg_p to NULL if nothing is received.
+    /// Buffers returned by recv handler are identified by handle and released by libindy with free_buffer handler.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// transport_name: Name of the transport. Must not be equal to built-in "zmq" and "simulated" transports.
+    /// open: "open" operation handler. Called for each opened pool.
+    /// send: "send" operation handler. Receives alias and address of the node from the pool ledger.
+    /// recv: "recv" operation handler. Returns single received message and alias of the node sent it.
+    /// free_buffer: Handler that allows to de-allocate buffers allocated in transport code
+    /// close: "close" operation handler. Called when the pool is closed.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_register_pool_transport(indy_handle_t  command_handle,
+                                                     const char*    transport_name,
+
+                                                     indy_error_t (*openFn)(const char*    pool_name,
+                                                                            indy_handle_t* transport_handle_p),
+
+                                                     indy_error_t (*sendFn)(indy_handle_t  transport_handle,
+                                                                            const char*    node_alias,
+                                                                            const char*    node_address,
+                                                                            const char*    msg),
+
+                                                     indy_error_t (*recvFn)(indy_handle_t  transport_handle,
+                                                                            const char**   node_alias_p,
+                                                                            const char**   msg_p,
+                                                                            indy_handle_t* buffer_handle_p),
+
+                                                     indy_error_t (*freeBufferFn)(indy_handle_t buffer_handle),
+
+                                                     indy_error_t (*closeFn)(indy_handle_t transport_handle),
+
+                                                     void         (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                     );
 #ifdef __cplusplus
 }
 #endif
@@ -144,6 +144,30 @@ extern "C" {
                                                   void         (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                                   );
 
+    /// Register transaction handlers for custom wallet storage implementation.
+    ///
+    /// Storage type must be registered with indy_register_wallet_storage call before.
+    /// Without registered transaction handlers wallets of this storage type don't support
+    /// indy_wallet_transaction_* calls and multi-record updates are applied without atomicity.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: Storage type name.
+    /// begin_transaction: WalletType begin transaction operation handler
+    /// commit_transaction: WalletType commit transaction operation handler
+    /// rollback_transaction: WalletType rollback transaction operation handler
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_register_wallet_storage_transactions(indy_handle_t  command_handle,
+                                                                  const char*    type_,
+                                                                  indy_error_t (*beginTransactionFn)(indy_handle_t handle),
+                                                                  indy_error_t (*commitTransactionFn)(indy_handle_t handle),
+                                                                  indy_error_t (*rollbackTransactionFn)(indy_handle_t handle),
+                                                                  void         (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                                  );
+
     /// Create a new secure wallet.
     ///
     /// #Params
@@ -246,6 +270,38 @@ extern "C" {
                                          void           (*fn)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t handle)
                                         );
 
+    /// Rotates master key of opened wallet without closing it.
+    ///
+    /// Wallet keys are re-encrypted with the new master key and stored in wallet metadata
+    /// with a single storage update, so an interrupted rotation leaves the old key valid.
+    /// All opened handles to the wallet keep working after rotation.
+    ///
+    /// #Params:
+    /// wallet_handle: wallet handle returned by indy_open_wallet
+    /// rotate_key_config: JSON containing new wallet key settings.
+    ///   {
+    ///     "key": <string>, New key or passphrase used for wallet key derivation.
+    ///                     Look to key_derivation_method param for information about supported key derivation methods.
+    ///     "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
+    ///                              ARGON2I_MOD - derive secured wallet master key (used by default)
+    ///                              ARGON2I_INT - derive secured wallet master key (less secured but faster)
+    ///                              RAW - raw wallet master key provided (skip derivation).
+    ///                                RAW keys can be generated with indy_generate_wallet_key call
+    ///   }
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_rotate_wallet_key(indy_handle_t  command_handle,
+                                               indy_handle_t  wallet_handle,
+                                               const char*    rotate_key_config,
+                                               void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                              );
+
     /// Exports opened wallet
     ///
     /// #Params:
@@ -255,11 +311,24 @@ extern "C" {
     ///     "path": <string>, Path of the file that contains exported wallet content
     ///     "key": <string>, Key or passphrase used for wallet export key derivation.
     ///                     Look to key_derivation_method param for information about supported key derivation methods.
+    ///                     Required for "encrypted" format.
     ///     "key_derivation_method": optional<string> Algorithm to use for export key derivation:
     ///                              ARGON2I_MOD - derive secured export key (used by default)
     ///                              ARGON2I_INT - derive secured export key (less secured but faster)
     ///                              RAW - raw export key provided (skip derivation).
     ///                                RAW keys can be generated with indy_generate_wallet_key call
+    ///     "format": optional<string> Format of exported file:
+    ///               encrypted - encrypted stream that can be imported with indy_import_wallet (used by default)
+    ///               plaintext_jsonl - DECRYPTED records as newline-delimited json, one record per line:
+    ///                 {"type": string, "id": string, "value": string, "tags": {"tagName": string, ...}}
+    ///                 Records are written as they are fetched from the storage.
+    ///     "acknowledge_plaintext_export": optional<bool> Must be set to true for plaintext_jsonl format
+    ///                                     to confirm that exported file will contain all wallet secrets unencrypted.
+    ///     "record_type": optional<string> Export only records of this type (plaintext_jsonl format only).
+    ///     "query": optional<object> WQL query to filter records of record_type (plaintext_jsonl format only).
+    ///     "offset": optional<int> Number of matched records to skip before export (plaintext_jsonl format only).
+    ///     "limit": optional<int> Max number of records to export (plaintext_jsonl format only).
+    ///               Records are exported in the storage order, so pages are consistent while wallet isn't modified.
     ///   }
     ///
     /// #Returns
@@ -313,7 +382,11 @@ extern "C" {
     /// import_config: Import settings json.
     /// {
     ///   "path": <string>, path of the file that contains exported wallet content
-    ///   "key": <string>, key used for export of the wallet
+    ///   "key": <string>, key used for export of the wallet. Required for "encrypted" format.
+    ///   "format": optional<string> Format of the file to import:
+    ///             encrypted - file created by indy_export_wallet with "encrypted" format (used by default)
+    ///             plaintext_jsonl - newline-delimited json records created by indy_export_wallet
+    ///                               with "plaintext_jsonl" format or by other agent frameworks
     /// }
     ///
     /// #Returns
@@ -347,6 +420,63 @@ extern "C" {
                                           void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                          );
 
+    /// Starts a transaction on opened wallet.
+    ///
+    /// All wallet changes made after this call (including changes made by DID, anoncreds
+    /// and non-secrets calls) are applied atomically by indy_wallet_transaction_commit
+    /// or discarded by indy_wallet_transaction_rollback. Nested transactions are not supported.
+    /// Transaction that is still active on indy_close_wallet call is rolled back.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handle returned by indy_open_wallet.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_wallet_transaction_begin(indy_handle_t  command_handle,
+                                                      indy_handle_t  wallet_handle,
+                                                      void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                     );
+
+    /// Commits the transaction started by indy_wallet_transaction_begin.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handle returned by indy_open_wallet.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_wallet_transaction_commit(indy_handle_t  command_handle,
+                                                       indy_handle_t  wallet_handle,
+                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                      );
+
+    /// Rolls back the transaction started by indy_wallet_transaction_begin.
+    /// All wallet changes made within the transaction are discarded.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handle returned by indy_open_wallet.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_wallet_transaction_rollback(indy_handle_t  command_handle,
+                                                         indy_handle_t  wallet_handle,
+                                                         void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                        );
+
     /// Deletes created wallet.
     ///
     /// #Params
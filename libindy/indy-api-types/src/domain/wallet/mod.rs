@@ -33,12 +33,49 @@ fn default_key_derivation_method() -> KeyDerivationMethod {
     KeyDerivationMethod::ARGON2I_MOD
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ExportFormat {
+    // Encrypted msgpack stream that can be imported back by indy_import_wallet
+    #[serde(rename = "encrypted")]
+    Encrypted,
+    // Decrypted records as newline-delimited JSON (one Record per line)
+    #[serde(rename = "plaintext_jsonl")]
+    PlaintextJsonl,
+}
+
+impl Default for ExportFormat {
+    fn default() -> Self {
+        ExportFormat::Encrypted
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ExportConfig {
-    pub key: String,
+    pub key: Option<String>,
     pub path: String,
     #[serde(default = "default_key_derivation_method")]
-    pub key_derivation_method: KeyDerivationMethod
+    pub key_derivation_method: KeyDerivationMethod,
+    #[serde(default)]
+    pub format: ExportFormat,
+    #[serde(default)]
+    pub acknowledge_plaintext_export: bool,
+    pub record_type: Option<String>,
+    pub query: Option<Value>,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl ExportConfig {
+    pub fn key(&self) -> &str {
+        self.key.as_ref().map(String::as_str).unwrap_or("")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RotateKeyConfig {
+    pub key: String,
+    #[serde(default = "default_key_derivation_method")]
+    pub key_derivation_method: KeyDerivationMethod,
 }
 
 #[derive(Debug, Deserialize)]
@@ -70,3 +107,33 @@ impl Validatable for Config {
     }
 }
 
+impl Validatable for ExportConfig {
+    fn validate(&self) -> Result<(), String> {
+        match self.format {
+            ExportFormat::Encrypted => {
+                if self.key.is_none() {
+                    return Err("Export key is required for encrypted format".to_string());
+                }
+
+                if self.record_type.is_some() || self.query.is_some() {
+                    return Err("Records filtering is supported only for plaintext_jsonl format".to_string());
+                }
+
+                if self.offset.is_some() || self.limit.is_some() {
+                    return Err("Records pagination is supported only for plaintext_jsonl format".to_string());
+                }
+            }
+            ExportFormat::PlaintextJsonl => {
+                if !self.acknowledge_plaintext_export {
+                    return Err("Plaintext export must be acknowledged by \"acknowledge_plaintext_export\": true".to_string());
+                }
+
+                if self.query.is_some() && self.record_type.is_none() {
+                    return Err("Records query requires record_type to be set".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
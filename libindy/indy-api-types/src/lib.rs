@@ -586,4 +586,23 @@ pub mod wallet {
     pub type WalletFreeSearch = extern fn(storage_handle: StorageHandle,
                                           search_handle: i32) -> ErrorCode;
 
+    /// Begin a storage transaction. All record and metadata modifications
+    /// made until commit or rollback handler call must be applied atomically.
+    ///
+    /// #Params
+    /// storage_handle: opened storage handle (See open handler)
+    pub type WalletBeginTransaction = extern fn(storage_handle: StorageHandle) -> ErrorCode;
+
+    /// Commit the storage transaction started by begin transaction handler
+    ///
+    /// #Params
+    /// storage_handle: opened storage handle (See open handler)
+    pub type WalletCommitTransaction = extern fn(storage_handle: StorageHandle) -> ErrorCode;
+
+    /// Discard all modifications made since begin transaction handler call
+    ///
+    /// #Params
+    /// storage_handle: opened storage handle (See open handler)
+    pub type WalletRollbackTransaction = extern fn(storage_handle: StorageHandle) -> ErrorCode;
+
 }
\ No newline at end of file
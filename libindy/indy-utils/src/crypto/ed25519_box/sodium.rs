@@ -2,6 +2,7 @@ extern crate sodiumoxide;
 
 use indy_api_types::errors::prelude::*;
 use self::sodiumoxide::crypto::box_;
+use self::sodiumoxide::crypto::scalarmult::curve25519;
 
 
 pub const NONCEBYTES: usize = box_::curve25519xsalsa20poly1305::NONCEBYTES;
@@ -35,6 +36,23 @@ pub fn gen_nonce() -> Nonce {
     Nonce(box_::gen_nonce())
 }
 
+pub fn create_key_pair() -> (PublicKey, SecretKey) {
+    let (pk, sk) = box_::gen_keypair();
+    (PublicKey(pk), SecretKey(sk))
+}
+
+pub fn scalarmult(secret_key: &SecretKey, public_key: &PublicKey) -> Result<Vec<u8>, IndyError> {
+    let scalar = curve25519::Scalar::from_slice(&secret_key[..])
+        .ok_or_else(|| IndyError::from_msg(IndyErrorKind::InvalidStructure, "Invalid X25519 secret key"))?;
+    let group_element = curve25519::GroupElement::from_slice(&public_key[..])
+        .ok_or_else(|| IndyError::from_msg(IndyErrorKind::InvalidStructure, "Invalid X25519 public key"))?;
+
+    let shared_secret = curve25519::scalarmult(&scalar, &group_element)
+        .map_err(|_| IndyError::from_msg(IndyErrorKind::InvalidStructure, "Unable to compute X25519 shared secret"))?;
+
+    Ok(shared_secret[..].to_vec())
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -66,4 +84,16 @@ mod tests {
         assert!(alice_decrypted_text.is_ok());
         assert_eq!(text, alice_decrypted_text.unwrap());
     }
+
+    #[test]
+    fn scalarmult_works() {
+        let (alice_pk, alice_sk) = create_key_pair();
+        let (bob_pk, bob_sk) = create_key_pair();
+
+        let alice_shared_secret = scalarmult(&alice_sk, &bob_pk).unwrap();
+        let bob_shared_secret = scalarmult(&bob_sk, &alice_pk).unwrap();
+
+        assert_eq!(32, alice_shared_secret.len());
+        assert_eq!(alice_shared_secret, bob_shared_secret);
+    }
 }
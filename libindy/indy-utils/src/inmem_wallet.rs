@@ -92,6 +92,10 @@ lazy_static! {
     static ref ACTIVE_SEARCHES: Mutex<HashMap<i32, Vec<InmemWalletRecord>,>> = Default::default();
 }
 
+lazy_static! {
+    static ref ACTIVE_TRANSACTIONS: Mutex<HashMap<i32, InmemWalletEntity>> = Default::default();
+}
+
 pub struct InmemWallet {}
 
 impl InmemWallet {
@@ -727,6 +731,67 @@ impl InmemWallet {
         ErrorCode::Success
     }
 
+    pub extern "C" fn begin_transaction(xhandle: i32) -> ErrorCode {
+        let handles = INMEM_OPEN_WALLETS.lock().unwrap();
+
+        let wallet_context = match handles.get(&xhandle) {
+            Some(wallet_context) => wallet_context,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let wallets = INMEM_WALLETS.lock().unwrap();
+
+        let wallet = match wallets.get(&wallet_context.id) {
+            Some(wallet) => wallet,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let mut transactions = ACTIVE_TRANSACTIONS.lock().unwrap();
+
+        if transactions.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        transactions.insert(xhandle, wallet.clone());
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn commit_transaction(xhandle: i32) -> ErrorCode {
+        let handles = INMEM_OPEN_WALLETS.lock().unwrap();
+
+        if !handles.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let mut transactions = ACTIVE_TRANSACTIONS.lock().unwrap();
+
+        match transactions.remove(&xhandle) {
+            Some(_) => ErrorCode::Success,
+            None => ErrorCode::CommonInvalidState
+        }
+    }
+
+    pub extern "C" fn rollback_transaction(xhandle: i32) -> ErrorCode {
+        let handles = INMEM_OPEN_WALLETS.lock().unwrap();
+
+        let wallet_context = match handles.get(&xhandle) {
+            Some(wallet_context) => wallet_context,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let mut transactions = ACTIVE_TRANSACTIONS.lock().unwrap();
+
+        let snapshot = match transactions.remove(&xhandle) {
+            Some(snapshot) => snapshot,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let mut wallets = INMEM_WALLETS.lock().unwrap();
+        wallets.insert(wallet_context.id.clone(), snapshot);
+
+        ErrorCode::Success
+    }
+
     pub extern "C" fn close(xhandle: i32) -> ErrorCode {
         let mut handles = INMEM_OPEN_WALLETS.lock().unwrap();
 
@@ -735,6 +800,7 @@ impl InmemWallet {
         }
 
         handles.remove(&xhandle);
+        ACTIVE_TRANSACTIONS.lock().unwrap().remove(&xhandle);
         ErrorCode::Success
     }
 
@@ -759,5 +825,8 @@ impl InmemWallet {
 
         let mut handles = INMEM_OPEN_WALLETS.lock().unwrap();
         handles.clear();
+
+        let mut transactions = ACTIVE_TRANSACTIONS.lock().unwrap();
+        transactions.clear();
     }
 }
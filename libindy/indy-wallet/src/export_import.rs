@@ -1,9 +1,10 @@
 use std::io;
-use std::io::{BufReader, BufWriter, Read, Write};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use rmp_serde;
+use serde_json;
 
 use indy_api_types::domain::wallet::Record;
 use indy_api_types::domain::wallet::KeyDerivationMethod;
@@ -12,7 +13,7 @@ use crate::encryption::KeyDerivationData;
 use indy_utils::crypto::{chacha20poly1305_ietf, pwhash_argon2i13};
 use indy_utils::crypto::hash::{hash, HASHBYTES};
 
-use super::{Wallet, WalletRecord};
+use super::{SearchOptions, Wallet, WalletRecord};
 
 const CHUNK_SIZE: usize = 1024;
 
@@ -134,6 +135,75 @@ pub(super) fn export_continue(wallet: &Wallet, writer: &mut dyn Write, version:
     Ok(())
 }
 
+// Streams decrypted records as newline-delimited JSON. Each line is a serialized Record:
+//
+// {"type": .., "id": .., "value": .., "tags": {..}}
+//
+// Records are fetched from the storage iterator one by one, so export doesn't keep
+// whole wallet in memory. If record_type is set only records of this type matched by
+// WQL query are exported.
+//
+// Storages have no pagination options, so first offset records are skipped while iterating
+// and iteration stops after limit records are exported.
+pub(super) fn export_plaintext(wallet: &Wallet, writer: &mut dyn Write, record_type: Option<&str>, query: Option<&str>, offset: usize, limit: Option<usize>) -> IndyResult<()> {
+    let mut writer = BufWriter::new(writer);
+
+    let mut records = match record_type {
+        Some(record_type) => wallet.search(record_type, query.unwrap_or("{}"), Some(&SearchOptions::full()))?,
+        None => wallet.get_all()?
+    };
+
+    for _ in 0..offset {
+        if records.next()?.is_none() {
+            break;
+        }
+    }
+
+    let mut exported = 0;
+
+    while limit.map_or(true, |limit| exported < limit) {
+        let WalletRecord { type_, id, value, tags } = match records.next()? {
+            Some(record) => record,
+            None => break
+        };
+
+        let record = Record {
+            type_: type_
+                .or_else(|| record_type.map(String::from))
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No type fetched for exported record"))?,
+            id,
+            value: value.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value fetched for exported record"))?,
+            tags: tags.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No tags fetched for exported record"))?,
+        };
+
+        serde_json::to_writer(&mut writer, &record)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize record")?;
+        writer.write_all(b"\n")?;
+
+        exported += 1;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+pub(super) fn finish_plaintext_import<T>(wallet: &Wallet, reader: BufReader<T>) -> IndyResult<()> where T: Read {
+    for line in reader.lines() {
+        let line = line.map_err(_map_io_err)?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record: Record = serde_json::from_str(&line)
+            .to_indy(IndyErrorKind::InvalidStructure, "Record is malformed json")?;
+
+        wallet.add(&record.type_, &record.id, &record.value, &record.tags)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 fn import<T>(wallet: &Wallet, reader: T, passphrase: &str) -> IndyResult<()> where T: Read {
     let (reader, import_key_derivation_data, nonce, chunk_size, header_bytes) = preparse_file_to_import(reader, passphrase)?;
@@ -460,6 +530,94 @@ mod tests {
         _cleanup("import_works_for_data_extended2");
     }
 
+    #[test]
+    fn export_import_plaintext_works_for_2_items() {
+        _cleanup("export_import_plaintext_works_for_2_items1");
+        _cleanup("export_import_plaintext_works_for_2_items2");
+        {
+            let mut output: Vec<u8> = Vec::new();
+            export_plaintext(&_add_2_records(_wallet("export_import_plaintext_works_for_2_items1")), &mut output, None, None, 0, None).unwrap();
+
+            let lines: Vec<serde_json::Value> = output.split(|b| *b == b'\n')
+                .filter(|line| !line.is_empty())
+                .map(|line| serde_json::from_slice(line).unwrap())
+                .collect();
+            assert_eq!(2, lines.len());
+            assert!(lines.contains(&json!({"type": _type1(), "id": _id1(), "value": _value1(), "tags": _tags1()})));
+
+            let wallet = _wallet("export_import_plaintext_works_for_2_items2");
+            _assert_is_empty(&wallet);
+
+            finish_plaintext_import(&wallet, BufReader::new(output.as_slice())).unwrap();
+            _assert_has_2_records(&wallet);
+        }
+        _cleanup("export_import_plaintext_works_for_2_items1");
+        _cleanup("export_import_plaintext_works_for_2_items2");
+    }
+
+    #[test]
+    fn export_plaintext_works_for_record_type_and_query() {
+        _cleanup("export_plaintext_works_for_record_type_and_query");
+        {
+            let wallet = _add_300_records(_wallet("export_plaintext_works_for_record_type_and_query"));
+
+            let mut output: Vec<u8> = Vec::new();
+            export_plaintext(&wallet, &mut output, Some(&_type(1)), None, 0, None).unwrap();
+            assert_eq!(100, output.split(|b| *b == b'\n').filter(|line| !line.is_empty()).count());
+
+            let query = json!({"~tag_id_4_3": "tag_value_4_3"}).to_string();
+
+            let mut output: Vec<u8> = Vec::new();
+            export_plaintext(&wallet, &mut output, Some(&_type(1)), Some(&query), 0, None).unwrap();
+
+            let record: serde_json::Value = serde_json::from_slice(&output[..output.len() - 1]).unwrap();
+            assert_eq!(json!({"type": _type(1), "id": _id(4), "value": _value(4), "tags": _tags(4)}), record);
+        }
+        _cleanup("export_plaintext_works_for_record_type_and_query");
+    }
+
+    #[test]
+    fn export_plaintext_works_for_offset_and_limit() {
+        _cleanup("export_plaintext_works_for_offset_and_limit");
+        {
+            let wallet = _add_300_records(_wallet("export_plaintext_works_for_offset_and_limit"));
+
+            let export_page = |offset: usize, limit: Option<usize>| -> Vec<serde_json::Value> {
+                let mut output: Vec<u8> = Vec::new();
+                export_plaintext(&wallet, &mut output, Some(&_type(1)), None, offset, limit).unwrap();
+                output.split(|b| *b == b'\n')
+                    .filter(|line| !line.is_empty())
+                    .map(|line| serde_json::from_slice(line).unwrap())
+                    .collect()
+            };
+
+            let all = export_page(0, None);
+            assert_eq!(100, all.len());
+
+            let first_page = export_page(0, Some(40));
+            let second_page = export_page(40, Some(40));
+            let last_page = export_page(80, Some(40));
+
+            assert_eq!(all[..40].to_vec(), first_page);
+            assert_eq!(all[40..80].to_vec(), second_page);
+            assert_eq!(all[80..].to_vec(), last_page);
+            assert!(export_page(100, Some(40)).is_empty());
+        }
+        _cleanup("export_plaintext_works_for_offset_and_limit");
+    }
+
+    #[test]
+    fn import_plaintext_works_for_malformed_line() {
+        _cleanup("import_plaintext_works_for_malformed_line");
+
+        let input = r#"{"type": "type_1", "id": "id_1", "value": "id_1", "tags": {}}
+{"type": "type_1", "id": "#;
+
+        let res = finish_plaintext_import(&_wallet("import_plaintext_works_for_malformed_line"), BufReader::new(input.as_bytes()));
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        _cleanup("import_plaintext_works_for_malformed_line");
+    }
+
     fn _cleanup(name: &str) {
         test::cleanup_storage(name)
     }
@@ -22,16 +22,16 @@ use serde_json::Value as SValue;
 
 use indy_api_types::wallet::*;
 
-use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, Tags};
+use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, ExportFormat, RotateKeyConfig, Tags};
 use indy_api_types::errors::prelude::*;
 pub use crate::encryption::KeyDerivationData;
 use indy_utils::crypto::chacha20poly1305_ietf;
 use indy_utils::crypto::chacha20poly1305_ietf::Key as MasterKey;
 
-use self::export_import::{export_continue, finish_import, preparse_file_to_import};
+use self::export_import::{export_continue, export_plaintext, finish_import, finish_plaintext_import, preparse_file_to_import};
 use self::storage::{WalletStorage, WalletStorageType};
 use self::storage::default::SQLiteStorageType;
-use self::storage::plugged::PluggedStorageType;
+use self::storage::plugged::{PluggedStorageType, PluggedTransactionHandlers};
 use self::wallet::{Keys, Wallet};
 use indy_api_types::{WalletHandle};
 
@@ -50,6 +50,7 @@ pub struct WalletService {
     wallet_ids: RefCell<HashSet<String>>,
     pending_for_open: RefCell<HashMap<WalletHandle, (String /* id */, Box<dyn WalletStorage>, Metadata, Option<KeyDerivationData>)>>,
     pending_for_import: RefCell<HashMap<WalletHandle, (BufReader<::std::fs::File>, chacha20poly1305_ietf::Nonce, usize, Vec<u8>, KeyDerivationData)>>,
+    pending_for_plaintext_import: RefCell<HashMap<WalletHandle, (BufReader<::std::fs::File>, KeyDerivationData)>>,
 }
 
 impl WalletService {
@@ -66,6 +67,7 @@ impl WalletService {
             wallet_ids: RefCell::new(HashSet::new()),
             pending_for_open: RefCell::new(HashMap::new()),
             pending_for_import: RefCell::new(HashMap::new()),
+            pending_for_plaintext_import: RefCell::new(HashMap::new()),
         }
     }
 
@@ -119,6 +121,29 @@ impl WalletService {
         Ok(())
     }
 
+    pub fn register_wallet_storage_transactions(&self,
+                                                type_: &str,
+                                                begin_transaction: WalletBeginTransaction,
+                                                commit_transaction: WalletCommitTransaction,
+                                                rollback_transaction: WalletRollbackTransaction) -> IndyResult<()> {
+        trace!("register_wallet_storage_transactions >>> type_: {:?}", type_);
+
+        let storage_types = self.storage_types.borrow();
+
+        let storage_type = storage_types
+            .get(type_)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownWalletStorageType, format!("Wallet storage is not registered for type: {}", type_)))?;
+
+        storage_type.set_transaction_handlers(PluggedTransactionHandlers {
+            begin_handler: begin_transaction,
+            commit_handler: commit_transaction,
+            rollback_handler: rollback_transaction,
+        })?;
+
+        trace!("register_wallet_storage_transactions <<<");
+        Ok(())
+    }
+
     pub fn create_wallet(&self,
                          config: &Config,
                          credentials: &Credentials,
@@ -256,6 +281,40 @@ impl WalletService {
         Ok(())
     }
 
+    pub fn rotate_wallet_key_prepare(&self, wallet_handle: WalletHandle, rotate_config: &RotateKeyConfig) -> IndyResult<KeyDerivationData> {
+        trace!("rotate_wallet_key_prepare >>> wallet_handle: {:?}, rotate_config: {:?}", wallet_handle, secret!(rotate_config));
+
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) if wallet.is_transaction_started() =>
+                return Err(err_msg(IndyErrorKind::InvalidState, "Wallet key can't be rotated while transaction is in progress")),
+            Some(_) => (),
+            None => return Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        };
+
+        let key_data = KeyDerivationData::from_passphrase_with_new_salt(&rotate_config.key, &rotate_config.key_derivation_method);
+
+        trace!("rotate_wallet_key_prepare <<<");
+        Ok(key_data)
+    }
+
+    pub fn rotate_wallet_key_continue(&self, wallet_handle: WalletHandle, key: (&KeyDerivationData, &MasterKey)) -> IndyResult<()> {
+        trace!("rotate_wallet_key_continue >>> wallet_handle: {:?}", wallet_handle);
+
+        let (key_data, master_key) = key;
+
+        let wallets = self.wallets.borrow();
+        let wallet = wallets
+            .get(&wallet_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))?;
+
+        // Only the master key changes, records stay encrypted with the same Keys
+        let metadata = self._prepare_metadata(master_key, key_data, wallet.get_keys())?;
+        wallet.set_master_key_metadata(&metadata)?;
+
+        trace!("rotate_wallet_key_continue <<<");
+        Ok(())
+    }
+
     fn _map_wallet_storage_error(err: IndyError, type_: &str, name: &str) -> IndyError {
         match err.kind() {
             IndyErrorKind::WalletItemAlreadyExists => err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Wallet item already exists with type: {}, id: {}", type_, name)),
@@ -429,6 +488,63 @@ impl WalletService {
         }
     }
 
+    pub fn begin_transaction(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => wallet.begin_transaction(),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        }
+    }
+
+    pub fn commit_transaction(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => wallet.commit_transaction(),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        }
+    }
+
+    pub fn rollback_transaction(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => wallet.rollback_transaction(),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        }
+    }
+
+    pub fn is_transaction_started(&self, wallet_handle: WalletHandle) -> IndyResult<bool> {
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => Ok(wallet.is_transaction_started()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        }
+    }
+
+    // Runs f atomically if wallet storage supports transactions. Joins the transaction
+    // started by the caller if any. Storages without transactions support (plugged storages
+    // without registered transaction handlers) keep previous non-atomic behaviour.
+    pub fn with_transaction<T, F>(&self, wallet_handle: WalletHandle, f: F) -> IndyResult<T>
+        where F: FnOnce() -> IndyResult<T> {
+        let (supports_transactions, transaction_started) = match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => (wallet.supports_transactions(), wallet.is_transaction_started()),
+            None => return Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        };
+
+        if !supports_transactions || transaction_started {
+            return f();
+        }
+
+        self.begin_transaction(wallet_handle)?;
+
+        match f() {
+            Ok(res) => {
+                self.commit_transaction(wallet_handle)?;
+                Ok(res)
+            }
+            Err(err) => {
+                self.rollback_transaction(wallet_handle)
+                    .unwrap_or_else(|rollback_err| warn!("Unable to rollback wallet transaction: {:?}", rollback_err));
+                Err(err)
+            }
+        }
+    }
+
     pub fn check(&self, handle: WalletHandle) -> IndyResult<()> {
         match self.wallets.borrow().get(&handle) {
             Some(_) => Ok(()),
@@ -450,6 +566,48 @@ impl WalletService {
             .get(&wallet_handle)
             .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))?;
 
+        let mut export_file = WalletService::_create_export_file(export_config)?;
+
+        let res = export_continue(wallet, &mut export_file, version, key.clone(), key_data);
+
+        trace!("export_wallet <<<");
+
+        res
+    }
+
+    pub fn export_wallet_plaintext(&self, wallet_handle: WalletHandle, export_config: &ExportConfig) -> IndyResult<()> {
+        trace!("export_wallet_plaintext >>> wallet_handle: {:?}, export_config: {:?}", wallet_handle, secret!(export_config));
+
+        if export_config.format != ExportFormat::PlaintextJsonl || !export_config.acknowledge_plaintext_export {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Plaintext export must be explicitly acknowledged"));
+        }
+
+        let query = export_config.query
+            .as_ref()
+            .map(|query| serde_json::to_string(query))
+            .map_or(Ok(None), |v| v.map(Some))
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot serialize records query")?;
+
+        let wallets = self.wallets.borrow();
+        let wallet = wallets
+            .get(&wallet_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))?;
+
+        let mut export_file = WalletService::_create_export_file(export_config)?;
+
+        let res = export_plaintext(wallet,
+                                   &mut export_file,
+                                   export_config.record_type.as_ref().map(String::as_str),
+                                   query.as_ref().map(String::as_str),
+                                   export_config.offset.unwrap_or(0),
+                                   export_config.limit);
+
+        trace!("export_wallet_plaintext <<<");
+
+        res
+    }
+
+    fn _create_export_file(export_config: &ExportConfig) -> IndyResult<fs::File> {
         let path = PathBuf::from(&export_config.path);
 
         if let Some(parent_path) = path.parent() {
@@ -458,17 +616,13 @@ impl WalletService {
                 .create(parent_path)?;
         }
 
-        let mut export_file =
+        let export_file =
             fs::OpenOptions::new()
                 .write(true)
                 .create_new(true)
                 .open(export_config.path.clone())?;
 
-        let res = export_continue(wallet, &mut export_file, version, key.clone(), key_data);
-
-        trace!("export_wallet <<<");
-
-        res
+        Ok(export_file)
     }
 
     pub fn import_wallet_prepare(&self,
@@ -482,7 +636,7 @@ impl WalletService {
                 .read(true)
                 .open(&export_config.path)?;
 
-        let (reader, import_key_derivation_data, nonce, chunk_size, header_bytes) = preparse_file_to_import(exported_file_to_import, &export_config.key)?;
+        let (reader, import_key_derivation_data, nonce, chunk_size, header_bytes) = preparse_file_to_import(exported_file_to_import, export_config.key())?;
         let key_data = KeyDerivationData::from_passphrase_with_new_salt(&credentials.key, &credentials.key_derivation_method);
 
         let wallet_handle = indy_utils::next_wallet_handle();
@@ -499,7 +653,50 @@ impl WalletService {
 
         let (import_key, master_key) = key;
 
-        let keys = self._create_wallet(config, credentials, (&key_data, &master_key))?;
+        let res = self._import_into_new_wallet(config, credentials, (&key_data, &master_key), |wallet| {
+            finish_import(wallet, reader, import_key, nonce, chunk_size, header_bytes)
+        });
+
+        trace!("import_wallet <<<");
+        res
+    }
+
+    pub fn import_wallet_plaintext_prepare(&self,
+                                           config: &Config,
+                                           credentials: &Credentials,
+                                           import_config: &ExportConfig) -> IndyResult<(WalletHandle, KeyDerivationData)> {
+        trace!("import_wallet_plaintext_prepare >>> config: {:?}, credentials: {:?}, import_config: {:?}", config, secret!(credentials), secret!(import_config));
+
+        let file_to_import =
+            fs::OpenOptions::new()
+                .read(true)
+                .open(&import_config.path)?;
+
+        let key_data = KeyDerivationData::from_passphrase_with_new_salt(&credentials.key, &credentials.key_derivation_method);
+
+        let wallet_handle = indy_utils::next_wallet_handle();
+
+        self.pending_for_plaintext_import.borrow_mut().insert(wallet_handle, (BufReader::new(file_to_import), key_data.clone()));
+
+        Ok((wallet_handle, key_data))
+    }
+
+    pub fn import_wallet_plaintext_continue(&self, wallet_handle: WalletHandle, config: &Config, credentials: &Credentials, master_key: MasterKey) -> IndyResult<()> {
+        let (reader, key_data) = self.pending_for_plaintext_import.borrow_mut().remove(&wallet_handle).unwrap();
+
+        let res = self._import_into_new_wallet(config, credentials, (&key_data, &master_key), |wallet| {
+            finish_plaintext_import(wallet, reader)
+        });
+
+        trace!("import_wallet_plaintext <<<");
+        res
+    }
+
+    fn _import_into_new_wallet<F>(&self, config: &Config, credentials: &Credentials, key: (&KeyDerivationData, &MasterKey), import: F) -> IndyResult<()>
+        where F: FnOnce(&Wallet) -> IndyResult<()> {
+        let (key_data, master_key) = key;
+
+        let keys = self._create_wallet(config, credentials, (key_data, master_key))?;
 
         self._is_id_from_config_not_used(config)?;
         let storage = self._open_storage(config, credentials)?;
@@ -508,19 +705,16 @@ impl WalletService {
         let res = {
             let wallet = Wallet::new(WalletService::_get_wallet_id(&config), storage, Rc::new(keys));
 
-            finish_import(&wallet, reader, import_key, nonce, chunk_size, header_bytes)
+            import(&wallet)
         };
 
         if res.is_err() {
             let metadata: Metadata = serde_json::from_slice(&metadata)
                 .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize metadata")?;
 
-            self.delete_wallet_continue(config, credentials, &metadata, &master_key)?;
+            self.delete_wallet_continue(config, credentials, &metadata, master_key)?;
         }
 
-        //        self.close_wallet(wallet_handle)?;
-
-        trace!("import_wallet <<<");
         res
     }
 
@@ -533,7 +727,7 @@ impl WalletService {
     }
 
     pub fn get_pending_for_import_count(&self) -> usize {
-        self.pending_for_import.borrow().len()
+        self.pending_for_import.borrow().len() + self.pending_for_plaintext_import.borrow().len()
     }
 
     pub fn get_pending_for_open_count(&self) -> usize {
@@ -777,6 +971,18 @@ pub struct SearchOptions {
 }
 
 impl SearchOptions {
+    pub fn full() -> String {
+        let options = SearchOptions {
+            retrieve_records: true,
+            retrieve_total_count: false,
+            retrieve_type: true,
+            retrieve_value: true,
+            retrieve_tags: true,
+        };
+
+        serde_json::to_string(&options).unwrap()
+    }
+
     pub fn id_value() -> String {
         let options = SearchOptions {
             retrieve_records: true,
@@ -857,6 +1063,12 @@ mod tests {
             self.open_wallet_continue(wallet_handle, (&key, rekey.as_ref()))
         }
 
+        fn rotate_wallet_key(&self, wallet_handle: WalletHandle, rotate_config: &RotateKeyConfig) -> IndyResult<()> {
+            let key_data = self.rotate_wallet_key_prepare(wallet_handle, rotate_config)?;
+            let key = key_data.calc_master_key()?;
+            self.rotate_wallet_key_continue(wallet_handle, (&key_data, &key))
+        }
+
         pub fn import_wallet(&self,
                              config: &Config,
                              credentials: &Credentials,
@@ -868,7 +1080,7 @@ mod tests {
                     .read(true)
                     .open(&export_config.path)?;
 
-            let (reader, import_key_derivation_data, nonce, chunk_size, header_bytes) = preparse_file_to_import(exported_file_to_import, &export_config.key)?;
+            let (reader, import_key_derivation_data, nonce, chunk_size, header_bytes) = preparse_file_to_import(exported_file_to_import, export_config.key())?;
             let key_data = KeyDerivationData::from_passphrase_with_new_salt(&credentials.key, &credentials.key_derivation_method);
 
             let wallet_handle = next_wallet_handle();
@@ -881,6 +1093,16 @@ mod tests {
             self.import_wallet_continue(wallet_handle, config, credentials, (import_key, master_key))
         }
 
+        pub fn import_wallet_plaintext(&self,
+                                       config: &Config,
+                                       credentials: &Credentials,
+                                       import_config: &ExportConfig) -> IndyResult<()> {
+            let (wallet_handle, key_data) = self.import_wallet_plaintext_prepare(config, credentials, import_config)?;
+            let master_key = key_data.calc_master_key()?;
+
+            self.import_wallet_plaintext_continue(wallet_handle, config, credentials, master_key)
+        }
+
         pub fn delete_wallet(&self, config: &Config, credentials: &Credentials) -> IndyResult<()> {
             if self.wallets.borrow_mut().values().any(|ref wallet| wallet.get_id() == WalletService::_get_wallet_id(config)) {
                 return Err(err_msg(IndyErrorKind::InvalidState, format!("Wallet has to be closed before deleting: {:?}", WalletService::_get_wallet_id(config))))?;
@@ -1311,6 +1533,131 @@ mod tests {
         wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
     }
 
+    #[test]
+    fn wallet_service_with_transaction_works_for_commit() {
+        test::cleanup_wallet("wallet_service_with_transaction_works_for_commit");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_with_transaction_works_for_commit"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_with_transaction_works_for_commit"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.with_transaction(wallet_handle, || {
+                wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new())?;
+                wallet_service.add_record(wallet_handle, "type", "key2", "value2", &HashMap::new())
+            }).unwrap();
+
+            assert!(!wallet_service.is_transaction_started(wallet_handle).unwrap());
+            wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
+            wallet_service.get_record(wallet_handle, "type", "key2", "{}").unwrap();
+        }
+        test::cleanup_wallet("wallet_service_with_transaction_works_for_commit");
+    }
+
+    #[test]
+    fn wallet_service_with_transaction_works_for_rollback() {
+        test::cleanup_wallet("wallet_service_with_transaction_works_for_rollback");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_with_transaction_works_for_rollback"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_with_transaction_works_for_rollback"), &RAW_CREDENTIAL).unwrap();
+
+            let res = wallet_service.with_transaction(wallet_handle, || {
+                wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new())?;
+                wallet_service.add_record(wallet_handle, "type", "key1", "value2", &HashMap::new())
+            });
+            assert_kind!(IndyErrorKind::WalletItemAlreadyExists, res);
+
+            assert!(!wallet_service.is_transaction_started(wallet_handle).unwrap());
+            let res = wallet_service.get_record(wallet_handle, "type", "key1", "{}");
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+        test::cleanup_wallet("wallet_service_with_transaction_works_for_rollback");
+    }
+
+    #[test]
+    fn wallet_service_with_transaction_works_for_started_transaction() {
+        test::cleanup_wallet("wallet_service_with_transaction_works_for_started_transaction");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_with_transaction_works_for_started_transaction"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_with_transaction_works_for_started_transaction"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.begin_transaction(wallet_handle).unwrap();
+
+            wallet_service.with_transaction(wallet_handle, || {
+                wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new())
+            }).unwrap();
+
+            assert!(wallet_service.is_transaction_started(wallet_handle).unwrap());
+            wallet_service.rollback_transaction(wallet_handle).unwrap();
+
+            let res = wallet_service.get_record(wallet_handle, "type", "key1", "{}");
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+        test::cleanup_wallet("wallet_service_with_transaction_works_for_started_transaction");
+    }
+
+    #[test]
+    fn wallet_service_register_wallet_storage_transactions_works_for_unknown_type() {
+        let wallet_service = WalletService::new();
+
+        let res = wallet_service.register_wallet_storage_transactions(
+            "unknown",
+            InmemWallet::begin_transaction,
+            InmemWallet::commit_transaction,
+            InmemWallet::rollback_transaction,
+        );
+        assert_kind!(IndyErrorKind::UnknownWalletStorageType, res);
+    }
+
+    #[test]
+    fn wallet_service_with_transaction_works_for_plugged() {
+        _cleanup("wallet_service_with_transaction_works_for_plugged");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+        wallet_service.register_wallet_storage_transactions(
+            "inmem",
+            InmemWallet::begin_transaction,
+            InmemWallet::commit_transaction,
+            InmemWallet::rollback_transaction,
+        ).unwrap();
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+        let res: IndyResult<()> = wallet_service.with_transaction(wallet_handle, || {
+            wallet_service.add_record(wallet_handle, "type", "key2", "value2", &HashMap::new())?;
+            Err(err_msg(IndyErrorKind::InvalidState, "Test error"))
+        });
+        assert_kind!(IndyErrorKind::InvalidState, res);
+
+        wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
+        let res = wallet_service.get_record(wallet_handle, "type", "key2", "{}");
+        assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+    }
+
+    #[test]
+    fn wallet_service_with_transaction_works_for_plugged_without_transactions() {
+        _cleanup("wallet_service_with_transaction_works_for_plugged_without_transactions");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        wallet_service.with_transaction(wallet_handle, || {
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new())
+        }).unwrap();
+        wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
+
+        let res = wallet_service.begin_transaction(wallet_handle);
+        assert_kind!(IndyErrorKind::WalletStorageError, res);
+    }
+
     #[test]
     fn wallet_service_get_record_works_for_id_only() {
         test::cleanup_wallet("wallet_service_get_record_works_for_id_only");
@@ -1896,6 +2243,90 @@ mod tests {
         test::cleanup_wallet("wallet_service_key_rotation_for_rekey_raw_method");
     }
 
+    #[test]
+    fn wallet_service_rotate_wallet_key_works_for_moderate_method() {
+        test::cleanup_wallet("wallet_service_rotate_wallet_key_works_for_moderate_method");
+        {
+            let config: &Config = &_config("wallet_service_rotate_wallet_key_works_for_moderate_method");
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(config, &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(config, &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+            wallet_service.rotate_wallet_key(wallet_handle, &_rotate_key_config_moderate()).unwrap();
+
+            // Wallet handle is still usable after rotation
+            wallet_service.add_record(wallet_handle, "type", "key2", "value2", &HashMap::new()).unwrap();
+            let record = wallet_service.get_record(wallet_handle, "type", "key1", &_fetch_options(true, true, true)).unwrap();
+            assert_eq!("value1", record.get_value().unwrap());
+            wallet_service.close_wallet(wallet_handle).unwrap();
+
+            // Access failed for old key
+            let res = wallet_service.open_wallet(config, &RAW_CREDENTIAL);
+            assert_kind!(IndyErrorKind::WalletAccessFailed, res);
+
+            let wallet_handle = wallet_service.open_wallet(config, &_credentials_for_new_key_moderate()).unwrap();
+            let record = wallet_service.get_record(wallet_handle, "type", "key1", &_fetch_options(true, true, true)).unwrap();
+            assert_eq!("value1", record.get_value().unwrap());
+            let record = wallet_service.get_record(wallet_handle, "type", "key2", &_fetch_options(true, true, true)).unwrap();
+            assert_eq!("value2", record.get_value().unwrap());
+        }
+        test::cleanup_wallet("wallet_service_rotate_wallet_key_works_for_moderate_method");
+    }
+
+    #[test]
+    fn wallet_service_rotate_wallet_key_works_for_raw_method() {
+        test::cleanup_wallet("wallet_service_rotate_wallet_key_works_for_raw_method");
+        {
+            let config: &Config = &_config("wallet_service_rotate_wallet_key_works_for_raw_method");
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(config, &ARGON_INT_CREDENTIAL, (&INTERACTIVE_KDD, &INTERACTIVE_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(config, &ARGON_INT_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+            wallet_service.rotate_wallet_key(wallet_handle, &_rotate_key_config_raw()).unwrap();
+            wallet_service.close_wallet(wallet_handle).unwrap();
+
+            let res = wallet_service.open_wallet(config, &ARGON_INT_CREDENTIAL);
+            assert_kind!(IndyErrorKind::WalletAccessFailed, res);
+
+            let wallet_handle = wallet_service.open_wallet(config, &_credentials_for_new_key_raw()).unwrap();
+            let record = wallet_service.get_record(wallet_handle, "type", "key1", &_fetch_options(true, true, true)).unwrap();
+            assert_eq!("value1", record.get_value().unwrap());
+        }
+        test::cleanup_wallet("wallet_service_rotate_wallet_key_works_for_raw_method");
+    }
+
+    #[test]
+    fn wallet_service_rotate_wallet_key_fails_for_started_transaction() {
+        test::cleanup_wallet("wallet_service_rotate_wallet_key_fails_for_started_transaction");
+        {
+            let config: &Config = &_config("wallet_service_rotate_wallet_key_fails_for_started_transaction");
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(config, &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(config, &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.begin_transaction(wallet_handle).unwrap();
+            let res = wallet_service.rotate_wallet_key(wallet_handle, &_rotate_key_config_raw());
+            assert_kind!(IndyErrorKind::InvalidState, res);
+            wallet_service.rollback_transaction(wallet_handle).unwrap();
+            wallet_service.close_wallet(wallet_handle).unwrap();
+
+            // Old key is still valid
+            wallet_service.open_wallet(config, &RAW_CREDENTIAL).unwrap();
+        }
+        test::cleanup_wallet("wallet_service_rotate_wallet_key_fails_for_started_transaction");
+    }
+
+    #[test]
+    fn wallet_service_rotate_wallet_key_fails_for_invalid_handle() {
+        let wallet_service = WalletService::new();
+        let res = wallet_service.rotate_wallet_key_prepare(INVALID_WALLET_HANDLE, &_rotate_key_config_raw());
+        assert_kind!(IndyErrorKind::InvalidWalletHandle, res);
+    }
+
     fn remove_exported_wallet(export_config: &ExportConfig) -> &Path {
         let export_path = Path::new(&export_config.path);
         if export_path.exists() {
@@ -2198,6 +2629,56 @@ mod tests {
         test::cleanup_wallet("wallet_service_export_import_wallet_if_empty");
     }
 
+    #[test]
+    fn wallet_service_export_import_wallet_plaintext_works() {
+        test::cleanup_wallet("wallet_service_export_import_wallet_plaintext_works");
+        let export_config = _export_config_plaintext("wallet_service_export_import_wallet_plaintext_works");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_export_import_wallet_plaintext_works"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_export_import_wallet_plaintext_works"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+            let export_path = remove_exported_wallet(&export_config);
+            wallet_service.export_wallet_plaintext(wallet_handle, &export_config).unwrap();
+
+            let exported = fs::read_to_string(&export_path).unwrap();
+            assert_eq!(json!({"type": "type", "id": "key1", "value": "value1", "tags": {}}),
+                       serde_json::from_str::<SValue>(exported.trim_end()).unwrap());
+
+            wallet_service.close_wallet(wallet_handle).unwrap();
+            wallet_service.delete_wallet(&_config("wallet_service_export_import_wallet_plaintext_works"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.import_wallet_plaintext(&_config("wallet_service_export_import_wallet_plaintext_works"), &RAW_CREDENTIAL, &export_config).unwrap();
+            assert_eq!(0, wallet_service.get_pending_for_import_count());
+
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_export_import_wallet_plaintext_works"), &RAW_CREDENTIAL).unwrap();
+            let record = wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
+            assert_eq!("value1", record.get_value().unwrap());
+        }
+        let _export_path = remove_exported_wallet(&export_config);
+        test::cleanup_wallet("wallet_service_export_import_wallet_plaintext_works");
+    }
+
+    #[test]
+    fn wallet_service_export_wallet_plaintext_returns_error_if_not_acknowledged() {
+        test::cleanup_wallet("wallet_service_export_wallet_plaintext_returns_error_if_not_acknowledged");
+        let mut export_config = _export_config_plaintext("wallet_service_export_wallet_plaintext_returns_error_if_not_acknowledged");
+        export_config.acknowledge_plaintext_export = false;
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_export_wallet_plaintext_returns_error_if_not_acknowledged"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_export_wallet_plaintext_returns_error_if_not_acknowledged"), &RAW_CREDENTIAL).unwrap();
+
+            let export_path = remove_exported_wallet(&export_config);
+            let res = wallet_service.export_wallet_plaintext(wallet_handle, &export_config);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+            assert!(!export_path.exists());
+        }
+        test::cleanup_wallet("wallet_service_export_wallet_plaintext_returns_error_if_not_acknowledged");
+    }
+
     #[test]
     fn wallet_service_export_import_returns_error_if_path_missing() {
         _cleanup("wallet_service_export_import_returns_error_if_path_missing");
@@ -2388,6 +2869,20 @@ mod tests {
         }
     }
 
+    fn _rotate_key_config_moderate() -> RotateKeyConfig {
+        RotateKeyConfig {
+            key: "my_new_key".to_string(),
+            key_derivation_method: KeyDerivationMethod::ARGON2I_MOD,
+        }
+    }
+
+    fn _rotate_key_config_raw() -> RotateKeyConfig {
+        RotateKeyConfig {
+            key: "7nxtSiXFvBd593Y2DCed2dYvRY1PGK9WMtxCBjLzKgbw".to_string(),
+            key_derivation_method: KeyDerivationMethod::RAW,
+        }
+    }
+
     fn _export_file_path(name: &str) -> PathBuf {
         let mut path = environment::tmp_path();
         path.push(name);
@@ -2396,14 +2891,20 @@ mod tests {
 
     fn _export_config_moderate(name: &str) -> ExportConfig {
         ExportConfig {
-            key: "export_key".to_string(),
+            key: Some("export_key".to_string()),
             path: _export_file_path(name).to_str().unwrap().to_string(),
             key_derivation_method: KeyDerivationMethod::ARGON2I_MOD,
+            format: ExportFormat::Encrypted,
+            acknowledge_plaintext_export: false,
+            record_type: None,
+            query: None,
+            offset: None,
+            limit: None,
         }
     }
 
     fn _calc_key(export_config: &ExportConfig) -> (KeyDerivationData, MasterKey) {
-        let kdd = KeyDerivationData::from_passphrase_with_new_salt(&export_config.key, &export_config.key_derivation_method);
+        let kdd = KeyDerivationData::from_passphrase_with_new_salt(export_config.key(), &export_config.key_derivation_method);
         let master_key = kdd.calc_master_key().unwrap();
         (kdd, master_key)
     }
@@ -2414,9 +2915,15 @@ mod tests {
 
     fn _export_config_interactive(name: &str) -> ExportConfig {
         ExportConfig {
-            key: "export_key".to_string(),
+            key: Some("export_key".to_string()),
             path: _export_file_path(name).to_str().unwrap().to_string(),
             key_derivation_method: KeyDerivationMethod::ARGON2I_INT,
+            format: ExportFormat::Encrypted,
+            acknowledge_plaintext_export: false,
+            record_type: None,
+            query: None,
+            offset: None,
+            limit: None,
         }
     }
 
@@ -2426,9 +2933,29 @@ mod tests {
 
     fn _export_config_raw(name: &str) -> ExportConfig {
         ExportConfig {
-            key: "6nxtSiXFvBd593Y2DCed2dYvRY1PGK9WMtxCBjLzKgbw".to_string(),
+            key: Some("6nxtSiXFvBd593Y2DCed2dYvRY1PGK9WMtxCBjLzKgbw".to_string()),
             path: _export_file_path(name).to_str().unwrap().to_string(),
             key_derivation_method: KeyDerivationMethod::RAW,
+            format: ExportFormat::Encrypted,
+            acknowledge_plaintext_export: false,
+            record_type: None,
+            query: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    fn _export_config_plaintext(name: &str) -> ExportConfig {
+        ExportConfig {
+            key: None,
+            path: _export_file_path(name).to_str().unwrap().to_string(),
+            key_derivation_method: KeyDerivationMethod::ARGON2I_MOD,
+            format: ExportFormat::PlaintextJsonl,
+            acknowledge_plaintext_export: true,
+            record_type: None,
+            query: None,
+            offset: None,
+            limit: None,
         }
     }
 
@@ -567,6 +567,47 @@ impl WalletStorage for SQLiteStorage {
         }
     }
 
+    fn supports_transactions(&self) -> bool {
+        true
+    }
+
+    ///
+    /// Starts a transaction spanning all following storage modifications.
+    /// Single record operations executed inside of it use savepoints,
+    /// so they are published only on `commit_transaction`.
+    ///
+    /// # Errors
+    ///
+    ///  * `IndyError::InvalidState` - Transaction is already started
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    fn begin_transaction(&self) -> IndyResult<()> {
+        if !self.conn.is_autocommit() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Storage transaction is already started"));
+        }
+
+        self.conn.execute_batch("BEGIN DEFERRED")?;
+        Ok(())
+    }
+
+    fn commit_transaction(&self) -> IndyResult<()> {
+        if self.conn.is_autocommit() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Storage transaction is not started"));
+        }
+
+        self.conn.execute_batch("COMMIT")?;
+        Ok(())
+    }
+
+    fn rollback_transaction(&self) -> IndyResult<()> {
+        if self.conn.is_autocommit() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Storage transaction is not started"));
+        }
+
+        self.conn.execute_batch("ROLLBACK")?;
+        Ok(())
+    }
+
     fn close(&mut self) -> IndyResult<()> {
         Ok(())
     }
@@ -1313,6 +1354,102 @@ mod tests {
         _cleanup("sqlite_storage_delete_tags_works_for_non_existing_id");
     }
 
+    #[test]
+    fn sqlite_storage_transaction_commit_works() {
+        _cleanup("sqlite_storage_transaction_commit_works");
+        {
+            let storage = _storage("sqlite_storage_transaction_commit_works");
+
+            storage.begin_transaction().unwrap();
+            storage.add(&_type1(), &_id1(), &_value1(), &_tags()).unwrap();
+            storage.add(&_type1(), &_id2(), &_value2(), &_tags()).unwrap();
+            storage.update_tags(&_type1(), &_id1(), &_new_tags()).unwrap();
+            storage.commit_transaction().unwrap();
+
+            let record = storage.get(&_type1(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+
+            let record = storage.get(&_type1(), &_id2(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": false}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value2());
+        }
+        _cleanup("sqlite_storage_transaction_commit_works");
+    }
+
+    #[test]
+    fn sqlite_storage_transaction_rollback_works() {
+        _cleanup("sqlite_storage_transaction_rollback_works");
+        {
+            let storage = _storage("sqlite_storage_transaction_rollback_works");
+
+            storage.add(&_type1(), &_id1(), &_value1(), &_tags()).unwrap();
+
+            storage.begin_transaction().unwrap();
+            storage.update(&_type1(), &_id1(), &_value2()).unwrap();
+            storage.add(&_type1(), &_id2(), &_value2(), &_tags()).unwrap();
+            storage.rollback_transaction().unwrap();
+
+            let record = storage.get(&_type1(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": false}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+
+            let res = storage.get(&_type1(), &_id2(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": false}"##);
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+        _cleanup("sqlite_storage_transaction_rollback_works");
+    }
+
+    #[test]
+    fn sqlite_storage_transaction_keeps_previous_changes_after_failed_operation() {
+        _cleanup("sqlite_storage_transaction_keeps_previous_changes_after_failed_operation");
+        {
+            let storage = _storage("sqlite_storage_transaction_keeps_previous_changes_after_failed_operation");
+
+            storage.begin_transaction().unwrap();
+            storage.add(&_type1(), &_id1(), &_value1(), &_tags()).unwrap();
+
+            let res = storage.add(&_type1(), &_id1(), &_value2(), &_new_tags());
+            assert_kind!(IndyErrorKind::WalletItemAlreadyExists, res);
+
+            storage.commit_transaction().unwrap();
+
+            let record = storage.get(&_type1(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+        }
+        _cleanup("sqlite_storage_transaction_keeps_previous_changes_after_failed_operation");
+    }
+
+    #[test]
+    fn sqlite_storage_begin_transaction_works_for_twice() {
+        _cleanup("sqlite_storage_begin_transaction_works_for_twice");
+        {
+            let storage = _storage("sqlite_storage_begin_transaction_works_for_twice");
+
+            storage.begin_transaction().unwrap();
+
+            let res = storage.begin_transaction();
+            assert_kind!(IndyErrorKind::InvalidState, res);
+
+            storage.rollback_transaction().unwrap();
+        }
+        _cleanup("sqlite_storage_begin_transaction_works_for_twice");
+    }
+
+    #[test]
+    fn sqlite_storage_commit_transaction_works_for_not_started() {
+        _cleanup("sqlite_storage_commit_transaction_works_for_not_started");
+        {
+            let storage = _storage("sqlite_storage_commit_transaction_works_for_not_started");
+
+            let res = storage.commit_transaction();
+            assert_kind!(IndyErrorKind::InvalidState, res);
+
+            let res = storage.rollback_transaction();
+            assert_kind!(IndyErrorKind::InvalidState, res);
+        }
+        _cleanup("sqlite_storage_commit_transaction_works_for_not_started");
+    }
+
     fn _cleanup(name: &str) {
         test::cleanup_storage(name)
     }
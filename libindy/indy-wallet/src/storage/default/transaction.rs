@@ -1,27 +1,40 @@
 use rusqlite::{TransactionBehavior, Connection, DropBehavior, Result};
 use std::ops::Deref;
 
+const _NESTED_SAVEPOINT: &str = "indy_nested";
+
 pub struct Transaction<'conn> {
     conn: &'conn Connection,
     drop_behavior: DropBehavior,
     committed: bool,
+    nested: bool,
 }
 
 impl<'conn> Transaction<'conn> {
-    /// Begin a new transaction. Cannot be nested; see `savepoint` for nested transactions.
+    /// Begin a new transaction. If the connection is already inside of a transaction
+    /// (started by `SQLiteStorage::begin_transaction`) a savepoint is used instead, so the
+    /// changes are published only when the outer transaction is committed.
     pub fn new(conn: &Connection, behavior: TransactionBehavior) -> Result<Transaction> {
-        let query = match behavior {
-            TransactionBehavior::Deferred => "BEGIN DEFERRED",
-            TransactionBehavior::Immediate => "BEGIN IMMEDIATE",
-            TransactionBehavior::Exclusive => "BEGIN EXCLUSIVE",
-            _ => ""
+        let nested = !conn.is_autocommit();
+
+        let query = if nested {
+            format!("SAVEPOINT {}", _NESTED_SAVEPOINT)
+        } else {
+            match behavior {
+                TransactionBehavior::Deferred => "BEGIN DEFERRED",
+                TransactionBehavior::Immediate => "BEGIN IMMEDIATE",
+                TransactionBehavior::Exclusive => "BEGIN EXCLUSIVE",
+                _ => ""
+            }.to_string()
         };
-        conn.execute_batch(query)
+
+        conn.execute_batch(&query)
             .map(move |_| {
                 Transaction {
                     conn,
                     drop_behavior: DropBehavior::Rollback,
                     committed: false,
+                    nested,
                 }
             })
     }
@@ -44,7 +57,11 @@ impl<'conn> Transaction<'conn> {
 
     fn commit_(&mut self) -> Result<()> {
         self.committed = true;
-        self.conn.execute_batch("COMMIT")
+        if self.nested {
+            self.conn.execute_batch(&format!("RELEASE {}", _NESTED_SAVEPOINT))
+        } else {
+            self.conn.execute_batch("COMMIT")
+        }
     }
 
     /// A convenience method which consumes and rolls back a transaction.
@@ -55,7 +72,11 @@ impl<'conn> Transaction<'conn> {
 
     fn rollback_(&mut self) -> Result<()> {
         self.committed = true;
-        self.conn.execute_batch("ROLLBACK")
+        if self.nested {
+            self.conn.execute_batch(&format!("ROLLBACK TO {0}; RELEASE {0}", _NESTED_SAVEPOINT))
+        } else {
+            self.conn.execute_batch("ROLLBACK")
+        }
     }
 
     /// Consumes the transaction, committing or rolling back according to the current setting
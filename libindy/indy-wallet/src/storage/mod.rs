@@ -4,6 +4,7 @@ pub mod plugged;
 use indy_api_types::errors::prelude::*;
 use crate::language;
 use crate::wallet::EncryptedValue;
+use self::plugged::PluggedTransactionHandlers;
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Tag {
@@ -53,6 +54,10 @@ pub trait WalletStorage {
     fn set_storage_metadata(&self, metadata: &[u8]) -> Result<(), IndyError>;
     fn get_all(&self) -> Result<Box<dyn StorageIterator>, IndyError>;
     fn search(&self, type_: &[u8], query: &language::Operator, options: Option<&str>) -> Result<Box<dyn StorageIterator>, IndyError>;
+    fn supports_transactions(&self) -> bool;
+    fn begin_transaction(&self) -> Result<(), IndyError>;
+    fn commit_transaction(&self) -> Result<(), IndyError>;
+    fn rollback_transaction(&self) -> Result<(), IndyError>;
     fn close(&mut self) -> Result<(), IndyError>;
 }
 
@@ -60,4 +65,7 @@ pub trait WalletStorageType {
     fn create_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>, metadata: &[u8]) -> Result<(), IndyError>;
     fn open_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> Result<Box<dyn WalletStorage>, IndyError>;
     fn delete_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), IndyError>;
+    fn set_transaction_handlers(&self, _handlers: PluggedTransactionHandlers) -> Result<(), IndyError> {
+        Err(err_msg(IndyErrorKind::InvalidState, "Wallet storage type doesn't accept transaction handlers"))
+    }
 }
\ No newline at end of file
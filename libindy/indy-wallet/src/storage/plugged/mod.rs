@@ -1,4 +1,5 @@
 use std::{slice, str};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::ptr;
@@ -53,6 +54,15 @@ impl Drop for ResourceGuard {
     }
 }
 
+// Transaction handlers are optional for plugged storages and are registered
+// separately from the main set of handlers (see indy_register_wallet_storage_transactions).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PluggedTransactionHandlers {
+    pub begin_handler: WalletBeginTransaction,
+    pub commit_handler: WalletCommitTransaction,
+    pub rollback_handler: WalletRollbackTransaction,
+}
+
 
 #[derive(PartialEq, Debug)]
 struct PluggedStorageIterator {
@@ -235,6 +245,7 @@ struct PluggedStorage {
     fetch_search_next_record_handler: WalletFetchSearchNextRecord,
     free_search_handler: WalletFreeSearch,
     close_handler: WalletClose,
+    transaction_handlers: Option<PluggedTransactionHandlers>,
 }
 
 impl PluggedStorage {
@@ -259,7 +270,8 @@ impl PluggedStorage {
            get_search_total_count_handler: WalletGetSearchTotalCount,
            fetch_search_next_record_handler: WalletFetchSearchNextRecord,
            free_search_handler: WalletFreeSearch,
-           close_handler: WalletClose) -> PluggedStorage {
+           close_handler: WalletClose,
+           transaction_handlers: Option<PluggedTransactionHandlers>) -> PluggedStorage {
         PluggedStorage {
             handle,
             add_record_handler,
@@ -283,8 +295,15 @@ impl PluggedStorage {
             fetch_search_next_record_handler,
             free_search_handler,
             close_handler,
+            transaction_handlers,
         }
     }
+
+    fn _transaction_handlers(&self) -> IndyResult<&PluggedTransactionHandlers> {
+        self.transaction_handlers
+            .as_ref()
+            .ok_or_else(|| err_msg(IndyErrorKind::WalletStorageError, "Wallet storage doesn't support transactions"))
+    }
 }
 
 fn _tags_to_json(tags: &[Tag]) -> IndyResult<String> {
@@ -606,6 +625,40 @@ impl WalletStorage for PluggedStorage {
         ))
     }
 
+    fn supports_transactions(&self) -> bool {
+        self.transaction_handlers.is_some()
+    }
+
+    fn begin_transaction(&self) -> IndyResult<()> {
+        let err = (self._transaction_handlers()?.begin_handler)(self.handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    fn commit_transaction(&self) -> IndyResult<()> {
+        let err = (self._transaction_handlers()?.commit_handler)(self.handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    fn rollback_transaction(&self) -> IndyResult<()> {
+        let err = (self._transaction_handlers()?.rollback_handler)(self.handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
     fn close(&mut self) -> IndyResult<()> {
         let err = (self.close_handler)(self.handle);
 
@@ -655,6 +708,7 @@ pub struct PluggedStorageType {
     get_search_total_count_handler: WalletGetSearchTotalCount,
     fetch_search_next_record_handler: WalletFetchSearchNextRecord,
     free_search_handler: WalletFreeSearch,
+    transaction_handlers: RefCell<Option<PluggedTransactionHandlers>>,
 }
 
 
@@ -708,6 +762,7 @@ impl PluggedStorageType {
             get_search_total_count_handler,
             fetch_search_next_record_handler,
             free_search_handler,
+            transaction_handlers: RefCell::new(None),
         }
     }
 }
@@ -781,7 +836,8 @@ impl WalletStorageType for PluggedStorageType {
                 self.get_search_total_count_handler,
                 self.fetch_search_next_record_handler,
                 self.free_search_handler,
-                self.close_handler)))
+                self.close_handler,
+                *self.transaction_handlers.borrow())))
     }
 
     fn delete_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> IndyResult<()> {
@@ -805,6 +861,17 @@ impl WalletStorageType for PluggedStorageType {
 
         Ok(())
     }
+
+    fn set_transaction_handlers(&self, handlers: PluggedTransactionHandlers) -> IndyResult<()> {
+        let mut transaction_handlers = self.transaction_handlers.borrow_mut();
+
+        if transaction_handlers.is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Transaction handlers are already registered for this wallet storage type"));
+        }
+
+        *transaction_handlers = Some(handlers);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -867,6 +934,9 @@ mod tests {
         GetSearchTotalCountHandler(i32, i32),
         FetchSearchNextRecordHandler(i32, i32),
         FreeSearchHandler(i32, i32),
+        BeginTransactionHandler(i32),
+        CommitTransactionHandler(i32),
+        RollbackTransactionHandler(i32),
     }
 
     fn _random_vector(len: usize) -> Vec<u8> {
@@ -1340,6 +1410,38 @@ mod tests {
         ErrorCode::Success
     }
 
+    extern "C" fn _mock_begin_transaction_handler(storage_handle: i32) -> ErrorCode {
+        DEBUG_VEC.write().unwrap().push(
+            Call::BeginTransactionHandler(storage_handle)
+        );
+
+        ErrorCode::Success
+    }
+
+    extern "C" fn _mock_commit_transaction_handler(storage_handle: i32) -> ErrorCode {
+        DEBUG_VEC.write().unwrap().push(
+            Call::CommitTransactionHandler(storage_handle)
+        );
+
+        ErrorCode::Success
+    }
+
+    extern "C" fn _mock_rollback_transaction_handler(storage_handle: i32) -> ErrorCode {
+        DEBUG_VEC.write().unwrap().push(
+            Call::RollbackTransactionHandler(storage_handle)
+        );
+
+        ErrorCode::Success
+    }
+
+    fn _transaction_handlers() -> PluggedTransactionHandlers {
+        PluggedTransactionHandlers {
+            begin_handler: _mock_begin_transaction_handler,
+            commit_handler: _mock_commit_transaction_handler,
+            rollback_handler: _mock_rollback_transaction_handler,
+        }
+    }
+
     fn _create_storage_type() -> PluggedStorageType {
         PluggedStorageType::new(
             _mock_create_handler,
@@ -2185,4 +2287,44 @@ mod tests {
         assert_eq!(&expected_free_record_call, debug.get(6).unwrap());
         assert_eq!(&expected_free_search_call, debug.get(7).unwrap());
     }
+
+    #[test]
+    fn plugged_storage_transaction_works() {
+        let storage_type = _create_storage_type();
+        storage_type.set_transaction_handlers(_transaction_handlers()).unwrap();
+
+        let storage = storage_type.open_storage("wallet1", None, Some("credentials")).unwrap();
+
+        DEBUG_VEC.write().unwrap().clear();
+
+        storage.begin_transaction().unwrap();
+        storage.commit_transaction().unwrap();
+        storage.begin_transaction().unwrap();
+        storage.rollback_transaction().unwrap();
+
+        let debug = DEBUG_VEC.read().unwrap();
+
+        assert_eq!(debug.len(), 4);
+        assert_eq!(&Call::BeginTransactionHandler(RETURN_STORAGE_HANDLE), debug.get(0).unwrap());
+        assert_eq!(&Call::CommitTransactionHandler(RETURN_STORAGE_HANDLE), debug.get(1).unwrap());
+        assert_eq!(&Call::BeginTransactionHandler(RETURN_STORAGE_HANDLE), debug.get(2).unwrap());
+        assert_eq!(&Call::RollbackTransactionHandler(RETURN_STORAGE_HANDLE), debug.get(3).unwrap());
+    }
+
+    #[test]
+    fn plugged_storage_transaction_works_for_not_registered_handlers() {
+        let storage = _open_storage();
+
+        let res = storage.begin_transaction();
+        assert_kind!(IndyErrorKind::WalletStorageError, res);
+    }
+
+    #[test]
+    fn plugged_storage_type_set_transaction_handlers_works_for_twice() {
+        let storage_type = _create_storage_type();
+        storage_type.set_transaction_handlers(_transaction_handlers()).unwrap();
+
+        let res = storage_type.set_transaction_handlers(_transaction_handlers());
+        assert_kind!(IndyErrorKind::InvalidState, res);
+    }
 }
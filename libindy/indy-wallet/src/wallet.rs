@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -116,11 +117,12 @@ pub(super) struct Wallet {
     id: String,
     storage: Box<dyn storage::WalletStorage>,
     keys: Rc<Keys>,
+    transaction_started: Cell<bool>,
 }
 
 impl Wallet {
     pub fn new(id: String, storage: Box<dyn storage::WalletStorage>, keys: Rc<Keys>) -> Wallet {
-        Wallet { id, storage, keys }
+        Wallet { id, storage, keys, transaction_started: Cell::new(false) }
     }
 
     pub fn add(&self, type_: &str, name: &str, value: &str, tags: &HashMap<String, String>) -> IndyResult<()> {
@@ -201,7 +203,63 @@ impl Wallet {
         Ok(wallet_iterator)
     }
 
+    pub fn begin_transaction(&self) -> IndyResult<()> {
+        if self.transaction_started.get() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Wallet transaction is already started"));
+        }
+
+        self.storage.begin_transaction()?;
+        self.transaction_started.set(true);
+        Ok(())
+    }
+
+    pub fn commit_transaction(&self) -> IndyResult<()> {
+        if !self.transaction_started.get() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Wallet transaction is not started"));
+        }
+
+        self.storage.commit_transaction()?;
+        self.transaction_started.set(false);
+        Ok(())
+    }
+
+    pub fn rollback_transaction(&self) -> IndyResult<()> {
+        if !self.transaction_started.get() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Wallet transaction is not started"));
+        }
+
+        // Transaction is considered as finished even if storage failed to rollback it
+        self.transaction_started.set(false);
+        self.storage.rollback_transaction()
+    }
+
+    pub fn supports_transactions(&self) -> bool {
+        self.storage.supports_transactions()
+    }
+
+    pub fn is_transaction_started(&self) -> bool {
+        self.transaction_started.get()
+    }
+
+    pub fn set_master_key_metadata(&self, metadata: &[u8]) -> IndyResult<()> {
+        if self.transaction_started.get() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Wallet key can't be rotated while transaction is in progress"));
+        }
+
+        // Metadata is replaced by a single storage update, so an interrupted rotation keeps the old key valid
+        self.storage.set_storage_metadata(metadata)
+    }
+
+    pub fn get_keys(&self) -> &Keys {
+        &self.keys
+    }
+
     pub fn close(&mut self) -> IndyResult<()> {
+        if self.transaction_started.get() {
+            self.rollback_transaction()
+                .unwrap_or_else(|err| warn!("Unable to rollback wallet transaction on close: {:?}", err));
+        }
+
         self.storage.close()
             .map_err(IndyError::from)
     }
@@ -495,6 +553,96 @@ mod tests {
         test::cleanup_wallet("wallet_delete_works");
     }
 
+    #[test]
+    fn wallet_transaction_commit_works() {
+        test::cleanup_wallet("wallet_transaction_commit_works");
+        {
+            let mut wallet = _wallet("wallet_transaction_commit_works");
+
+            wallet.begin_transaction().unwrap();
+            assert!(wallet.is_transaction_started());
+
+            wallet.add(_type1(), _id1(), _value1(), &_tags()).unwrap();
+            wallet.add(_type1(), _id2(), _value2(), &_tags()).unwrap();
+            wallet.commit_transaction().unwrap();
+            assert!(!wallet.is_transaction_started());
+
+            wallet.close().unwrap();
+
+            let mut wallet = _exists_wallet("wallet_transaction_commit_works");
+
+            let record = wallet.get(_type1(), _id1(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+
+            let record = wallet.get(_type1(), _id2(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(record.value.unwrap(), _value2());
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_transaction_commit_works");
+    }
+
+    #[test]
+    fn wallet_transaction_rollback_works() {
+        test::cleanup_wallet("wallet_transaction_rollback_works");
+        {
+            let mut wallet = _wallet("wallet_transaction_rollback_works");
+            wallet.add(_type1(), _id1(), _value1(), &_tags()).unwrap();
+
+            wallet.begin_transaction().unwrap();
+            wallet.update(_type1(), _id1(), _value2()).unwrap();
+            wallet.delete(_type1(), _id1()).unwrap();
+            wallet.add(_type1(), _id2(), _value2(), &_tags()).unwrap();
+            wallet.rollback_transaction().unwrap();
+
+            let record = wallet.get(_type1(), _id1(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+
+            let res = wallet.get(_type1(), _id2(), &_fetch_options(false, true, true));
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_transaction_rollback_works");
+    }
+
+    #[test]
+    fn wallet_close_works_for_started_transaction() {
+        test::cleanup_wallet("wallet_close_works_for_started_transaction");
+        {
+            let mut wallet = _wallet("wallet_close_works_for_started_transaction");
+
+            wallet.begin_transaction().unwrap();
+            wallet.add(_type1(), _id1(), _value1(), &_tags()).unwrap();
+            wallet.close().unwrap();
+
+            let mut wallet = _exists_wallet("wallet_close_works_for_started_transaction");
+
+            let res = wallet.get(_type1(), _id1(), &_fetch_options(false, true, true));
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_close_works_for_started_transaction");
+    }
+
+    #[test]
+    fn wallet_commit_transaction_works_for_not_started() {
+        test::cleanup_wallet("wallet_commit_transaction_works_for_not_started");
+        {
+            let mut wallet = _wallet("wallet_commit_transaction_works_for_not_started");
+
+            let res = wallet.commit_transaction();
+            assert_kind!(IndyErrorKind::InvalidState, res);
+
+            let res = wallet.rollback_transaction();
+            assert_kind!(IndyErrorKind::InvalidState, res);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_commit_transaction_works_for_not_started");
+    }
+
     #[test]
     fn wallet_delete_works_for_non_existing_id() {
         test::cleanup_wallet("wallet_delete_works_for_non_existing_id");
@@ -1,4 +1,4 @@
-use indy_api_types::{ErrorCode, IndyHandle, CommandHandle, WalletHandle, SearchHandle, INVALID_SEARCH_HANDLE};
+use indy_api_types::{ErrorCode, IndyHandle, CommandHandle, WalletHandle, PoolHandle, SearchHandle, INVALID_SEARCH_HANDLE};
 use indy_api_types::errors::prelude::*;
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::anoncreds::AnoncredsCommand;
@@ -11,17 +11,19 @@ use crate::domain::anoncreds::credential_definition::{CredentialDefinition, Cred
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
-use crate::domain::anoncreds::credential::{Credential, CredentialValues};
+use crate::domain::anoncreds::credential::{Credential, CredentialIssuanceRequests, RawCredentialValues};
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition, RevocationRegistryId, RevocationRegistryDefinitions};
 use crate::domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use crate::domain::anoncreds::proof::Proof;
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry::RevocationRegistries;
+use crate::domain::anoncreds::revocation_registry_pool::RevocationRegistryPoolConfig;
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
 use indy_utils::ctypes;
 
 use libc::c_char;
+use std::collections::HashMap;
 use std::ptr;
 
 use crate::indy_api_types::validation::Validatable;
@@ -555,7 +557,8 @@ pub extern fn indy_issuer_create_credential_offer(command_handle: CommandHandle,
 ///      "attr1" : {"raw": "value1", "encoded": "value1_as_int" },
 ///      "attr2" : {"raw": "value1", "encoded": "value1_as_int" }
 ///     }
-///   If you want to use empty value for some credential field, you should set "raw" to "" and "encoded" should not be empty
+///   "encoded" is optional. If omitted, it is calculated from "raw" (see `indy_encode_credential_attributes`).
+///   If you want to use empty value for some credential field, you should set "raw" to ""
 /// rev_reg_id: id of revocation registry stored in the wallet
 /// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
 /// cb: Callback that takes command result as parameter.
@@ -605,7 +608,7 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
 
     check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
     check_useful_validatable_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
-    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, CredentialValues);
+    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, RawCredentialValues);
     check_useful_validatable_opt_string!(rev_reg_id, ErrorCode::CommonInvalidParam6, RevocationRegistryId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
 
@@ -644,6 +647,395 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
     res
 }
 
+/// Issue a batch of Credentials for the given pairs of Cred Offer and Cred Request.
+///
+/// All Cred Offers must reference the same credential definition. If revocation registry is set
+/// revocation ids for all credentials are allocated at once and the registry is stored into the wallet
+/// only once after all credentials are signed. Either all credentials are issued or none of them.
+///
+/// This call returns a single revoc registry delta covering all issued credentials as json file
+/// intended to be shared as REVOC_REG_ENTRY transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_requests_json: a list of credentials to issue
+///     [
+///         {
+///             "cred_offer": a cred offer created by indy_issuer_create_credential_offer,
+///             "cred_req": a credential request created by indy_prover_create_credential_req,
+///             "cred_values": credential values (see `cred_values_json` of indy_issuer_create_credential)
+///         }
+///     ]
+/// rev_reg_id: (Optional) id of revocation registry stored in the wallet
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+///     or -1 if revocation registry isn't used.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// creds_json: list of issued credentials in the order of cred_requests_json
+///     [
+///         {
+///             "cred": credential json (see `cred_json` of indy_issuer_create_credential),
+///             "cred_revoc_id": Optional<string> - local id for revocation info
+///         }
+///     ]
+/// revoc_reg_delta_json: (Optional) Revocation registry delta json with all newly issued credentials
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_credentials_batch(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   cred_requests_json: *const c_char,
+                                                   rev_reg_id: *const c_char,
+                                                   blob_storage_reader_handle: IndyHandle,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                        creds_json: *const c_char,
+                                                                        revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credentials_batch: >>> wallet_handle: {:?}, cred_requests_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
+           wallet_handle, cred_requests_json, rev_reg_id, blob_storage_reader_handle);
+
+    check_useful_validatable_json!(cred_requests_json, ErrorCode::CommonInvalidParam3, CredentialIssuanceRequests);
+    check_useful_validatable_opt_string!(rev_reg_id, ErrorCode::CommonInvalidParam4, RevocationRegistryId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let blob_storage_reader_handle = if blob_storage_reader_handle != -1 { Some(blob_storage_reader_handle) } else { None };
+
+    trace!("indy_issuer_create_credentials_batch: entities >>> wallet_handle: {:?}, cred_requests_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
+           wallet_handle, secret!(&cred_requests_json), secret!(&rev_reg_id), blob_storage_reader_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredentialsBatch(
+                    wallet_handle,
+                    cred_requests_json,
+                    rev_reg_id,
+                    blob_storage_reader_handle,
+                    Box::new(move |result| {
+                        let (err, creds_json, revoc_reg_delta_json) = prepare_result_2!(result, String::new(), None);
+                        trace!("indy_issuer_create_credentials_batch: creds_json: {:?}, revoc_reg_delta_json: {:?}",
+                               secret!(creds_json.as_str()), revoc_reg_delta_json);
+                        let creds_json = ctypes::string_to_cstring(creds_json);
+                        let revoc_reg_delta_json = revoc_reg_delta_json.map(ctypes::string_to_cstring);
+                        cb(command_handle, err, creds_json.as_ptr(),
+                           revoc_reg_delta_json.as_ref().map(|delta| delta.as_ptr()).unwrap_or(ptr::null()))
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_credentials_batch: <<< res: {:?}", res);
+
+    res
+}
+
+/// Create a pool of revocation registries for the given credential definition managed by the wallet.
+///
+/// The pool consists of the active registry used for issuance and the pre-generated standby registry.
+/// When the active registry is full the standby one replaces it once it's confirmed as published
+/// with `indy_issuer_confirm_revocation_registry_published`, so issuance through
+/// `indy_issuer_create_credential_from_revocation_registry_pool` doesn't fail with AnoncredsRevocationRegistryFullError.
+/// Next standby registries are generated in advance with `indy_issuer_add_revocation_registry_to_pool`.
+///
+/// Registries are created in the same way as `indy_issuer_create_and_store_revoc_reg` does. Ledger requests
+/// for registries which aren't published yet can be got with `indy_issuer_get_revocation_registry_pool_pending_requests`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// issuer_did: a DID of the issuer signing transactions to the Ledger
+/// cred_def_id: id of stored in ledger credential definition
+/// config_json: pool configuration json
+///     {
+///         "tag_prefix": string - (optional, default "pool_") prefix of registry tags, number of the registry in the pool is appended to it,
+///         "revoc_def_type": string - (optional, default "CL_ACCUM") type of revocation registries,
+///         "issuance_type": string - (optional, default "ISSUANCE_ON_DEMAND") type of issuance,
+///         "max_cred_num": int - (optional, default 100000) maximum number of credentials in each registry,
+///         "tails_writer_type": string - (optional, default "default") type of blob storage to write tails,
+///         "tails_writer_config": object - blob storage writer config (see `indy_open_blob_storage_writer`),
+///         "tails_reader_type": string - (optional, default "tails_writer_type") type of blob storage to read tails,
+///         "tails_reader_config": object - (optional, default "tails_writer_config") blob storage reader config
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// pool_json: state of the pool
+///     {
+///         "issuer_did": string,
+///         "cred_def_id": string,
+///         "config": <see config_json above>,
+///         "registries": [{"rev_reg_id": string, "state": "active"|"standby"|"full", "published": bool}]
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_revocation_registry_pool(command_handle: CommandHandle,
+                                                          wallet_handle: WalletHandle,
+                                                          issuer_did: *const c_char,
+                                                          cred_def_id: *const c_char,
+                                                          config_json: *const c_char,
+                                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                               pool_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_revocation_registry_pool: >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json);
+
+    check_useful_validatable_string!(issuer_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam4, CredentialDefinitionId);
+    check_useful_validatable_json!(config_json, ErrorCode::CommonInvalidParam5, RevocationRegistryPoolConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_create_revocation_registry_pool: entities >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateRevocationRegistryPool(
+                    wallet_handle,
+                    issuer_did,
+                    cred_def_id,
+                    config_json,
+                    boxed_callback_string!("indy_issuer_create_revocation_registry_pool", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_revocation_registry_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Issue Credential for the given Cred Request using the active registry of the revocation registry pool
+/// created by `indy_issuer_create_revocation_registry_pool` for the credential definition of Cred Offer.
+///
+/// If the active registry is full it is replaced by the standby registry published to the ledger before issuance.
+/// Standby registries are never generated during issuance, so AnoncredsRevocationRegistryFullError is returned
+/// if there is no published standby registry (see `indy_issuer_add_revocation_registry_to_pool`).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_offer_json: a cred offer created by indy_issuer_create_credential_offer
+/// cred_req_json: a credential request created by indy_prover_create_credential_req
+/// cred_values_json: a credential containing attribute values for each of requested attribute names
+///     (see `cred_values_json` of indy_issuer_create_credential).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_json: Credential json containing signed credential values (see `cred_json` of indy_issuer_create_credential)
+/// rev_reg_id: id of revocation registry the credential was issued in
+/// cred_revoc_id: local id for revocation info (Can be used for revocation of this credential)
+/// revoc_reg_delta_json: Revocation registry delta json with a newly issued credential
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_credential_from_revocation_registry_pool(command_handle: CommandHandle,
+                                                                          wallet_handle: WalletHandle,
+                                                                          cred_offer_json: *const c_char,
+                                                                          cred_req_json: *const c_char,
+                                                                          cred_values_json: *const c_char,
+                                                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                               cred_json: *const c_char,
+                                                                                               rev_reg_id: *const c_char,
+                                                                                               cred_revoc_id: *const c_char,
+                                                                                               revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credential_from_revocation_registry_pool: >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}",
+           wallet_handle, cred_offer_json, cred_req_json, cred_values_json);
+
+    check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
+    check_useful_validatable_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
+    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, RawCredentialValues);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_create_credential_from_revocation_registry_pool: entities >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}",
+           wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredentialFromRevocationRegistryPool(
+                    wallet_handle,
+                    cred_offer_json,
+                    cred_req_json,
+                    cred_values_json,
+                    Box::new(move |result| {
+                        let (err, cred_json, rev_reg_id, revoc_id, revoc_reg_delta_json) = prepare_result_4!(result, String::new(), String::new(), String::new(), None);
+                        trace!("indy_issuer_create_credential_from_revocation_registry_pool: cred_json: {:?}, rev_reg_id: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
+                               secret!(cred_json.as_str()), rev_reg_id, secret!(&revoc_id), revoc_reg_delta_json);
+                        let cred_json = ctypes::string_to_cstring(cred_json);
+                        let rev_reg_id = ctypes::string_to_cstring(rev_reg_id);
+                        let revoc_id = ctypes::string_to_cstring(revoc_id);
+                        let revoc_reg_delta_json = revoc_reg_delta_json.map(ctypes::string_to_cstring);
+                        cb(command_handle, err, cred_json.as_ptr(), rev_reg_id.as_ptr(), revoc_id.as_ptr(),
+                           revoc_reg_delta_json.as_ref().map(|delta| delta.as_ptr()).unwrap_or(ptr::null()))
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_credential_from_revocation_registry_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Build ledger requests for registries of the revocation registry pool which aren't published yet.
+///
+/// Requests are built with the issuer DID of the pool as submitter and must be signed and sent to the ledger.
+/// REVOC_REG_ENTRY request contains the current accumulator of the registry with all credentials issued so far.
+/// After both requests are written call `indy_issuer_confirm_revocation_registry_published`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition of the pool
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// requests_json: list of pending requests
+///     [
+///         {
+///             "rev_reg_id": string - id of revocation registry,
+///             "rev_reg_def_request": string - REVOC_REG_DEF request,
+///             "rev_reg_entry_request": string - REVOC_REG_ENTRY request
+///         }
+///     ]
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_get_revocation_registry_pool_pending_requests(command_handle: CommandHandle,
+                                                                        wallet_handle: WalletHandle,
+                                                                        cred_def_id: *const c_char,
+                                                                        cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                             requests_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_get_revocation_registry_pool_pending_requests: >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_get_revocation_registry_pool_pending_requests: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::GetRevocationRegistryPoolPendingRequests(
+                    wallet_handle,
+                    cred_def_id,
+                    boxed_callback_string!("indy_issuer_get_revocation_registry_pool_pending_requests", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_get_revocation_registry_pool_pending_requests: <<< res: {:?}", res);
+
+    res
+}
+
+/// Generate the standby registry of the revocation registry pool in advance.
+///
+/// Tails generation takes a while, so call it outside issuance, e.g. when the active registry of the pool
+/// is close to be full. The registry replaces the active one only after it's published to the ledger
+/// (see `indy_issuer_get_revocation_registry_pool_pending_requests` and `indy_issuer_confirm_revocation_registry_published`).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition of the pool
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// rev_reg_id: id of the generated revocation registry
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_add_revocation_registry_to_pool(command_handle: CommandHandle,
+                                                          wallet_handle: WalletHandle,
+                                                          cred_def_id: *const c_char,
+                                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                               rev_reg_id: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_add_revocation_registry_to_pool: >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_add_revocation_registry_to_pool: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::AddRevocationRegistryToPool(
+                    wallet_handle,
+                    cred_def_id,
+                    boxed_callback_string!("indy_issuer_add_revocation_registry_to_pool", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_add_revocation_registry_to_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Mark the registry of the revocation registry pool as published to the ledger,
+/// so requests for it aren't returned by `indy_issuer_get_revocation_registry_pool_pending_requests` anymore.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// rev_reg_id: id of revocation registry of the pool
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_confirm_revocation_registry_published(command_handle: CommandHandle,
+                                                                wallet_handle: WalletHandle,
+                                                                rev_reg_id: *const c_char,
+                                                                cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_confirm_revocation_registry_published: >>> wallet_handle: {:?}, rev_reg_id: {:?}", wallet_handle, rev_reg_id);
+
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_confirm_revocation_registry_published: entities >>> wallet_handle: {:?}, rev_reg_id: {:?}", wallet_handle, rev_reg_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::ConfirmRevocationRegistryPublished(
+                    wallet_handle,
+                    rev_reg_id,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_confirm_revocation_registry_published:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_confirm_revocation_registry_published: <<< res: {:?}", res);
+
+    res
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -711,6 +1103,129 @@ pub extern fn indy_issuer_revoke_credential(command_handle: CommandHandle,
     res
 }
 
+/// Revoke several credentials of the same revocation registry at once.
+///
+/// The corresponding credential definition and revocation registry must be already
+/// created and stored into the wallet.
+///
+/// All credentials are revoked in one step: if any of ids can't be revoked the registry stays unchanged.
+/// This call returns single revoc registry delta for all revoked credentials
+/// intended to be shared as REVOC_REG_ENTRY transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`).
+/// rev_reg_id: id of revocation registry stored in wallet
+/// cred_revoc_ids_json: list of local ids for revocation info related to issued credentials
+///     ["<cred_revoc_id>", ...]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_delta_json: Revocation registry delta json with revoked credentials
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         revoked: array<number> an array of revoked indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_revoke_credentials(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             blob_storage_reader_cfg_handle: IndyHandle,
+                                             rev_reg_id: *const c_char,
+                                             cred_revoc_ids_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                  revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_revoke_credentials: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_ids_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_ids_json);
+
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam4, RevocationRegistryId);
+    check_useful_json!(cred_revoc_ids_json, ErrorCode::CommonInvalidParam5, Vec<String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_revoke_credentials: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_ids_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, secret!(&cred_revoc_ids_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RevokeCredentials(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    rev_reg_id,
+                    cred_revoc_ids_json,
+                    boxed_callback_string!("indy_issuer_revoke_credentials", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_revoke_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get status of credentials issued by the revocation registry stored in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// rev_reg_id: id of revocation registry stored in wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// status_list_json: Revocation status list json
+/// {
+///     rev_reg_id: string - id of revocation registry,
+///     issuance_type: string - type of issuance of revocation registry,
+///     max_cred_num: number - maximum number of credentials the registry can serve,
+///     issued_count: number - number of indexes of the registry used for issuance,
+///     issued: array<number> - an array of indices of not revoked credentials,
+///     revoked: array<number> - an array of indices of revoked credentials,
+///     status_list: string - base64 encoded bitmap of `max_cred_num` bits,
+///                  bit `index - 1` (most significant bit first) is set if credential is revoked
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_get_revocation_status_list(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     rev_reg_id: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                          status_list_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_get_revocation_status_list: >>> wallet_handle: {:?}, rev_reg_id: {:?}", wallet_handle, rev_reg_id);
+
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_get_revocation_status_list: entities >>> wallet_handle: {:?}, rev_reg_id: {:?}", wallet_handle, rev_reg_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::GetRevocationStatusList(
+                    wallet_handle,
+                    rev_reg_id,
+                    boxed_callback_string!("indy_issuer_get_revocation_status_list", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_get_revocation_status_list: <<< res: {:?}", res);
+
+    res
+}
+
 /*/// Recover a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -1925,6 +2440,7 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///         },
 ///     }
 /// Note: use credential_id instead rev_reg_id in case proving several credentials from the same revocation registry.
+/// Note: pass null to use revocation states cached in the wallet by `indy_prover_refresh_revocation_states`.
 /// cb: Callback that takes command result as parameter.
 ///
 /// where
@@ -2037,7 +2553,7 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
     check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam5);
     check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam6, Schemas);
     check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam7, CredentialDefinitions);
-    check_useful_json!(rev_states_json, ErrorCode::CommonInvalidParam8, RevocationStates);
+    check_useful_opt_json!(rev_states_json, ErrorCode::CommonInvalidParam8, RevocationStates);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
 
     trace!("indy_prover_create_proof: entities >>> wallet_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, master_secret_id: {:?}, \
@@ -2094,6 +2610,8 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
 ///             - "2.0" to use fully qualified identifiers for restrictions
 ///     }
 /// proof_json: created for request proof json
+///     NOTE: revealed attributes which `encoded` value doesn't match canonical encoding of `raw` value (see `indy_encode_credential_attributes`)
+///     don't make proof invalid. Use `indy_verifier_verify_proof_with_details` to get them.
 ///     {
 ///         "requested_proof": {
 ///             "revealed_attrs": {
@@ -2259,6 +2777,83 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     res
 }
 
+/// Verifies a proof (of multiple credential) the same way as `indy_verifier_verify_proof`
+/// and returns revealed attributes which `encoded` value doesn't match canonical encoding
+/// of their `raw` value (see `indy_encode_credential_attributes`).
+/// Such attributes don't make proof invalid as some agents use their own encodings,
+/// so check them on application level if custom encoding isn't expected.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof`)
+/// proof_json: created for request proof json (see `indy_verifier_verify_proof`)
+/// schemas_json: all schemas participating in the proof (see `indy_verifier_verify_proof`)
+/// credential_defs_json: all credential definitions participating in the proof (see `indy_verifier_verify_proof`)
+/// rev_reg_defs_json: all revocation registry definitions participating in the proof (see `indy_verifier_verify_proof`)
+/// rev_regs_json: all revocation registries participating in the proof (see `indy_verifier_verify_proof`)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// details_json: verification details json
+///     {
+///         "valid": true - if signature is valid, false - otherwise
+///         "non_canonical_attrs": [
+///             {
+///                 "referent": <attr_referent>,
+///                 "name": <attribute name>,
+///                 "raw": <revealed raw value>,
+///                 "encoded": <revealed encoded value>
+///             },
+///             ...
+///         ]
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_details(command_handle: CommandHandle,
+                                                      proof_request_json: *const c_char,
+                                                      proof_json: *const c_char,
+                                                      schemas_json: *const c_char,
+                                                      credential_defs_json: *const c_char,
+                                                      rev_reg_defs_json: *const c_char,
+                                                      rev_regs_json: *const c_char,
+                                                      cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                           details_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_details: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_verifier_verify_proof_with_details: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofWithDetails(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            boxed_callback_string!("indy_verifier_verify_proof_with_details", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_with_details: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
 /// Note that revocation delta must cover the whole registry existence time.
@@ -2400,6 +2995,74 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
     res
 }
 
+/// Refresh revocation states of credentials stored in the wallet and cache them in the wallet.
+///
+/// For each credential the latest cached revocation state is updated incrementally with the revocation registry delta
+/// received from the ledger (GET_REVOC_REG_DELTA from the state timestamp till now).
+/// If there is no cached state yet, it is created from the delta covering the whole registry history.
+/// Revocation registry definitions are received from the ledger too.
+///
+/// Cached states can be used by `indy_prover_create_proof` when `rev_states_json` isn't passed.
+/// Only 10 latest states are kept for each credential, older ones are dropped on refresh.
+/// Cached states are deleted together with the credential by `indy_prover_delete_credential`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// cred_ids_json: ids of credentials in the wallet which revocation states should be refreshed (each id must be listed once)
+///     ["<cred_id>", ...]
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// refreshed_json: registry and timestamp of the latest cached revocation state for each credential
+///     {
+///         "<cred_id>": {
+///             "rev_reg_id": string,
+///             "timestamp": integer - timestamp to use in `requested_credentials_json` of `indy_prover_create_proof`
+///         },
+///         ...
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_refresh_revocation_states(command_handle: CommandHandle,
+                                                    pool_handle: PoolHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    blob_storage_reader_handle: IndyHandle,
+                                                    cred_ids_json: *const c_char,
+                                                    cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                         refreshed_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_refresh_revocation_states: >>> pool_handle: {:?}, wallet_handle: {:?}, blob_storage_reader_handle: {:?}, cred_ids_json: {:?}",
+           pool_handle, wallet_handle, blob_storage_reader_handle, cred_ids_json);
+
+    check_useful_json!(cred_ids_json, ErrorCode::CommonInvalidParam5, Vec<String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_prover_refresh_revocation_states: entities >>> pool_handle: {:?}, wallet_handle: {:?}, blob_storage_reader_handle: {:?}, cred_ids_json: {:?}",
+           pool_handle, wallet_handle, blob_storage_reader_handle, cred_ids_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::RefreshRevocationStates(
+            pool_handle,
+            wallet_handle,
+            blob_storage_reader_handle,
+            cred_ids_json,
+            boxed_callback_string!("indy_prover_refresh_revocation_states", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_refresh_revocation_states: <<< res: {:?}", res);
+
+    res
+}
+
 
 ///  Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
@@ -2486,3 +3149,51 @@ pub  extern fn indy_to_unqualified(command_handle: CommandHandle,
     res
 }
 
+/// Encodes raw credential attribute values the way accepted by Indy based agents:
+/// 32-bit integers are encoded as themselves, other values as decimal representation of SHA-256 hash of their UTF-8 bytes.
+///
+/// Note that `indy_verifier_verify_proof_with_details` reports revealed attributes
+/// which aren't encoded this way.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// attributes_json: raw values of the credential attributes:
+///     {
+///         "attr1": "value1",
+///         "attr2": "value2"
+///     }
+///
+/// #Returns
+/// cred_values_json: credential values to pass to `indy_issuer_create_credential`:
+///     {
+///         "attr1" : {"raw": "value1", "encoded": "value1_as_int" },
+///         "attr2" : {"raw": "value2", "encoded": "value2_as_int" }
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_encode_credential_attributes(command_handle: CommandHandle,
+                                                attributes_json: *const c_char,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     cred_values_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_encode_credential_attributes: >>> attributes_json: {:?}", attributes_json);
+
+    check_useful_json!(attributes_json, ErrorCode::CommonInvalidParam2, HashMap<String, String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_encode_credential_attributes: entities >>> attributes_json: {:?}", secret!(&attributes_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::EncodeCredentialAttributes(
+            attributes_json,
+            boxed_callback_string!("indy_encode_credential_attributes", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_encode_credential_attributes: <<< res: {:?}", res);
+
+    res
+}
@@ -1,15 +1,152 @@
 
-use indy_api_types::{ErrorCode, CommandHandle, WalletHandle};
+use indy_api_types::{ErrorCode, CommandHandle, IndyHandle, WalletHandle};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::crypto::CryptoCommand;
 use crate::domain::crypto::pack::JWE;
+use crate::domain::crypto::jws::JwsOptions;
+use indy_api_types::validation::Validatable;
 use crate::domain::crypto::key::KeyInfo;
+use crate::services::crypto::plugged::PluggedKeyBackend;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 
 use serde_json;
 use libc::c_char;
 
+/// Create a new non-exportable key inside of the key backend
+///
+/// #Params
+/// key_info_json: key information as json. {"seed": optional<string>, "crypto_type": string}
+/// verkey_p: pointer to the base58 encoded verkey of created key (without crypto type suffix)
+/// verkey_handle_p: pointer to the handle that will be passed to free_buffer to release verkey string
+pub type KeyBackendCreateKey = extern fn(key_info_json: *const c_char,
+                                         verkey_p: *mut *const c_char,
+                                         verkey_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Sign a message with the key that belongs to the key backend
+///
+/// #Params
+/// verkey: full verkey of the key (with crypto type suffix)
+/// message_raw: a pointer to first byte of message to be signed
+/// message_len: a message length
+/// signature_raw_p: pointer to the first byte of produced signature
+/// signature_len_p: pointer to the length of produced signature
+/// signature_handle_p: pointer to the handle that will be passed to free_buffer to release signature
+pub type KeyBackendSign = extern fn(verkey: *const c_char,
+                                    message_raw: *const u8,
+                                    message_len: u32,
+                                    signature_raw_p: *mut *const u8,
+                                    signature_len_p: *mut u32,
+                                    signature_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Verify a signature produced by the key of the key backend
+///
+/// #Params
+/// verkey: full verkey of the key (with crypto type suffix)
+/// message_raw: a pointer to first byte of message that was signed
+/// message_len: a message length
+/// signature_raw: a pointer to first byte of signature to be verified
+/// signature_len: a signature length
+/// valid_p: pointer to the verification result
+pub type KeyBackendVerify = extern fn(verkey: *const c_char,
+                                      message_raw: *const u8,
+                                      message_len: u32,
+                                      signature_raw: *const u8,
+                                      signature_len: u32,
+                                      valid_p: *mut bool) -> ErrorCode;
+
+/// Encrypt a message by authenticated-encryption scheme (used by authcrypt and pack_msg)
+///
+/// #Params
+/// my_verkey: full verkey of the sender key that belongs to the key backend
+/// their_verkey: verkey of the recipient
+/// message_raw: a pointer to first byte of message to be encrypted
+/// message_len: a message length
+/// nonce_raw: a pointer to first byte of nonce generated by libindy
+/// nonce_len: a nonce length
+/// encrypted_raw_p: pointer to the first byte of encrypted message
+/// encrypted_len_p: pointer to the length of encrypted message
+/// encrypted_handle_p: pointer to the handle that will be passed to free_buffer to release encrypted message
+pub type KeyBackendCryptoBox = extern fn(my_verkey: *const c_char,
+                                         their_verkey: *const c_char,
+                                         message_raw: *const u8,
+                                         message_len: u32,
+                                         nonce_raw: *const u8,
+                                         nonce_len: u32,
+                                         encrypted_raw_p: *mut *const u8,
+                                         encrypted_len_p: *mut u32,
+                                         encrypted_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Release the buffer allocated by the key backend
+///
+/// #Params
+/// buffer_handle: buffer handle returned by one of key backend handlers
+pub type KeyBackendFreeBuffer = extern fn(buffer_handle: IndyHandle) -> ErrorCode;
+
+/// Register external key backend (secure enclave, HSM, KMS and etc.) that keeps non-exportable keys.
+///
+/// Keys created with "crypto_type" equal to registered type are stored in the wallet without secret part.
+/// indy_create_key, indy_create_and_store_my_did, indy_crypto_sign, indy_crypto_verify, indy_sign_request and
+/// authcrypt part of indy_pack_message delegate to the backend handlers for verkeys with ":<crypto_type>" suffix.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// crypto_type: Crypto type name that identifies keys of the backend. Must not be equal to built-in crypto types.
+/// create_key: "create_key" operation handler
+/// sign: "sign" operation handler
+/// verify: "verify" operation handler
+/// crypto_box: (optional) "crypto_box" operation handler. If not set authenticated encryption with backend keys isn't supported.
+/// free_buffer: Handler that allows to de-allocate buffers allocated in backend code
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_register_key_backend(command_handle: CommandHandle,
+                                        crypto_type: *const c_char,
+                                        create_key: Option<KeyBackendCreateKey>,
+                                        sign: Option<KeyBackendSign>,
+                                        verify: Option<KeyBackendVerify>,
+                                        crypto_box: Option<KeyBackendCryptoBox>,
+                                        free_buffer: Option<KeyBackendFreeBuffer>,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_key_backend: >>> crypto_type: {:?}", crypto_type);
+
+    check_useful_c_str!(crypto_type, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(create_key, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(sign, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(verify, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(free_buffer, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_register_key_backend: entities >>> crypto_type: {:?}", crypto_type);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::RegisterKeyBackend(
+            crypto_type,
+            PluggedKeyBackend::new(create_key, sign, verify, crypto_box, free_buffer),
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_key_backend: cb command_handle: {:?} err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_key_backend: <<< res: {:?}", res);
+
+    res
+}
+
 
 /// Creates keys pair and stores in the wallet.
 ///
@@ -20,7 +157,9 @@ use libc::c_char;
 /// {
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
-///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently 'ed25519', 'secp256k1', 'p256' values
+///                            // or crypto type registered with indy_register_key_backend are supported for this field.
+///                            // 'secp256k1' and 'p256' keys can be used only for signing and verification.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -285,6 +424,142 @@ pub  extern fn indy_crypto_verify(command_handle: CommandHandle,
     res
 }
 
+/// Signs a payload with a key and produces JSON Web Signature (RFC 7515).
+///
+/// Algorithm of signature is defined by crypto type of the key:
+/// "EdDSA" for ed25519, "ES256K" for secp256k1 and "ES256" for p256.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// signer_vk: id (verkey) of payload signer. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// payload_raw: a pointer to first byte of payload to be signed
+/// payload_len: a payload length
+/// options_json: (optional) signing options as json:
+/// {
+///     "serialization": (optional) "compact" or "json", "compact" by default,
+///     "detached": (optional) if true payload isn't included into JWS (RFC 7515 Appendix F), false by default,
+///     "did": (optional) DID of the signer used to build "kid" header. Unqualified DID is qualified with "sov" method
+///            and "#key-1" fragment is appended if DID has no fragment.
+///            If not set "kid" is did:key DID URL of signer_vk.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// JWS string:
+/// for compact serialization:
+///     b64URLencode({"alg": <alg>, "kid": <kid>}).b64URLencode(payload).b64URLencode(signature)
+/// for json serialization:
+/// {
+///     "payload": b64URLencode(payload),
+///     "signatures": [
+///         {
+///             "protected": b64URLencode({"alg": <alg>}),
+///             "header": {"kid": <kid>},
+///             "signature": b64URLencode(signature)
+///         }
+///     ]
+/// }
+/// Encoded payload is empty (compact) or omitted (json) for detached signature.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_sign_jws(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    signer_vk: *const c_char,
+                                    payload_raw: *const u8,
+                                    payload_len: u32,
+                                    options_json: *const c_char,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode,
+                                                         jws: *const c_char)>) -> ErrorCode {
+    trace!("indy_crypto_sign_jws: >>> wallet_handle: {:?}, signer_vk: {:?}, payload_raw: {:?}, payload_len: {:?}, options_json: {:?}",
+           wallet_handle, signer_vk, payload_raw, payload_len, options_json);
+
+    check_useful_c_str!(signer_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(payload_raw, payload_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_validatable_json!(options_json, ErrorCode::CommonInvalidParam6, JwsOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_crypto_sign_jws: entities >>> wallet_handle: {:?}, signer_vk: {:?}, payload_raw: {:?}, payload_len: {:?}, options_json: {:?}",
+           wallet_handle, signer_vk, payload_raw, payload_len, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::CryptoSignJws(
+            wallet_handle,
+            signer_vk,
+            payload_raw,
+            options_json.unwrap_or_default(),
+            boxed_callback_string!("indy_crypto_sign_jws", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_sign_jws: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verify JSON Web Signature (RFC 7515) with a verkey.
+///
+/// Both compact and json serializations are accepted. Json serialization must contain a single signature.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// signer_vk: verkey of the payload signer
+/// jws: JWS string to be verified
+/// detached_payload_raw: (optional) a pointer to first byte of detached payload. Must be set only if JWS has no payload.
+/// detached_payload_len: a detached payload length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_verify_jws(command_handle: CommandHandle,
+                                      signer_vk: *const c_char,
+                                      jws: *const c_char,
+                                      detached_payload_raw: *const u8,
+                                      detached_payload_len: u32,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           valid: bool)>) -> ErrorCode {
+    trace!("indy_crypto_verify_jws: >>> signer_vk: {:?}, jws: {:?}, detached_payload_raw: {:?}, detached_payload_len: {:?}",
+           signer_vk, jws, detached_payload_raw, detached_payload_len);
+
+    check_useful_c_str!(signer_vk, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(jws, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_byte_array!(detached_payload_raw, detached_payload_len, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_verify_jws: entities >>> signer_vk: {:?}, jws: {:?}, detached_payload_raw: {:?}, detached_payload_len: {:?}",
+           signer_vk, jws, detached_payload_raw, detached_payload_len);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::CryptoVerifyJws(
+            signer_vk,
+            jws,
+            detached_payload_raw,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_crypto_verify_jws: valid: {:?}", valid);
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_verify_jws: <<< res: {:?}", res);
+
+    res
+}
+
 /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_pack_message() INSTEAD ****
 /// Encrypt a message by authenticated-encryption scheme.
 ///
@@ -763,3 +1038,191 @@ pub extern fn indy_unpack_message(
 
     res
 }
+
+/// Packs a message into DIDComm v2 format (Experimental)
+///
+/// Plaintext can be wrapped into JWS signed envelope and/or encrypted as JWE in general JSON serialization.
+/// Anoncrypt uses X25519 ECDH-ES+A256KW key management, authcrypt uses X25519 ECDH-1PU+A256KW.
+/// Content is encrypted with A256CBC-HS512. Only ed25519 keys can be used for encryption,
+/// secp256k1 and p256 keys can be used for signing.
+///
+/// Key ids can be either verkeys or did:key DID URLs. Verkeys are converted to did:key DID URLs
+/// in the message headers.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// message: a pointer to the first byte of the message to be packed
+/// message_len: the length of the message
+/// receiver_kids: a json list of receiver's key ids the message is being encrypted for.
+///                Can be empty if only signed message is needed.
+///                Example:
+///                "["did:key:z6Mk...#z6LS...", <receiver verkey>]"
+/// sender_kid: the sender's key id. When null pointer is used in this parameter, anoncrypt is used
+/// signer_kid: the signer's key id. When set the message is signed before encryption
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// an encrypted message:
+/// {
+///     "protected": b64URLencode({
+///         "typ": "application/didcomm-encrypted+json",
+///         "alg": "ECDH-1PU+A256KW" or "ECDH-ES+A256KW",
+///         "enc": "A256CBC-HS512",
+///         "epk": {"kty": "OKP", "crv": "X25519", "x": <b64URLencode(ephemeral public key)>},
+///         "apv": b64URLencode(sha256(<sorted recipient kids joined with ".">)),
+///         "skid": <sender kid>, // authcrypt only
+///         "apu": b64URLencode(<sender kid>) // authcrypt only
+///     }),
+///     "recipients": [
+///         {
+///             "header": {"kid": <recipient kid>},
+///             "encrypted_key": b64URLencode(A256KW(kek, cek))
+///         }
+///     ],
+///     "iv": b64URLencode(iv),
+///     "ciphertext": b64URLencode(ciphertext),
+///     "tag": b64URLencode(tag)
+/// }
+///
+/// OR signed message if no receivers passed:
+/// {
+///     "payload": b64URLencode(message),
+///     "signatures": [
+///         {
+///             "protected": b64URLencode({"typ": "application/didcomm-signed+json", "alg": "EdDSA" or "ES256K" or "ES256"}),
+///             "header": {"kid": <signer kid>},
+///             "signature": b64URLencode(signature)
+///         }
+///     ]
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_message_v2(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    message: *const u8,
+    message_len: u32,
+    receiver_kids: *const c_char,
+    sender_kid: *const c_char,
+    signer_kid: *const c_char,
+    cb: Option<extern fn(xcommand_handle: CommandHandle, err: ErrorCode, packed_data: *const u8, packed_len: u32)>,
+) -> ErrorCode {
+    trace!("indy_pack_message_v2: >>> wallet_handle: {:?}, message: {:?}, message_len {:?},\
+            receiver_kids: {:?}, sender_kid: {:?}, signer_kid: {:?}", wallet_handle, message, message_len, receiver_kids, sender_kid, signer_kid);
+
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(receiver_kids, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(sender_kid, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(signer_kid, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_pack_message_v2: entities >>> wallet_handle: {:?}, message: {:?}, message_len {:?},\
+            receiver_kids: {:?}, sender_kid: {:?}, signer_kid: {:?}", wallet_handle, message, message_len, receiver_kids, sender_kid, signer_kid);
+
+    //parse json array of key ids
+    let receiver_list = match serde_json::from_str::<Vec<String>>(&receiver_kids) {
+        Ok(x) => x,
+        Err(_) => {
+            return IndyError::from_msg(IndyErrorKind::InvalidParam(4), "Invalid RecipientKids has been passed").into();
+        },
+    };
+
+    //break early and error out if message can be neither encrypted nor signed
+    if receiver_list.is_empty() && signer_kid.is_none() {
+        return IndyError::from_msg(IndyErrorKind::InvalidParam(4), "Empty RecipientKids has been passed without signer").into();
+    }
+
+    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::PackMessageV2(
+        message,
+        receiver_list,
+        sender_kid,
+        signer_kid,
+        wallet_handle,
+        Box::new(move |result| {
+            let (err, packed) = prepare_result_1!(result, Vec::new());
+            trace!("indy_pack_message_v2: packed: {:?}", packed);
+            let (packed_data, packed_len) = ctypes::vec_to_pointer(&packed);
+            cb(command_handle, err, packed_data, packed_len)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_message_v2: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unpacks a message packed by indy_pack_message_v2 or indy_pack_message (Experimental)
+///
+/// Format of the message is detected automatically: DIDComm v2 encrypted message (JWE),
+/// DIDComm v2 signed message (JWS) or JWE-like message produced by indy_pack_message.
+/// Signed envelope inside of encrypted message is verified and unwrapped.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// packed_data: a pointer to the first byte of the message to be unpacked
+/// packed_len: the length of the message in bytes
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// {
+///     message: <decrypted message>,
+///     recipient_kid: <recipient kid>, // if message was encrypted
+///     sender_kid: <sender kid>, // if message was authcrypted
+///     signer_kid: <signer kid> // if message was signed
+/// }
+/// For messages produced by indy_pack_message kids are equal to verkeys.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_unpack_message_v2(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    packed_data: *const u8,
+    packed_len: u32,
+    cb: Option<
+        extern fn(
+            xcommand_handle: CommandHandle,
+            err: ErrorCode,
+            res_json_data : *const u8,
+            res_json_len : u32
+        ),
+    >,
+) -> ErrorCode {
+    trace!("indy_unpack_message_v2: >>> wallet_handle: {:?}, packed_data: {:?}, packed_len {:?}",
+           wallet_handle, packed_data, packed_len);
+
+    check_useful_c_byte_array!(packed_data, packed_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_unpack_message_v2: entities >>> wallet_handle: {:?}, packed_data: {:?}, packed_len {:?}",
+           wallet_handle, packed_data, packed_len);
+
+    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::UnpackMessageV2(
+        packed_data,
+        wallet_handle,
+        Box::new(move |result| {
+            let (err, res_json) = prepare_result_1!(result, Vec::new());
+            trace!("indy_unpack_message_v2: cb command_handle: {:?}, err: {:?}, res_json: {:?}",
+                   command_handle, err, res_json);
+            let (res_json_data, res_json_len) = ctypes::vec_to_pointer(&res_json);
+            cb(command_handle, err, res_json_data, res_json_len)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_message_v2: <<< res: {:?}", res);
+
+    res
+}
@@ -33,7 +33,8 @@ use crate::domain::ledger::attrib::Endpoint;
 ///     "seed": string, (optional) Seed that allows deterministic did creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               currently 'ed25519', 'secp256k1' and 'p256' values are supported for this field;
+///               'secp256k1' and 'p256' keys can be used only for signing and verification)
 ///     "cid": bool, (optional; if not set then false is used;)
 ///     "method_name": string, (optional) method name to create fully qualified did.
 /// }
@@ -368,6 +369,69 @@ pub extern fn indy_key_for_local_did(command_handle: CommandHandle,
     res
 }
 
+/// Returns ver key (key id) the given DID had at the given time.
+///
+/// The wallet keeps dated history of keys for DIDs: key of my DID is recorded on
+/// "indy_create_and_store_my_did" and on every "indy_replace_keys_apply" call, key of their DID
+/// is recorded on "indy_store_their_did" call and when their DID is fetched from the ledger.
+///
+/// If history stored in the wallet doesn't cover requested time the call falls back to
+/// GET_NYM request with timestamp sent to the ledger. Result of this request isn't stored in the wallet.
+///
+/// Note that history is collected only starting from the version of libindy that supports this call.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// pool_handle:   Pool handle (created by open_pool).
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID to resolve key.
+/// timestamp - Time (POSIX timestamp in seconds) to get the key for.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - key - The DIDs ver key (key id) at the given time.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_key_for_did_at(command_handle: CommandHandle,
+                                  pool_handle: PoolHandle,
+                                  wallet_handle: WalletHandle,
+                                  did: *const c_char,
+                                  timestamp: u64,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode,
+                                                       key: *const c_char)>) -> ErrorCode {
+    trace!("indy_key_for_did_at: >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, timestamp: {:?}", pool_handle, wallet_handle, did, timestamp);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_key_for_did_at: entities >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, timestamp: {:?}", pool_handle, wallet_handle, did, timestamp);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::KeyForDidAt(
+            pool_handle,
+            wallet_handle,
+            did,
+            timestamp,
+            boxed_callback_string!("indy_key_for_did_at", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_key_for_did_at: <<< res: {:?}", res);
+
+    res
+}
+
 /// Set/replaces endpoint information for the given DID.
 ///
 /// #Params
@@ -485,6 +549,87 @@ pub extern fn indy_get_endpoint_for_did(command_handle: CommandHandle,
     res
 }
 
+/// Resolves DID into W3C DID Document built from NYM and endpoint ATTRIB transactions written to the ledger.
+///
+/// Unqualified DIDs are resolved as "did:sov" DIDs.
+/// "did:indy" DIDs can contain ledger namespace (did:indy:<namespace>:<id>) which isn't checked against the pool.
+/// Note that the result is not cached in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// pool_handle: Pool handle (created by open_pool).
+/// did - The DID to resolve ("did:sov", "did:indy" or unqualified).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - resolution_result_json - DID Document with resolution metadata:
+/// {
+///     "didDocument": {
+///         "@context": ["https://www.w3.org/ns/did/v1"],
+///         "id": <did>,
+///         "verificationMethod": [{
+///             "id": <did>#key-1,
+///             "type": "Ed25519VerificationKey2018",
+///             "controller": <did>,
+///             "publicKeyBase58": <full verkey of NYM>
+///         }],
+///         "authentication": [<did>#key-1],
+///         "assertionMethod": [<did>#key-1],
+///         "service": [ // omitted if DID has no endpoint ATTRIB
+///             {
+///                 "id": <did>#<type>,
+///                 "type": "endpoint" or "did-communication" or "DIDCommMessaging",
+///                 "serviceEndpoint": <endpoint>,
+///                 "priority": optional<int>,
+///                 "recipientKeys": optional<[<key id>]>,
+///                 "routingKeys": optional<[<verkey>]>
+///             }
+///         ]
+///     },
+///     "didResolutionMetadata": {
+///         "contentType": "application/did+ld+json",
+///         "seqNo": <seqNo of NYM transaction>,
+///         "txnTime": <time of NYM transaction>,
+///         "endpoint": optional<response metadata of endpoint ATTRIB> // see indy_get_response_metadata
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// Pool*
+#[no_mangle]
+pub extern fn indy_resolve_did(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               did: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    resolution_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_resolve_did: >>> pool_handle: {:?}, did: {:?}", pool_handle, did);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_resolve_did: entities >>> pool_handle: {:?}, did: {:?}", pool_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::ResolveDid(
+            pool_handle,
+            did,
+            boxed_callback_string!("indy_resolve_did", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_resolve_did: <<< res: {:?}", res);
+
+    res
+}
+
 /// Saves/replaces the meta information for the giving DID in the wallet.
 ///
 /// #Params
@@ -12,8 +12,10 @@ use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistr
 use crate::domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use crate::domain::anoncreds::schema::{Schema, SchemaId};
 use crate::domain::crypto::did::DidValue;
-use crate::domain::ledger::auth_rule::{AuthRules, Constraint};
+use crate::domain::ledger::auth_rule::{AuthRule, AuthRules, AuthRuleSigner, Constraint};
 use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData};
+use crate::domain::ledger::batch::SubmitBatchOptions;
+use crate::domain::ledger::endorser::PendingTransaction;
 use crate::domain::ledger::node::NodeOperationData;
 use crate::domain::ledger::pool::Schedule;
 
@@ -23,6 +25,10 @@ use crate::domain::ledger::pool::Schedule;
 /// sign key (see wallet_sign), and sends signed request message
 /// to validator pool (see write_request).
 ///
+/// If transaction author agreement acceptance is registered on the pool handle
+/// (see indy_set_txn_author_agreement_acceptance_for_pool) and request is a write to DOMAIN ledger
+/// without taaAcceptance field, the acceptance is appended to the request before signing.
+///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// pool_handle: pool handle (created by open_pool_ledger).
@@ -118,6 +124,136 @@ pub extern fn indy_submit_request(command_handle: CommandHandle,
     res
 }
 
+/// Publishes request message to validator pool (no signing, like submit_request)
+/// and returns the reply together with per node results of consensus.
+///
+/// Unlike submit_request, the callback is called with the details also
+/// in case pool couldn't reach consensus, so a caller can find out which nodes
+/// rejected the request and why.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: Request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result with consensus details as json.
+/// {
+///     "reply": <json> - reply agreed by f+1 nodes, null if consensus is not reached,
+///     "consensus": { - null for read requests which are sent to a single node
+///         "reached": bool - whether f+1 nodes agreed on the same reply,
+///         "f": int - number of tolerated faulty nodes,
+///         "nodes": {
+///             <node alias>: {
+///                 "acked": bool - whether node acknowledged the request (REQACK),
+///                 "status": string - one of "REPLY", "REQNACK", "REJECT", "TIMEOUT", "NO_REPLY",
+///                 "reason": Option<string> - reason of REQNACK or REJECT returned by node,
+///                 "errorKind": Option<string> - classified reason:
+///                     "AuthRule", "TaaMismatch", "InvalidSignature", "InvalidRequest", "Other"
+///             },
+///             ...
+///         }
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_request_with_details(command_handle: CommandHandle,
+                                               pool_handle: PoolHandle,
+                                               request_json: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    response_with_details_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_request_with_details: >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_submit_request_with_details: entities >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SubmitRequestWithDetails(
+            pool_handle,
+            request_json,
+            boxed_callback_string!("indy_submit_request_with_details", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_request_with_details: <<< res: {:?}", res);
+
+    res
+}
+
+/// Publishes batch of request messages to validator pool (no signing, like submit_request).
+///
+/// Requests are sent to the validator pool as is. It's assumed that they are already prepared.
+/// Requests are pipelined: up to `max_in_flight` of them are sent without waiting for replies.
+/// Pool sends up to `conn_limit` (pool open option) of them over each connection, so if `max_in_flight`
+/// exceeds it requests in flight are spread across several pool connections.
+/// Consensus and state proof verification are performed for each request independently,
+/// so failure of one request doesn't affect the others.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// requests_json: Array of request data jsons.
+///     [{request_1}, {request_2}, ...]
+/// options_json: (Optional) batch options.
+///     {
+///         "max_in_flight": int - (Optional) maximum number of requests sent to the pool
+///                                without reply received. `conn_limit` pool open option
+///                                by default, so requests in flight share single pool connection.
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Array of per-request results in the same order as requests:
+///     [
+///         {"reply": {reply json received from the pool}},
+///         {"error": {"code": int - error code, "message": string - error description}},
+///         ...
+///     ]
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_requests_batch(command_handle: CommandHandle,
+                                         pool_handle: PoolHandle,
+                                         requests_json: *const c_char,
+                                         options_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              results_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_requests_batch: >>> pool_handle: {:?}, requests_json: {:?}, options_json: {:?}", pool_handle, requests_json, options_json);
+
+    check_useful_json!(requests_json, ErrorCode::CommonInvalidParam3, Vec<serde_json::Value>);
+    check_useful_opt_validatable_json!(options_json, ErrorCode::CommonInvalidParam4, SubmitBatchOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_submit_requests_batch: entities >>> pool_handle: {:?}, requests_json: {:?}, options_json: {:?}", pool_handle, requests_json, options_json);
+
+    let requests = requests_json.iter().map(serde_json::Value::to_string).collect();
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SubmitRequestsBatch(
+            pool_handle,
+            requests,
+            options_json.unwrap_or_default(),
+            boxed_callback_string!("indy_submit_requests_batch", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_requests_batch: <<< res: {:?}", res);
+
+    res
+}
+
 /// Send action to particular nodes of validator pool.
 ///
 /// The list of requests can be send:
@@ -1751,7 +1887,8 @@ pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHan
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
-/// response: response of write or get request.
+/// response: response of write or get request
+///     or result of indy_submit_request_with_details.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -1760,7 +1897,9 @@ pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHan
 ///     "seqNo": Option<u64> - transaction sequence number,
 ///     "txnTime": Option<u64> - transaction ordering time,
 ///     "lastSeqNo": Option<u64> - the latest transaction seqNo for particular Node,
-///     "lastTxnTime": Option<u64> - the latest transaction ordering time for particular Node
+///     "lastTxnTime": Option<u64> - the latest transaction ordering time for particular Node,
+///     "consensus": Option<json> - per node results of consensus,
+///         only for result of indy_submit_request_with_details (see its description)
 /// }
 ///
 /// #Errors
@@ -1792,6 +1931,63 @@ pub extern fn indy_get_response_metadata(command_handle: CommandHandle,
     res
 }
 
+/// Verifies that transaction returned in GET_TXN response is included into the ledger.
+///
+/// GET_TXN response contains audit path of the transaction and the ledger root hash
+/// signed by BLS multi signature of the pool nodes. This function checks that audit path
+/// leads from the transaction to the root hash and that the root hash is signed by enough nodes
+/// of the pool (keys of the nodes are taken from the local copy of the pool ledger).
+/// So the transaction can be trusted even if the response was received from a single node
+/// or from a third party.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// get_txn_response: response of GET_TXN request (see `indy_build_get_txn_request`).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// verified inclusion info.
+/// {
+///     "seqNo": u64 - sequence number of the transaction,
+///     "txnTime": Option<u64> - transaction ordering time,
+///     "ledgerId": Option<u64> - id of the ledger signed by nodes,
+///     "ledgerSize": u64 - size of the ledger the audit path is built for,
+///     "rootHash": string - verified ledger root hash (base58),
+///     "multiSignatureTimestamp": Option<u64> - time when the root hash was signed by nodes
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_verify_txn_inclusion(command_handle: CommandHandle,
+                                        pool_handle: PoolHandle,
+                                        get_txn_response: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             txn_inclusion_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verify_txn_inclusion: >>> pool_handle: {:?}, get_txn_response: {:?}", pool_handle, get_txn_response);
+
+    check_useful_c_str!(get_txn_response, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_verify_txn_inclusion: entities >>> pool_handle: {:?}, get_txn_response: {:?}", pool_handle, get_txn_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::VerifyTxnInclusion(
+            pool_handle,
+            get_txn_response,
+            boxed_callback_string!("indy_verify_txn_inclusion", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_txn_inclusion: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
 ///
 /// #Params
@@ -2093,6 +2289,99 @@ pub extern fn indy_build_get_auth_rule_request(command_handle: CommandHandle,
     res
 }
 
+/// Checks locally whether a set of signers satisfies the auth rule constraint for a ledger action.
+///
+/// The rule is selected from `auth_rules_json` in the same way as the ledger does it:
+/// rules with exact `field`, `old_value` and `new_value` take precedence over rules with `*` values.
+/// Nothing is sent to the ledger, so the result can be used to predict whether a write will be rejected.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// auth_rules_json: list of auth rules in the format of `data` field of GET_AUTH_RULE reply:
+/// [
+///     {
+///         auth_type: ledger transaction alias or associated value,
+///         auth_action: type of an action ("ADD" or "EDIT"),
+///         field: transaction field,
+///         old_value: (Optional) old value of a field,
+///         new_value: (Optional) new value of a field,
+///         constraint: set of constraints (see `indy_build_auth_rule_request`)
+///     }
+/// ]
+/// txn_type: ledger transaction alias or associated value.
+/// action: type of an action. Can be either "ADD" or "EDIT".
+/// field: transaction field.
+/// old_value: (Optional) old value of a field (mandatory for EDIT action).
+/// new_value: (Optional) new value of a field.
+/// signers_json: list of transaction signers:
+/// [
+///     {
+///         role: (Optional) role of the signer on the ledger (alias or associated value, null for common USER),
+///         isOwner: (Optional) whether the signer is an owner of the transaction (false by default),
+///         offLedger: (Optional) whether the signer DID isn't present on the ledger (false by default)
+///     }
+/// ]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Check result json:
+/// {
+///     allowed: bool - whether the signers satisfy the constraint,
+///     explanation: string - human readable explanation of the constraint evaluation,
+///     fees: [string] - fees aliases set in the metadata of satisfied constraints
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_check_auth_rule(command_handle: CommandHandle,
+                                   auth_rules_json: *const c_char,
+                                   txn_type: *const c_char,
+                                   action: *const c_char,
+                                   field: *const c_char,
+                                   old_value: *const c_char,
+                                   new_value: *const c_char,
+                                   signers_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_check_auth_rule: >>> auth_rules_json: {:?}, txn_type: {:?}, action: {:?}, field: {:?}, \
+    old_value: {:?}, new_value: {:?}, signers_json: {:?}",
+           auth_rules_json, txn_type, action, field, old_value, new_value, signers_json);
+
+    check_useful_json!(auth_rules_json, ErrorCode::CommonInvalidParam2, Vec<AuthRule>);
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(action, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(field, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(old_value, ErrorCode::CommonInvalidParam6);
+    check_useful_opt_c_str!(new_value, ErrorCode::CommonInvalidParam7);
+    check_useful_json!(signers_json, ErrorCode::CommonInvalidParam8, Vec<AuthRuleSigner>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_check_auth_rule: entities >>> auth_rules_json: {:?}, txn_type: {:?}, action: {:?}, field: {:?}, \
+    old_value: {:?}, new_value: {:?}, signers_json: {:?}",
+           auth_rules_json, txn_type, action, field, old_value, new_value, signers_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::CheckAuthRule(
+            auth_rules_json,
+            txn_type,
+            action,
+            field,
+            old_value,
+            new_value,
+            signers_json,
+            boxed_callback_string!("indy_check_auth_rule", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_check_auth_rule: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
 ///
 /// EXPERIMENTAL
@@ -2454,6 +2743,81 @@ pub extern fn indy_append_txn_author_agreement_acceptance_to_request(command_han
     res
 }
 
+/// Register transaction author agreement acceptance on the pool handle.
+/// indy_sign_and_submit_request will append it to writes sent through the pool
+/// which require transaction author agreement (NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF, REVOC_REG_ENTRY)
+/// and don't contain acceptance data yet.
+///
+/// EXPERIMENTAL
+///
+/// Before the first write the latest agreement is read from the ledger (GET_TXN_AUTHR_AGRMT) and cached for the pool handle.
+/// The cached agreement is read again after 10 minutes or when the pool rejects a write because of the acceptance.
+/// Writes fail without sending to the pool if the accepted agreement isn't the latest one or has been retired.
+/// Registered acceptance is dropped when the pool is closed. Repeated call replaces the acceptance.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// text and version - (optional) raw data about TAA from ledger.
+///     These parameters should be passed together.
+///     These parameters are required if taa_digest parameter is omitted.
+/// taa_digest - (optional) digest on text and version.
+///     Digest is sha256 hash calculated on concatenated strings: version || text.
+///     This parameter is required if text and version parameters are omitted.
+/// mechanism - mechanism how user has accepted the TAA
+/// time - UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_set_txn_author_agreement_acceptance_for_pool(command_handle: CommandHandle,
+                                                                pool_handle: PoolHandle,
+                                                                text: *const c_char,
+                                                                version: *const c_char,
+                                                                taa_digest: *const c_char,
+                                                                mechanism: *const c_char,
+                                                                time: u64,
+                                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                     err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_txn_author_agreement_acceptance_for_pool: >>> pool_handle: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, \
+        mechanism: {:?}, time: {:?}",
+           pool_handle, text, version, taa_digest, mechanism, time);
+
+    check_useful_opt_c_str!(text, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(version, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(taa_digest, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(mechanism, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_set_txn_author_agreement_acceptance_for_pool: entities >>> pool_handle: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, \
+        mechanism: {:?}, time: {:?}",
+           pool_handle, text, version, taa_digest, mechanism, time);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::SetTxnAuthorAgreementAcceptanceForPool(
+                pool_handle,
+                text,
+                version,
+                taa_digest,
+                mechanism,
+                time,
+                Box::new(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_set_txn_author_agreement_acceptance_for_pool:");
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_txn_author_agreement_acceptance_for_pool: <<< res: {:?}", res);
+
+    res
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -2501,5 +2865,342 @@ pub extern fn indy_append_request_endorser(command_handle: CommandHandle,
 
     trace!("indy_append_request_endorser: <<< res: {:?}", res);
 
+    res
+}
+
+/// Prepares a request for an Endorser.
+///
+/// Appends Endorser to the request, signs it by the Transaction Author and wraps it
+/// into a pending transaction which can be passed to the Endorser (see `indy_endorse_pending_transaction`).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the Transaction Author. Must be the same as an identifier of the request.
+/// request_json: original request.
+/// endorser_did: DID of the Endorser that will submit the transaction.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Pending transaction json
+/// {
+///     ver: "1.0",
+///     txnType: type of the request,
+///     author: short DID of the Transaction Author,
+///     endorser: short DID of the Endorser,
+///     request: request with Endorser field appended and signed by the Transaction Author
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_prepare_request_for_endorser(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                submitter_did: *const c_char,
+                                                request_json: *const c_char,
+                                                endorser_did: *const c_char,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     pending_txn_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prepare_request_for_endorser: >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}, endorser_did: {:?}",
+           wallet_handle, submitter_did, request_json, endorser_did);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam4);
+    check_useful_validatable_string!(endorser_did, ErrorCode::CommonInvalidParam5, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_prepare_request_for_endorser: entities >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}, endorser_did: {:?}",
+           wallet_handle, submitter_did, request_json, endorser_did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::PrepareRequestForEndorser(
+                wallet_handle,
+                submitter_did,
+                request_json,
+                endorser_did,
+                boxed_callback_string!("indy_prepare_request_for_endorser", cb, command_handle)
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prepare_request_for_endorser: <<< res: {:?}", res);
+
+    res
+}
+
+/// Endorses a pending transaction prepared by `indy_prepare_request_for_endorser` and submits it to validator pool.
+///
+/// Before co-signing the request roles of the Endorser and the Transaction Author are read from the ledger (GET_NYM)
+/// and their signatures are checked against auth rules set on the ledger (GET_AUTH_RULE) for adding the transaction
+/// (see `indy_check_auth_rule`). Author not written to the ledger yet is checked as an off-ledger signer.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// endorser_did: DID of the Endorser. Must be the same as the Endorser of the pending transaction.
+/// pending_txn_json: pending transaction json (see `indy_prepare_request_for_endorser`).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_endorse_pending_transaction(command_handle: CommandHandle,
+                                               pool_handle: PoolHandle,
+                                               wallet_handle: WalletHandle,
+                                               endorser_did: *const c_char,
+                                               pending_txn_json: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    request_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_endorse_pending_transaction: >>> pool_handle: {:?}, wallet_handle: {:?}, endorser_did: {:?}, pending_txn_json: {:?}",
+           pool_handle, wallet_handle, endorser_did, pending_txn_json);
+
+    check_useful_validatable_string!(endorser_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_json!(pending_txn_json, ErrorCode::CommonInvalidParam5, PendingTransaction);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_endorse_pending_transaction: entities >>> pool_handle: {:?}, wallet_handle: {:?}, endorser_did: {:?}, pending_txn_json: {:?}",
+           pool_handle, wallet_handle, endorser_did, pending_txn_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::EndorsePendingTransaction(
+                pool_handle,
+                wallet_handle,
+                endorser_did,
+                pending_txn_json,
+                boxed_callback_string!("indy_endorse_pending_transaction", cb, command_handle)
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_endorse_pending_transaction: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a Rich Schema object request (JSON_LD_CONTEXT, RICH_SCHEMA, RICH_SCHEMA_ENCODING,
+/// RICH_SCHEMA_MAPPING, RICH_SCHEMA_CRED_DEF or RICH_SCHEMA_PRES_DEF depending on `rs_type`).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// rs_id: identifier of the Rich Schema object.
+/// rs_content: JSON-LD content of the object as a string.
+///     Must contain `@context` for `ctx` objects and `@id` equal to `rs_id` for others.
+/// rs_name: name of the Rich Schema object.
+/// rs_version: version of the Rich Schema object.
+/// rs_type: type of the Rich Schema object. One of:
+///     "ctx" - JSON-LD context
+///     "sch" - Rich Schema
+///     "enc" - Encoding
+///     "map" - Mapping
+///     "cdf" - Credential Definition
+///     "pdf" - Presentation Definition
+/// ver: version of the Rich Schema object json format.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_rich_schema_request(command_handle: CommandHandle,
+                                             submitter_did: *const c_char,
+                                             rs_id: *const c_char,
+                                             rs_content: *const c_char,
+                                             rs_name: *const c_char,
+                                             rs_version: *const c_char,
+                                             rs_type: *const c_char,
+                                             ver: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_rich_schema_request: >>> submitter_did: {:?}, rs_id: {:?}, rs_content: {:?}, rs_name: {:?}, rs_version: {:?}, \
+        rs_type: {:?}, ver: {:?}", submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(rs_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(rs_content, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(rs_name, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(rs_version, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(rs_type, ErrorCode::CommonInvalidParam7);
+    check_useful_c_str!(ver, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_build_rich_schema_request: entities >>> submitter_did: {:?}, rs_id: {:?}, rs_content: {:?}, rs_name: {:?}, rs_version: {:?}, \
+        rs_type: {:?}, ver: {:?}", submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildRichSchemaRequest(
+            submitter_did,
+            rs_id,
+            rs_content,
+            rs_name,
+            rs_version,
+            rs_type,
+            ver,
+            boxed_callback_string!("indy_build_rich_schema_request", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_rich_schema_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get a Rich Schema object by its identifier.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// rs_id: identifier of the Rich Schema object.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_rich_schema_object_by_id_request(command_handle: CommandHandle,
+                                                              submitter_did: *const c_char,
+                                                              rs_id: *const c_char,
+                                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                   err: ErrorCode,
+                                                                                   request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_rich_schema_object_by_id_request: >>> submitter_did: {:?}, rs_id: {:?}", submitter_did, rs_id);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(rs_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_get_rich_schema_object_by_id_request: entities >>> submitter_did: {:?}, rs_id: {:?}", submitter_did, rs_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetRichSchemaObjectByIdRequest(
+            submitter_did,
+            rs_id,
+            boxed_callback_string!("indy_build_get_rich_schema_object_by_id_request", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_rich_schema_object_by_id_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+/// Request to get a Rich Schema object by its type, name and version.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// rs_type: type of the Rich Schema object (one of "ctx", "sch", "enc", "map", "cdf", "pdf").
+/// rs_name: name of the Rich Schema object.
+/// rs_version: version of the Rich Schema object.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle,
+                                                                    submitter_did: *const c_char,
+                                                                    rs_type: *const c_char,
+                                                                    rs_name: *const c_char,
+                                                                    rs_version: *const c_char,
+                                                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                         err: ErrorCode,
+                                                                                         request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_rich_schema_object_by_metadata_request: >>> submitter_did: {:?}, rs_type: {:?}, rs_name: {:?}, rs_version: {:?}",
+           submitter_did, rs_type, rs_name, rs_version);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(rs_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(rs_name, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(rs_version, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_build_get_rich_schema_object_by_metadata_request: entities >>> submitter_did: {:?}, rs_type: {:?}, rs_name: {:?}, rs_version: {:?}",
+           submitter_did, rs_type, rs_name, rs_version);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetRichSchemaObjectByMetadataRequest(
+            submitter_did,
+            rs_type,
+            rs_name,
+            rs_version,
+            boxed_callback_string!("indy_build_get_rich_schema_object_by_metadata_request", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_rich_schema_object_by_metadata_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_rich_schema_object_response: response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Rich Schema object json.
+/// {
+///     id: identifier of the object
+///     content: JSON-LD content of the object as a string
+///     rsName: name of the object
+///     rsVersion: version of the object
+///     rsType: type of the object
+///     ver: version of the object json format
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_rich_schema_object_response(command_handle: CommandHandle,
+                                                         get_rich_schema_object_response: *const c_char,
+                                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                              err: ErrorCode,
+                                                                              rich_schema_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_rich_schema_object_response: >>> get_rich_schema_object_response: {:?}", get_rich_schema_object_response);
+
+    check_useful_c_str!(get_rich_schema_object_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_rich_schema_object_response: entities >>> get_rich_schema_object_response: {:?}", get_rich_schema_object_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetRichSchemaObjectResponse(
+            get_rich_schema_object_response,
+            boxed_callback_string!("indy_parse_get_rich_schema_object_response", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_rich_schema_object_response: <<< res: {:?}", res);
+
     res
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 
-use indy_api_types::{ErrorCode, CommandHandle, PoolHandle, INVALID_POOL_HANDLE};
+use indy_api_types::{ErrorCode, CommandHandle, IndyHandle, PoolHandle, INVALID_POOL_HANDLE};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::pool::PoolCommand;
 use crate::domain::pool::{PoolConfig, PoolOpenConfig};
+use crate::services::pool::plugged_transport::PluggedTransportType;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 use indy_api_types::validation::Validatable;
@@ -10,6 +11,50 @@ use indy_api_types::validation::Validatable;
 use serde_json;
 use libc::c_char;
 
+/// Open connection of the pool transport for the pool being opened
+///
+/// #Params
+/// pool_name: name of the pool ledger configuration
+/// transport_handle_p: pointer to the handle of opened connection that will be passed to other transport handlers
+pub type PoolTransportOpen = extern fn(pool_name: *const c_char,
+                                       transport_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Send a message to the pool node. Must not block waiting for the reply.
+///
+/// #Params
+/// transport_handle: handle of the connection returned by open handler
+/// node_alias: alias of the node from the pool ledger
+/// node_address: address of the node from the pool ledger (tcp://<client_ip>:<client_port>)
+/// msg: message to send
+pub type PoolTransportSend = extern fn(transport_handle: IndyHandle,
+                                       node_alias: *const c_char,
+                                       node_address: *const c_char,
+                                       msg: *const c_char) -> ErrorCode;
+
+/// Receive a single message from the pool nodes. Must not block if nothing is received.
+///
+/// #Params
+/// transport_handle: handle of the connection returned by open handler
+/// node_alias_p: pointer to the alias of the node sent message or to NULL if nothing is received
+/// msg_p: pointer to the received message or to NULL if nothing is received
+/// buffer_handle_p: pointer to the handle that will be passed to free_buffer to release node alias and message
+pub type PoolTransportRecv = extern fn(transport_handle: IndyHandle,
+                                       node_alias_p: *mut *const c_char,
+                                       msg_p: *mut *const c_char,
+                                       buffer_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Release the buffer allocated by the pool transport
+///
+/// #Params
+/// buffer_handle: buffer handle returned by recv handler
+pub type PoolTransportFreeBuffer = extern fn(buffer_handle: IndyHandle) -> ErrorCode;
+
+/// Close connection of the pool transport when the pool is closed
+///
+/// #Params
+/// transport_handle: handle of the connection returned by open handler
+pub type PoolTransportClose = extern fn(transport_handle: IndyHandle) -> ErrorCode;
+
 /// Creates a new local pool ledger configuration that can be used later to connect pool nodes.
 ///
 /// #Params
@@ -80,6 +125,23 @@ pub extern fn indy_create_pool_ledger_config(command_handle: CommandHandle,
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
 ///     "socks_proxy": string (optional) - ZMQ socks proxy host name and port (example: proxy1.intranet.company.com:1080)
+///     "transport": string (optional) - transport used to communicate with the pool nodes:
+///         "zmq" (default) - ZMQ connections to the real Indy Nodes listed in the genesis transactions.
+///         "simulated" - in-process simulated pool backed by in-memory ledger (for hermetic testing).
+///         Nodes of the simulated pool sign state proofs with BLS keys derived from their aliases,
+///         so genesis `blskey` values must match them. The ledger is owned by the opened pool and dropped on close.
+///         Any other value - name of the transport registered with indy_register_pool_transport.
+///     "read_cache_size": int (optional) - the number of read replies to cache (0 by default - caching is disabled)
+///         Replies to GET_NYM, GET_ATTRIB, GET_SCHEMA, GET_CRED_DEF, GET_REVOC_REG_DEF, GET_AUTH_RULE and other requests
+///         verified by `state proof` are cached and returned without sending request to the pool
+///         while their BLS multi-signature is fresh according to freshness threshold (see `indy_set_runtime_config`).
+///         Any write request sent through this pool handle drops the cache.
+///     "mirror_ledgers": bool (optional) - whether to keep local copies of DOMAIN and CONFIG ledgers (false by default)
+///         If enabled Libindy catches up DOMAIN and CONFIG ledgers after connecting to the pool
+///         and stores transactions verified by consistency proofs next to the pool ledger.
+///         While the pool is opened new transactions are caught up every minute.
+///         Local copy that is not consistent with the pool is dropped and caught up from scratch.
+///         Local copies can be queried offline with `indy_get_local_txn` and `indy_get_local_txns_by_type`.
 /// }
 ///
 /// #Returns
@@ -313,3 +375,235 @@ pub extern fn indy_set_protocol_version(command_handle: CommandHandle,
 
     res
 }
+
+/// Gets transaction from the local copy of the pool ledger configuration's ledger.
+///
+/// POOL ledger is always stored locally. DOMAIN and CONFIG ledgers are stored
+/// only if pool was opened with `mirror_ledgers` option (see `indy_open_pool_ledger`).
+/// All transactions of the local copy passed consistency proof check against
+/// the state agreed by f+1 nodes during catch-up, so it can be used offline.
+///
+/// #Params
+/// config_name: Name of the pool ledger configuration.
+/// ledger_id: Id of the ledger: 0 - POOL, 1 - DOMAIN, 2 - CONFIG.
+/// seq_no: Sequence number of the transaction (starts from 1).
+///
+/// #Returns
+/// Transaction json as it is stored in the ledger.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_local_txn(command_handle: CommandHandle,
+                                 config_name: *const c_char,
+                                 ledger_id: usize,
+                                 seq_no: usize,
+                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                      err: ErrorCode,
+                                                      txn_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_local_txn: >>> config_name: {:?}, ledger_id: {:?}, seq_no: {:?}", config_name, ledger_id, seq_no);
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_local_txn: entities >>> config_name: {:?}, ledger_id: {:?}, seq_no: {:?}", config_name, ledger_id, seq_no);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::GetLocalTxn(
+            config_name,
+            ledger_id,
+            seq_no,
+            boxed_callback_string!("indy_get_local_txn", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_local_txn: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets transactions of the given type from the local copy of the pool ledger configuration's ledger.
+///
+/// See `indy_get_local_txn` for details about the local copies of the ledgers.
+///
+/// #Params
+/// config_name: Name of the pool ledger configuration.
+/// ledger_id: Id of the ledger: 0 - POOL, 1 - DOMAIN, 2 - CONFIG.
+/// txn_type: Type of transactions to return (for example "1" for NYM, "101" for SCHEMA).
+/// from_seq_no: Sequence number of the transaction to start search from (starts from 1).
+/// count: Maximum number of transactions to return.
+///
+/// #Returns
+/// Json array of transactions ordered by sequence number.
+/// Use `txnMetadata.seqNo` of the last one + 1 as `from_seq_no` to get the next chunk.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_local_txns_by_type(command_handle: CommandHandle,
+                                          config_name: *const c_char,
+                                          ledger_id: usize,
+                                          txn_type: *const c_char,
+                                          from_seq_no: usize,
+                                          count: usize,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               txns_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_local_txns_by_type: >>> config_name: {:?}, ledger_id: {:?}, txn_type: {:?}, from_seq_no: {:?}, count: {:?}",
+           config_name, ledger_id, txn_type, from_seq_no, count);
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_get_local_txns_by_type: entities >>> config_name: {:?}, ledger_id: {:?}, txn_type: {:?}, from_seq_no: {:?}, count: {:?}",
+           config_name, ledger_id, txn_type, from_seq_no, count);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::GetLocalTxnsByType(
+            config_name,
+            ledger_id,
+            txn_type,
+            from_seq_no,
+            count,
+            boxed_callback_string!("indy_get_local_txns_by_type", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_local_txns_by_type: <<< res: {:?}", res);
+
+    res
+}
+
+/// Checks health of the pool without opening it.
+///
+/// Connects to each node from the local copy of the pool ledger, sends LEDGER_STATUS
+/// and reports how the node responded, together with validity of its BLS key.
+/// Ledger statuses received from the nodes are checked the same way as during pool opening.
+///
+/// #Params
+/// config_name: Name of the pool ledger configuration.
+/// config (optional): Runtime pool configuration json (see `indy_open_pool_ledger`).
+///     Only "zmq" transport is supported. `timeout` limits the time to wait for the node replies.
+///
+/// #Returns
+/// Pool diagnostics json:
+/// {
+///     "f": int - the number of faulty nodes the pool tolerates,
+///     "localMerkleRoot": string - root of the local copy of the pool ledger,
+///     "localLedgerSize": int - size of the local copy of the pool ledger,
+///     "consensus": string - result of the nodes ledger status check:
+///         "synced", "catchup_needed", "not_reached" or "failed",
+///     "consensusMerkleRoot": string (optional) - merkle root agreed by the nodes if consensus is reached,
+///     "consensusLedgerSize": int (optional) - ledger size agreed by the nodes if consensus is reached,
+///     "error": string (optional) - error of the check,
+///     "nodes": [{
+///         "alias": string - node alias,
+///         "address": string (optional) - node ip and port,
+///         "status": string - "reachable", "no_reply", "handshake_failed", "unreachable" or "invalid",
+///         "latencyMs": int (optional) - time to receive the reply,
+///         "merkleRoot": string (optional) - pool ledger merkle root reported by the node,
+///         "ledgerSize": int (optional) - pool ledger size reported by the node,
+///         "blsKey": string - "valid", "missing", "invalid" or "invalid_proof_of_possession",
+///         "error": string (optional) - details of the node failure
+///     }]
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_diagnose_pool(command_handle: CommandHandle,
+                                 config_name: *const c_char,
+                                 config: *const c_char,
+                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                      err: ErrorCode,
+                                                      diagnostics_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_diagnose_pool: >>> config_name: {:?}, config: {:?}", config_name, config);
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_validatable_json!(config, ErrorCode::CommonInvalidParam3, PoolOpenConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_diagnose_pool: entities >>> config_name: {:?}, config: {:?}", config_name, config);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::Diagnose(
+            config_name,
+            config,
+            boxed_callback_string!("indy_diagnose_pool", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_diagnose_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Register custom pool transport that delivers messages to the pool nodes instead of ZMQ connections.
+///
+/// Pool opened with "transport" config value equal to registered name sends messages through the transport handlers.
+/// Libindy still verifies state proofs, BLS multi-signatures and catchup replies received through the transport.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// transport_name: Name of the transport. Must not be equal to built-in "zmq" and "simulated" transports.
+/// open: "open" operation handler
+/// send: "send" operation handler
+/// recv: "recv" operation handler
+/// free_buffer: Handler that allows to de-allocate buffers allocated in transport code
+/// close: "close" operation handler
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_pool_transport(command_handle: CommandHandle,
+                                           transport_name: *const c_char,
+                                           open: Option<PoolTransportOpen>,
+                                           send: Option<PoolTransportSend>,
+                                           recv: Option<PoolTransportRecv>,
+                                           free_buffer: Option<PoolTransportFreeBuffer>,
+                                           close: Option<PoolTransportClose>,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_pool_transport: >>> transport_name: {:?}", transport_name);
+
+    check_useful_c_str!(transport_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(open, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(send, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(recv, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(free_buffer, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(close, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_register_pool_transport: entities >>> transport_name: {:?}", transport_name);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::RegisterTransport(
+            transport_name,
+            PluggedTransportType::new(open, send, recv, free_buffer, close),
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_pool_transport: cb command_handle: {:?} err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_pool_transport: <<< res: {:?}", res);
+
+    res
+}
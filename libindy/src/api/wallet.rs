@@ -2,7 +2,7 @@
 use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, INVALID_WALLET_HANDLE};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::wallet::WalletCommand;
-use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, KeyConfig};
+use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, ExportFormat, KeyConfig, RotateKeyConfig};
 use indy_api_types::wallet::*;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
@@ -143,6 +143,59 @@ pub extern fn indy_register_wallet_storage(command_handle: CommandHandle,
     res
 }
 
+/// Register transaction handlers for custom wallet storage implementation.
+///
+/// Storage type must be registered with indy_register_wallet_storage call before.
+/// Without registered transaction handlers wallets of this storage type don't support
+/// indy_wallet_transaction_* calls and multi-record updates are applied without atomicity.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: Storage type name.
+/// begin_transaction: WalletType begin transaction operation handler
+/// commit_transaction: WalletType commit transaction operation handler
+/// rollback_transaction: WalletType rollback transaction operation handler
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_register_wallet_storage_transactions(command_handle: CommandHandle,
+                                                        type_: *const c_char,
+                                                        begin_transaction: Option<WalletBeginTransaction>,
+                                                        commit_transaction: Option<WalletCommitTransaction>,
+                                                        rollback_transaction: Option<WalletRollbackTransaction>,
+                                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_wallet_storage_transactions: >>> command_handle: {:?}, type_: {:?}, cb: {:?}",
+           command_handle, type_, cb);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(begin_transaction, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(commit_transaction, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(rollback_transaction, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_register_wallet_storage_transactions: params type_: {:?}", type_);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(
+            WalletCommand::RegisterWalletTransactions(
+                type_,
+                begin_transaction,
+                commit_transaction,
+                rollback_transaction,
+                Box::new(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_register_wallet_storage_transactions: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+    trace!("indy_register_wallet_storage_transactions: <<< res: {:?}", res);
+    res
+}
+
 /// Create a new secure wallet.
 ///
 /// #Params
@@ -172,7 +225,7 @@ pub extern fn indy_register_wallet_storage(command_handle: CommandHandle,
 ///   "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
 ///                          ARGON2I_MOD - derive secured wallet master key (used by default)
 ///                          ARGON2I_INT - derive secured wallet master key (less secured but faster)
-///                          RAW - raw wallet key master provided (skip derivation).
+///                          RAW - raw wallet master key provided (skip derivation).
 ///                                RAW keys can be generated with indy_generate_wallet_key call
 /// }
 ///
@@ -247,7 +300,7 @@ pub extern fn indy_create_wallet(command_handle: CommandHandle,
 ///       "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
 ///                          ARGON2I_MOD - derive secured wallet master key (used by default)
 ///                          ARGON2I_INT - derive secured wallet master key (less secured but faster)
-///                          RAW - raw wallet key master provided (skip derivation).
+///                          RAW - raw wallet master key provided (skip derivation).
 ///                                RAW keys can be generated with indy_generate_wallet_key call
 ///       "rekey_derivation_method": optional<string> Algorithm to use for wallet rekey derivation:
 ///                          ARGON2I_MOD - derive secured wallet master rekey (used by default)
@@ -297,6 +350,60 @@ pub extern fn indy_open_wallet(command_handle: CommandHandle,
     res
 }
 
+/// Rotates master key of opened wallet without closing it.
+///
+/// Wallet keys are re-encrypted with the new master key and stored in wallet metadata
+/// with a single storage update, so an interrupted rotation leaves the old key valid.
+/// All opened handles to the wallet keep working after rotation.
+///
+/// #Params:
+/// wallet_handle: wallet handle returned by indy_open_wallet
+/// rotate_key_config: JSON containing new wallet key settings.
+///   {
+///     "key": <string>, New key or passphrase used for wallet key derivation.
+///                     Look to key_derivation_method param for information about supported key derivation methods.
+///     "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
+///                              ARGON2I_MOD - derive secured wallet master key (used by default)
+///                              ARGON2I_INT - derive secured wallet master key (less secured but faster)
+///                              RAW - raw wallet master key provided (skip derivation).
+///                                RAW keys can be generated with indy_generate_wallet_key call
+///   }
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_rotate_wallet_key(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     rotate_key_config: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_rotate_wallet_key: >>> wallet_handle: {:?}, rotate_key_config: {:?}", wallet_handle, rotate_key_config);
+
+    check_useful_json!(rotate_key_config, ErrorCode::CommonInvalidParam3, RotateKeyConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_rotate_wallet_key: params wallet_handle: {:?}, rotate_key_config: {:?}", wallet_handle, secret!(&rotate_key_config));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::RotateKey(
+            wallet_handle,
+            rotate_key_config,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_rotate_wallet_key: cb command_handle: {:?} err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+    trace!("indy_rotate_wallet_key: <<< res: {:?}", res);
+    res
+}
+
 /// Exports opened wallet
 ///
 /// #Params:
@@ -306,11 +413,24 @@ pub extern fn indy_open_wallet(command_handle: CommandHandle,
 ///     "path": <string>, Path of the file that contains exported wallet content
 ///     "key": <string>, Key or passphrase used for wallet export key derivation.
 ///                     Look to key_derivation_method param for information about supported key derivation methods.
+///                     Required for "encrypted" format.
 ///     "key_derivation_method": optional<string> Algorithm to use for wallet export key derivation:
 ///                              ARGON2I_MOD - derive secured export key (used by default)
 ///                              ARGON2I_INT - derive secured export key (less secured but faster)
 ///                              RAW - raw export key provided (skip derivation).
 ///                                RAW keys can be generated with indy_generate_wallet_key call
+///     "format": optional<string> Format of exported file:
+///               encrypted - encrypted stream that can be imported with indy_import_wallet (used by default)
+///               plaintext_jsonl - DECRYPTED records as newline-delimited json, one record per line:
+///                 {"type": string, "id": string, "value": string, "tags": {"tagName": string, ...}}
+///                 Records are written as they are fetched from the storage.
+///     "acknowledge_plaintext_export": optional<bool> Must be set to true for plaintext_jsonl format
+///                                     to confirm that exported file will contain all wallet secrets unencrypted.
+///     "record_type": optional<string> Export only records of this type (plaintext_jsonl format only).
+///     "query": optional<object> WQL query to filter records of record_type (plaintext_jsonl format only).
+///     "offset": optional<int> Number of matched records to skip before export (plaintext_jsonl format only).
+///     "limit": optional<int> Max number of records to export (plaintext_jsonl format only).
+///               Records are exported in the storage order, so pages are consistent while wallet isn't modified.
 ///   }
 ///
 /// #Returns
@@ -327,7 +447,7 @@ pub extern fn indy_export_wallet(command_handle: CommandHandle,
                                                       err: ErrorCode)>) -> ErrorCode {
     trace!("indy_export_wallet: >>> wallet_handle: {:?}, export_config: {:?}", wallet_handle, export_config);
 
-    check_useful_json!(export_config, ErrorCode::CommonInvalidParam3, ExportConfig);
+    check_useful_validatable_json!(export_config, ErrorCode::CommonInvalidParam3, ExportConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_export_wallet: params wallet_handle: {:?}, export_config: {:?}", wallet_handle, secret!(&export_config));
@@ -380,13 +500,17 @@ pub extern fn indy_export_wallet(command_handle: CommandHandle,
 ///   "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
 ///                             ARGON2I_MOD - derive secured wallet master key (used by default)
 ///                             ARGON2I_INT - derive secured wallet master key (less secured but faster)
-///                             RAW - raw wallet key master provided (skip derivation).
+///                             RAW - raw wallet master key provided (skip derivation).
 ///                                RAW keys can be generated with indy_generate_wallet_key call
 /// }
 /// import_config: Import settings json.
 /// {
 ///   "path": <string>, path of the file that contains exported wallet content
-///   "key": <string>, key used for export of the wallet
+///   "key": <string>, key used for export of the wallet. Required for "encrypted" format.
+///   "format": optional<string> Format of the file to import:
+///             encrypted - file created by indy_export_wallet with "encrypted" format (used by default)
+///             plaintext_jsonl - newline-delimited json records created by indy_export_wallet
+///                               with "plaintext_jsonl" format or by other agent frameworks
 /// }
 ///
 /// #Returns
@@ -410,6 +534,10 @@ pub extern fn indy_import_wallet(command_handle: CommandHandle,
     check_useful_json!(import_config, ErrorCode::CommonInvalidParam4, ExportConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
+    if import_config.format == ExportFormat::Encrypted && import_config.key.is_none() {
+        return err_msg(IndyErrorKind::InvalidStructure, "Import key is required for encrypted format").into();
+    }
+
     trace!("indy_import_wallet: params config: {:?}, credentials: {:?}, import_config: {:?}",
            config, secret!(&credentials), secret!(&import_config));
 
@@ -469,6 +597,132 @@ pub extern fn indy_close_wallet(command_handle: CommandHandle,
     res
 }
 
+/// Starts a transaction on opened wallet.
+///
+/// All wallet changes made after this call (including changes made by DID, anoncreds
+/// and non-secrets calls) are applied atomically by indy_wallet_transaction_commit
+/// or discarded by indy_wallet_transaction_rollback. Nested transactions are not supported.
+/// Transaction that is still active on indy_close_wallet call is rolled back.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: wallet handle returned by indy_open_wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_wallet_transaction_begin(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_wallet_transaction_begin: >>> command_handle: {:?}, wallet_handle: {:?}, cb: {:?}",
+           command_handle, wallet_handle, cb);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_wallet_transaction_begin: params wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::BeginTransaction(
+            wallet_handle,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_wallet_transaction_begin: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+    trace!("indy_wallet_transaction_begin: <<< res: {:?}", res);
+    res
+}
+
+/// Commits the transaction started by indy_wallet_transaction_begin.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: wallet handle returned by indy_open_wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_wallet_transaction_commit(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_wallet_transaction_commit: >>> command_handle: {:?}, wallet_handle: {:?}, cb: {:?}",
+           command_handle, wallet_handle, cb);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_wallet_transaction_commit: params wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::CommitTransaction(
+            wallet_handle,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_wallet_transaction_commit: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+    trace!("indy_wallet_transaction_commit: <<< res: {:?}", res);
+    res
+}
+
+/// Rolls back the transaction started by indy_wallet_transaction_begin.
+/// All wallet changes made within the transaction are discarded.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: wallet handle returned by indy_open_wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_wallet_transaction_rollback(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_wallet_transaction_rollback: >>> command_handle: {:?}, wallet_handle: {:?}, cb: {:?}",
+           command_handle, wallet_handle, cb);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_wallet_transaction_rollback: params wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::RollbackTransaction(
+            wallet_handle,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_wallet_transaction_rollback: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+    trace!("indy_wallet_transaction_rollback: <<< res: {:?}", res);
+    res
+}
+
 /// Deletes created wallet.
 ///
 /// #Params
@@ -498,7 +752,7 @@ pub extern fn indy_close_wallet(command_handle: CommandHandle,
 ///   "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
 ///                             ARGON2I_MOD - derive secured wallet master key (used by default)
 ///                             ARGON2I_INT - derive secured wallet master key (less secured but faster)
-///                             RAW - raw wallet key master provided (skip derivation).
+///                             RAW - raw wallet master key provided (skip derivation).
 ///                                RAW keys can be generated with indy_generate_wallet_key call
 /// }
 ///
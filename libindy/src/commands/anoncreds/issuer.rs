@@ -4,14 +4,24 @@ use std::rc::Rc;
 
 use ursa::cl::{
     new_nonce,
+    CredentialSignature,
+    RevocationKeyPrivate,
+    RevocationRegistry as CryptoRevocationRegistry,
     RevocationRegistryDelta as CryptoRevocationRegistryDelta,
+    SignatureCorrectnessProof,
     Witness,
 };
 use ursa::cl::{CredentialKeyCorrectnessProof, CredentialPrivateKey};
 
 use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
 use crate::commands::anoncreds::AnoncredsCommand;
-use crate::domain::anoncreds::credential::{CredentialValues, Credential};
+use crate::domain::anoncreds::credential::{
+    Credential,
+    CredentialIssuanceRequests,
+    CredentialValues,
+    IssuedCredential,
+    RawCredentialValues,
+};
 use crate::domain::anoncreds::credential_definition::{
     CredentialDefinition,
     CredentialDefinitionConfig,
@@ -44,20 +54,30 @@ use crate::domain::anoncreds::revocation_registry_delta::{
     RevocationRegistryDelta,
     RevocationRegistryDeltaV1,
 };
+use crate::domain::anoncreds::revocation_registry_pool::{
+    RevocationRegistryPendingRequests,
+    RevocationRegistryPool,
+    RevocationRegistryPoolConfig,
+};
+use crate::domain::anoncreds::revocation_status_list::{RevocationStatusList, revocation_status_bitmap};
 use crate::domain::anoncreds::schema::{AttributeNames, Schema, SchemaV1, SchemaId};
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
-use crate::services::anoncreds::helpers::parse_cred_rev_id;
+use crate::services::anoncreds::helpers::{encode_credential_values, parse_cred_rev_id};
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
+use crate::services::ledger::LedgerService;
 use crate::services::pool::PoolService;
 use indy_wallet::{RecordOptions, WalletService};
 
-use super::tails::{SDKTailsAccessor, store_tails_from_generator};
+use crate::services::anoncreds::issuer::Issuer;
+
+use super::tails::{PreloadedTailsAccessor, SDKTailsAccessor, store_tails_from_generator};
 use indy_api_types::{WalletHandle, CommandHandle};
 use indy_utils::next_command_handle;
+use indy_utils::crypto::base64;
 
 pub enum IssuerCommand {
     CreateSchema(
@@ -121,7 +141,7 @@ pub enum IssuerCommand {
         WalletHandle,
         CredentialOffer, // credential offer
         CredentialRequest, // credential request
-        CredentialValues, // credential values
+        RawCredentialValues, // credential values
         Option<RevocationRegistryId>, // revocation registry id
         Option<i32>, // blob storage reader config handle
         Box<dyn Fn(IndyResult<(String, Option<String>, Option<String>)>) + Send>),
@@ -131,6 +151,16 @@ pub enum IssuerCommand {
         RevocationRegistryId, //revocation registry id
         String, //credential revoc id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    RevokeCredentials(
+        WalletHandle,
+        i32, // blob storage reader config handle
+        RevocationRegistryId, //revocation registry id
+        Vec<String>, //credential revoc ids
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRevocationStatusList(
+        WalletHandle,
+        RevocationRegistryId, //revocation registry id
+        Box<dyn Fn(IndyResult<String>) + Send>),
     /*    RecoverCredential(
             WalletHandle,
             i32, // blob storage reader config handle
@@ -141,6 +171,108 @@ pub enum IssuerCommand {
         RevocationRegistryDelta, //revocation registry delta
         RevocationRegistryDelta, //other revocation registry delta
         Box<dyn Fn(IndyResult<String>) + Send>),
+    CreateCredentialsBatch(
+        WalletHandle,
+        CredentialIssuanceRequests, // credential offers, requests and values
+        Option<RevocationRegistryId>, // revocation registry id
+        Option<i32>, // blob storage reader config handle
+        Box<dyn Fn(IndyResult<(String, Option<String>)>) + Send>),
+    CreateCredentialsBatchContinue(
+        IndyResult<SignedCredentialsBatch>,
+        CommandHandle),
+    CreateRevocationRegistryPool(
+        WalletHandle,
+        DidValue, // issuer did
+        CredentialDefinitionId, // credential definition id
+        RevocationRegistryPoolConfig, // pool config
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CreateCredentialFromRevocationRegistryPool(
+        WalletHandle,
+        CredentialOffer, // credential offer
+        CredentialRequest, // credential request
+        RawCredentialValues, // credential values
+        Box<dyn Fn(IndyResult<(String, String, String, Option<String>)>) + Send>),
+    GetRevocationRegistryPoolPendingRequests(
+        WalletHandle,
+        CredentialDefinitionId, // credential definition id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    AddRevocationRegistryToPool(
+        WalletHandle,
+        CredentialDefinitionId, // credential definition id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ConfirmRevocationRegistryPublished(
+        WalletHandle,
+        RevocationRegistryId, // revocation registry id
+        Box<dyn Fn(IndyResult<()>) + Send>),
+}
+
+/// Credentials of a batch prepared to be signed on the crypto thread pool.
+pub struct CredentialsBatch {
+    cred_def: CredentialDefinitionV1,
+    cred_priv_key: CredentialPrivateKey,
+    credentials: Vec<(CredentialOffer, CredentialRequest, CredentialValues, Option<u32>)>,
+    rev_reg_def: Option<RevocationRegistryDefinitionV1>,
+    rev_reg: Option<CryptoRevocationRegistry>,
+    rev_key_priv: Option<RevocationKeyPrivate>,
+    tails_accessor: Option<PreloadedTailsAccessor>,
+}
+
+pub struct SignedCredentialsBatch {
+    credentials: Vec<(CredentialOffer, CredentialValues, Option<u32>, CredentialSignature, SignatureCorrectnessProof)>,
+    rev_reg_def: Option<RevocationRegistryDefinitionV1>,
+    rev_reg: Option<CryptoRevocationRegistry>,
+    rev_reg_delta: Option<CryptoRevocationRegistryDelta>,
+}
+
+impl CredentialsBatch {
+    /// Signs credentials one by one threading the revocation registry through them,
+    /// so deltas of the credentials are merged into one.
+    fn sign(self) -> IndyResult<SignedCredentialsBatch> {
+        let CredentialsBatch { cred_def, cred_priv_key, credentials, rev_reg_def, mut rev_reg, rev_key_priv, tails_accessor } = self;
+
+        let issuer = Issuer::new();
+        let mut signed_credentials = Vec::with_capacity(credentials.len());
+        let mut rev_reg_delta: Option<CryptoRevocationRegistryDelta> = None;
+
+        for (cred_offer, cred_request, cred_values, rev_idx) in credentials {
+            let (signature, signature_correctness_proof, delta) =
+                issuer.new_credential(&cred_def,
+                                      &cred_priv_key,
+                                      &cred_offer.nonce,
+                                      &cred_request,
+                                      &cred_values,
+                                      rev_idx,
+                                      rev_reg_def.as_ref(),
+                                      rev_reg.as_mut(),
+                                      rev_key_priv.as_ref(),
+                                      tails_accessor.as_ref())?;
+
+            rev_reg_delta = match (rev_reg_delta, delta) {
+                (Some(mut merged), Some(delta)) => {
+                    merged.merge(&delta)?;
+                    Some(merged)
+                }
+                (merged, delta) => merged.or(delta)
+            };
+
+            signed_credentials.push((cred_offer, cred_values, rev_idx, signature, signature_correctness_proof));
+        }
+
+        Ok(SignedCredentialsBatch {
+            credentials: signed_credentials,
+            rev_reg_def,
+            rev_reg,
+            rev_reg_delta,
+        })
+    }
+}
+
+struct PendingCredentialsBatch {
+    wallet_handle: WalletHandle,
+    rev_reg_id: Option<RevocationRegistryId>,
+    rev_reg_info: Option<RevocationRegistryInfo>,
+    blob_storage_reader_handle: Option<i32>,
+    cb: Box<dyn Fn(IndyResult<(String, Option<String>)>) + Send>,
 }
 
 pub struct IssuerCommandExecutor {
@@ -149,8 +281,13 @@ pub struct IssuerCommandExecutor {
     pub pool_service: Rc<PoolService>,
     pub wallet_service: Rc<WalletService>,
     pub crypto_service: Rc<CryptoService>,
+    pub ledger_service: Rc<LedgerService>,
     pending_str_str_callbacks: RefCell<HashMap<CommandHandle, BoxedCallbackStringStringSend>>,
     pending_str_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>) + Send>>>,
+    pending_credentials_batches: RefCell<HashMap<CommandHandle, PendingCredentialsBatch>>,
+    // blob storage config handles opened for tails of revocation registry pools by (type, config)
+    pool_tails_writers: RefCell<HashMap<(String, String), i32>>,
+    pool_tails_readers: RefCell<HashMap<(String, String), i32>>,
 }
 
 impl IssuerCommandExecutor {
@@ -158,15 +295,20 @@ impl IssuerCommandExecutor {
                pool_service: Rc<PoolService>,
                blob_storage_service: Rc<BlobStorageService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> IssuerCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               ledger_service: Rc<LedgerService>) -> IssuerCommandExecutor {
         IssuerCommandExecutor {
             anoncreds_service,
             pool_service,
             blob_storage_service,
             wallet_service,
             crypto_service,
+            ledger_service,
             pending_str_str_callbacks: RefCell::new(HashMap::new()),
             pending_str_callbacks: RefCell::new(HashMap::new()),
+            pending_credentials_batches: RefCell::new(HashMap::new()),
+            pool_tails_writers: RefCell::new(HashMap::new()),
+            pool_tails_readers: RefCell::new(HashMap::new()),
         }
     }
 
@@ -220,6 +362,14 @@ impl IssuerCommandExecutor {
                 debug!(target: "issuer_command_executor", "RevokeCredential command received");
                 cb(self.revoke_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
             }
+            IssuerCommand::RevokeCredentials(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_ids, cb) => {
+                debug!(target: "issuer_command_executor", "RevokeCredentials command received");
+                cb(self.revoke_credentials(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_ids));
+            }
+            IssuerCommand::GetRevocationStatusList(wallet_handle, rev_reg_id, cb) => {
+                debug!(target: "issuer_command_executor", "GetRevocationStatusList command received");
+                cb(self.get_revocation_status_list(wallet_handle, &rev_reg_id));
+            }
             /*            IssuerCommand::RecoverCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                             debug!(target: "issuer_command_executor", "RecoverCredential command received");
                             cb(self.recovery_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
@@ -229,6 +379,34 @@ impl IssuerCommandExecutor {
                 cb(self.merge_revocation_registry_deltas(&mut RevocationRegistryDeltaV1::from(rev_reg_delta),
                                                          &RevocationRegistryDeltaV1::from(other_rev_reg_delta)));
             }
+            IssuerCommand::CreateCredentialsBatch(wallet_handle, requests, rev_reg_id, blob_storage_reader_handle, cb) => {
+                debug!(target: "issuer_command_executor", "CreateCredentialsBatch command received");
+                self.create_credentials_batch(wallet_handle, requests, rev_reg_id, blob_storage_reader_handle, cb);
+            }
+            IssuerCommand::CreateCredentialsBatchContinue(result, cb_id) => {
+                debug!(target: "issuer_command_executor", "CreateCredentialsBatchContinue command received");
+                self._create_credentials_batch_continue(cb_id, result);
+            }
+            IssuerCommand::CreateRevocationRegistryPool(wallet_handle, issuer_did, cred_def_id, config, cb) => {
+                debug!(target: "issuer_command_executor", "CreateRevocationRegistryPool command received");
+                cb(self.create_revocation_registry_pool(wallet_handle, issuer_did, cred_def_id, config));
+            }
+            IssuerCommand::CreateCredentialFromRevocationRegistryPool(wallet_handle, cred_offer, cred_req, cred_values, cb) => {
+                debug!(target: "issuer_command_executor", "CreateCredentialFromRevocationRegistryPool command received");
+                cb(self.create_credential_from_revocation_registry_pool(wallet_handle, &cred_offer, &cred_req, &cred_values));
+            }
+            IssuerCommand::GetRevocationRegistryPoolPendingRequests(wallet_handle, cred_def_id, cb) => {
+                debug!(target: "issuer_command_executor", "GetRevocationRegistryPoolPendingRequests command received");
+                cb(self.get_revocation_registry_pool_pending_requests(wallet_handle, &cred_def_id));
+            }
+            IssuerCommand::AddRevocationRegistryToPool(wallet_handle, cred_def_id, cb) => {
+                debug!(target: "issuer_command_executor", "AddRevocationRegistryToPool command received");
+                cb(self.add_revocation_registry_to_pool(wallet_handle, &cred_def_id));
+            }
+            IssuerCommand::ConfirmRevocationRegistryPublished(wallet_handle, rev_reg_id, cb) => {
+                debug!(target: "issuer_command_executor", "ConfirmRevocationRegistryPublished command received");
+                cb(self.confirm_revocation_registry_published(wallet_handle, &rev_reg_id));
+            }
         };
     }
 
@@ -396,14 +574,16 @@ impl IssuerCommandExecutor {
 
         let schema_ = Schema::SchemaV1(schema.clone());
 
-        let cred_def_json = self.wallet_service.add_indy_object(wallet_handle, &cred_def_id.0, &cred_def, &HashMap::new())?;
-        self.wallet_service.add_indy_object(wallet_handle, &cred_def_id.0, &cred_def_priv_key, &HashMap::new())?;
-        self.wallet_service.add_indy_object(wallet_handle, &cred_def_id.0, &cred_def_correctness_proof, &HashMap::new())?;
-        let _ = self.wallet_service.add_indy_object(wallet_handle, &schema_id.0, &schema_, &HashMap::new()).ok();
+        let cred_def_json = self.wallet_service.with_transaction(wallet_handle, || {
+            let cred_def_json = self.wallet_service.add_indy_object(wallet_handle, &cred_def_id.0, &cred_def, &HashMap::new())?;
+            self.wallet_service.add_indy_object(wallet_handle, &cred_def_id.0, &cred_def_priv_key, &HashMap::new())?;
+            self.wallet_service.add_indy_object(wallet_handle, &cred_def_id.0, &cred_def_correctness_proof, &HashMap::new())?;
+            let _ = self.wallet_service.add_indy_object(wallet_handle, &schema_id.0, &schema_, &HashMap::new()).ok();
 
-        let schema_id = schema.id.clone();
+            self._wallet_set_schema_id(wallet_handle, &cred_def_id.0, &schema.id)?; // TODO: FIXME delete temporary storing of schema id
 
-        self._wallet_set_schema_id(wallet_handle, &cred_def_id.0, &schema_id)?; // TODO: FIXME delete temporary storing of schema id
+            Ok(cred_def_json)
+        })?;
 
         debug!("create_and_store_credential_definition <<< cred_def_id: {:?}, cred_def_json: {:?}", cred_def_id, cred_def_json);
         Ok((cred_def_id.0.clone(), cred_def_json))
@@ -530,11 +710,13 @@ impl IssuerCommandExecutor {
         let _cred_def: CredentialDefinition = self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
         let temp_cred_def: TemporaryCredentialDefinition = self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
 
-        self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &temp_cred_def.cred_def)?;
-        self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &temp_cred_def.cred_def_priv_key)?;
-        self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &temp_cred_def.cred_def_correctness_proof)?;
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &temp_cred_def.cred_def)?;
+            self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &temp_cred_def.cred_def_priv_key)?;
+            self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &temp_cred_def.cred_def_correctness_proof)?;
 
-        self.wallet_service.delete_indy_record::<TemporaryCredentialDefinition>(wallet_handle, &cred_def_id.0)?;
+            self.wallet_service.delete_indy_record::<TemporaryCredentialDefinition>(wallet_handle, &cred_def_id.0)
+        })?;
 
         debug!("rotate_credential_definition_apply <<<");
 
@@ -619,19 +801,23 @@ impl IssuerCommandExecutor {
             value: revoc_key_private
         };
 
-        let revoc_reg_def_json = self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &revoc_reg_def, &HashMap::new())?;
-
-        let revoc_reg_json = self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &revoc_reg, &HashMap::new())?;
-
-        self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &revoc_reg_def_priv, &HashMap::new())?;
-
         let rev_reg_info = RevocationRegistryInfo {
             id: rev_reg_id.clone(),
             curr_id: 0,
             used_ids: HashSet::new(),
         };
 
-        self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info, &HashMap::new())?;
+        let (revoc_reg_def_json, revoc_reg_json) = self.wallet_service.with_transaction(wallet_handle, || {
+            let revoc_reg_def_json = self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &revoc_reg_def, &HashMap::new())?;
+
+            let revoc_reg_json = self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &revoc_reg, &HashMap::new())?;
+
+            self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &revoc_reg_def_priv, &HashMap::new())?;
+
+            self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info, &HashMap::new())?;
+
+            Ok((revoc_reg_def_json, revoc_reg_json))
+        })?;
 
         debug!("create_and_store_revocation_registry <<< rev_reg_id: {:?}, revoc_reg_def_json: {:?}, revoc_reg_json: {:?}",
                rev_reg_id, revoc_reg_def_json, revoc_reg_json);
@@ -671,7 +857,7 @@ impl IssuerCommandExecutor {
                       wallet_handle: WalletHandle,
                       cred_offer: &CredentialOffer,
                       cred_request: &CredentialRequest,
-                      cred_values: &CredentialValues,
+                      cred_values: &RawCredentialValues,
                       rev_reg_id: Option<&RevocationRegistryId>,
                       blob_storage_reader_handle: Option<i32>) -> IndyResult<(String, Option<String>, Option<String>)> {
         debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
@@ -682,6 +868,8 @@ impl IssuerCommandExecutor {
             None => cred_offer.cred_def_id.clone()
         };
 
+        let cred_values = encode_credential_values(cred_values)?;
+
         let cred_def: CredentialDefinitionV1 =
             CredentialDefinitionV1::from(
                 self.wallet_service.get_indy_object::<CredentialDefinition>(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?);
@@ -692,6 +880,8 @@ impl IssuerCommandExecutor {
         let (rev_reg_def, mut rev_reg,
             rev_reg_def_priv, sdk_tails_accessor, rev_reg_info) = match rev_reg_id {
             Some(ref r_reg_id) => {
+                self._check_rev_reg_not_in_batch(r_reg_id)?;
+
                 let rev_reg_def: RevocationRegistryDefinitionV1 =
                     RevocationRegistryDefinitionV1::from(
                         self._wallet_get_rev_reg_def(wallet_handle, &r_reg_id)?);
@@ -785,8 +975,10 @@ impl IssuerCommandExecutor {
         if let (Some(r_reg), Some(r_reg_id), Some(r_reg_info)) = (credential.rev_reg, rev_reg_id, rev_reg_info.clone()) {
             let revoc_reg = RevocationRegistry::RevocationRegistryV1(RevocationRegistryV1 { value: r_reg });
 
-            self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &revoc_reg)?;
-            self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &r_reg_info)?;
+            self.wallet_service.with_transaction(wallet_handle, || {
+                self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &revoc_reg)?;
+                self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &r_reg_info)
+            })?;
         };
 
         let cred_rev_id = rev_reg_info.map(|r_reg_info| r_reg_info.curr_id.to_string());
@@ -796,6 +988,472 @@ impl IssuerCommandExecutor {
         Ok((cred_json, cred_rev_id, rev_reg_delta_json))
     }
 
+    fn create_credentials_batch(&self,
+                                wallet_handle: WalletHandle,
+                                requests: CredentialIssuanceRequests,
+                                rev_reg_id: Option<RevocationRegistryId>,
+                                blob_storage_reader_handle: Option<i32>,
+                                cb: Box<dyn Fn(IndyResult<(String, Option<String>)>) + Send>) {
+        debug!("create_credentials_batch >>> wallet_handle: {:?}, requests: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
+               wallet_handle, secret!(&requests), rev_reg_id, blob_storage_reader_handle);
+
+        let (batch, rev_reg_info) = match self._prepare_credentials_batch(wallet_handle, requests, rev_reg_id.as_ref(), blob_storage_reader_handle) {
+            Ok(res) => res,
+            Err(err) => return cb(Err(err))
+        };
+
+        let cb_id = next_command_handle();
+        self.pending_credentials_batches.borrow_mut().insert(cb_id, PendingCredentialsBatch {
+            wallet_handle,
+            rev_reg_id,
+            rev_reg_info,
+            blob_storage_reader_handle,
+            cb,
+        });
+
+        crate::commands::THREADPOOL.lock().unwrap().execute(move || {
+            let res = batch.sign();
+
+            CommandExecutor::instance().send(
+                Command::Anoncreds(
+                    AnoncredsCommand::Issuer(
+                        IssuerCommand::CreateCredentialsBatchContinue(res, cb_id)
+                    )
+                )).unwrap();
+        });
+
+        debug!("create_credentials_batch <<<");
+    }
+
+    fn _prepare_credentials_batch(&self,
+                                  wallet_handle: WalletHandle,
+                                  requests: CredentialIssuanceRequests,
+                                  rev_reg_id: Option<&RevocationRegistryId>,
+                                  blob_storage_reader_handle: Option<i32>) -> IndyResult<(CredentialsBatch, Option<RevocationRegistryInfo>)> {
+        let cred_def_id = {
+            let cred_offer = &requests.0.first()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Empty list of credentials has been passed"))?
+                .cred_offer;
+
+            match cred_offer.method_name {
+                Some(ref method_name) => cred_offer.cred_def_id.qualify(method_name),
+                None => cred_offer.cred_def_id.clone()
+            }
+        };
+
+        let cred_def: CredentialDefinitionV1 =
+            CredentialDefinitionV1::from(
+                self.wallet_service.get_indy_object::<CredentialDefinition>(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?);
+
+        let cred_def_priv_key: CredentialDefinitionPrivateKey =
+            self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        let count = requests.0.len() as u32;
+
+        let (rev_reg_def, rev_reg, rev_key_priv, tails_accessor, rev_reg_info, rev_idxs) = match rev_reg_id {
+            Some(r_reg_id) => {
+                self._check_rev_reg_not_in_batch(r_reg_id)?;
+
+                let rev_reg_def: RevocationRegistryDefinitionV1 =
+                    RevocationRegistryDefinitionV1::from(
+                        self._wallet_get_rev_reg_def(wallet_handle, r_reg_id)?);
+
+                let rev_reg: RevocationRegistryV1 =
+                    RevocationRegistryV1::from(
+                        self._wallet_get_rev_reg(wallet_handle, r_reg_id)?);
+
+                let rev_key_priv: RevocationRegistryDefinitionPrivate =
+                    self.wallet_service.get_indy_object(wallet_handle, &r_reg_id.0, &RecordOptions::id_value())?;
+
+                let mut rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, r_reg_id)?;
+
+                if rev_reg_info.curr_id + count > rev_reg_def.value.max_cred_num {
+                    return Err(err_msg(IndyErrorKind::RevocationRegistryFull, "RevocationRegistryAccumulator doesn't have enough space for the batch"));
+                }
+
+                let rev_idxs: Vec<u32> = (rev_reg_info.curr_id + 1..=rev_reg_info.curr_id + count).collect();
+                rev_reg_info.curr_id += count;
+
+                // Issuance on demand adds tail of each credential to the accumulator,
+                // so these tails are read in advance to sign credentials outside of the executor thread.
+                let tail_ids: Vec<u32> = if rev_reg_def.value.issuance_type == IssuanceType::ISSUANCE_ON_DEMAND {
+                    rev_reg_info.used_ids.extend(rev_idxs.iter());
+                    rev_idxs.iter().map(|rev_idx| rev_reg_def.value.max_cred_num + 1 - rev_idx).collect()
+                } else {
+                    Vec::new()
+                };
+
+                let blob_storage_reader_handle = blob_storage_reader_handle
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "TailsReaderHandle not found"))?;
+
+                let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                               blob_storage_reader_handle,
+                                                               &rev_reg_def)?;
+
+                let tails_accessor = PreloadedTailsAccessor::new(&sdk_tails_accessor, &tail_ids)?;
+
+                (Some(rev_reg_def), Some(rev_reg.value), Some(rev_key_priv.value), Some(tails_accessor),
+                 Some(rev_reg_info), rev_idxs.into_iter().map(Some).collect())
+            }
+            None => (None, None, None, None, None, vec![None; count as usize])
+        };
+
+        let credentials = requests.0.into_iter()
+            .zip(rev_idxs.into_iter())
+            .map(|(request, rev_idx)| {
+                let cred_values = encode_credential_values(&request.cred_values)?;
+                Ok((request.cred_offer, request.cred_req, cred_values, rev_idx))
+            })
+            .collect::<IndyResult<Vec<_>>>()?;
+
+        let batch = CredentialsBatch {
+            cred_def,
+            cred_priv_key: cred_def_priv_key.value,
+            credentials,
+            rev_reg_def,
+            rev_reg,
+            rev_key_priv,
+            tails_accessor,
+        };
+
+        Ok((batch, rev_reg_info))
+    }
+
+    fn _create_credentials_batch_continue(&self,
+                                          cb_id: CommandHandle,
+                                          result: IndyResult<SignedCredentialsBatch>) {
+        let batch = self.pending_credentials_batches.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let res = result.and_then(|signed| self._complete_credentials_batch(&batch, signed));
+
+        (batch.cb)(res)
+    }
+
+    fn _complete_credentials_batch(&self,
+                                   batch: &PendingCredentialsBatch,
+                                   signed: SignedCredentialsBatch) -> IndyResult<(String, Option<String>)> {
+        let SignedCredentialsBatch { credentials, rev_reg_def, rev_reg, rev_reg_delta } = signed;
+
+        // Witnesses of all credentials are calculated against the final state of the registry.
+        let witness_data = match (rev_reg_def.as_ref(), rev_reg.as_ref(), batch.rev_reg_info.as_ref(), batch.blob_storage_reader_handle) {
+            (Some(r_reg_def), Some(r_reg), Some(r_reg_info), Some(blob_storage_reader_handle)) => {
+                let (issued, revoked) = match r_reg_def.value.issuance_type {
+                    IssuanceType::ISSUANCE_ON_DEMAND => (r_reg_info.used_ids.clone(), HashSet::new()),
+                    IssuanceType::ISSUANCE_BY_DEFAULT => (HashSet::new(), r_reg_info.used_ids.clone())
+                };
+
+                let full_delta = CryptoRevocationRegistryDelta::from_parts(None, r_reg, &issued, &revoked);
+
+                let tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                           blob_storage_reader_handle,
+                                                           r_reg_def)?;
+
+                Some((r_reg_def, full_delta, tails_accessor))
+            }
+            _ => None
+        };
+
+        let mut issued_credentials = Vec::with_capacity(credentials.len());
+
+        for (cred_offer, cred_values, rev_idx, signature, signature_correctness_proof) in credentials {
+            let witness = match (rev_idx, witness_data.as_ref()) {
+                (Some(rev_idx), Some((r_reg_def, full_delta, tails_accessor))) =>
+                    Some(Witness::new(rev_idx, r_reg_def.value.max_cred_num,
+                                      r_reg_def.value.issuance_type.to_bool(), full_delta, tails_accessor)?),
+                _ => None
+            };
+
+            let cred_rev_reg_id = match (batch.rev_reg_id.as_ref(), cred_offer.method_name.as_ref()) {
+                (Some(rev_reg_id), Some(ref _method_name)) => Some(rev_reg_id.to_unqualified()),
+                (rev_reg_id, _) => rev_reg_id.cloned()
+            };
+
+            issued_credentials.push(IssuedCredential {
+                cred: Credential {
+                    schema_id: cred_offer.schema_id,
+                    cred_def_id: cred_offer.cred_def_id,
+                    rev_reg_id: cred_rev_reg_id,
+                    values: cred_values,
+                    signature,
+                    signature_correctness_proof,
+                    rev_reg: rev_reg.clone(),
+                    witness,
+                },
+                cred_revoc_id: rev_idx.map(|rev_idx| rev_idx.to_string()),
+            });
+        }
+
+        let creds_json = serde_json::to_string(&issued_credentials)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Credentials")?;
+
+        let rev_reg_delta_json = rev_reg_delta
+            .map(|r_reg_delta| RevocationRegistryDelta::RevocationRegistryDeltaV1(RevocationRegistryDeltaV1 { value: r_reg_delta }))
+            .as_ref()
+            .map(serde_json::to_string)
+            .map_or(Ok(None), |v| v.map(Some))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta")?;
+
+        if let (Some(r_reg), Some(r_reg_id), Some(r_reg_info)) = (rev_reg, batch.rev_reg_id.as_ref(), batch.rev_reg_info.as_ref()) {
+            let revoc_reg = RevocationRegistry::RevocationRegistryV1(RevocationRegistryV1 { value: r_reg });
+
+            self.wallet_service.with_transaction(batch.wallet_handle, || {
+                self.wallet_service.update_indy_object(batch.wallet_handle, &r_reg_id.0, &revoc_reg)?;
+                self.wallet_service.update_indy_object(batch.wallet_handle, &r_reg_id.0, r_reg_info)
+            })?;
+        }
+
+        debug!("create_credentials_batch <<< creds_json: {:?}, rev_reg_delta_json: {:?}", secret!(&creds_json), rev_reg_delta_json);
+
+        Ok((creds_json, rev_reg_delta_json))
+    }
+
+    /// Registry used by the batch being signed can't be changed until the batch is stored.
+    fn _check_rev_reg_not_in_batch(&self, rev_reg_id: &RevocationRegistryId) -> IndyResult<()> {
+        if self.pending_credentials_batches.borrow().values().any(|batch| batch.rev_reg_id.as_ref() == Some(rev_reg_id)) {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               format!("RevocationRegistry {:?} is being updated by credentials batch", rev_reg_id.0)));
+        }
+
+        Ok(())
+    }
+
+    fn create_revocation_registry_pool(&self,
+                                       wallet_handle: WalletHandle,
+                                       issuer_did: DidValue,
+                                       cred_def_id: CredentialDefinitionId,
+                                       config: RevocationRegistryPoolConfig) -> IndyResult<String> {
+        debug!("create_revocation_registry_pool >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config: {:?}",
+               wallet_handle, issuer_did, cred_def_id, config);
+
+        if self.wallet_service.record_exists::<RevocationRegistryPool>(wallet_handle, &cred_def_id.0)? {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               format!("RevocationRegistryPool for CredentialDefinition {:?} already exists", cred_def_id.0)));
+        }
+
+        let mut pool = RevocationRegistryPool::new(issuer_did, cred_def_id, config);
+
+        let pool_json = self.wallet_service.with_transaction(wallet_handle, || {
+            // active registry and the standby one which replaces it when it's full
+            self._add_revocation_registry_to_pool(wallet_handle, &mut pool)?;
+            self._add_revocation_registry_to_pool(wallet_handle, &mut pool)?;
+
+            self.wallet_service.add_indy_object(wallet_handle, &pool.cred_def_id.0, &pool, &HashMap::new())
+        })?;
+
+        debug!("create_revocation_registry_pool <<< pool_json: {:?}", pool_json);
+
+        Ok(pool_json)
+    }
+
+    fn create_credential_from_revocation_registry_pool(&self,
+                                                       wallet_handle: WalletHandle,
+                                                       cred_offer: &CredentialOffer,
+                                                       cred_request: &CredentialRequest,
+                                                       cred_values: &RawCredentialValues) -> IndyResult<(String, String, String, Option<String>)> {
+        debug!("create_credential_from_revocation_registry_pool >>> wallet_handle: {:?}, cred_offer: {:?}, cred_request: {:?}, cred_values: {:?}",
+               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values));
+
+        let cred_def_id = match cred_offer.method_name {
+            Some(ref method_name) => cred_offer.cred_def_id.qualify(method_name),
+            None => cred_offer.cred_def_id.clone()
+        };
+
+        let mut pool: RevocationRegistryPool =
+            self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        let rev_reg_id = self._get_revocation_registry_pool_active(wallet_handle, &mut pool)?;
+
+        let blob_storage_reader_handle = self._get_revocation_registry_pool_tails_reader(&pool.config)?;
+
+        let (cred_json, cred_revoc_id, rev_reg_delta_json) =
+            self.new_credential(wallet_handle, cred_offer, cred_request, cred_values, Some(&rev_reg_id), Some(blob_storage_reader_handle))?;
+
+        let cred_revoc_id = cred_revoc_id
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Credential revocation id isn't set"))?;
+
+        // Credential is already issued, so failed rollover is only reported and retried on the next issuance.
+        if let Err(err) = self._get_revocation_registry_pool_active(wallet_handle, &mut pool) {
+            warn!("create_credential_from_revocation_registry_pool: rollover of {:?} failed: {:?}", pool.cred_def_id, err);
+        }
+
+        debug!("create_credential_from_revocation_registry_pool <<< cred_json: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}, rev_reg_delta_json: {:?}",
+               secret!(&cred_json), rev_reg_id, secret!(&cred_revoc_id), rev_reg_delta_json);
+
+        Ok((cred_json, rev_reg_id.0, cred_revoc_id, rev_reg_delta_json))
+    }
+
+    fn get_revocation_registry_pool_pending_requests(&self,
+                                                     wallet_handle: WalletHandle,
+                                                     cred_def_id: &CredentialDefinitionId) -> IndyResult<String> {
+        debug!("get_revocation_registry_pool_pending_requests >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+        let pool: RevocationRegistryPool =
+            self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        let pending_requests = pool.registries.iter()
+            .filter(|entry| !entry.published)
+            .map(|entry| self._build_revocation_registry_requests(wallet_handle, &pool.issuer_did, &entry.rev_reg_id))
+            .collect::<IndyResult<Vec<RevocationRegistryPendingRequests>>>()?;
+
+        let pending_requests_json = serde_json::to_string(&pending_requests)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryPendingRequests")?;
+
+        debug!("get_revocation_registry_pool_pending_requests <<< pending_requests_json: {:?}", pending_requests_json);
+
+        Ok(pending_requests_json)
+    }
+
+    fn confirm_revocation_registry_published(&self,
+                                             wallet_handle: WalletHandle,
+                                             rev_reg_id: &RevocationRegistryId) -> IndyResult<()> {
+        debug!("confirm_revocation_registry_published >>> wallet_handle: {:?}, rev_reg_id: {:?}", wallet_handle, rev_reg_id);
+
+        let (_, cred_def_id, _, _) = rev_reg_id.parts()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid RevocationRegistryId: {:?}", rev_reg_id.0)))?;
+
+        let mut pool: RevocationRegistryPool =
+            self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        if !pool.set_published(rev_reg_id) {
+            return Err(err_msg(IndyErrorKind::WalletItemNotFound,
+                               format!("RevocationRegistry {:?} doesn't belong to RevocationRegistryPool", rev_reg_id.0)));
+        }
+
+        self.wallet_service.update_indy_object(wallet_handle, &pool.cred_def_id.0, &pool)?;
+
+        debug!("confirm_revocation_registry_published <<<");
+
+        Ok(())
+    }
+
+    fn add_revocation_registry_to_pool(&self,
+                                       wallet_handle: WalletHandle,
+                                       cred_def_id: &CredentialDefinitionId) -> IndyResult<String> {
+        debug!("add_revocation_registry_to_pool >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+        let rev_reg_id = self.wallet_service.with_transaction(wallet_handle, || {
+            let mut pool: RevocationRegistryPool =
+                self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+            let rev_reg_id = self._add_revocation_registry_to_pool(wallet_handle, &mut pool)?;
+
+            self.wallet_service.update_indy_object(wallet_handle, &pool.cred_def_id.0, &pool)?;
+
+            Ok(rev_reg_id)
+        })?;
+
+        debug!("add_revocation_registry_to_pool <<< rev_reg_id: {:?}", rev_reg_id);
+
+        Ok(rev_reg_id.0)
+    }
+
+    /// Returns id of the active registry of the pool.
+    /// If the active registry is full the standby one published to the ledger replaces it.
+    /// Standby registries are never generated here as generation of tails takes too long for issuance.
+    fn _get_revocation_registry_pool_active(&self,
+                                            wallet_handle: WalletHandle,
+                                            pool: &mut RevocationRegistryPool) -> IndyResult<RevocationRegistryId> {
+        let rev_reg_id = pool.active()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "RevocationRegistryPool doesn't have active RevocationRegistry"))?
+            .rev_reg_id.clone();
+
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, &rev_reg_id)?);
+        let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &rev_reg_id)?;
+
+        if rev_reg_info.curr_id < rev_reg_def.value.max_cred_num {
+            return Ok(rev_reg_id);
+        }
+
+        let rev_reg_id = pool.rollover()
+            .ok_or_else(|| err_msg(IndyErrorKind::RevocationRegistryFull,
+                                   format!("RevocationRegistry {:?} is full and RevocationRegistryPool doesn't have published standby RevocationRegistry", rev_reg_id.0)))?;
+
+        self.wallet_service.update_indy_object(wallet_handle, &pool.cred_def_id.0, pool)?;
+
+        debug!("_get_revocation_registry_pool_active: {:?} became active", rev_reg_id);
+
+        Ok(rev_reg_id)
+    }
+
+    fn _add_revocation_registry_to_pool(&self,
+                                        wallet_handle: WalletHandle,
+                                        pool: &mut RevocationRegistryPool) -> IndyResult<RevocationRegistryId> {
+        let tails_writer_handle = self._get_revocation_registry_pool_tails_writer(&pool.config)?;
+
+        let (rev_reg_id, _, _) =
+            self.create_and_store_revocation_registry(wallet_handle,
+                                                      &pool.issuer_did,
+                                                      pool.config.revoc_def_type.as_ref().map(String::as_str),
+                                                      &pool.next_tag(),
+                                                      &pool.cred_def_id,
+                                                      &pool.config.registry_config(),
+                                                      tails_writer_handle)?;
+
+        let rev_reg_id = RevocationRegistryId(rev_reg_id);
+
+        pool.add(rev_reg_id.clone());
+
+        Ok(rev_reg_id)
+    }
+
+    fn _build_revocation_registry_requests(&self,
+                                           wallet_handle: WalletHandle,
+                                           submitter_did: &DidValue,
+                                           rev_reg_id: &RevocationRegistryId) -> IndyResult<RevocationRegistryPendingRequests> {
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, rev_reg_id)?);
+        let rev_reg = RevocationRegistryV1::from(self._wallet_get_rev_reg(wallet_handle, rev_reg_id)?);
+        let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, rev_reg_id)?;
+
+        // The entry carries the current accumulator along with all indexes changed since the registry creation.
+        let (issued, revoked) = match rev_reg_def.value.issuance_type {
+            IssuanceType::ISSUANCE_ON_DEMAND => (rev_reg_info.used_ids, HashSet::new()),
+            IssuanceType::ISSUANCE_BY_DEFAULT => (HashSet::new(), rev_reg_info.used_ids)
+        };
+
+        let rev_reg_entry = RevocationRegistryDeltaV1 {
+            value: CryptoRevocationRegistryDelta::from_parts(None, &rev_reg.value, &issued, &revoked)
+        };
+
+        let revoc_def_type = rev_reg_def.revoc_def_type.to_str();
+
+        let rev_reg_entry_request = self.ledger_service.build_revoc_reg_entry_request(submitter_did, rev_reg_id, revoc_def_type, rev_reg_entry)?;
+        let rev_reg_def_request = self.ledger_service.build_revoc_reg_def_request(submitter_did, rev_reg_def)?;
+
+        Ok(RevocationRegistryPendingRequests {
+            rev_reg_id: rev_reg_id.clone(),
+            rev_reg_def_request,
+            rev_reg_entry_request,
+        })
+    }
+
+    fn _get_revocation_registry_pool_tails_writer(&self, config: &RevocationRegistryPoolConfig) -> IndyResult<i32> {
+        let (type_, config) = config.tails_writer();
+        let key = (type_.to_string(), config);
+
+        if let Some(handle) = self.pool_tails_writers.borrow().get(&key) {
+            return Ok(*handle);
+        }
+
+        let handle = self.blob_storage_service.open_writer(&key.0, &key.1)?;
+        self.pool_tails_writers.borrow_mut().insert(key, handle);
+
+        Ok(handle)
+    }
+
+    fn _get_revocation_registry_pool_tails_reader(&self, config: &RevocationRegistryPoolConfig) -> IndyResult<i32> {
+        let (type_, config) = config.tails_reader();
+        let key = (type_.to_string(), config);
+
+        if let Some(handle) = self.pool_tails_readers.borrow().get(&key) {
+            return Ok(*handle);
+        }
+
+        let handle = self.blob_storage_service.open_reader(&key.0, &key.1)?;
+        self.pool_tails_readers.borrow_mut().insert(key, handle);
+
+        Ok(handle)
+    }
+
     fn revoke_credential(&self,
                          wallet_handle: WalletHandle,
                          blob_storage_reader_handle: i32,
@@ -804,7 +1462,31 @@ impl IssuerCommandExecutor {
         debug!("revoke_credential >>> wallet_handle: {:?}, blob_storage_reader_handle:  {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}",
                wallet_handle, blob_storage_reader_handle, rev_reg_id, secret!(cred_revoc_id));
 
-        let cred_revoc_id = parse_cred_rev_id(cred_revoc_id)?;
+        let rev_reg_delta_json = self.revoke_credentials(wallet_handle, blob_storage_reader_handle, rev_reg_id, &[cred_revoc_id.to_string()])?;
+
+        debug!("revoke_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
+
+        Ok(rev_reg_delta_json)
+    }
+
+    fn revoke_credentials(&self,
+                          wallet_handle: WalletHandle,
+                          blob_storage_reader_handle: i32,
+                          rev_reg_id: &RevocationRegistryId,
+                          cred_revoc_ids: &[String]) -> IndyResult<String> {
+        debug!("revoke_credentials >>> wallet_handle: {:?}, blob_storage_reader_handle:  {:?}, rev_reg_id: {:?}, cred_revoc_ids: {:?}",
+               wallet_handle, blob_storage_reader_handle, rev_reg_id, secret!(cred_revoc_ids));
+
+        if cred_revoc_ids.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "List of credential revocation ids is empty"));
+        }
+
+        let cred_revoc_ids = cred_revoc_ids
+            .iter()
+            .map(|cred_revoc_id| parse_cred_rev_id(cred_revoc_id))
+            .collect::<IndyResult<Vec<u32>>>()?;
+
+        self._check_rev_reg_not_in_batch(rev_reg_id)?;
 
         let revocation_registry_definition: RevocationRegistryDefinitionV1 =
             RevocationRegistryDefinitionV1::from(
@@ -818,27 +1500,43 @@ impl IssuerCommandExecutor {
                                                        blob_storage_reader_handle,
                                                        &revocation_registry_definition)?;
 
-        if cred_revoc_id > revocation_registry_definition.value.max_cred_num + 1 {
-            return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
-        }
-
         let mut rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &rev_reg_id)?;
 
-        match revocation_registry_definition.value.issuance_type {
-            IssuanceType::ISSUANCE_ON_DEMAND => {
-                if !rev_reg_info.used_ids.remove(&cred_revoc_id) {
-                    return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
-                };
+        let mut merged_rev_reg_delta: Option<CryptoRevocationRegistryDelta> = None;
+
+        // Registry and info are changed in memory only, so nothing is stored if any of ids can't be revoked
+        for cred_revoc_id in cred_revoc_ids {
+            if cred_revoc_id > revocation_registry_definition.value.max_cred_num + 1 {
+                return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
             }
-            IssuanceType::ISSUANCE_BY_DEFAULT => {
-                if !rev_reg_info.used_ids.insert(cred_revoc_id) {
-                    return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+
+            match revocation_registry_definition.value.issuance_type {
+                IssuanceType::ISSUANCE_ON_DEMAND => {
+                    if !rev_reg_info.used_ids.remove(&cred_revoc_id) {
+                        return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+                    };
                 }
-            }
-        };
+                IssuanceType::ISSUANCE_BY_DEFAULT => {
+                    if !rev_reg_info.used_ids.insert(cred_revoc_id) {
+                        return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+                    }
+                }
+            };
 
-        let rev_reg_delta =
-            self.anoncreds_service.issuer.revoke(&mut rev_reg.value, revocation_registry_definition.value.max_cred_num, cred_revoc_id, &sdk_tails_accessor)?;
+            let rev_reg_delta =
+                self.anoncreds_service.issuer.revoke(&mut rev_reg.value, revocation_registry_definition.value.max_cred_num, cred_revoc_id, &sdk_tails_accessor)?;
+
+            merged_rev_reg_delta = match merged_rev_reg_delta {
+                Some(mut merged_rev_reg_delta) => {
+                    merged_rev_reg_delta.merge(&rev_reg_delta)?;
+                    Some(merged_rev_reg_delta)
+                }
+                None => Some(rev_reg_delta)
+            };
+        }
+
+        let rev_reg_delta = merged_rev_reg_delta
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "RevocationRegistryDelta wasn't created"))?;
 
         let rev_reg_delta = RevocationRegistryDelta::RevocationRegistryDeltaV1(RevocationRegistryDeltaV1 { value: rev_reg_delta });
 
@@ -847,14 +1545,49 @@ impl IssuerCommandExecutor {
 
         let rev_reg = RevocationRegistry::RevocationRegistryV1(rev_reg);
 
-        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
-        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)?;
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
+            self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)
+        })?;
 
-        debug!("revoke_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
+        debug!("revoke_credentials <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
     }
 
+    fn get_revocation_status_list(&self,
+                                  wallet_handle: WalletHandle,
+                                  rev_reg_id: &RevocationRegistryId) -> IndyResult<String> {
+        debug!("get_revocation_status_list >>> wallet_handle: {:?}, rev_reg_id: {:?}", wallet_handle, rev_reg_id);
+
+        let revocation_registry_definition: RevocationRegistryDefinitionV1 =
+            RevocationRegistryDefinitionV1::from(
+                self._wallet_get_rev_reg_def(wallet_handle, &rev_reg_id)?);
+
+        let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &rev_reg_id)?;
+
+        let issuance_type = revocation_registry_definition.value.issuance_type;
+        let max_cred_num = revocation_registry_definition.value.max_cred_num;
+        let revoked = rev_reg_info.revoked_ids(&issuance_type);
+
+        let status_list = RevocationStatusList {
+            rev_reg_id: rev_reg_id.clone(),
+            max_cred_num,
+            issued_count: rev_reg_info.curr_id,
+            issued: rev_reg_info.issued_ids(&issuance_type),
+            status_list: base64::encode(&revocation_status_bitmap(max_cred_num, &revoked)),
+            revoked,
+            issuance_type,
+        };
+
+        let status_list_json = serde_json::to_string(&status_list)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationStatusList")?;
+
+        debug!("get_revocation_status_list <<< status_list_json: {:?}", status_list_json);
+
+        Ok(status_list_json)
+    }
+
     fn _recovery_credential(&self,
                             wallet_handle: WalletHandle,
                             blob_storage_reader_handle: i32,
@@ -865,6 +1598,8 @@ impl IssuerCommandExecutor {
 
         let cred_revoc_id = parse_cred_rev_id(cred_revoc_id)?;
 
+        self._check_rev_reg_not_in_batch(rev_reg_id)?;
+
         let revocation_registry_definition: RevocationRegistryDefinitionV1 =
             RevocationRegistryDefinitionV1::from(
                 self._wallet_get_rev_reg_def(wallet_handle, &rev_reg_id)?);
@@ -906,8 +1641,10 @@ impl IssuerCommandExecutor {
 
         let rev_reg = RevocationRegistry::RevocationRegistryV1(rev_reg);
 
-        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
-        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)?;
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
+            self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)
+        })?;
 
         debug!("recovery_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
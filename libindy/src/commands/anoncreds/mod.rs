@@ -12,10 +12,12 @@ use crate::services::blob_storage::BlobStorageService;
 use crate::services::pool::PoolService;
 use indy_wallet::WalletService;
 use crate::services::crypto::CryptoService;
-use crate::services::anoncreds::helpers::to_unqualified;
+use crate::services::ledger::LedgerService;
+use crate::services::anoncreds::helpers::{encode_credential_attributes, to_unqualified};
 
 use indy_api_types::errors::prelude::*;
 
+use std::collections::HashMap;
 use std::rc::Rc;
 
 pub enum AnoncredsCommand {
@@ -24,6 +26,9 @@ pub enum AnoncredsCommand {
     Verifier(VerifierCommand),
     ToUnqualified(
         String, // entity
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EncodeCredentialAttributes(
+        HashMap<String, String>, // attributes
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
 
@@ -38,13 +43,14 @@ impl AnoncredsCommandExecutor {
                blob_storage_service: Rc<BlobStorageService>,
                pool_service: Rc<PoolService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> AnoncredsCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               ledger_service: Rc<LedgerService>) -> AnoncredsCommandExecutor {
         AnoncredsCommandExecutor {
             issuer_command_cxecutor: IssuerCommandExecutor::new(
                 anoncreds_service.clone(), pool_service.clone(),
-                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone()),
+                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone(), ledger_service.clone()),
             prover_command_cxecutor: ProverCommandExecutor::new(
-                anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone()),
+                anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone(), ledger_service.clone()),
             verifier_command_cxecutor: VerifierCommandExecutor::new(
                 anoncreds_service.clone()),
         }
@@ -68,6 +74,12 @@ impl AnoncredsCommandExecutor {
                 debug!("ToUnqualified command received");
                 cb(to_unqualified(&entity));
             }
+            AnoncredsCommand::EncodeCredentialAttributes(attributes, cb) => {
+                debug!("EncodeCredentialAttributes command received");
+                cb(encode_credential_attributes(&attributes)
+                    .and_then(|cred_values| ::serde_json::to_string(&cred_values)
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialValues")));
+            }
         };
     }
 }
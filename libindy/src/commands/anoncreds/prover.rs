@@ -1,6 +1,7 @@
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use ursa::cl::{new_nonce, RevocationRegistry, Witness};
 
@@ -15,9 +16,9 @@ use crate::domain::anoncreds::credential_request::{CredentialRequest, Credential
 use crate::domain::anoncreds::master_secret::MasterSecret;
 use crate::domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, ProofRequest, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
-use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
+use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
-use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
+use crate::domain::anoncreds::revocation_state::{CredentialRevocationStates, RevocationState, RevocationStates};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::errors::prelude::*;
@@ -25,13 +26,16 @@ use crate::services::anoncreds::AnoncredsService;
 use crate::services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval};
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
+use crate::services::ledger::LedgerService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
-use indy_utils::{next_search_handle};
+use indy_utils::{next_command_handle, next_search_handle};
 use crate::utils::wql::Query;
 
 use super::tails::SDKTailsAccessor;
-use indy_api_types::{WalletHandle, SearchHandle};
-use crate::commands::BoxedCallbackStringStringSend;
+use indy_api_types::{WalletHandle, SearchHandle, PoolHandle, CommandHandle};
+use crate::commands::{BoxedCallbackStringStringSend, Command, CommandExecutor};
+use crate::commands::anoncreds::AnoncredsCommand;
+use crate::commands::ledger::LedgerCommand;
 
 pub enum ProverCommand {
     CreateMasterSecret(
@@ -110,7 +114,7 @@ pub enum ProverCommand {
         String, // master secret name
         Schemas, // schemas
         CredentialDefinitions, // credential defs
-        RevocationStates, // revocation states
+        Option<RevocationStates>, // revocation states
         Box<dyn Fn(IndyResult<String>) + Send>),
     CreateRevocationState(
         i32, // blob storage reader handle
@@ -126,7 +130,16 @@ pub enum ProverCommand {
         RevocationRegistryDelta, // revocation registry delta
         u64, //timestamp
         String, //credential revocation id
-        Box<dyn Fn(IndyResult<String>) + Send>)
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    RefreshRevocationStates(
+        PoolHandle,
+        WalletHandle,
+        i32, // blob storage reader handle
+        Vec<String>, // credential ids
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    RefreshRevocationStatesContinue(
+        IndyResult<String>, // ledger response
+        CommandHandle),
 }
 
 struct SearchForProofRequest {
@@ -147,27 +160,51 @@ impl SearchForProofRequest {
     }
 }
 
+/// Credentials of one revocation registry whose revocation states are at the same timestamp,
+/// so they are updated with the same revocation registry delta.
+struct RevocationStatesFetch {
+    rev_reg_id: RevocationRegistryId,
+    from: Option<u64>,
+    credentials: Vec<(String, u32)>, // credential id and revocation index
+}
+
+/// Revocation states refresh waiting for the ledger responses.
+struct PendingRevocationStatesRefresh {
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    blob_storage_reader_handle: i32,
+    fetches: VecDeque<RevocationStatesFetch>,
+    rev_reg_defs: HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+    rev_states: HashMap<String, CredentialRevocationStates>,
+    cb: Box<dyn Fn(IndyResult<String>) + Send>,
+}
+
 pub struct ProverCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
     blob_storage_service: Rc<BlobStorageService>,
+    ledger_service: Rc<LedgerService>,
     searches: RefCell<HashMap<SearchHandle, Box<WalletSearch>>>,
     searches_for_proof_requests: RefCell<HashMap<SearchHandle, Box<HashMap<String, SearchForProofRequest>>>>,
+    pending_rev_states_refreshes: RefCell<HashMap<CommandHandle, PendingRevocationStatesRefresh>>,
 }
 
 impl ProverCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
                wallet_service: Rc<WalletService>,
                crypto_service: Rc<CryptoService>,
-               blob_storage_service: Rc<BlobStorageService>) -> ProverCommandExecutor {
+               blob_storage_service: Rc<BlobStorageService>,
+               ledger_service: Rc<LedgerService>) -> ProverCommandExecutor {
         ProverCommandExecutor {
             anoncreds_service,
             wallet_service,
             crypto_service,
             blob_storage_service,
+            ledger_service,
             searches: RefCell::new(HashMap::new()),
             searches_for_proof_requests: RefCell::new(HashMap::new()),
+            pending_rev_states_refreshes: RefCell::new(HashMap::new()),
         }
     }
 
@@ -244,7 +281,7 @@ impl ProverCommandExecutor {
                 cb(self.create_proof(wallet_handle, &proof_req, &requested_credentials, &master_secret_name,
                                      &schemas_map_to_schemas_v1_map(schemas),
                                      &cred_defs_map_to_cred_defs_v1_map(cred_defs),
-                                     &rev_states));
+                                     rev_states.as_ref()));
             }
             ProverCommand::CreateRevocationState(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
                 debug!(target: "prover_command_executor", "CreateRevocationState command received");
@@ -254,6 +291,14 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            ProverCommand::RefreshRevocationStates(pool_handle, wallet_handle, blob_storage_reader_handle, cred_ids, cb) => {
+                debug!(target: "prover_command_executor", "RefreshRevocationStates command received");
+                self.refresh_revocation_states(pool_handle, wallet_handle, blob_storage_reader_handle, &cred_ids, cb);
+            }
+            ProverCommand::RefreshRevocationStatesContinue(response, cb_id) => {
+                debug!(target: "prover_command_executor", "RefreshRevocationStatesContinue command received");
+                self._refresh_revocation_states_continue(response, cb_id);
+            }
         };
     }
 
@@ -666,7 +711,13 @@ impl ProverCommandExecutor {
             return Err(err_msg(IndyErrorKind::WalletItemNotFound, format!("Credential {} not found", cred_id)));
         }
 
-        self.wallet_service.delete_indy_record::<Credential>(wallet_handle, cred_id)
+        self.wallet_service.with_transaction(wallet_handle, || {
+            if self.wallet_service.record_exists::<CredentialRevocationStates>(wallet_handle, cred_id)? {
+                self.wallet_service.delete_indy_record::<CredentialRevocationStates>(wallet_handle, cred_id)?;
+            }
+
+            self.wallet_service.delete_indy_record::<Credential>(wallet_handle, cred_id)
+        })
     }
 
     fn create_proof(&self,
@@ -676,13 +727,22 @@ impl ProverCommandExecutor {
                     master_secret_id: &str,
                     schemas: &HashMap<SchemaId, SchemaV1>,
                     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
-                    rev_states: &RevocationStates) -> IndyResult<String> {
+                    rev_states: Option<&RevocationStates>) -> IndyResult<String> {
         debug!("create_proof >>> wallet_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}, master_secret_id: {:?}, schemas: {:?}, \
         cred_defs: {:?}, rev_states: {:?}",
                wallet_handle, proof_req, requested_credentials, master_secret_id, schemas, cred_defs, rev_states);
 
         let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &master_secret_id)?;
 
+        let cached_rev_states: RevocationStates;
+        let rev_states = match rev_states {
+            Some(rev_states) => rev_states,
+            None => {
+                cached_rev_states = self._get_cached_revocation_states(wallet_handle, requested_credentials)?;
+                &cached_rev_states
+            }
+        };
+
         let cred_refs_for_attrs =
             requested_credentials.requested_attributes
                 .values()
@@ -788,6 +848,277 @@ impl ProverCommandExecutor {
         Ok(rev_state_json)
     }
 
+    fn refresh_revocation_states(&self,
+                                 pool_handle: PoolHandle,
+                                 wallet_handle: WalletHandle,
+                                 blob_storage_reader_handle: i32,
+                                 cred_ids: &[String],
+                                 cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("refresh_revocation_states >>> pool_handle: {:?}, wallet_handle: {:?}, blob_storage_reader_handle: {:?}, cred_ids: {:?}",
+               pool_handle, wallet_handle, blob_storage_reader_handle, cred_ids);
+
+        let (fetches, rev_states) = try_cb!(self._prepare_revocation_states_refresh(wallet_handle, cred_ids), cb);
+
+        let cb_id = next_command_handle();
+
+        self.pending_rev_states_refreshes.borrow_mut().insert(cb_id, PendingRevocationStatesRefresh {
+            pool_handle,
+            wallet_handle,
+            blob_storage_reader_handle,
+            fetches,
+            rev_reg_defs: HashMap::new(),
+            rev_states,
+            cb,
+        });
+
+        self._refresh_revocation_states_next(cb_id);
+    }
+
+    fn _prepare_revocation_states_refresh(&self,
+                                          wallet_handle: WalletHandle,
+                                          cred_ids: &[String]) -> IndyResult<(VecDeque<RevocationStatesFetch>, HashMap<String, CredentialRevocationStates>)> {
+        if cred_ids.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "List of credential ids is empty"));
+        }
+
+        // Witness of the listed twice credential would be updated with the same delta twice.
+        let mut unique_cred_ids: HashSet<&str> = HashSet::new();
+
+        for cred_id in cred_ids {
+            if !unique_cred_ids.insert(cred_id.as_str()) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Credential {} is listed more than once", cred_id)));
+            }
+        }
+
+        let mut fetches: VecDeque<RevocationStatesFetch> = VecDeque::new();
+        let mut rev_states: HashMap<String, CredentialRevocationStates> = HashMap::new();
+
+        for cred_id in cred_ids {
+            let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, cred_id, &RecordOptions::id_value())?;
+
+            let rev_reg_id = credential.rev_reg_id.clone()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Credential {} doesn't support revocation", cred_id)))?;
+
+            let rev_idx = credential.signature.extract_index()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Credential {} doesn't support revocation", cred_id)))?;
+
+            let cred_rev_states = if self.wallet_service.record_exists::<CredentialRevocationStates>(wallet_handle, cred_id)? {
+                self.wallet_service.get_indy_object(wallet_handle, cred_id, &RecordOptions::id_value())?
+            } else {
+                CredentialRevocationStates::new(rev_reg_id.clone())
+            };
+
+            let from = cred_rev_states.latest().map(|rev_state| rev_state.timestamp);
+
+            match fetches.iter_mut().find(|fetch| fetch.rev_reg_id == rev_reg_id && fetch.from == from) {
+                Some(fetch) => fetch.credentials.push((cred_id.to_string(), rev_idx)),
+                None => fetches.push_back(RevocationStatesFetch { rev_reg_id, from, credentials: vec![(cred_id.to_string(), rev_idx)] })
+            }
+
+            rev_states.insert(cred_id.to_string(), cred_rev_states);
+        }
+
+        Ok((fetches, rev_states))
+    }
+
+    fn _refresh_revocation_states_next(&self, cb_id: CommandHandle) {
+        let request = match self._build_revocation_states_refresh_request(cb_id) {
+            Ok(request) => request,
+            Err(err) => return self._refresh_revocation_states_complete(cb_id, Err(err))
+        };
+
+        let (pool_handle, request_json) = match request {
+            Some(request) => request,
+            None => {
+                let res = self._store_refreshed_revocation_states(cb_id);
+                return self._refresh_revocation_states_complete(cb_id, res);
+            }
+        };
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::SubmitRequest(
+                    pool_handle,
+                    request_json,
+                    Box::new(move |response| {
+                        CommandExecutor::instance().send(
+                            Command::Anoncreds(
+                                AnoncredsCommand::Prover(
+                                    ProverCommand::RefreshRevocationStatesContinue(
+                                        response,
+                                        cb_id,
+                                    )
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    /// Registry definition is requested once per registry before the first delta of this registry.
+    fn _build_revocation_states_refresh_request(&self, cb_id: CommandHandle) -> IndyResult<Option<(PoolHandle, String)>> {
+        let pending_refreshes = self.pending_rev_states_refreshes.borrow();
+        let pending = pending_refreshes.get(&cb_id)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Pending revocation states refresh not found"))?;
+
+        let fetch = match pending.fetches.front() {
+            Some(fetch) => fetch,
+            None => return Ok(None)
+        };
+
+        let request_json = if pending.rev_reg_defs.contains_key(&fetch.rev_reg_id) {
+            let to = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time has gone backwards").as_secs() as i64;
+
+            self.ledger_service.build_get_revoc_reg_delta_request(None, &fetch.rev_reg_id, fetch.from.map(|from| from as i64), to)?
+        } else {
+            self.ledger_service.build_get_revoc_reg_def_request(None, &fetch.rev_reg_id)?
+        };
+
+        Ok(Some((pending.pool_handle, request_json)))
+    }
+
+    fn _refresh_revocation_states_continue(&self, response: IndyResult<String>, cb_id: CommandHandle) {
+        match response.and_then(|response| self._apply_revocation_states_refresh_response(cb_id, &response)) {
+            Ok(()) => self._refresh_revocation_states_next(cb_id),
+            Err(err) => self._refresh_revocation_states_complete(cb_id, Err(err))
+        }
+    }
+
+    fn _apply_revocation_states_refresh_response(&self, cb_id: CommandHandle, response: &str) -> IndyResult<()> {
+        let mut pending_refreshes = self.pending_rev_states_refreshes.borrow_mut();
+        let pending = pending_refreshes.get_mut(&cb_id)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Pending revocation states refresh not found"))?;
+
+        let rev_reg_id = pending.fetches.front()
+            .map(|fetch| fetch.rev_reg_id.clone())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation states refresh is already finished"))?;
+
+        if !pending.rev_reg_defs.contains_key(&rev_reg_id) {
+            let (_, rev_reg_def_json) = self.ledger_service.parse_get_revoc_reg_def_response(response)?;
+
+            let rev_reg_def: RevocationRegistryDefinition = serde_json::from_str(&rev_reg_def_json)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDefinition")?;
+
+            pending.rev_reg_defs.insert(rev_reg_id, RevocationRegistryDefinitionV1::from(rev_reg_def));
+            return Ok(());
+        }
+
+        let fetch = pending.fetches.pop_front()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation states refresh is already finished"))?;
+
+        let (_, rev_reg_delta_json, timestamp) = self.ledger_service.parse_get_revoc_reg_delta_response(response)?;
+
+        let rev_reg_delta: RevocationRegistryDelta = serde_json::from_str(&rev_reg_delta_json)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDelta")?;
+        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
+
+        let rev_reg_def = &pending.rev_reg_defs[&fetch.rev_reg_id];
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       pending.blob_storage_reader_handle,
+                                                       rev_reg_def)?;
+
+        for (cred_id, rev_idx) in fetch.credentials {
+            let cred_rev_states = pending.rev_states.get_mut(&cred_id)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("Revocation states of credential {} not found", cred_id)))?;
+
+            let witness = match cred_rev_states.latest() {
+                Some(rev_state) => {
+                    let mut witness = rev_state.witness.clone();
+                    witness.update(rev_idx, rev_reg_def.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
+                    witness
+                }
+                None => Witness::new(rev_idx, rev_reg_def.value.max_cred_num, rev_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?
+            };
+
+            cred_rev_states.add(RevocationState {
+                witness,
+                rev_reg: RevocationRegistry::from(rev_reg_delta.value.clone()),
+                timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// All refreshed states are stored at once when every delta is applied.
+    fn _store_refreshed_revocation_states(&self, cb_id: CommandHandle) -> IndyResult<String> {
+        let pending_refreshes = self.pending_rev_states_refreshes.borrow();
+        let pending = pending_refreshes.get(&cb_id)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Pending revocation states refresh not found"))?;
+
+        self.wallet_service.with_transaction(pending.wallet_handle, || {
+            for (cred_id, cred_rev_states) in pending.rev_states.iter() {
+                self.wallet_service.upsert_indy_object(pending.wallet_handle, cred_id, cred_rev_states)?;
+            }
+            Ok(())
+        })?;
+
+        let mut refreshed: HashMap<&str, Value> = HashMap::new();
+
+        for (cred_id, cred_rev_states) in pending.rev_states.iter() {
+            let rev_state = cred_rev_states.latest()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("Revocation state of credential {} not created", cred_id)))?;
+
+            refreshed.insert(cred_id, json!({
+                "rev_reg_id": cred_rev_states.rev_reg_id,
+                "timestamp": rev_state.timestamp,
+            }));
+        }
+
+        let refreshed_json = serde_json::to_string(&refreshed)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize refreshed revocation states")?;
+
+        debug!("refresh_revocation_states <<< refreshed_json: {:?}", refreshed_json);
+
+        Ok(refreshed_json)
+    }
+
+    fn _refresh_revocation_states_complete(&self, cb_id: CommandHandle, res: IndyResult<String>) {
+        let pending = self.pending_rev_states_refreshes.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        (pending.cb)(res)
+    }
+
+    fn _get_cached_revocation_states(&self,
+                                     wallet_handle: WalletHandle,
+                                     requested_credentials: &RequestedCredentials) -> IndyResult<RevocationStates> {
+        let cred_keys = requested_credentials.requested_attributes
+            .values()
+            .map(|requested_attr| (&requested_attr.cred_id, requested_attr.timestamp))
+            .chain(requested_credentials.requested_predicates
+                .values()
+                .map(|requested_predicate| (&requested_predicate.cred_id, requested_predicate.timestamp)));
+
+        let mut rev_states: RevocationStates = HashMap::new();
+
+        for (cred_id, timestamp) in cred_keys {
+            let timestamp = match timestamp {
+                Some(timestamp) => timestamp,
+                None => continue
+            };
+
+            if rev_states.get(cred_id).map(|states| states.contains_key(&timestamp)).unwrap_or(false) {
+                continue;
+            }
+
+            if !self.wallet_service.record_exists::<CredentialRevocationStates>(wallet_handle, cred_id)? {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Revocation states of credential {} are not cached", cred_id)));
+            }
+
+            let mut cred_rev_states: CredentialRevocationStates =
+                self.wallet_service.get_indy_object(wallet_handle, cred_id, &RecordOptions::id_value())?;
+
+            let rev_state = cred_rev_states.states.remove(&timestamp)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Revocation state of credential {} not cached for timestamp: {:?}", cred_id, timestamp)))?;
+
+            rev_states.entry(cred_id.to_string()).or_insert_with(HashMap::new).insert(timestamp, rev_state);
+        }
+
+        Ok(rev_states)
+    }
+
     fn _get_credential_info(&self,
                             referent: &str,
                             credential: Credential) -> CredentialInfo {
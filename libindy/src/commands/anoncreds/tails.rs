@@ -7,6 +7,7 @@ use ursa::errors::prelude::{UrsaCryptoError, UrsaCryptoErrorKind};
 
 use rust_base58::{ToBase58, FromBase58};
 
+use std::collections::HashMap;
 use std::rc::Rc;
 
 const TAILS_BLOB_TAG_SZ: u8 = 2;
@@ -45,16 +46,22 @@ impl Drop for SDKTailsAccessor {
     }
 }
 
-impl RevocationTailsAccessor for SDKTailsAccessor {
-    fn access_tail(&self, tail_id: u32, accessor: &mut dyn FnMut(&Tail)) -> Result<(), UrsaCryptoError> {
-        debug!("access_tail >>> tail_id: {:?}", tail_id);
-
-        let tail_bytes = self.tails_service
+impl SDKTailsAccessor {
+    fn read_tail_bytes(&self, tail_id: u32) -> Result<Vec<u8>, UrsaCryptoError> {
+        self.tails_service
             .read(self.tails_reader_handle,
                   TAIL_SIZE,
                   TAIL_SIZE * tail_id as usize + TAILS_BLOB_TAG_SZ as usize)
             .map_err(|_|
-                UrsaCryptoError::from_msg(UrsaCryptoErrorKind::InvalidState, "Can't read tail bytes from blob storage"))?; // FIXME: IO error should be returned
+                UrsaCryptoError::from_msg(UrsaCryptoErrorKind::InvalidState, "Can't read tail bytes from blob storage")) // FIXME: IO error should be returned
+    }
+}
+
+impl RevocationTailsAccessor for SDKTailsAccessor {
+    fn access_tail(&self, tail_id: u32, accessor: &mut dyn FnMut(&Tail)) -> Result<(), UrsaCryptoError> {
+        debug!("access_tail >>> tail_id: {:?}", tail_id);
+
+        let tail_bytes = self.read_tail_bytes(tail_id)?;
 
         let tail = Tail::from_bytes(tail_bytes.as_slice())?;
         accessor(&tail);
@@ -64,6 +71,36 @@ impl RevocationTailsAccessor for SDKTailsAccessor {
     }
 }
 
+/// Tails read from the blob storage in advance.
+/// Unlike `SDKTailsAccessor` it can be moved to the crypto thread pool.
+pub struct PreloadedTailsAccessor {
+    tails: HashMap<u32, Vec<u8>>,
+}
+
+impl PreloadedTailsAccessor {
+    pub fn new(tails_accessor: &SDKTailsAccessor, tail_ids: &[u32]) -> IndyResult<PreloadedTailsAccessor> {
+        let mut tails = HashMap::new();
+
+        for tail_id in tail_ids {
+            tails.insert(*tail_id, tails_accessor.read_tail_bytes(*tail_id)?);
+        }
+
+        Ok(PreloadedTailsAccessor { tails })
+    }
+}
+
+impl RevocationTailsAccessor for PreloadedTailsAccessor {
+    fn access_tail(&self, tail_id: u32, accessor: &mut dyn FnMut(&Tail)) -> Result<(), UrsaCryptoError> {
+        let tail_bytes = self.tails.get(&tail_id)
+            .ok_or_else(|| UrsaCryptoError::from_msg(UrsaCryptoErrorKind::InvalidState, format!("Tail {} isn't preloaded", tail_id)))?;
+
+        let tail = Tail::from_bytes(tail_bytes.as_slice())?;
+        accessor(&tail);
+
+        Ok(())
+    }
+}
+
 pub fn store_tails_from_generator(service: Rc<BlobStorageService>,
                                   writer_handle: i32,
                                   rtg: &mut RevocationTailsGenerator) -> IndyResult<(String, String)> {
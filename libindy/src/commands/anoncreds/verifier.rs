@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
-use crate::domain::anoncreds::proof::Proof;
+use crate::domain::anoncreds::proof::{Proof, ProofVerificationDetails};
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestPayload};
 use crate::domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map, RevocationRegistryV1, RevocationRegistries};
 use crate::domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinitionV1, RevocationRegistryId, RevocationRegistryDefinitions};
@@ -19,6 +19,14 @@ pub enum VerifierCommand {
         RevocationRegistryDefinitions, // rev reg defs
         RevocationRegistries, // rev reg entries
         Box<dyn Fn(IndyResult<bool>) + Send>),
+    VerifyProofWithDetails(
+        ProofRequest, // proof request
+        Proof, // proof
+        Schemas, // credential schemas
+        CredentialDefinitions, // credential defs
+        RevocationRegistryDefinitions, // rev reg defs
+        RevocationRegistries, // rev reg entries
+        Box<dyn Fn(IndyResult<String>) + Send>),
     GenerateNonce(
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
@@ -42,7 +50,16 @@ impl VerifierCommandExecutor {
                                      &schemas_map_to_schemas_v1_map(schemas),
                                      &cred_defs_map_to_cred_defs_v1_map(credential_defs),
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
-                                     &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+                                     &rev_regs_map_to_rev_regs_local_map(rev_regs))
+                    .map(|details| details.valid));
+            }
+            VerifierCommand::VerifyProofWithDetails(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyProofWithDetails command received");
+                cb(self.verify_proof_with_details(&proof_request.value(), proof,
+                                                  &schemas_map_to_schemas_v1_map(schemas),
+                                                  &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                                  &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                                  &rev_regs_map_to_rev_regs_local_map(rev_regs)));
             }
             VerifierCommand::GenerateNonce(cb) => {
                 debug!(target: "verifier_command_executor", "GenerateNonce command received");
@@ -57,7 +74,7 @@ impl VerifierCommandExecutor {
                     schemas: &HashMap<SchemaId, SchemaV1>,
                     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
                     rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
-                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
+                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<ProofVerificationDetails> {
         debug!("verify_proof >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
                rev_reg_defs: {:?}, rev_regs: {:?}",
                proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
@@ -74,6 +91,27 @@ impl VerifierCommandExecutor {
         Ok(result)
     }
 
+    fn verify_proof_with_details(&self,
+                                 proof_req: &ProofRequestPayload,
+                                 proof: Proof,
+                                 schemas: &HashMap<SchemaId, SchemaV1>,
+                                 cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                                 rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                                 rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<String> {
+        debug!("verify_proof_with_details >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let details = self.verify_proof(proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        let res = serde_json::to_string(&details)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofVerificationDetails")?;
+
+        debug!("verify_proof_with_details <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn generate_nonce(&self) -> IndyResult<String> {
         debug!("generate_nonce >>> ");
 
@@ -2,15 +2,23 @@ use std::collections::HashMap;
 
 use crate::domain::crypto::key::{Key, KeyInfo, KeyMetadata};
 use crate::domain::crypto::pack::*;
+use crate::domain::crypto::jws::{JwsOptions, JwsSerialization};
 use indy_api_types::errors::prelude::*;
-use crate::services::crypto::CryptoService;
+use crate::services::crypto::{CryptoService, DEFAULT_CRYPTO_TYPE, P256_CRYPTO_TYPE, SECP256K1_CRYPTO_TYPE};
+use crate::services::crypto::jose;
+use crate::services::crypto::plugged::PluggedKeyBackend;
 use indy_wallet::{RecordOptions, WalletService};
 
 use std::rc::Rc;
 use std::str;
 use indy_utils::crypto::base64;
 use indy_utils::crypto::chacha20poly1305_ietf;
+use indy_utils::crypto::ed25519_box;
+use indy_utils::crypto::hash::hash;
+use indy_utils::crypto::randombytes::randombytes;
 use crate::domain::crypto::combo_box::ComboBox;
+use crate::utils::crypto::did_key;
+use crate::utils::crypto::verkey_builder::verkey_get_cryptoname;
 use indy_api_types::WalletHandle;
 
 pub const PROTECTED_HEADER_ENC: &str = "xchacha20poly1305_ietf";
@@ -18,7 +26,18 @@ pub const PROTECTED_HEADER_TYP: &str = "JWM/1.0";
 pub const PROTECTED_HEADER_ALG_AUTH: &str = "Authcrypt";
 pub const PROTECTED_HEADER_ALG_ANON: &str = "Anoncrypt";
 
+pub const DIDCOMM_V2_ENCRYPTED_TYP: &str = "application/didcomm-encrypted+json";
+pub const DIDCOMM_V2_SIGNED_TYP: &str = "application/didcomm-signed+json";
+pub const DIDCOMM_V2_ALG_AUTH: &str = "ECDH-1PU+A256KW";
+pub const DIDCOMM_V2_ALG_ANON: &str = "ECDH-ES+A256KW";
+pub const DIDCOMM_V2_ENC: &str = "A256CBC-HS512";
+
 pub enum CryptoCommand {
+    RegisterKeyBackend(
+        String, // crypto type
+        PluggedKeyBackend, // key backend handlers
+        Box<dyn Fn(IndyResult<()>) + Send>,
+    ),
     CreateKey(
         WalletHandle,
         KeyInfo, // key info
@@ -47,6 +66,19 @@ pub enum CryptoCommand {
         Vec<u8>, // signature
         Box<dyn Fn(IndyResult<bool>) + Send>,
     ),
+    CryptoSignJws(
+        WalletHandle,
+        String,  // my vk
+        Vec<u8>, // payload
+        JwsOptions,
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
+    CryptoVerifyJws(
+        String,  // their vk
+        String,  // jws
+        Option<Vec<u8>>, // detached payload
+        Box<dyn Fn(IndyResult<bool>) + Send>,
+    ),
     AuthenticatedEncrypt(
         WalletHandle,
         String,  // my vk
@@ -83,6 +115,19 @@ pub enum CryptoCommand {
         WalletHandle,
         Box<dyn Fn(IndyResult<Vec<u8>>) + Send>,
     ),
+    PackMessageV2(
+        Vec<u8>, // plaintext message
+        Vec<String>,  // list of receiver's key ids
+        Option<String>,  // sender's key id
+        Option<String>,  // signer's key id
+        WalletHandle,
+        Box<dyn Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    UnpackMessageV2(
+        Vec<u8>, // packed message
+        WalletHandle,
+        Box<dyn Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
 }
 
 pub struct CryptoCommandExecutor {
@@ -103,6 +148,10 @@ impl CryptoCommandExecutor {
 
     pub fn execute(&self, command: CryptoCommand) {
         match command {
+            CryptoCommand::RegisterKeyBackend(crypto_type, key_backend, cb) => {
+                debug!("RegisterKeyBackend command received");
+                cb(self.register_key_backend(&crypto_type, key_backend));
+            }
             CryptoCommand::CreateKey(wallet_handle, key_info, cb) => {
                 debug!("CreateKey command received");
                 cb(self.create_key(wallet_handle, &key_info));
@@ -123,6 +172,14 @@ impl CryptoCommandExecutor {
                 debug!("CryptoVerify command received");
                 cb(self.crypto_verify(&their_vk, &msg, &signature));
             }
+            CryptoCommand::CryptoSignJws(wallet_handle, my_vk, payload, options, cb) => {
+                debug!("CryptoSignJws command received");
+                cb(self.crypto_sign_jws(wallet_handle, &my_vk, &payload, &options));
+            }
+            CryptoCommand::CryptoVerifyJws(their_vk, jws, detached_payload, cb) => {
+                debug!("CryptoVerifyJws command received");
+                cb(self.crypto_verify_jws(&their_vk, &jws, detached_payload.as_ref().map(Vec::as_slice)));
+            }
             CryptoCommand::AuthenticatedEncrypt(wallet_handle, my_vk, their_vk, msg, cb) => {
                 debug!("AuthenticatedEncrypt command received");
                 cb(self.authenticated_encrypt(wallet_handle, &my_vk, &their_vk, &msg));
@@ -147,9 +204,27 @@ impl CryptoCommandExecutor {
                 debug!("UnpackMessage command received");
                 cb(self.unpack_msg(jwe_json, wallet_handle));
             }
+            CryptoCommand::PackMessageV2(message, receivers, sender_kid, signer_kid, wallet_handle, cb) => {
+                debug!("PackMessageV2 command received");
+                cb(self.pack_msg_v2(message, receivers, sender_kid, signer_kid, wallet_handle));
+            }
+            CryptoCommand::UnpackMessageV2(packed_message, wallet_handle, cb) => {
+                debug!("UnpackMessageV2 command received");
+                cb(self.unpack_msg_v2(&packed_message, wallet_handle));
+            }
         };
     }
 
+    fn register_key_backend(&self, crypto_type: &str, key_backend: PluggedKeyBackend) -> IndyResult<()> {
+        debug!("register_key_backend >>> crypto_type: {:?}", crypto_type);
+
+        self.crypto_service.register_key_backend(crypto_type, key_backend)?;
+
+        debug!("register_key_backend <<<");
+
+        Ok(())
+    }
+
     fn create_key(&self, wallet_handle: WalletHandle, key_info: &KeyInfo) -> IndyResult<String> {
         debug!(
             "create_key >>> wallet_handle: {:?}, key_info: {:?}",
@@ -205,6 +280,101 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn crypto_sign_jws(&self,
+                       wallet_handle: WalletHandle,
+                       my_vk: &str,
+                       payload: &[u8],
+                       options: &JwsOptions) -> IndyResult<String> {
+        trace!(
+            "crypto_sign_jws >>> wallet_handle: {:?}, sender_vk: {:?}, payload: {:?}, options: {:?}",
+            wallet_handle, my_vk, payload, options
+        );
+
+        self.crypto_service.validate_key(my_vk)?;
+
+        let key: Key = self.wallet_service.get_indy_object(
+            wallet_handle,
+            &my_vk,
+            &RecordOptions::id_value(),
+        )?;
+
+        let kid = match options.kid() {
+            Some(kid) => kid,
+            None => did_key::verkey_to_kid(&key.verkey)?
+        };
+
+        // Compact serialization has no unprotected header so kid goes to protected one
+        let (protected, header) = match options.serialization {
+            JwsSerialization::Compact => (JwsProtected {
+                typ: None,
+                alg: CryptoCommandExecutor::_jws_alg(&key.verkey)?.to_string(),
+                kid: Some(kid),
+            }, None),
+            JwsSerialization::Json => (JwsProtected {
+                typ: None,
+                alg: CryptoCommandExecutor::_jws_alg(&key.verkey)?.to_string(),
+                kid: None,
+            }, Some(JwsHeader { kid })),
+        };
+
+        let payload = base64::encode_urlsafe_no_pad(payload);
+        let (protected, signature) = self._jws_signature(&key, &protected, &payload)?;
+
+        let payload = if options.detached { String::new() } else { payload };
+
+        let res = match header {
+            None => format!("{}.{}.{}", protected, payload, signature),
+            Some(header) => CryptoCommandExecutor::_to_json(&Jws {
+                payload,
+                signatures: vec![JwsSignature { protected, header, signature }],
+            }, "JWS")?
+        };
+
+        trace!("crypto_sign_jws <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn crypto_verify_jws(&self,
+                         their_vk: &str,
+                         jws: &str,
+                         detached_payload: Option<&[u8]>) -> IndyResult<bool> {
+        trace!(
+            "crypto_verify_jws >>> their_vk: {:?}, jws: {:?}, detached_payload: {:?}",
+            their_vk, jws, detached_payload
+        );
+
+        self.crypto_service.validate_key(their_vk)?;
+
+        let (protected, payload, signature) = if jws.trim_start().starts_with('{') {
+            let jws: Jws = serde_json::from_str(jws)
+                .to_indy(IndyErrorKind::InvalidStructure, "Failed to deserialize JWS")?;
+
+            match jws.signatures.as_slice() {
+                [signature] => (signature.protected.clone(), jws.payload, signature.signature.clone()),
+                _ => return Err(err_msg(IndyErrorKind::InvalidStructure, "Only JWS with a single signature is supported"))
+            }
+        } else {
+            match jws.split('.').collect::<Vec<&str>>().as_slice() {
+                [protected, payload, signature] => (protected.to_string(), payload.to_string(), signature.to_string()),
+                _ => return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS compact serialization must consist of 3 parts"))
+            }
+        };
+
+        let payload = match (payload.is_empty(), detached_payload) {
+            (true, Some(detached_payload)) => base64::encode_urlsafe_no_pad(detached_payload),
+            (true, None) => return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS payload is detached but wasn't passed")),
+            (false, Some(_)) => return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS payload is attached but detached payload was passed")),
+            (false, None) => payload,
+        };
+
+        let res = self._verify_jws_signature(their_vk, &protected, &payload, &signature)?;
+
+        trace!("crypto_verify_jws <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     //TODO begin deprecation process this function. It will be replaced by pack
     fn authenticated_encrypt(
         &self,
@@ -621,4 +791,360 @@ impl CryptoCommandExecutor {
         Ok((None, cek))
     }
 
+    pub fn pack_msg_v2(
+        &self,
+        message: Vec<u8>,
+        receiver_kids: Vec<String>,
+        sender_kid: Option<String>,
+        signer_kid: Option<String>,
+        wallet_handle: WalletHandle,
+    ) -> IndyResult<Vec<u8>> {
+        //signed only message doesn't need receivers
+        if receiver_kids.is_empty() && signer_kid.is_none() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "No receiver keys found".to_string()));
+        }
+
+        //wrap plaintext into JWS envelope before encryption
+        let payload = match signer_kid {
+            Some(signer_kid) => self._sign_jws(&message, &signer_kid, wallet_handle)?,
+            None => message
+        };
+
+        if receiver_kids.is_empty() {
+            return Ok(payload);
+        }
+
+        self._encrypt_jwe_v2(&payload, receiver_kids, sender_kid, wallet_handle)
+    }
+
+    fn _sign_jws(&self, payload: &[u8], signer_kid: &str, wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
+        let signer_key: Key = self._get_key_by_kid(signer_kid, wallet_handle)?;
+
+        let protected = JwsProtected {
+            typ: Some(DIDCOMM_V2_SIGNED_TYP.to_string()),
+            alg: CryptoCommandExecutor::_jws_alg(&signer_key.verkey)?.to_string(),
+            kid: None,
+        };
+
+        let payload = base64::encode_urlsafe_no_pad(payload);
+        let (protected, signature) = self._jws_signature(&signer_key, &protected, &payload)?;
+
+        let kid = if signer_kid.starts_with(did_key::DID_KEY_PREFIX) {
+            signer_kid.to_string()
+        } else {
+            did_key::verkey_to_kid(&signer_key.verkey)?
+        };
+
+        let jws = Jws {
+            payload,
+            signatures: vec![JwsSignature {
+                protected,
+                header: JwsHeader { kid },
+                signature,
+            }],
+        };
+
+        Ok(CryptoCommandExecutor::_to_json(&jws, "JWS")?.into_bytes())
+    }
+
+    // Returns encoded protected header and signature over JWS signing input
+    fn _jws_signature(&self, key: &Key, protected: &JwsProtected, payload: &str) -> IndyResult<(String, String)> {
+        let protected = base64::encode_urlsafe_no_pad(CryptoCommandExecutor::_to_json(protected, "JWS protected header")?.as_bytes());
+
+        let signature = self.crypto_service.sign(key, format!("{}.{}", protected, payload).as_bytes())?;
+
+        Ok((protected, base64::encode_urlsafe_no_pad(&signature)))
+    }
+
+    fn _encrypt_jwe_v2(&self,
+                       payload: &[u8],
+                       receiver_kids: Vec<String>,
+                       sender_kid: Option<String>,
+                       wallet_handle: WalletHandle,
+    ) -> IndyResult<Vec<u8>> {
+        let mut receivers: Vec<(String, ed25519_box::PublicKey)> = Vec::with_capacity(receiver_kids.len());
+
+        for kid in receiver_kids {
+            let receiver_verkey = did_key::kid_to_verkey(&kid)?;
+            let receiver_pk = self.crypto_service.convert_to_x25519_public_key(&receiver_verkey)?;
+            let kid = self._key_agreement_kid(kid, &receiver_verkey, &receiver_pk)?;
+
+            receivers.push((kid, receiver_pk));
+        }
+
+        let sender = match sender_kid {
+            Some(sender_kid) => {
+                let sender_key: Key = self._get_key_by_kid(&sender_kid, wallet_handle)?;
+                let sender_sk = self.crypto_service.convert_to_x25519_secret_key(&sender_key)?;
+                let sender_pk = self.crypto_service.convert_to_x25519_public_key(&sender_key.verkey)?;
+                let sender_kid = self._key_agreement_kid(sender_kid, &sender_key.verkey, &sender_pk)?;
+
+                Some((sender_kid, sender_sk))
+            }
+            None => None
+        };
+
+        let alg = if sender.is_some() { DIDCOMM_V2_ALG_AUTH } else { DIDCOMM_V2_ALG_ANON };
+
+        //all recipients share the same ephemeral key
+        let (epk, esk) = ed25519_box::create_key_pair();
+
+        let skid = sender.as_ref().map(|(sender_kid, _)| sender_kid.clone());
+        let apu = skid.as_ref().map(|skid| base64::encode_urlsafe_no_pad(skid.as_bytes()));
+        let apv = CryptoCommandExecutor::_apv(receivers.iter().map(|(kid, _)| kid.as_str()).collect())?;
+
+        let protected = ProtectedV2 {
+            typ: DIDCOMM_V2_ENCRYPTED_TYP.to_string(),
+            alg: alg.to_string(),
+            enc: DIDCOMM_V2_ENC.to_string(),
+            epk: Jwk {
+                kty: "OKP".to_string(),
+                crv: "X25519".to_string(),
+                x: base64::encode_urlsafe_no_pad(&epk[..]),
+            },
+            apv: apv.clone(),
+            skid,
+            apu: apu.clone(),
+        };
+
+        let protected = base64::encode_urlsafe_no_pad(CryptoCommandExecutor::_to_json(&protected, "protected header")?.as_bytes());
+
+        // Use protected header as associated data
+        let cek = randombytes(jose::A256CBC_HS512_KEY_BYTES);
+        let iv = randombytes(jose::A256CBC_HS512_IV_BYTES);
+        let (ciphertext, tag) = jose::a256cbc_hs512_encrypt(&cek, &iv, payload, protected.as_bytes())?;
+
+        let apu = apu.as_ref().map(|apu| base64::decode_urlsafe(apu)).transpose()?.unwrap_or_default();
+        let apv = base64::decode_urlsafe(&apv)?;
+
+        let mut recipients = Vec::with_capacity(receivers.len());
+
+        for (kid, receiver_pk) in receivers {
+            let mut z = ed25519_box::scalarmult(&esk, &receiver_pk)?;
+
+            // ECDH-1PU also binds the key to the static key of the sender and to the tag
+            let kdf_tag = if let Some((_, ref sender_sk)) = sender {
+                z.extend(ed25519_box::scalarmult(sender_sk, &receiver_pk)?);
+                Some(&tag[..])
+            } else {
+                None
+            };
+
+            let kek = jose::concat_kdf(&z, alg, &apu, &apv, jose::A256KW_KEY_BYTES, kdf_tag)?;
+            let encrypted_key = jose::aes_key_wrap(&kek, &cek)?;
+
+            recipients.push(RecipientV2 {
+                header: RecipientHeaderV2 { kid },
+                encrypted_key: base64::encode_urlsafe_no_pad(&encrypted_key),
+            });
+        }
+
+        let jwe = JweV2 {
+            protected,
+            recipients,
+            iv: base64::encode_urlsafe_no_pad(&iv),
+            ciphertext: base64::encode_urlsafe_no_pad(&ciphertext),
+            tag: base64::encode_urlsafe_no_pad(&tag),
+        };
+
+        Ok(CryptoCommandExecutor::_to_json(&jwe, "JWE")?.into_bytes())
+    }
+
+    pub fn unpack_msg_v2(&self, packed_message: &[u8], wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
+        let packed_message_json: serde_json::Value = serde_json::from_slice(packed_message)
+            .to_indy(IndyErrorKind::InvalidStructure, "Packed message is not a valid json")?;
+
+        //detect format of packed message
+        let res = if packed_message_json.get("signatures").is_some() {
+            let (message, signer_kid) = self._verify_jws(packed_message)?;
+
+            UnpackMessageV2 {
+                message: CryptoCommandExecutor::_to_utf8(message)?,
+                recipient_kid: None,
+                sender_kid: None,
+                signer_kid: Some(signer_kid),
+            }
+        } else if packed_message_json.get("recipients").is_some() {
+            let jwe: JweV2 = serde_json::from_value(packed_message_json)
+                .to_indy(IndyErrorKind::InvalidStructure, "Failed to deserialize DIDComm v2 JWE")?;
+
+            let (payload, recipient_kid, sender_kid) = self._decrypt_jwe_v2(jwe, wallet_handle)?;
+
+            //encrypted message may contain signed envelope
+            let (message, signer_kid) = if CryptoCommandExecutor::_is_jws(&payload) {
+                let (message, signer_kid) = self._verify_jws(&payload)?;
+                (message, Some(signer_kid))
+            } else {
+                (payload, None)
+            };
+
+            UnpackMessageV2 {
+                message: CryptoCommandExecutor::_to_utf8(message)?,
+                recipient_kid: Some(recipient_kid),
+                sender_kid,
+                signer_kid,
+            }
+        } else {
+            let jwe: JWE = serde_json::from_value(packed_message_json)
+                .to_indy(IndyErrorKind::InvalidStructure, "Packed message has unknown format")?;
+
+            let unpacked = self.unpack_msg(jwe, wallet_handle)?;
+            let unpacked: UnpackMessage = serde_json::from_slice(&unpacked)
+                .to_indy(IndyErrorKind::InvalidState, "Failed to deserialize unpacked message")?;
+
+            UnpackMessageV2 {
+                message: unpacked.message,
+                recipient_kid: Some(unpacked.recipient_verkey),
+                sender_kid: unpacked.sender_verkey,
+                signer_kid: None,
+            }
+        };
+
+        Ok(CryptoCommandExecutor::_to_json(&res, "message")?.into_bytes())
+    }
+
+    fn _decrypt_jwe_v2(&self, jwe: JweV2, wallet_handle: WalletHandle) -> IndyResult<(Vec<u8>, String, Option<String>)> {
+        let protected: ProtectedV2 = serde_json::from_slice(&base64::decode_urlsafe(&jwe.protected)?)
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to deserialize protected data")?;
+
+        if protected.enc != DIDCOMM_V2_ENC || protected.epk.crv != "X25519" {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Unsupported encryption of DIDComm v2 message: {} {}", protected.enc, protected.epk.crv)));
+        }
+
+        let is_authcrypt = match protected.alg.as_str() {
+            DIDCOMM_V2_ALG_AUTH => true,
+            DIDCOMM_V2_ALG_ANON => false,
+            alg => return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Unsupported key management algorithm: {}", alg)))
+        };
+
+        let tag = base64::decode_urlsafe(&jwe.tag)?;
+        let epk = ed25519_box::PublicKey::from_slice(&base64::decode_urlsafe(&protected.epk.x)?)?;
+        let apv = base64::decode_urlsafe(&protected.apv)?;
+
+        let (recipient, my_key) = self._find_correct_recipient_v2(jwe.recipients, wallet_handle)?;
+        let my_sk = self.crypto_service.convert_to_x25519_secret_key(&my_key)?;
+
+        let mut z = ed25519_box::scalarmult(&my_sk, &epk)?;
+
+        let (sender_kid, apu, kdf_tag) = if is_authcrypt {
+            let sender_kid = protected.skid
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Sender key id isn't set for authcrypted message"))?;
+            let apu = base64::decode_urlsafe(protected.apu.as_ref().map(String::as_str).unwrap_or_default())?;
+
+            if apu != sender_kid.as_bytes() {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Sender key id doesn't match apu header"));
+            }
+
+            let sender_verkey = did_key::kid_to_verkey(&sender_kid)?;
+            let sender_pk = self.crypto_service.convert_to_x25519_public_key(&sender_verkey)?;
+            z.extend(ed25519_box::scalarmult(&my_sk, &sender_pk)?);
+
+            (Some(sender_kid), apu, Some(&tag[..]))
+        } else {
+            (None, Vec::new(), None)
+        };
+
+        let kek = jose::concat_kdf(&z, &protected.alg, &apu, &apv, jose::A256KW_KEY_BYTES, kdf_tag)?;
+        let cek = jose::aes_key_unwrap(&kek, &base64::decode_urlsafe(&recipient.encrypted_key)?)?;
+
+        let payload = jose::a256cbc_hs512_decrypt(&cek,
+                                                  &base64::decode_urlsafe(&jwe.iv)?,
+                                                  &base64::decode_urlsafe(&jwe.ciphertext)?,
+                                                  jwe.protected.as_bytes(),
+                                                  &tag)?;
+
+        Ok((payload, recipient.header.kid, sender_kid))
+    }
+
+    fn _find_correct_recipient_v2(&self, recipients: Vec<RecipientV2>, wallet_handle: WalletHandle) -> IndyResult<(RecipientV2, Key)> {
+        for recipient in recipients {
+            if let Ok(my_key) = self._get_key_by_kid(&recipient.header.kid, wallet_handle) {
+                return Ok((recipient, my_key));
+            }
+        }
+        Err(IndyError::from(IndyErrorKind::WalletItemNotFound))
+    }
+
+    fn _verify_jws(&self, jws: &[u8]) -> IndyResult<(Vec<u8>, String)> {
+        let jws: Jws = serde_json::from_slice(jws)
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to deserialize JWS")?;
+
+        if jws.signatures.len() != 1 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Only JWS with a single signature is supported"));
+        }
+
+        let signature = &jws.signatures[0];
+
+        let signer_verkey = did_key::kid_to_verkey(&signature.header.kid)?;
+
+        if !self._verify_jws_signature(&signer_verkey, &signature.protected, &jws.payload, &signature.signature)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS signature is invalid"));
+        }
+
+        Ok((base64::decode_urlsafe(&jws.payload)?, signature.header.kid.clone()))
+    }
+
+    fn _verify_jws_signature(&self, verkey: &str, protected: &str, payload: &str, signature: &str) -> IndyResult<bool> {
+        let protected_header: JwsProtected = serde_json::from_slice(&base64::decode_urlsafe(protected)?)
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to deserialize JWS protected header")?;
+
+        if protected_header.alg != CryptoCommandExecutor::_jws_alg(verkey)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("JWS algorithm {} doesn't match signer key", protected_header.alg)));
+        }
+
+        self.crypto_service.verify(verkey,
+                                   format!("{}.{}", protected, payload).as_bytes(),
+                                   &base64::decode_urlsafe(signature)?)
+    }
+
+    fn _get_key_by_kid(&self, kid: &str, wallet_handle: WalletHandle) -> IndyResult<Key> {
+        let verkey = did_key::kid_to_verkey(kid)?;
+
+        self.wallet_service.get_indy_object(
+            wallet_handle,
+            &verkey,
+            &RecordOptions::id_value()
+        )
+    }
+
+    fn _key_agreement_kid(&self, kid: String, verkey: &str, x25519_pk: &ed25519_box::PublicKey) -> IndyResult<String> {
+        if kid.starts_with(did_key::DID_KEY_PREFIX) {
+            Ok(kid)
+        } else {
+            did_key::verkey_to_key_agreement_kid(verkey, &x25519_pk[..])
+        }
+    }
+
+    fn _apv(mut kids: Vec<&str>) -> IndyResult<String> {
+        kids.sort();
+        Ok(base64::encode_urlsafe_no_pad(&hash(kids.join(".").as_bytes())?))
+    }
+
+    fn _jws_alg(verkey: &str) -> IndyResult<&'static str> {
+        match verkey_get_cryptoname(verkey) {
+            DEFAULT_CRYPTO_TYPE => Ok("EdDSA"),
+            SECP256K1_CRYPTO_TYPE => Ok("ES256K"),
+            P256_CRYPTO_TYPE => Ok("ES256"),
+            crypto_type => Err(err_msg(IndyErrorKind::UnknownCrypto,
+                                       format!("Crypto type {} can't be used for JWS", crypto_type)))
+        }
+    }
+
+    fn _is_jws(payload: &[u8]) -> bool {
+        serde_json::from_slice::<serde_json::Value>(payload)
+            .map(|payload| payload.get("signatures").is_some() && payload.get("payload").is_some())
+            .unwrap_or(false)
+    }
+
+    fn _to_json<T: serde::Serialize>(value: &T, name: &str) -> IndyResult<String> {
+        serde_json::to_string(value)
+            .to_indy(IndyErrorKind::InvalidState, format!("Failed to serialize {}", name))
+    }
+
+    fn _to_utf8(message: Vec<u8>) -> IndyResult<String> {
+        String::from_utf8(message)
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to utf-8 encode message")
+    }
 }
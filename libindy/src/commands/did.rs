@@ -1,12 +1,13 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde_json;
 
 use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
 use crate::commands::ledger::LedgerCommand;
-use crate::domain::crypto::did::{Did, DidValue, DidMetadata, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo, DidMethod};
+use crate::domain::crypto::did::{Did, DidValue, DidMetadata, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo, DidMethod, DidKeyHistory};
 use crate::domain::crypto::key::KeyInfo;
 use crate::domain::ledger::attrib::{AttribData, Endpoint, GetAttrReplyResult};
 use crate::domain::ledger::nym::{GetNymReplyResult, GetNymResultDataV0};
@@ -15,6 +16,7 @@ use crate::domain::pairwise::Pairwise;
 use indy_api_types::errors::prelude::*;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
+use crate::services::pool::parse_response_metadata;
 use indy_wallet::{RecordOptions, SearchOptions, WalletService};
 use indy_api_types::{WalletHandle, PoolHandle, CommandHandle};
 use indy_utils::next_command_handle;
@@ -54,6 +56,12 @@ pub enum DidCommand {
         WalletHandle,
         DidValue, // did (my or their)
         Box<dyn Fn(IndyResult<String/*key*/>) + Send>),
+    KeyForDidAt(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // did (my or their)
+        u64, // timestamp
+        Box<dyn Fn(IndyResult<String/*key*/>) + Send>),
     SetEndpointForDid(
         WalletHandle,
         DidValue, // did
@@ -64,6 +72,10 @@ pub enum DidCommand {
         PoolHandle, // pool handle
         DidValue, // did
         Box<dyn Fn(IndyResult<(String, Option<String>)>) + Send>),
+    ResolveDid(
+        PoolHandle, // pool handle
+        DidValue, // did
+        Box<dyn Fn(IndyResult<String>) + Send>),
     SetDidMetadata(
         WalletHandle,
         DidValue, // did
@@ -84,12 +96,27 @@ pub enum DidCommand {
         IndyResult<String>, // GetNym Result
         CommandHandle, // deferred cmd id
     ),
-    // Internal commands
     GetAttribAck(
         WalletHandle,
         IndyResult<String>, // GetAttrib Result
         CommandHandle, // deferred cmd id
     ),
+    ResolveDidGetNymAck(
+        PoolHandle, // pool handle
+        DidValue, // did
+        IndyResult<String>, // GetNym Result
+        CommandHandle, // deferred cmd id
+    ),
+    ResolveDidGetAttribAck(
+        DidValue, // did
+        String, // GetNym Reply
+        IndyResult<String>, // GetAttrib Result
+        CommandHandle, // deferred cmd id
+    ),
+    KeyForDidAtGetNymAck(
+        IndyResult<String>, // GetNym Result
+        CommandHandle, // deferred cmd id
+    ),
     QualifyDid(
         WalletHandle,
         DidValue, // did
@@ -162,6 +189,10 @@ impl DidCommandExecutor {
                 debug!("KeyForLocalDid command received");
                 cb(self.key_for_local_did(wallet_handle, &did));
             }
+            DidCommand::KeyForDidAt(pool_handle, wallet_handle, did, timestamp, cb) => {
+                debug!("KeyForDidAt command received");
+                self.key_for_did_at(pool_handle, wallet_handle, did, timestamp, cb);
+            }
             DidCommand::SetEndpointForDid(wallet_handle, did, endpoint, cb) => {
                 debug!("SetEndpointForDid command received");
                 cb(self.set_endpoint_for_did(wallet_handle, &did, &endpoint));
@@ -170,6 +201,10 @@ impl DidCommandExecutor {
                 debug!("GetEndpointForDid command received");
                 self.get_endpoint_for_did(wallet_handle, pool_handle, did, cb);
             }
+            DidCommand::ResolveDid(pool_handle, did, cb) => {
+                debug!("ResolveDid command received");
+                self.resolve_did(pool_handle, did, cb);
+            }
             DidCommand::SetDidMetadata(wallet_handle, did, metadata, cb) => {
                 debug!("SetDidMetadata command received");
                 cb(self.set_did_metadata(wallet_handle, &did, metadata));
@@ -190,6 +225,18 @@ impl DidCommandExecutor {
                 debug!("GetAttribAck command received");
                 self.get_attrib_ack(wallet_handle, result, deferred_cmd_id);
             }
+            DidCommand::ResolveDidGetNymAck(pool_handle, did, result, deferred_cmd_id) => {
+                debug!("ResolveDidGetNymAck command received");
+                self.resolve_did_get_nym_ack(pool_handle, did, result, deferred_cmd_id);
+            }
+            DidCommand::ResolveDidGetAttribAck(did, get_nym_reply, result, deferred_cmd_id) => {
+                debug!("ResolveDidGetAttribAck command received");
+                self.resolve_did_get_attrib_ack(did, get_nym_reply, result, deferred_cmd_id);
+            }
+            DidCommand::KeyForDidAtGetNymAck(result, deferred_cmd_id) => {
+                debug!("KeyForDidAtGetNymAck command received");
+                self.key_for_did_at_get_nym_ack(result, deferred_cmd_id);
+            }
             DidCommand::QualifyDid(wallet_handle, did, method, cb) => {
                 debug!("QualifyDid command received");
                 cb(self.qualify_did(wallet_handle, &did, &method));
@@ -213,8 +260,11 @@ impl DidCommandExecutor {
             }
         }
 
-        self.wallet_service.add_indy_object(wallet_handle, &did.did.0, &did, &HashMap::new())?;
-        let _ = self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new()).ok();
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.add_indy_object(wallet_handle, &did.did.0, &did, &HashMap::new())?;
+            let _ = self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new()).ok();
+            self._update_key_history(wallet_handle, &did.did, None, &did.verkey, _current_timestamp())
+        })?;
 
         let res = (did.did.0, did.verkey);
 
@@ -236,8 +286,10 @@ impl DidCommandExecutor {
         let temporary_key = self.crypto_service.create_key(&key_info)?;
         let my_temporary_did = TemporaryDid { did: my_did.did, verkey: temporary_key.verkey.clone() };
 
-        self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &HashMap::new())?;
-        self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did.0, &my_temporary_did, &HashMap::new())?;
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &HashMap::new())?;
+            self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did.0, &my_temporary_did, &HashMap::new())
+        })?;
 
         let res = my_temporary_did.verkey;
 
@@ -253,14 +305,17 @@ impl DidCommandExecutor {
 
         self.crypto_service.validate_did(my_did)?;
 
-        let my_did = self._wallet_get_my_did(wallet_handle, my_did)?;
+        let curr_did = self._wallet_get_my_did(wallet_handle, my_did)?;
         let my_temporary_did: TemporaryDid =
-            self.wallet_service.get_indy_object(wallet_handle, &my_did.did.0, &RecordOptions::id_value())?;
+            self.wallet_service.get_indy_object(wallet_handle, &curr_did.did.0, &RecordOptions::id_value())?;
 
         let my_did = Did::from(my_temporary_did);
 
-        self.wallet_service.update_indy_object(wallet_handle, &my_did.did.0, &my_did)?;
-        self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.did.0)?;
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.update_indy_object(wallet_handle, &my_did.did.0, &my_did)?;
+            self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.did.0)?;
+            self._update_key_history(wallet_handle, &my_did.did, Some(&curr_did.verkey), &my_did.verkey, _current_timestamp())
+        })?;
 
         debug!("replace_keys_apply <<<");
 
@@ -273,8 +328,13 @@ impl DidCommandExecutor {
         debug!("store_their_did >>> wallet_handle: {:?}, their_did_info: {:?}", wallet_handle, their_did_info);
 
         let their_did = self.crypto_service.create_their_did(their_did_info)?;
+        let curr_their_did = self.wallet_service.get_indy_opt_object::<TheirDid>(wallet_handle, &their_did.did.0, &RecordOptions::id_value())?;
 
-        self.wallet_service.upsert_indy_object(wallet_handle, &their_did.did.0, &their_did)?;
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.upsert_indy_object(wallet_handle, &their_did.did.0, &their_did)?;
+            self._update_key_history(wallet_handle, &their_did.did, curr_their_did.as_ref().map(|did| did.verkey.as_str()),
+                                     &their_did.verkey, _current_timestamp())
+        })?;
 
         debug!("store_their_did <<<");
 
@@ -428,6 +488,43 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
+    fn key_for_did_at(&self,
+                      pool_handle: PoolHandle,
+                      wallet_handle: WalletHandle,
+                      did: DidValue,
+                      timestamp: u64,
+                      cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("key_for_did_at >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, timestamp: {:?}", pool_handle, wallet_handle, did, timestamp);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        let key_history = try_cb!(self._wallet_get_key_history(wallet_handle, &did), cb);
+
+        if let Some(verkey) = key_history.as_ref().and_then(|key_history| key_history.key_at(timestamp)) {
+            let res = verkey.to_string();
+            debug!("key_for_did_at <<< res: {:?}", res);
+            return cb(Ok(res));
+        }
+
+        // Key history doesn't cover requested time. Defer this command until DID state at this time is fetched from ledger.
+        let get_nym_request = try_cb!(self.ledger_service.build_get_nym_request(None, &did, None, Some(timestamp)), cb);
+
+        let deferred_cmd_id = self._defer_command(DidCommand::KeyForDidAt(pool_handle, wallet_handle, did, timestamp, cb));
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_nym_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::KeyForDidAtGetNymAck(
+                            result,
+                            deferred_cmd_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
     fn set_endpoint_for_did(&self,
                             wallet_handle: WalletHandle,
                             did: &DidValue,
@@ -473,6 +570,34 @@ impl DidCommandExecutor {
         };
     }
 
+    fn resolve_did(&self,
+                   pool_handle: PoolHandle,
+                   did: DidValue,
+                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("resolve_did >>> pool_handle: {:?}, did: {:?}", pool_handle, did);
+
+        let (_, dest) = try_cb!(self.ledger_service.parse_resolvable_did(&did), cb);
+        let get_nym_request = try_cb!(self.ledger_service.build_get_nym_request(None, &dest, None, None), cb);
+
+        // Defer this command until NYM and endpoint ATTRIB are fetched from ledger.
+        let deferred_cmd_id = self._defer_command(DidCommand::ResolveDid(pool_handle, did.clone(), cb));
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_nym_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::ResolveDidGetNymAck(
+                            pool_handle,
+                            did.clone(),
+                            result,
+                            deferred_cmd_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
     fn set_did_metadata(&self,
                         wallet_handle: WalletHandle,
                         did: &DidValue,
@@ -546,6 +671,16 @@ impl DidCommandExecutor {
 
         curr_did.did = DidValue::new(&did.to_short().0, Some(&method.0));
 
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self._move_did_records(wallet_handle, did, &curr_did)
+        })?;
+
+        debug!("qualify_did <<< res: {:?}", curr_did.did);
+
+        Ok(curr_did.did.0)
+    }
+
+    fn _move_did_records(&self, wallet_handle: WalletHandle, did: &DidValue, curr_did: &Did) -> IndyResult<()> {
         self.wallet_service.delete_indy_record::<Did>(wallet_handle, &did.0)?;
         self.wallet_service.add_indy_object(wallet_handle, &curr_did.did.0, &curr_did, &HashMap::new())?;
 
@@ -562,6 +697,9 @@ impl DidCommandExecutor {
         // move endpoint
         self.update_dependent_entity_reference::<Endpoint>(wallet_handle, &did.0, &curr_did.did.0)?;
 
+        // move key history
+        self.update_dependent_entity_reference::<DidKeyHistory>(wallet_handle, &did.0, &curr_did.did.0)?;
+
         // move all pairwise
         let mut pairwise_search =
             self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &RecordOptions::id_value())?;
@@ -578,9 +716,7 @@ impl DidCommandExecutor {
             }
         }
 
-        debug!("qualify_did <<< res: {:?}", curr_did.did);
-
-        Ok(curr_did.did.0)
+        Ok(())
     }
 
     fn update_dependent_entity_reference<T>(&self, wallet_handle: WalletHandle, id: &str, new_id: &str) -> IndyResult<()>
@@ -625,7 +761,15 @@ impl DidCommandExecutor {
 
         let their_did = self.crypto_service.create_their_did(&their_did_info)?;
 
-        self.wallet_service.add_indy_object(wallet_handle, &their_did.did.0, &their_did, &HashMap::new())?;
+        // Key is in effect since NYM transaction that set it was written to the ledger
+        let valid_from = parse_response_metadata(&get_nym_reply).ok()
+            .and_then(|metadata| metadata.txn_time)
+            .unwrap_or_else(_current_timestamp);
+
+        self.wallet_service.with_transaction(wallet_handle, || {
+            self.wallet_service.add_indy_object(wallet_handle, &their_did.did.0, &their_did, &HashMap::new())?;
+            self._update_key_history(wallet_handle, &their_did.did, None, &their_did.verkey, valid_from)
+        })?;
 
         trace!("_get_nym_ack <<<");
 
@@ -665,6 +809,84 @@ impl DidCommandExecutor {
         Ok(())
     }
 
+    fn resolve_did_get_nym_ack(&self,
+                               pool_handle: PoolHandle,
+                               did: DidValue,
+                               get_nym_reply_result: IndyResult<String>,
+                               deferred_cmd_id: CommandHandle) {
+        trace!("resolve_did_get_nym_ack >>> pool_handle: {:?}, did: {:?}, get_nym_reply_result: {:?}", pool_handle, did, get_nym_reply_result);
+
+        let get_attrib_request = get_nym_reply_result
+            .and_then(|get_nym_reply| {
+                // Fail early if DID isn't written to the ledger
+                self.ledger_service.parse_get_nym_response(&get_nym_reply)?;
+
+                let (_, dest) = self.ledger_service.parse_resolvable_did(&did)?;
+                let get_attrib_request = self.ledger_service.build_get_attrib_request(None, &dest, Some("endpoint"), None, None)?;
+
+                Ok((get_nym_reply, get_attrib_request))
+            });
+
+        let (get_nym_reply, get_attrib_request) = match get_attrib_request {
+            Ok(res) => res,
+            Err(err) => return self._complete_deferred_command(deferred_cmd_id, Err(err))
+        };
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_attrib_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::ResolveDidGetAttribAck(
+                            did.clone(),
+                            get_nym_reply.clone(),
+                            result,
+                            deferred_cmd_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
+    fn resolve_did_get_attrib_ack(&self,
+                                  did: DidValue,
+                                  get_nym_reply: String,
+                                  get_attrib_reply_result: IndyResult<String>,
+                                  deferred_cmd_id: CommandHandle) {
+        trace!("resolve_did_get_attrib_ack >>> did: {:?}, get_attrib_reply_result: {:?}", did, get_attrib_reply_result);
+
+        let res = get_attrib_reply_result
+            .and_then(|get_attrib_reply| self.ledger_service.build_did_document(&did, &get_nym_reply, Some(&get_attrib_reply)));
+
+        self._complete_deferred_command(deferred_cmd_id, res);
+    }
+
+    fn key_for_did_at_get_nym_ack(&self,
+                                  get_nym_reply_result: IndyResult<String>,
+                                  deferred_cmd_id: CommandHandle) {
+        trace!("key_for_did_at_get_nym_ack >>> get_nym_reply_result: {:?}", get_nym_reply_result);
+
+        let res = get_nym_reply_result
+            .and_then(|get_nym_reply| self.ledger_service.parse_get_nym_verkey(&get_nym_reply));
+
+        self._complete_deferred_command(deferred_cmd_id, res);
+    }
+
+    fn _complete_deferred_command(&self, deferred_cmd_id: CommandHandle, res: IndyResult<String>) {
+        match self.deferred_commands.borrow_mut().remove(&deferred_cmd_id) {
+            Some(DidCommand::ResolveDid(_, _, cb)) => {
+                debug!("resolve_did <<< res: {:?}", res);
+                cb(res)
+            }
+            Some(DidCommand::KeyForDidAt(_, _, _, _, cb)) => {
+                debug!("key_for_did_at <<< res: {:?}", res);
+                cb(res)
+            }
+            Some(_) => error!("Unexpected deferred command for id: {:?}", deferred_cmd_id),
+            None => error!("No deferred command for id: {:?}", deferred_cmd_id)
+        }
+    }
+
     fn _defer_command(&self, cmd: DidCommand) -> CommandHandle {
         let deferred_cmd_id = next_command_handle();
         self.deferred_commands.borrow_mut().insert(deferred_cmd_id, cmd);
@@ -703,6 +925,12 @@ impl DidCommandExecutor {
             DidCommand::GetEndpointForDid(_, _, _, cb) => {
                 cb(Err(err));
             }
+            DidCommand::ResolveDid(_, _, cb) => {
+                cb(Err(err));
+            }
+            DidCommand::KeyForDidAt(_, _, _, _, cb) => {
+                cb(Err(err));
+            }
             _ => {}
         }
     }
@@ -715,7 +943,7 @@ impl DidCommandExecutor {
 
         // TODO we need passing of my_did as identifier
         // TODO: FIXME: Remove this unwrap by sending GetNymAck with the error.
-        let get_nym_request = self.ledger_service.build_get_nym_request(None, did).unwrap();
+        let get_nym_request = self.ledger_service.build_get_nym_request(None, did, None, None).unwrap();
         let did = did.clone();
 
         CommandExecutor::instance()
@@ -766,4 +994,38 @@ impl DidCommandExecutor {
     fn _wallet_get_their_did(&self, wallet_handle: WalletHandle, their_did: &DidValue) -> IndyResult<TheirDid> {
         self.wallet_service.get_indy_object(wallet_handle, &their_did.0, &RecordOptions::id_value())
     }
+
+    fn _wallet_get_key_history(&self, wallet_handle: WalletHandle, did: &DidValue) -> IndyResult<Option<DidKeyHistory>> {
+        self.wallet_service.get_indy_opt_object(wallet_handle, &did.0, &RecordOptions::id_value())
+    }
+
+    // `previous_verkey` is the key DID had before this update. It seeds the history of DIDs
+    // stored before key history was introduced, so their original key isn't lost on the first rotation.
+    fn _update_key_history(&self, wallet_handle: WalletHandle, did: &DidValue, previous_verkey: Option<&str>, verkey: &str, valid_from: u64) -> IndyResult<()> {
+        match self._wallet_get_key_history(wallet_handle, did)? {
+            Some(mut key_history) => {
+                if key_history.push(verkey, valid_from) {
+                    self.wallet_service.update_indy_object(wallet_handle, &did.0, &key_history)?;
+                }
+            }
+            None => {
+                let key_history = match previous_verkey {
+                    Some(previous_verkey) => {
+                        let mut key_history = DidKeyHistory::new_with_unknown_start(previous_verkey);
+                        key_history.push(verkey, valid_from);
+                        key_history
+                    }
+                    None => DidKeyHistory::new(verkey, valid_from)
+                };
+                self.wallet_service.add_indy_object(wallet_handle, &did.0, &key_history, &HashMap::new())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn _current_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or(0)
 }
@@ -1,10 +1,12 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 use std::string::ToString;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use indy_api_types::{CommandHandle, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
 use indy_utils::next_command_handle;
 use rust_base58::ToBase58;
 use serde_json;
@@ -20,11 +22,16 @@ use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelt
 use crate::domain::anoncreds::schema::{Schema, SchemaId, SchemaV1};
 use crate::domain::crypto::did::{Did, DidValue};
 use crate::domain::crypto::key::Key;
-use crate::domain::ledger::auth_rule::{AuthRules, Constraint};
-use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData};
+use crate::domain::ledger::auth_rule::{AuthRule, AuthRules, AuthRuleSigner, Constraint};
+use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData, TxnAuthorAgreementData};
+use crate::domain::ledger::constants::TXN_AUTHR_AGRMT_REQUIRED_REQUESTS;
+use crate::domain::ledger::batch::{BatchRequestResult, SubmitBatchOptions};
+use crate::domain::ledger::endorser::PendingTransaction;
 use crate::domain::ledger::node::NodeOperationData;
+use crate::domain::ledger::nym::NymData;
 use crate::domain::ledger::pool::Schedule;
-use crate::domain::ledger::request::Request;
+use crate::domain::ledger::request::{Request, TxnAuthrAgrmtAcceptanceData};
+use crate::domain::ledger::response::ConsensusDetails;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
 use crate::services::pool::{
@@ -40,6 +47,14 @@ pub enum LedgerCommand {
         DidValue, // submitter did
         String, // request json
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SignAndSubmitRequestContinue(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // submitter did
+        String, // request json
+        IndyResult<String>, // GET_TXN_AUTHR_AGRMT response
+        CommandHandle, // callback id
+    ),
     SubmitRequest(
         PoolHandle, // pool handle
         String, // request json
@@ -48,6 +63,24 @@ pub enum LedgerCommand {
         CommandHandle,
         IndyResult<String>, // result json or error
     ),
+    SubmitRequestWithDetails(
+        PoolHandle, // pool handle
+        String, // request json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitConsensusDetails(
+        CommandHandle,
+        ConsensusDetails, // per node results collected by pool
+    ),
+    SubmitRequestsBatch(
+        PoolHandle, // pool handle
+        Vec<String>, // requests json
+        SubmitBatchOptions, // options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitRequestsBatchContinue(
+        CommandHandle, // batch id
+        usize, // index of request in the batch
+        IndyResult<String>, // result json or error
+    ),
     SubmitAction(
         PoolHandle, // pool handle
         String, // request json
@@ -196,6 +229,10 @@ pub enum LedgerCommand {
     GetResponseMetadata(
         String, // response
         Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyTxnInclusion(
+        PoolHandle, // pool handle
+        String, // get txn response
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildAuthRuleRequest(
         DidValue, // submitter did
         String, // auth type
@@ -217,6 +254,37 @@ pub enum LedgerCommand {
         Option<String>, // old value
         Option<String>, // new value
         Box<dyn Fn(IndyResult<String>) + Send>),
+    CheckAuthRule(
+        Vec<AuthRule>, // auth rules
+        String, // auth type
+        String, // auth action
+        String, // field
+        Option<String>, // old value
+        Option<String>, // new value
+        Vec<AuthRuleSigner>, // signers
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildRichSchemaRequest(
+        DidValue, // submitter did
+        String, // id
+        String, // content
+        String, // name
+        String, // version
+        String, // type
+        String, // ver
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildGetRichSchemaObjectByIdRequest(
+        Option<DidValue>, // submitter did
+        String, // id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildGetRichSchemaObjectByMetadataRequest(
+        Option<DidValue>, // submitter did
+        String, // type
+        String, // name
+        String, // version
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetRichSchemaObjectResponse(
+        String, // response
+        Box<dyn Fn(IndyResult<String>) + Send>),
     GetSchema(
         PoolHandle,
         Option<DidValue>,
@@ -272,6 +340,54 @@ pub enum LedgerCommand {
         String, // acceptance mechanism type
         u64, // time of acceptance
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SetTxnAuthorAgreementAcceptanceForPool(
+        PoolHandle, // pool handle
+        Option<String>, // text
+        Option<String>, // version
+        Option<String>, // hash
+        String, // acceptance mechanism type
+        u64, // time of acceptance
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    PrepareRequestForEndorser(
+        WalletHandle,
+        DidValue, // author did
+        String, // request json
+        DidValue, // endorser did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EndorsePendingTransaction(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // endorser did
+        PendingTransaction,
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EndorsePendingTransactionNymContinue(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // endorser did
+        PendingTransaction,
+        IndyResult<String>, // GET_NYM response of endorser
+        CommandHandle, // callback id
+    ),
+    EndorsePendingTransactionAuthorNymContinue(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // endorser did
+        PendingTransaction,
+        Option<String>, // endorser role
+        IndyResult<String>, // GET_NYM response of author
+        CommandHandle, // callback id
+    ),
+    EndorsePendingTransactionAuthRulesContinue(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // endorser did
+        PendingTransaction,
+        Option<String>, // endorser role
+        Option<String>, // author role
+        bool, // author is written to the ledger
+        IndyResult<String>, // GET_AUTH_RULE response
+        CommandHandle, // callback id
+    ),
     AppendRequestEndorser(
         String, // request json
         DidValue, // endorser did
@@ -292,7 +408,21 @@ pub struct LedgerCommandExecutor {
     ledger_service: Rc<LedgerService>,
 
     send_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
+    pending_details: RefCell<HashMap<CommandHandle, Option<ConsensusDetails>>>,
+    pending_taa_submits: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>) + Send>>>,
+    pending_taa_writes: RefCell<HashMap<CommandHandle, PoolHandle>>,
+    pending_endorsements: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>) + Send>>>,
     pending_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String)>)>>>,
+    pending_batches: RefCell<HashMap<CommandHandle, PendingBatch>>,
+}
+
+struct PendingBatch {
+    pool_handle: PoolHandle,
+    max_in_flight: usize,
+    in_flight: usize,
+    requests: VecDeque<(usize, String)>, // requests to send with their indexes in the batch
+    results: Vec<Option<BatchRequestResult>>,
+    cb: Box<dyn Fn(IndyResult<String>) + Send>,
 }
 
 impl LedgerCommandExecutor {
@@ -306,7 +436,12 @@ impl LedgerCommandExecutor {
             wallet_service,
             ledger_service,
             send_callbacks: RefCell::new(HashMap::new()),
+            pending_details: RefCell::new(HashMap::new()),
+            pending_taa_submits: RefCell::new(HashMap::new()),
+            pending_taa_writes: RefCell::new(HashMap::new()),
+            pending_endorsements: RefCell::new(HashMap::new()),
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_batches: RefCell::new(HashMap::new()),
         }
     }
 
@@ -316,12 +451,25 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "SignAndSubmitRequest command received");
                 self.sign_and_submit_request(pool_handle, wallet_handle, &submitter_did, &request_json, cb);
             }
+            LedgerCommand::SignAndSubmitRequestContinue(pool_handle, wallet_handle, submitter_did, request_json, taa_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "SignAndSubmitRequestContinue command received");
+                self._sign_and_submit_request_continue(pool_handle, wallet_handle, &submitter_did, &request_json, taa_response, cb_id);
+            }
             LedgerCommand::SubmitRequest(handle, request_json, cb) => {
                 debug!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_request(handle, &request_json, cb);
             }
             LedgerCommand::SubmitAck(handle, result) => {
                 debug!(target: "ledger_command_executor", "SubmitAck command received");
+                if let Some(pool_handle) = self.pending_taa_writes.borrow_mut().remove(&handle) {
+                    if _is_txn_author_agreement_rejection(&result) {
+                        self.pool_service.forget_ledger_taa(pool_handle);
+                    }
+                }
+                let result = match self.pending_details.borrow_mut().remove(&handle) {
+                    Some(details) => _wrap_result_with_details(result, details),
+                    None => result
+                };
                 match self.send_callbacks.borrow_mut().remove(&handle) {
                     Some(cb) => cb(result.map_err(IndyError::from)),
                     None => {
@@ -330,6 +478,24 @@ impl LedgerCommandExecutor {
                     }
                 }
             }
+            LedgerCommand::SubmitRequestWithDetails(handle, request_json, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestWithDetails command received");
+                self.submit_request_with_details(handle, &request_json, cb);
+            }
+            LedgerCommand::SubmitConsensusDetails(handle, details) => {
+                debug!(target: "ledger_command_executor", "SubmitConsensusDetails command received");
+                if let Some(pending) = self.pending_details.borrow_mut().get_mut(&handle) {
+                    *pending = Some(details);
+                }
+            }
+            LedgerCommand::SubmitRequestsBatch(handle, requests, options, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestsBatch command received");
+                self.submit_requests_batch(handle, requests, &options, cb);
+            }
+            LedgerCommand::SubmitRequestsBatchContinue(batch_id, index, result) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestsBatchContinue command received");
+                self._submit_requests_batch_continue(batch_id, index, result);
+            }
             LedgerCommand::SubmitAction(handle, request_json, nodes, timeout, cb) => {
                 debug!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_action(handle, &request_json, nodes.as_ref().map(String::as_str), timeout, cb);
@@ -466,6 +632,10 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "GetResponseMetadata command received");
                 cb(self.get_response_metadata(&response));
             }
+            LedgerCommand::VerifyTxnInclusion(pool_handle, response, cb) => {
+                debug!(target: "ledger_command_executor", "VerifyTxnInclusion command received");
+                cb(self.verify_txn_inclusion(pool_handle, &response));
+            }
             LedgerCommand::BuildAuthRuleRequest(submitter_did, txn_type, action, field, old_value, new_value, constraint, cb) => {
                 debug!(target: "ledger_command_executor", "BuildAuthRuleRequest command received");
                 cb(self.build_auth_rule_request(&submitter_did, &txn_type, &action, &field, old_value.as_ref().map(String::as_str), new_value.as_ref().map(String::as_str), constraint));
@@ -483,6 +653,29 @@ impl LedgerCommandExecutor {
                                                     old_value.as_ref().map(String::as_str),
                                                     new_value.as_ref().map(String::as_str)));
             }
+            LedgerCommand::CheckAuthRule(auth_rules, txn_type, action, field, old_value, new_value, signers, cb) => {
+                debug!(target: "ledger_command_executor", "CheckAuthRule command received");
+                cb(self.check_auth_rule(&auth_rules, &txn_type, &action, &field,
+                                        old_value.as_ref().map(String::as_str),
+                                        new_value.as_ref().map(String::as_str),
+                                        &signers));
+            }
+            LedgerCommand::BuildRichSchemaRequest(submitter_did, id, content, rs_name, rs_version, rs_type, ver, cb) => {
+                debug!(target: "ledger_command_executor", "BuildRichSchemaRequest command received");
+                cb(self.build_rich_schema_request(&submitter_did, &id, &content, &rs_name, &rs_version, &rs_type, &ver));
+            }
+            LedgerCommand::BuildGetRichSchemaObjectByIdRequest(submitter_did, id, cb) => {
+                debug!(target: "ledger_command_executor", "BuildGetRichSchemaObjectByIdRequest command received");
+                cb(self.build_get_rich_schema_object_by_id_request(submitter_did.as_ref(), &id));
+            }
+            LedgerCommand::BuildGetRichSchemaObjectByMetadataRequest(submitter_did, rs_type, rs_name, rs_version, cb) => {
+                debug!(target: "ledger_command_executor", "BuildGetRichSchemaObjectByMetadataRequest command received");
+                cb(self.build_get_rich_schema_object_by_metadata_request(submitter_did.as_ref(), &rs_type, &rs_name, &rs_version));
+            }
+            LedgerCommand::ParseGetRichSchemaObjectResponse(response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetRichSchemaObjectResponse command received");
+                cb(self.parse_get_rich_schema_object_response(&response));
+            }
             LedgerCommand::GetSchema(pool_handle, submitter_did, id, cb) => {
                 debug!(target: "ledger_command_executor", "GetSchema command received");
                 self.get_schema(pool_handle, submitter_did.as_ref(), &id, cb);
@@ -530,6 +723,38 @@ impl LedgerCommandExecutor {
                                                                           &acc_mech_type,
                                                                           time_of_acceptance));
             }
+            LedgerCommand::SetTxnAuthorAgreementAcceptanceForPool(pool_handle, text, version, hash, acc_mech_type, time_of_acceptance, cb) => {
+                debug!(target: "ledger_command_executor", "SetTxnAuthorAgreementAcceptanceForPool command received");
+                cb(self.set_txn_author_agreement_acceptance_for_pool(pool_handle,
+                                                                     text.as_ref().map(String::as_str),
+                                                                     version.as_ref().map(String::as_str),
+                                                                     hash.as_ref().map(String::as_str),
+                                                                     &acc_mech_type,
+                                                                     time_of_acceptance));
+            }
+            LedgerCommand::PrepareRequestForEndorser(wallet_handle, author_did, request_json, endorser_did, cb) => {
+                debug!(target: "ledger_command_executor", "PrepareRequestForEndorser command received");
+                cb(self.prepare_request_for_endorser(wallet_handle, &author_did, &request_json, &endorser_did));
+            }
+            LedgerCommand::EndorsePendingTransaction(pool_handle, wallet_handle, endorser_did, pending_transaction, cb) => {
+                debug!(target: "ledger_command_executor", "EndorsePendingTransaction command received");
+                self.endorse_pending_transaction(pool_handle, wallet_handle, endorser_did, pending_transaction, cb);
+            }
+            LedgerCommand::EndorsePendingTransactionNymContinue(pool_handle, wallet_handle, endorser_did, pending_transaction, get_nym_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "EndorsePendingTransactionNymContinue command received");
+                self._endorse_pending_transaction_nym_continue(pool_handle, wallet_handle, endorser_did, pending_transaction, get_nym_response, cb_id);
+            }
+            LedgerCommand::EndorsePendingTransactionAuthorNymContinue(pool_handle, wallet_handle, endorser_did, pending_transaction, endorser_role, get_nym_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "EndorsePendingTransactionAuthorNymContinue command received");
+                self._endorse_pending_transaction_author_nym_continue(pool_handle, wallet_handle, endorser_did, pending_transaction, endorser_role, get_nym_response, cb_id);
+            }
+            LedgerCommand::EndorsePendingTransactionAuthRulesContinue(pool_handle, wallet_handle, endorser_did, pending_transaction, endorser_role, author_role,
+                                                                      author_on_ledger, get_auth_rule_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "EndorsePendingTransactionAuthRulesContinue command received");
+                self._endorse_pending_transaction_auth_rules_continue(pool_handle, wallet_handle, &endorser_did, &pending_transaction,
+                                                                      endorser_role.as_ref().map(String::as_str), author_role.as_ref().map(String::as_str),
+                                                                      author_on_ledger, get_auth_rule_response, cb_id);
+            }
             LedgerCommand::AppendRequestEndorser(request_json, endorser_did, cb) => {
                 debug!(target: "ledger_command_executor", "AppendRequestEndorser command received");
                 cb(self.append_request_endorser(&request_json,
@@ -564,12 +789,110 @@ impl LedgerCommandExecutor {
         debug!("sign_and_submit_request >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
                pool_handle, wallet_handle, submitter_did, request_json);
 
+        let taa_acceptance = match self.pool_service.get_taa_acceptance(pool_handle, _current_timestamp()) {
+            Some(taa_acceptance) if _is_txn_author_agreement_required(request_json) => taa_acceptance,
+            _ => return self._sign_and_submit_request(pool_handle, wallet_handle, submitter_did, request_json, cb)
+        };
+
+        if let Some(ledger_taa) = taa_acceptance.ledger_taa {
+            let request_json = try_cb!(self._append_pool_taa_acceptance(request_json, &taa_acceptance.acceptance, &ledger_taa), cb);
+            return self._sign_and_submit_taa_request(pool_handle, wallet_handle, submitter_did, &request_json, cb);
+        }
+
+        // agreement isn't read from the ledger yet or cached one is outdated -- read the latest one
+        let get_taa_request = try_cb!(self.ledger_service.build_get_txn_author_agreement_request(None, None), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_taa_submits.borrow_mut().insert(cb_id, cb);
+
+        let submitter_did = submitter_did.clone();
+        let request_json = request_json.to_string();
+
+        self.submit_request(pool_handle, &get_taa_request, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SignAndSubmitRequestContinue(
+                        pool_handle,
+                        wallet_handle,
+                        submitter_did.clone(),
+                        request_json.clone(),
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _sign_and_submit_request_continue(&self,
+                                         pool_handle: PoolHandle,
+                                         wallet_handle: WalletHandle,
+                                         submitter_did: &DidValue,
+                                         request_json: &str,
+                                         taa_response: IndyResult<String>,
+                                         cb_id: CommandHandle) {
+        let cb = self.pending_taa_submits.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let taa_response = try_cb!(taa_response, cb);
+        let ledger_taa = try_cb!(self.ledger_service.parse_get_txn_author_agreement_response(&taa_response), cb);
+
+        let cur_time = _current_timestamp();
+        self.pool_service.set_ledger_taa(pool_handle, ledger_taa.clone(), cur_time);
+
+        match self.pool_service.get_taa_acceptance(pool_handle, cur_time) {
+            Some(taa_acceptance) => {
+                let request_json = try_cb!(self._append_pool_taa_acceptance(request_json, &taa_acceptance.acceptance, &ledger_taa), cb);
+                self._sign_and_submit_taa_request(pool_handle, wallet_handle, submitter_did, &request_json, cb)
+            }
+            None => self._sign_and_submit_request(pool_handle, wallet_handle, submitter_did, request_json, cb)
+        }
+    }
+
+    /// Submits write with appended pool acceptance.
+    /// Agreement cached for the pool is dropped if the ledger rejects the write because of the acceptance.
+    fn _sign_and_submit_taa_request(&self,
+                                    pool_handle: PoolHandle,
+                                    wallet_handle: WalletHandle,
+                                    submitter_did: &DidValue,
+                                    request_json: &str,
+                                    cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        let signed_request = try_cb!(self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single), cb);
+
+        match self.pool_service.send_tx(pool_handle, &signed_request) {
+            Ok(cmd_id) => {
+                self.pending_taa_writes.borrow_mut().insert(cmd_id, pool_handle);
+                self.send_callbacks.borrow_mut().insert(cmd_id, cb);
+            }
+            Err(err) => cb(Err(err))
+        }
+    }
+
+    fn _sign_and_submit_request(&self,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                submitter_did: &DidValue,
+                                request_json: &str,
+                                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
         match self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single) {
             Ok(signed_request) => self.submit_request(pool_handle, signed_request.as_str(), cb),
             Err(err) => cb(Err(err))
         }
     }
 
+    fn _append_pool_taa_acceptance(&self,
+                                   request_json: &str,
+                                   acceptance: &TxnAuthrAgrmtAcceptanceData,
+                                   ledger_taa: &TxnAuthorAgreementData) -> IndyResult<String> {
+        self.ledger_service.check_txn_author_agreement_acceptance(acceptance, ledger_taa, _current_timestamp())?;
+
+        let mut request: serde_json::Value = serde_json::from_str(request_json)
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Cannot deserialize request: {:?}", err)))?;
+
+        request["taaAcceptance"] = json!(acceptance);
+
+        serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request after adding author agreement acceptance data")
+    }
+
     fn _sign_request(&self,
                      wallet_handle: WalletHandle,
                      submitter_did: &DidValue,
@@ -635,6 +958,140 @@ impl LedgerCommandExecutor {
         };
     }
 
+    fn submit_request_with_details(&self,
+                                   handle: PoolHandle,
+                                   request_json: &str,
+                                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_request_with_details >>> handle: {:?}, request_json: {:?}", handle, request_json);
+
+        if let Err(err) = serde_json::from_str::<Request<serde_json::Value>>(&request_json) {
+            return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err))));
+        }
+
+        match self.pool_service.send_tx(handle, request_json) {
+            Ok(cmd_id) => {
+                self.pending_details.borrow_mut().insert(cmd_id, None);
+                self.send_callbacks.borrow_mut().insert(cmd_id, cb);
+            }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
+    fn submit_requests_batch(&self,
+                             handle: PoolHandle,
+                             requests: Vec<String>,
+                             options: &SubmitBatchOptions,
+                             cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_requests_batch >>> handle: {:?}, requests: {:?}, options: {:?}", handle, requests, options);
+
+        if requests.is_empty() {
+            return cb(Err(err_msg(IndyErrorKind::InvalidStructure, "Batch of requests is empty")));
+        }
+
+        // By default requests of the batch in flight fit into single pool connection.
+        let conn_limit = match self.pool_service.get_conn_limit(handle) {
+            Ok(conn_limit) => conn_limit,
+            Err(err) => return cb(Err(err))
+        };
+
+        let batch_id = next_command_handle();
+
+        let batch = PendingBatch {
+            pool_handle: handle,
+            max_in_flight: options.max_in_flight.unwrap_or(conn_limit),
+            in_flight: 0,
+            results: vec![None; requests.len()],
+            requests: requests.into_iter().enumerate().collect(),
+            cb,
+        };
+
+        self.pending_batches.borrow_mut().insert(batch_id, batch);
+
+        self._send_batch_requests(batch_id);
+    }
+
+    // Sends next requests of the batch keeping at most `max_in_flight` of them waiting for replies.
+    // Pool sends up to `conn_limit` of them over each connection, opening new connections for the rest,
+    // and processes each of them independently.
+    fn _send_batch_requests(&self, batch_id: CommandHandle) {
+        let (pool_handle, requests) = match self.pending_batches.borrow_mut().get_mut(&batch_id) {
+            Some(batch) => {
+                let mut requests = Vec::new();
+
+                while batch.in_flight < batch.max_in_flight {
+                    match batch.requests.pop_front() {
+                        Some(request) => {
+                            batch.in_flight += 1;
+                            requests.push(request);
+                        }
+                        None => break
+                    }
+                }
+
+                (batch.pool_handle, requests)
+            }
+            None => return
+        };
+
+        for (index, request_json) in requests {
+            self.submit_request(pool_handle, &request_json, Box::new(move |result| {
+                CommandExecutor::instance().send(
+                    Command::Ledger(
+                        LedgerCommand::SubmitRequestsBatchContinue(
+                            batch_id,
+                            index,
+                            result,
+                        )
+                    )
+                ).unwrap();
+            }));
+        }
+    }
+
+    fn _submit_requests_batch_continue(&self, batch_id: CommandHandle, index: usize, result: IndyResult<String>) {
+        trace!("_submit_requests_batch_continue >>> batch_id: {:?}, index: {:?}, result: {:?}", batch_id, index, result);
+
+        if let Err(ref err) = result {
+            if err.kind() == IndyErrorKind::InvalidPoolHandle {
+                // Pool isn't opened so the rest of requests can't be sent as well
+                let batch = self.pending_batches.borrow_mut().remove(&batch_id);
+                if let Some(batch) = batch {
+                    (batch.cb)(Err(err.clone()));
+                }
+                return;
+            }
+        }
+
+        let completed = match self.pending_batches.borrow_mut().get_mut(&batch_id) {
+            Some(batch) => {
+                batch.results[index] = Some(BatchRequestResult::new(result));
+                batch.in_flight -= 1;
+                batch.in_flight == 0 && batch.requests.is_empty()
+            }
+            None => {
+                debug!("Batch {:?} has been already completed", batch_id);
+                return;
+            }
+        };
+
+        if !completed {
+            return self._send_batch_requests(batch_id);
+        }
+
+        let batch = self.pending_batches.borrow_mut().remove(&batch_id);
+
+        if let Some(batch) = batch {
+            let results: Vec<BatchRequestResult> = batch.results.into_iter().flatten().collect();
+
+            let res = serde_json::to_string(&results)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize batch results");
+
+            debug!("submit_requests_batch <<< res: {:?}", res);
+
+            (batch.cb)(res)
+        }
+    }
+
     fn submit_action(&self,
                      handle: PoolHandle,
                      request_json: &str,
@@ -772,7 +1229,9 @@ impl LedgerCommandExecutor {
         self.crypto_service.validate_did(target_did)?;
 
         let res = self.ledger_service.build_get_nym_request(submitter_did,
-                                                            target_did)?;
+                                                            target_did,
+                                                            None,
+                                                            None)?;
 
         debug!("build_get_attrib_request <<< res: {:?}", res);
 
@@ -1092,6 +1551,21 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn verify_txn_inclusion(&self,
+                            pool_handle: PoolHandle,
+                            response: &str) -> IndyResult<String> {
+        debug!("verify_txn_inclusion >>> pool_handle: {:?}, response: {:?}", pool_handle, response);
+
+        let inclusion = self.pool_service.verify_txn_inclusion(pool_handle, response)?;
+
+        let res = serde_json::to_string(&inclusion)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize TxnInclusion")?;
+
+        debug!("verify_txn_inclusion <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_auth_rule_request(&self,
                                submitter_did: &DidValue,
                                txn_type: &str,
@@ -1145,6 +1619,89 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn check_auth_rule(&self,
+                       auth_rules: &[AuthRule],
+                       txn_type: &str,
+                       action: &str,
+                       field: &str,
+                       old_value: Option<&str>,
+                       new_value: Option<&str>,
+                       signers: &[AuthRuleSigner]) -> IndyResult<String> {
+        debug!("check_auth_rule >>> auth_rules: {:?}, auth_type: {:?}, auth_action: {:?}, field: {:?}, \
+            old_value: {:?}, new_value: {:?}, signers: {:?}", auth_rules, txn_type, action, field, old_value, new_value, signers);
+
+        let res = self.ledger_service.check_auth_rule(auth_rules, txn_type, action, field, old_value, new_value, signers)?;
+
+        let res = serde_json::to_string(&res)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize auth rule check result")?;
+
+        debug!("check_auth_rule <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_rich_schema_request(&self,
+                                 submitter_did: &DidValue,
+                                 id: &str,
+                                 content: &str,
+                                 rs_name: &str,
+                                 rs_version: &str,
+                                 rs_type: &str,
+                                 ver: &str) -> IndyResult<String> {
+        debug!("build_rich_schema_request >>> submitter_did: {:?}, id: {:?}, content: {:?}, rs_name: {:?}, rs_version: {:?}, \
+            rs_type: {:?}, ver: {:?}", submitter_did, id, content, rs_name, rs_version, rs_type, ver);
+
+        self.validate_opt_did(Some(submitter_did))?;
+
+        let res = self.ledger_service.build_rich_schema_request(submitter_did, id, content, rs_name, rs_version, rs_type, ver)?;
+
+        debug!("build_rich_schema_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_rich_schema_object_by_id_request(&self,
+                                                  submitter_did: Option<&DidValue>,
+                                                  id: &str) -> IndyResult<String> {
+        debug!("build_get_rich_schema_object_by_id_request >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_rich_schema_object_by_id_request(submitter_did, id)?;
+
+        debug!("build_get_rich_schema_object_by_id_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_rich_schema_object_by_metadata_request(&self,
+                                                        submitter_did: Option<&DidValue>,
+                                                        rs_type: &str,
+                                                        rs_name: &str,
+                                                        rs_version: &str) -> IndyResult<String> {
+        debug!("build_get_rich_schema_object_by_metadata_request >>> submitter_did: {:?}, rs_type: {:?}, rs_name: {:?}, rs_version: {:?}",
+               submitter_did, rs_type, rs_name, rs_version);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_rich_schema_object_by_metadata_request(submitter_did, rs_type, rs_name, rs_version)?;
+
+        debug!("build_get_rich_schema_object_by_metadata_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_rich_schema_object_response(&self,
+                                             response: &str) -> IndyResult<String> {
+        debug!("parse_get_rich_schema_object_response >>> response: {:?}", response);
+
+        let res = self.ledger_service.parse_get_rich_schema_object_response(response)?;
+
+        debug!("parse_get_rich_schema_object_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_txn_author_agreement_request(&self,
                                           submitter_did: &DidValue,
                                           text: Option<&str>,
@@ -1243,6 +1800,25 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn set_txn_author_agreement_acceptance_for_pool(&self,
+                                                    pool_handle: PoolHandle,
+                                                    text: Option<&str>,
+                                                    version: Option<&str>,
+                                                    taa_digest: Option<&str>,
+                                                    acc_mech_type: &str,
+                                                    time: u64) -> IndyResult<()> {
+        debug!("set_txn_author_agreement_acceptance_for_pool >>> pool_handle: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, acc_mech_type: {:?}, time: {:?}",
+               pool_handle, text, version, taa_digest, acc_mech_type, time);
+
+        let acceptance = self.ledger_service.prepare_acceptance_data(text, version, taa_digest, acc_mech_type, time)?;
+
+        self.pool_service.set_taa_acceptance(pool_handle, acceptance)?;
+
+        debug!("set_txn_author_agreement_acceptance_for_pool <<<");
+
+        Ok(())
+    }
+
     fn append_request_endorser(&self,
                                request_json: &str,
                                endorser_did: &DidValue) -> IndyResult<String> {
@@ -1265,6 +1841,177 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn prepare_request_for_endorser(&self,
+                                    wallet_handle: WalletHandle,
+                                    author_did: &DidValue,
+                                    request_json: &str,
+                                    endorser_did: &DidValue) -> IndyResult<String> {
+        debug!("prepare_request_for_endorser >>> wallet_handle: {:?}, author_did: {:?}, request_json: {:?}, endorser_did: {:?}",
+               wallet_handle, author_did, request_json, endorser_did);
+
+        self.crypto_service.validate_did(author_did)?;
+
+        let request = self.append_request_endorser(request_json, endorser_did)?;
+        let request = self._sign_request(wallet_handle, author_did, &request, SignatureType::Multi)?;
+
+        let request: Value = serde_json::from_str(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize signed request")?;
+
+        let pending_transaction = PendingTransaction::new(author_did.to_short(), endorser_did.to_short(), request);
+
+        pending_transaction.validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        let res = serde_json::to_string(&pending_transaction)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize pending transaction")?;
+
+        debug!("prepare_request_for_endorser <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn endorse_pending_transaction(&self,
+                                   pool_handle: PoolHandle,
+                                   wallet_handle: WalletHandle,
+                                   endorser_did: DidValue,
+                                   pending_transaction: PendingTransaction,
+                                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("endorse_pending_transaction >>> pool_handle: {:?}, wallet_handle: {:?}, endorser_did: {:?}, pending_transaction: {:?}",
+               pool_handle, wallet_handle, endorser_did, pending_transaction);
+
+        try_cb!(self.crypto_service.validate_did(&endorser_did), cb);
+
+        if endorser_did.to_short() != pending_transaction.endorser {
+            return cb(Err(err_msg(IndyErrorKind::InvalidStructure,
+                                  format!("Transaction is prepared for endorser {:?}", pending_transaction.endorser))));
+        }
+
+        // roles of endorser and author are checked against auth rules set on the ledger before co-signing
+        let get_nym_request = try_cb!(self.build_get_nym_request(None, &endorser_did), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_endorsements.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &get_nym_request, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::EndorsePendingTransactionNymContinue(
+                        pool_handle,
+                        wallet_handle,
+                        endorser_did.clone(),
+                        pending_transaction.clone(),
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _endorse_pending_transaction_nym_continue(&self,
+                                                 pool_handle: PoolHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 endorser_did: DidValue,
+                                                 pending_transaction: PendingTransaction,
+                                                 get_nym_response: IndyResult<String>,
+                                                 cb_id: CommandHandle) {
+        let cb = self.pending_endorsements.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let get_nym_response = try_cb!(get_nym_response, cb);
+
+        let nym_data = try_cb!(self.ledger_service.parse_get_nym_response(&get_nym_response), cb);
+        let nym_data: NymData = try_cb!(serde_json::from_str(&nym_data)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize NYM data"), cb);
+
+        let author_did = pending_transaction.author.qualify(None);
+        let get_nym_request = try_cb!(self.build_get_nym_request(None, &author_did), cb);
+
+        self.pending_endorsements.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &get_nym_request, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::EndorsePendingTransactionAuthorNymContinue(
+                        pool_handle,
+                        wallet_handle,
+                        endorser_did.clone(),
+                        pending_transaction.clone(),
+                        nym_data.role.clone(),
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _endorse_pending_transaction_author_nym_continue(&self,
+                                                        pool_handle: PoolHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        endorser_did: DidValue,
+                                                        pending_transaction: PendingTransaction,
+                                                        endorser_role: Option<String>,
+                                                        get_nym_response: IndyResult<String>,
+                                                        cb_id: CommandHandle) {
+        let cb = self.pending_endorsements.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let get_nym_response = try_cb!(get_nym_response, cb);
+
+        // author may be not written to the ledger yet, then its signature is checked as off-ledger one
+        let (author_role, author_on_ledger) = match self.ledger_service.parse_get_nym_response(&get_nym_response) {
+            Ok(nym_data) => {
+                let nym_data: NymData = try_cb!(serde_json::from_str(&nym_data)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize NYM data"), cb);
+                (nym_data.role, true)
+            }
+            Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => (None, false),
+            Err(err) => return cb(Err(err))
+        };
+
+        let get_auth_rule_request = try_cb!(self.ledger_service.build_get_auth_rule_request(None, None, None, None, None, None), cb);
+
+        self.pending_endorsements.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &get_auth_rule_request, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::EndorsePendingTransactionAuthRulesContinue(
+                        pool_handle,
+                        wallet_handle,
+                        endorser_did.clone(),
+                        pending_transaction.clone(),
+                        endorser_role.clone(),
+                        author_role.clone(),
+                        author_on_ledger,
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _endorse_pending_transaction_auth_rules_continue(&self,
+                                                        pool_handle: PoolHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        endorser_did: &DidValue,
+                                                        pending_transaction: &PendingTransaction,
+                                                        endorser_role: Option<&str>,
+                                                        author_role: Option<&str>,
+                                                        author_on_ledger: bool,
+                                                        get_auth_rule_response: IndyResult<String>,
+                                                        cb_id: CommandHandle) {
+        let cb = self.pending_endorsements.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let get_auth_rule_response = try_cb!(get_auth_rule_response, cb);
+
+        let auth_rules = try_cb!(self.ledger_service.parse_get_auth_rule_response(&get_auth_rule_response), cb);
+        try_cb!(self.ledger_service.check_endorsement(pending_transaction, author_role, author_on_ledger, endorser_role, &auth_rules), cb);
+
+        let request = try_cb!(serde_json::to_string(&pending_transaction.request)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize request"), cb);
+        let request = try_cb!(self._sign_request(wallet_handle, endorser_did, &request, SignatureType::Multi), cb);
+
+        self.submit_request(pool_handle, &request, cb)
+    }
+
     fn validate_opt_did(&self, did: Option<&DidValue>) -> IndyResult<()> {
         match did {
             Some(did) => Ok(self.crypto_service.validate_did(did)?),
@@ -1349,6 +2096,54 @@ impl LedgerCommandExecutor {
     }
 }
 
+fn _is_txn_author_agreement_required(request_json: &str) -> bool {
+    let request: Value = match serde_json::from_str(request_json) {
+        Ok(request) => request,
+        Err(_) => return false
+    };
+
+    // explicitly appended acceptance takes precedence over registered one
+    request["taaAcceptance"].is_null() &&
+        request["operation"]["type"].as_str()
+            .map(|type_| TXN_AUTHR_AGRMT_REQUIRED_REQUESTS.contains(&type_))
+            .unwrap_or(false)
+}
+
+fn _is_txn_author_agreement_rejection(result: &IndyResult<String>) -> bool {
+    let response: Value = match result.as_ref().map(|response| serde_json::from_str(response)) {
+        Ok(Ok(response)) => response,
+        _ => return false
+    };
+
+    match response["op"].as_str() {
+        Some("REJECT") | Some("REQNACK") => response["reason"].as_str()
+            .map(|reason| reason.to_lowercase().contains("author agreement"))
+            .unwrap_or(false),
+        _ => false
+    }
+}
+
+fn _current_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or(0)
+}
+
+fn _wrap_result_with_details(result: IndyResult<String>, details: Option<ConsensusDetails>) -> IndyResult<String> {
+    let reply = match result {
+        Ok(reply) => serde_json::from_str::<Value>(&reply)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Cannot deserialize pool reply")?,
+        // consensus details explain why the pool failed to agree on the reply
+        Err(ref err) if err.kind() == IndyErrorKind::PoolTimeout && details.is_some() => Value::Null,
+        Err(err) => return Err(err)
+    };
+
+    Ok(json!({
+        "reply": reply,
+        "consensus": details,
+    }).to_string())
+}
+
 enum SignatureType {
     Single,
     Multi
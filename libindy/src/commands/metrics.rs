@@ -1,5 +1,6 @@
 use crate::services::metrics::models::MetricsValue;
 use crate::services::metrics::MetricsService;
+use crate::services::pool::read_cache::read_cache_counters;
 use indy_api_types::errors::prelude::*;
 use indy_wallet::WalletService;
 use serde_json::{Map, Value};
@@ -14,6 +15,8 @@ const OPENED_WALLETS_COUNT: &str = "opened";
 const OPENED_WALLET_IDS_COUNT: &str = "opened_ids";
 const PENDING_FOR_IMPORT_WALLETS_COUNT: &str = "pending_for_import";
 const PENDING_FOR_OPEN_WALLETS_COUNT: &str = "pending_for_open";
+const POOL_READ_CACHE_HITS_COUNT: &str = "hit";
+const POOL_READ_CACHE_MISSES_COUNT: &str = "miss";
 
 pub enum MetricsCommand {
     CollectMetrics(Box<dyn Fn(IndyResult<String>) + Send>),
@@ -49,6 +52,7 @@ impl MetricsCommandExecutor {
         let mut metrics_map = serde_json::Map::new();
         self.append_threapool_metrics(&mut metrics_map)?;
         self.append_wallet_metrics(&mut metrics_map)?;
+        self.append_pool_read_cache_metrics(&mut metrics_map)?;
         self.metrics_service
             .append_command_metrics(&mut metrics_map)?;
         let res = serde_json::to_string(&metrics_map)
@@ -133,6 +137,23 @@ impl MetricsCommandExecutor {
         Ok(())
     }
 
+    fn append_pool_read_cache_metrics(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
+        let (hits, misses) = read_cache_counters();
+
+        let read_cache_count = vec![
+            self.get_metric_json(POOL_READ_CACHE_HITS_COUNT, hits)?,
+            self.get_metric_json(POOL_READ_CACHE_MISSES_COUNT, misses)?,
+        ];
+
+        metrics_map.insert(
+            String::from("pool_read_cache_count"),
+            serde_json::to_value(read_cache_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+
+        Ok(())
+    }
+
     fn get_metric_json(&self, label: &str, value: usize) -> IndyResult<Value> {
         let mut tag = HashMap::<String, String>::new();
         tag.insert(String::from("label"), String::from(label));
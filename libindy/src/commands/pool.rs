@@ -3,9 +3,10 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::domain::ledger::request::ProtocolVersion;
-use crate::domain::pool::{PoolConfig, PoolOpenConfig};
+use crate::domain::pool::{PoolConfig, PoolDiagnostics, PoolOpenConfig};
 use indy_api_types::errors::prelude::*;
 use crate::services::pool::PoolService;
+use crate::services::pool::plugged_transport::PluggedTransportType;
 use indy_api_types::{PoolHandle, CommandHandle};
 
 pub enum PoolCommand {
@@ -38,6 +39,29 @@ pub enum PoolCommand {
     SetProtocolVersion(
         usize, // protocol version
         Box<dyn Fn(IndyResult<()>) + Send>),
+    GetLocalTxn(
+        String, // name
+        usize, // ledger id
+        usize, // seq no
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetLocalTxnsByType(
+        String, // name
+        usize, // ledger id
+        String, // txn type
+        usize, // from seq no
+        usize, // count
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    Diagnose(
+        String, // name
+        Option<PoolOpenConfig>, // config
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    DiagnoseAck(
+        CommandHandle, // cmd id
+        IndyResult<PoolDiagnostics>),
+    RegisterTransport(
+        String, // transport name
+        PluggedTransportType, // transport handlers
+        Box<dyn Fn(IndyResult<()>) + Send>),
 }
 
 pub struct PoolCommandExecutor {
@@ -45,6 +69,7 @@ pub struct PoolCommandExecutor {
     close_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<()>)>>>,
     refresh_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<()>)>>>,
     open_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<PoolHandle>)>>>,
+    diagnose_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
 }
 
 impl PoolCommandExecutor {
@@ -54,6 +79,7 @@ impl PoolCommandExecutor {
             close_callbacks: RefCell::new(HashMap::new()),
             refresh_callbacks: RefCell::new(HashMap::new()),
             open_callbacks: RefCell::new(HashMap::new()),
+            diagnose_callbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -132,6 +158,37 @@ impl PoolCommandExecutor {
                 debug!(target: "pool_command_executor", "SetProtocolVersion command received");
                 cb(self.set_protocol_version(protocol_version));
             }
+            PoolCommand::GetLocalTxn(name, ledger_id, seq_no, cb) => {
+                debug!(target: "pool_command_executor", "GetLocalTxn command received");
+                cb(self.get_local_txn(&name, ledger_id, seq_no));
+            }
+            PoolCommand::GetLocalTxnsByType(name, ledger_id, txn_type, from_seq_no, count, cb) => {
+                debug!(target: "pool_command_executor", "GetLocalTxnsByType command received");
+                cb(self.get_local_txns_by_type(&name, ledger_id, &txn_type, from_seq_no, count));
+            }
+            PoolCommand::Diagnose(name, config, cb) => {
+                debug!(target: "pool_command_executor", "Diagnose command received");
+                self.diagnose(&name, config, cb);
+            }
+            PoolCommand::DiagnoseAck(handle, result) => {
+                debug!(target: "pool_command_executor", "DiagnoseAck command received");
+                match self.diagnose_callbacks.try_borrow_mut() {
+                    Ok(mut cbs) => {
+                        match cbs.remove(&handle) {
+                            Some(cb) => cb(result.and_then(|diagnostics| ::serde_json::to_string(&diagnostics)
+                                .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool diagnostics"))),
+                            None => {
+                                error!("Can't process PoolCommand::DiagnoseAck for handle {:?} - appropriate callback not found!", handle);
+                            }
+                        }
+                    }
+                    Err(err) => { error!("{:?}", err); }
+                }
+            }
+            PoolCommand::RegisterTransport(name, transport_type, cb) => {
+                debug!(target: "pool_command_executor", "RegisterTransport command received");
+                cb(self.register_transport(&name, transport_type));
+            }
         };
     }
 
@@ -221,6 +278,34 @@ impl PoolCommandExecutor {
         debug!("refresh <<<");
     }
 
+    fn diagnose(&self, name: &str, config: Option<PoolOpenConfig>, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("diagnose >>> name: {:?}, config: {:?}", name, config);
+
+        let result = self.pool_service.diagnose(name, config)
+            .and_then(|cmd_id| {
+                match self.diagnose_callbacks.try_borrow_mut() {
+                    Ok(cbs) => Ok((cbs, cmd_id)),
+                    Err(err) => Err(err.into())
+                }
+            });
+        match result {
+            Err(err) => { cb(Err(err)); }
+            Ok((mut cbs, cmd_id)) => { cbs.insert(cmd_id, cb); }
+        };
+
+        debug!("diagnose <<<");
+    }
+
+    fn register_transport(&self, name: &str, transport_type: PluggedTransportType) -> IndyResult<()> {
+        debug!("register_transport >>> name: {:?}", name);
+
+        PoolService::register_transport(name, transport_type)?;
+
+        debug!("register_transport <<<");
+
+        Ok(())
+    }
+
     fn set_protocol_version(&self, version: usize) -> IndyResult<()> {
         debug!("set_protocol_version >>> version: {:?}", version);
 
@@ -234,4 +319,28 @@ impl PoolCommandExecutor {
 
         Ok(())
     }
+
+    fn get_local_txn(&self, name: &str, ledger_id: usize, seq_no: usize) -> IndyResult<String> {
+        debug!("get_local_txn >>> name: {:?}, ledger_id: {:?}, seq_no: {:?}", name, ledger_id, seq_no);
+
+        let res = self.pool_service.get_local_txn(name, ledger_id, seq_no)?.to_string();
+
+        debug!("get_local_txn << res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_local_txns_by_type(&self, name: &str, ledger_id: usize, txn_type: &str, from_seq_no: usize, count: usize) -> IndyResult<String> {
+        debug!("get_local_txns_by_type >>> name: {:?}, ledger_id: {:?}, txn_type: {:?}, from_seq_no: {:?}, count: {:?}",
+               name, ledger_id, txn_type, from_seq_no, count);
+
+        let res = self.pool_service
+            .get_local_txns_by_type(name, ledger_id, txn_type, from_seq_no, count)
+            .and_then(|txns| ::serde_json::to_string(&txns)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize transactions list"))?;
+
+        debug!("get_local_txns_by_type << res: {:?}", res);
+
+        Ok(res)
+    }
 }
@@ -4,7 +4,7 @@ use std::rc::Rc;
 
 use indy_api_types::wallet::*;
 use crate::commands::{Command, CommandExecutor};
-use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, KeyConfig};
+use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, ExportFormat, KeyConfig, RotateKeyConfig};
 use indy_api_types::errors::prelude::*;
 use crate::services::crypto::CryptoService;
 use indy_wallet::{KeyDerivationData, WalletService, Metadata};
@@ -42,6 +42,11 @@ pub enum WalletCommand {
                        WalletFetchSearchNextRecord, // fetch search next record
                        WalletFreeSearch, // free search
                        Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterWalletTransactions(String, // type_
+                               WalletBeginTransaction, // begin transaction
+                               WalletCommitTransaction, // commit transaction
+                               WalletRollbackTransaction, // rollback transaction
+                               Box<dyn Fn(IndyResult<()>) + Send>),
     Create(Config, // config
            Credentials, // credentials
            Box<dyn Fn(IndyResult<()>) + Send>),
@@ -84,6 +89,25 @@ pub enum WalletCommand {
                    WalletHandle,
                    CallbackHandle
     ),
+    ImportPlaintextContinue(Config, // config
+                            Credentials, // credentials
+                            DeriveKeyResult<MasterKey>, // derive_key_result
+                            WalletHandle,
+                            CallbackHandle
+    ),
+    RotateKey(WalletHandle,
+              RotateKeyConfig, // rotate key config
+              Box<dyn Fn(IndyResult<()>) + Send>),
+    RotateKeyContinue(WalletHandle,
+                      KeyDerivationData,
+                      DeriveKeyResult<MasterKey>,
+                      CallbackHandle),
+    BeginTransaction(WalletHandle,
+                     Box<dyn Fn(IndyResult<()>) + Send>),
+    CommitTransaction(WalletHandle,
+                      Box<dyn Fn(IndyResult<()>) + Send>),
+    RollbackTransaction(WalletHandle,
+                        Box<dyn Fn(IndyResult<()>) + Send>),
     GenerateKey(Option<KeyConfig>, // config
                 Box<dyn Fn(IndyResult<String>) + Send>),
     DeriveKey(KeyDerivationData,
@@ -130,6 +154,10 @@ impl WalletCommandExecutor {
                                        free_storage_metadata, search_records, search_all_records, get_search_total_count,
                                        fetch_search_next_record, free_search));
             }
+            WalletCommand::RegisterWalletTransactions(type_, begin_transaction, commit_transaction, rollback_transaction, cb) => {
+                debug!(target: "wallet_command_executor", "RegisterWalletTransactions command received");
+                cb(self._register_transactions(&type_, begin_transaction, commit_transaction, rollback_transaction));
+            }
             WalletCommand::Create(config, credentials, cb) => {
                 debug!(target: "wallet_command_executor", "Create command received");
                 self._create(&config, &credentials, cb)
@@ -174,6 +202,30 @@ impl WalletCommandExecutor {
                 debug!(target: "wallet_command_executor", "ImportContinue command received");
                 self._import_continue(cb_id, wallet_handle, &config, &credential, key_result);
             }
+            WalletCommand::ImportPlaintextContinue(config, credential, key_result, wallet_handle, cb_id) => {
+                debug!(target: "wallet_command_executor", "ImportPlaintextContinue command received");
+                self._import_plaintext_continue(cb_id, wallet_handle, &config, &credential, key_result);
+            }
+            WalletCommand::RotateKey(wallet_handle, rotate_config, cb) => {
+                debug!(target: "wallet_command_executor", "RotateKey command received");
+                self._rotate_key(wallet_handle, &rotate_config, cb);
+            }
+            WalletCommand::RotateKeyContinue(wallet_handle, key_data, key_result, cb_id) => {
+                debug!(target: "wallet_command_executor", "RotateKeyContinue command received");
+                self._rotate_key_continue(cb_id, wallet_handle, key_data, key_result);
+            }
+            WalletCommand::BeginTransaction(wallet_handle, cb) => {
+                debug!(target: "wallet_command_executor", "BeginTransaction command received");
+                cb(self._begin_transaction(wallet_handle));
+            }
+            WalletCommand::CommitTransaction(wallet_handle, cb) => {
+                debug!(target: "wallet_command_executor", "CommitTransaction command received");
+                cb(self._commit_transaction(wallet_handle));
+            }
+            WalletCommand::RollbackTransaction(wallet_handle, cb) => {
+                debug!(target: "wallet_command_executor", "RollbackTransaction command received");
+                cb(self._rollback_transaction(wallet_handle));
+            }
             WalletCommand::GenerateKey(config, cb) => {
                 debug!(target: "wallet_command_executor", "DeriveKey command received");
                 cb(self._generate_key(config.as_ref()));
@@ -226,6 +278,86 @@ impl WalletCommandExecutor {
         Ok(())
     }
 
+    fn _register_transactions(&self,
+                              type_: &str,
+                              begin_transaction: WalletBeginTransaction,
+                              commit_transaction: WalletCommitTransaction,
+                              rollback_transaction: WalletRollbackTransaction) -> IndyResult<()> {
+        trace!("_register_transactions >>> type_: {:?}", type_);
+
+        self
+            .wallet_service
+            .register_wallet_storage_transactions(type_, begin_transaction, commit_transaction, rollback_transaction)?;
+
+        trace!("_register_transactions <<< res: ()");
+        Ok(())
+    }
+
+    fn _rotate_key(&self,
+                   wallet_handle: WalletHandle,
+                   rotate_config: &RotateKeyConfig,
+                   cb: Box<dyn Fn(IndyResult<()>) + Send>) {
+        trace!("_rotate_key >>> wallet_handle: {:?}, rotate_config: {:?}", wallet_handle, secret!(rotate_config));
+
+        let key_data = try_cb!(self.wallet_service.rotate_wallet_key_prepare(wallet_handle, rotate_config), cb);
+
+        let cb_id = indy_utils::sequence::get_next_id();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Wallet(WalletCommand::DeriveKey(
+                key_data.clone(),
+                Box::new(move |master_key_res| {
+                    CommandExecutor::instance().send(Command::Wallet(WalletCommand::RotateKeyContinue(
+                        wallet_handle,
+                        key_data.clone(),
+                        master_key_res,
+                        cb_id,
+                    ))).unwrap();
+                })
+            ))
+        ).unwrap();
+
+        trace!("_rotate_key <<<");
+    }
+
+    fn _rotate_key_continue(&self,
+                            cb_id: CallbackHandle,
+                            wallet_handle: WalletHandle,
+                            key_data: KeyDerivationData,
+                            key_result: DeriveKeyResult<MasterKey>) {
+        let cb = get_cb!(self, cb_id);
+        cb(key_result
+            .and_then(|key| self.wallet_service.rotate_wallet_key_continue(wallet_handle, (&key_data, &key))))
+    }
+
+    fn _begin_transaction(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        trace!("_begin_transaction >>> wallet_handle: {:?}", wallet_handle);
+
+        self.wallet_service.begin_transaction(wallet_handle)?;
+
+        trace!("_begin_transaction <<< res: ()");
+        Ok(())
+    }
+
+    fn _commit_transaction(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        trace!("_commit_transaction >>> wallet_handle: {:?}", wallet_handle);
+
+        self.wallet_service.commit_transaction(wallet_handle)?;
+
+        trace!("_commit_transaction <<< res: ()");
+        Ok(())
+    }
+
+    fn _rollback_transaction(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        trace!("_rollback_transaction >>> wallet_handle: {:?}", wallet_handle);
+
+        self.wallet_service.rollback_transaction(wallet_handle)?;
+
+        trace!("_rollback_transaction <<< res: ()");
+        Ok(())
+    }
+
     fn _create(&self,
                config: &Config,
                credentials: &Credentials,
@@ -393,7 +525,14 @@ impl WalletCommandExecutor {
                cb: Box<dyn Fn(IndyResult<()>) + Send>) {
         trace!("_export >>> handle: {:?}, export_config: {:?}", wallet_handle, secret!(export_config));
 
-        let key_data = KeyDerivationData::from_passphrase_with_new_salt(&export_config.key, &export_config.key_derivation_method);
+        if export_config.format == ExportFormat::PlaintextJsonl {
+            // Plaintext export doesn't require export key derivation
+            cb(self.wallet_service.export_wallet_plaintext(wallet_handle, export_config));
+            trace!("_export <<<");
+            return;
+        }
+
+        let key_data = KeyDerivationData::from_passphrase_with_new_salt(export_config.key(), &export_config.key_derivation_method);
 
         let cb_id = indy_utils::sequence::get_next_id();
         self.pending_callbacks.borrow_mut().insert(cb_id, cb);
@@ -437,6 +576,10 @@ impl WalletCommandExecutor {
         trace!("_import >>> config: {:?}, credentials: {:?}, import_config: {:?}",
                config, secret!(credentials), secret!(import_config));
 
+        if import_config.format == ExportFormat::PlaintextJsonl {
+            return self._import_plaintext(config, credentials, import_config, cb);
+        }
+
         let (wallet_handle, key_data, import_key_data) = try_cb!(self.wallet_service.import_wallet_prepare(&config, &credentials, &import_config), cb);
 
         let cb_id : CallbackHandle = indy_utils::sequence::get_next_id();
@@ -485,6 +628,51 @@ impl WalletCommandExecutor {
             .and_then(|key| self.wallet_service.import_wallet_continue(wallet_handle, &config, &credential, key)))
     }
 
+    fn _import_plaintext(&self,
+                         config: &Config,
+                         credentials: &Credentials,
+                         import_config: &ExportConfig,
+                         cb: Box<dyn Fn(IndyResult<()>) + Send>) {
+        trace!("_import_plaintext >>> config: {:?}, credentials: {:?}, import_config: {:?}",
+               config, secret!(credentials), secret!(import_config));
+
+        let (wallet_handle, key_data) = try_cb!(self.wallet_service.import_wallet_plaintext_prepare(&config, &credentials, &import_config), cb);
+
+        let cb_id : CallbackHandle = indy_utils::sequence::get_next_id();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        let config = config.clone();
+        let credentials = credentials.clone();
+
+        CommandExecutor::instance().send(
+            Command::Wallet(WalletCommand::DeriveKey(
+                key_data,
+                Box::new(move |key_result| {
+                    CommandExecutor::instance().send(Command::Wallet(WalletCommand::ImportPlaintextContinue(
+                        config.clone(),
+                        credentials.clone(),
+                        key_result,
+                        wallet_handle,
+                        cb_id
+                    ))).unwrap();
+                }),
+            ))
+        ).unwrap();
+
+        trace!("_import_plaintext <<<");
+    }
+
+    fn _import_plaintext_continue(&self,
+                                  cb_id: CallbackHandle,
+                                  wallet_handle: WalletHandle,
+                                  config: &Config,
+                                  credential: &Credentials,
+                                  key_result: DeriveKeyResult<MasterKey>) {
+        let cb = get_cb!(self, cb_id);
+        cb(key_result
+            .and_then(|key| self.wallet_service.import_wallet_plaintext_continue(wallet_handle, &config, &credential, key)))
+    }
+
     fn _generate_key(&self,
                      config: Option<&KeyConfig>) -> IndyResult<String> {
         trace!("_generate_key >>>config: {:?}", secret!(config));
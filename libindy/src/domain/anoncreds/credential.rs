@@ -10,6 +10,8 @@ use ursa::cl::{
 use indy_api_types::validation::Validatable;
 
 use super::credential_definition::CredentialDefinitionId;
+use super::credential_offer::CredentialOffer;
+use super::credential_request::CredentialRequest;
 use super::revocation_registry_definition::RevocationRegistryId;
 use super::schema::SchemaId;
 
@@ -65,6 +67,27 @@ impl Validatable for CredentialValues {
     }
 }
 
+/// Credential values passed by the issuer.
+/// Encoded value is calculated from the raw one if omitted.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct RawCredentialValues(pub HashMap<String, RawAttributeValues>);
+
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct RawAttributeValues {
+    pub raw: String,
+    pub encoded: Option<String>
+}
+
+impl Validatable for RawCredentialValues {
+    fn validate(&self) -> Result<(), String> {
+        if self.0.is_empty() {
+            return Err(String::from("CredentialValues validation failed: empty list has been passed"));
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable for Credential {
     fn validate(&self) -> Result<(), String> {
         self.schema_id.validate()?;
@@ -81,4 +104,40 @@ impl Validatable for Credential {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Offer, request and values of the credential issued as part of a batch.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialIssuanceRequest {
+    pub cred_offer: CredentialOffer,
+    pub cred_req: CredentialRequest,
+    pub cred_values: RawCredentialValues,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialIssuanceRequests(pub Vec<CredentialIssuanceRequest>);
+
+impl Validatable for CredentialIssuanceRequests {
+    fn validate(&self) -> Result<(), String> {
+        let first = self.0.first()
+            .ok_or_else(|| String::from("CredentialIssuanceRequests validation failed: empty list has been passed"))?;
+
+        for request in self.0.iter() {
+            request.cred_offer.validate()?;
+            request.cred_req.validate()?;
+            request.cred_values.validate()?;
+
+            if request.cred_offer.cred_def_id != first.cred_offer.cred_def_id {
+                return Err(String::from("CredentialIssuanceRequests validation failed: all offers must be for the same CredentialDefinition"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IssuedCredential {
+    pub cred: Credential,
+    pub cred_revoc_id: Option<String>,
+}
@@ -10,7 +10,9 @@ pub mod requested_credential;
 pub mod revocation_registry_definition;
 pub mod revocation_registry_delta;
 pub mod revocation_registry;
+pub mod revocation_registry_pool;
 pub mod revocation_state;
+pub mod revocation_status_list;
 pub mod schema;
 pub mod master_secret;
 
@@ -64,6 +64,21 @@ pub struct AttributeValue {
     pub encoded: String
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ProofVerificationDetails {
+    pub valid: bool,
+    pub non_canonical_attrs: Vec<NonCanonicalAttribute>,
+}
+
+/// Revealed attribute which `encoded` value doesn't match canonical encoding of its `raw` value.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct NonCanonicalAttribute {
+    pub referent: String,
+    pub name: String,
+    pub raw: String,
+    pub encoded: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct Identifier {
     pub schema_id: SchemaId,
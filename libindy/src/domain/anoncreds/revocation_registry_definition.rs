@@ -4,7 +4,7 @@ use super::DELIMITER;
 use super::credential_definition::CredentialDefinitionId;
 use super::super::crypto::did::DidValue;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use indy_api_types::validation::Validatable;
 use crate::utils::qualifier;
@@ -129,6 +129,23 @@ pub struct RevocationRegistryInfo {
     pub used_ids: HashSet<u32>
 }
 
+impl RevocationRegistryInfo {
+    /// `used_ids` contains issued indexes for ISSUANCE_ON_DEMAND and revoked ones for ISSUANCE_BY_DEFAULT.
+    pub fn issued_ids(&self, issuance_type: &IssuanceType) -> BTreeSet<u32> {
+        match issuance_type {
+            IssuanceType::ISSUANCE_ON_DEMAND => self.used_ids.iter().cloned().collect(),
+            IssuanceType::ISSUANCE_BY_DEFAULT => (1..=self.curr_id).filter(|idx| !self.used_ids.contains(idx)).collect()
+        }
+    }
+
+    pub fn revoked_ids(&self, issuance_type: &IssuanceType) -> BTreeSet<u32> {
+        match issuance_type {
+            IssuanceType::ISSUANCE_ON_DEMAND => (1..=self.curr_id).filter(|idx| !self.used_ids.contains(idx)).collect(),
+            IssuanceType::ISSUANCE_BY_DEFAULT => self.used_ids.iter().cloned().collect()
+        }
+    }
+}
+
 qualifiable_type!(RevocationRegistryId);
 
 impl RevocationRegistryId {
@@ -269,4 +286,30 @@ mod tests {
             _rev_reg_id_qualified().validate().unwrap();
         }
     }
+
+    mod rev_reg_info {
+        use super::*;
+
+        fn _rev_reg_info() -> RevocationRegistryInfo {
+            RevocationRegistryInfo {
+                id: _rev_reg_id_unqualified(),
+                curr_id: 5,
+                used_ids: vec![1, 3, 4].into_iter().collect(),
+            }
+        }
+
+        #[test]
+        fn test_rev_reg_info_ids_for_issuance_on_demand() {
+            let rev_reg_info = _rev_reg_info();
+            assert_eq!(vec![1, 3, 4], rev_reg_info.issued_ids(&IssuanceType::ISSUANCE_ON_DEMAND).into_iter().collect::<Vec<u32>>());
+            assert_eq!(vec![2, 5], rev_reg_info.revoked_ids(&IssuanceType::ISSUANCE_ON_DEMAND).into_iter().collect::<Vec<u32>>());
+        }
+
+        #[test]
+        fn test_rev_reg_info_ids_for_issuance_by_default() {
+            let rev_reg_info = _rev_reg_info();
+            assert_eq!(vec![2, 5], rev_reg_info.issued_ids(&IssuanceType::ISSUANCE_BY_DEFAULT).into_iter().collect::<Vec<u32>>());
+            assert_eq!(vec![1, 3, 4], rev_reg_info.revoked_ids(&IssuanceType::ISSUANCE_BY_DEFAULT).into_iter().collect::<Vec<u32>>());
+        }
+    }
 }
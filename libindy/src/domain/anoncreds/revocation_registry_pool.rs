@@ -0,0 +1,246 @@
+use serde_json::Value;
+
+use indy_api_types::validation::Validatable;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::revocation_registry_definition::{IssuanceType, RevocationRegistryConfig, RevocationRegistryId};
+use super::super::crypto::did::DidValue;
+
+pub const DEFAULT_TAG_PREFIX: &str = "pool_";
+pub const DEFAULT_TAILS_STORAGE_TYPE: &str = "default";
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RevocationRegistryPoolConfig {
+    pub tag_prefix: Option<String>,
+    pub revoc_def_type: Option<String>,
+    pub issuance_type: Option<IssuanceType>,
+    pub max_cred_num: Option<u32>,
+    pub tails_writer_type: Option<String>,
+    pub tails_writer_config: Value,
+    pub tails_reader_type: Option<String>,
+    pub tails_reader_config: Option<Value>,
+}
+
+impl RevocationRegistryPoolConfig {
+    pub fn registry_config(&self) -> RevocationRegistryConfig {
+        RevocationRegistryConfig {
+            issuance_type: self.issuance_type.clone(),
+            max_cred_num: self.max_cred_num,
+        }
+    }
+
+    pub fn tails_writer(&self) -> (&str, String) {
+        (self.tails_writer_type.as_ref().map(String::as_str).unwrap_or(DEFAULT_TAILS_STORAGE_TYPE),
+         self.tails_writer_config.to_string())
+    }
+
+    /// Tails are read from the location they were written to unless the reader is configured explicitly.
+    pub fn tails_reader(&self) -> (&str, String) {
+        (self.tails_reader_type.as_ref().or(self.tails_writer_type.as_ref()).map(String::as_str).unwrap_or(DEFAULT_TAILS_STORAGE_TYPE),
+         self.tails_reader_config.as_ref().unwrap_or(&self.tails_writer_config).to_string())
+    }
+}
+
+impl Validatable for RevocationRegistryPoolConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.registry_config().validate()?;
+
+        if let Some(ref tag_prefix) = self.tag_prefix {
+            if tag_prefix.contains(':') {
+                return Err(String::from("RevocationRegistryPoolConfig validation failed: `tag_prefix` must not contain `:`"));
+            }
+        }
+
+        if !self.tails_writer_config.is_object() {
+            return Err(String::from("RevocationRegistryPoolConfig validation failed: `tails_writer_config` must be an object"));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RevocationRegistryPoolEntryState {
+    /// Registry used for issuance.
+    Active,
+    /// Pre-generated registry which becomes active when the active one is full if it's published.
+    Standby,
+    /// Registry which has no free indexes left.
+    Full,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RevocationRegistryPoolEntry {
+    pub rev_reg_id: RevocationRegistryId,
+    pub state: RevocationRegistryPoolEntryState,
+    pub published: bool,
+}
+
+/// Revocation registries of the credential definition managed by the wallet.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevocationRegistryPool {
+    pub issuer_did: DidValue,
+    pub cred_def_id: CredentialDefinitionId,
+    pub config: RevocationRegistryPoolConfig,
+    pub registries: Vec<RevocationRegistryPoolEntry>,
+}
+
+impl RevocationRegistryPool {
+    pub fn new(issuer_did: DidValue, cred_def_id: CredentialDefinitionId, config: RevocationRegistryPoolConfig) -> RevocationRegistryPool {
+        RevocationRegistryPool {
+            issuer_did,
+            cred_def_id,
+            config,
+            registries: Vec::new(),
+        }
+    }
+
+    pub fn next_tag(&self) -> String {
+        format!("{}{}",
+                self.config.tag_prefix.as_ref().map(String::as_str).unwrap_or(DEFAULT_TAG_PREFIX),
+                self.registries.len() + 1)
+    }
+
+    /// First registry added to the pool becomes active, the next ones wait in standby.
+    pub fn add(&mut self, rev_reg_id: RevocationRegistryId) {
+        let state = if self.active().is_some() {
+            RevocationRegistryPoolEntryState::Standby
+        } else {
+            RevocationRegistryPoolEntryState::Active
+        };
+
+        self.registries.push(RevocationRegistryPoolEntry { rev_reg_id, state, published: false });
+    }
+
+    pub fn active(&self) -> Option<&RevocationRegistryPoolEntry> {
+        self.registries.iter().find(|entry| entry.state == RevocationRegistryPoolEntryState::Active)
+    }
+
+    pub fn standby(&self) -> Option<&RevocationRegistryPoolEntry> {
+        self.registries.iter().find(|entry| entry.state == RevocationRegistryPoolEntryState::Standby)
+    }
+
+    /// Marks the active registry as full and promotes the first standby one published to the ledger.
+    /// Returns id of the new active registry or None if there is no published standby registry.
+    pub fn rollover(&mut self) -> Option<RevocationRegistryId> {
+        let standby_idx = self.registries.iter()
+            .position(|entry| entry.state == RevocationRegistryPoolEntryState::Standby && entry.published)?;
+
+        for entry in self.registries.iter_mut().filter(|entry| entry.state == RevocationRegistryPoolEntryState::Active) {
+            entry.state = RevocationRegistryPoolEntryState::Full;
+        }
+
+        let standby = &mut self.registries[standby_idx];
+        standby.state = RevocationRegistryPoolEntryState::Active;
+        Some(standby.rev_reg_id.clone())
+    }
+
+    pub fn set_published(&mut self, rev_reg_id: &RevocationRegistryId) -> bool {
+        match self.registries.iter_mut().find(|entry| entry.rev_reg_id == *rev_reg_id) {
+            Some(entry) => {
+                entry.published = true;
+                true
+            }
+            None => false
+        }
+    }
+}
+
+/// Ledger requests for the registry of the pool which hasn't been published yet.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevocationRegistryPendingRequests {
+    pub rev_reg_id: RevocationRegistryId,
+    pub rev_reg_def_request: String,
+    pub rev_reg_entry_request: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _config() -> RevocationRegistryPoolConfig {
+        serde_json::from_value(json!({
+            "max_cred_num": 5,
+            "tails_writer_config": {"base_dir": "/tmp/tails", "uri_pattern": ""}
+        })).unwrap()
+    }
+
+    fn _pool() -> RevocationRegistryPool {
+        let mut pool = RevocationRegistryPool::new(DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string()),
+                                                   CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string()),
+                                                   _config());
+        pool.add(RevocationRegistryId("rev_reg_1".to_string()));
+        pool.add(RevocationRegistryId("rev_reg_2".to_string()));
+        pool
+    }
+
+    #[test]
+    fn revocation_registry_pool_config_validate_works() {
+        _config().validate().unwrap();
+
+        let mut config = _config();
+        config.tag_prefix = Some("pool:".to_string());
+        assert!(config.validate().is_err());
+
+        let mut config = _config();
+        config.tails_writer_config = json!("/tmp/tails");
+        assert!(config.validate().is_err());
+
+        let mut config = _config();
+        config.max_cred_num = Some(0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn revocation_registry_pool_config_tails_reader_works_for_writer_fallback() {
+        let config = _config();
+        assert_eq!(config.tails_writer(), config.tails_reader());
+        assert_eq!("default", config.tails_reader().0);
+    }
+
+    #[test]
+    fn revocation_registry_pool_add_works() {
+        let pool = _pool();
+        assert_eq!("pool_3", pool.next_tag());
+        assert_eq!(RevocationRegistryId("rev_reg_1".to_string()), pool.active().unwrap().rev_reg_id);
+        assert_eq!(RevocationRegistryId("rev_reg_2".to_string()), pool.standby().unwrap().rev_reg_id);
+    }
+
+    #[test]
+    fn revocation_registry_pool_rollover_works() {
+        let mut pool = _pool();
+        pool.set_published(&RevocationRegistryId("rev_reg_2".to_string()));
+
+        assert_eq!(Some(RevocationRegistryId("rev_reg_2".to_string())), pool.rollover());
+        assert_eq!(RevocationRegistryPoolEntryState::Full, pool.registries[0].state);
+        assert_eq!(RevocationRegistryId("rev_reg_2".to_string()), pool.active().unwrap().rev_reg_id);
+        assert!(pool.standby().is_none());
+
+        assert_eq!(None, pool.rollover());
+        assert_eq!(RevocationRegistryId("rev_reg_2".to_string()), pool.active().unwrap().rev_reg_id);
+    }
+
+    #[test]
+    fn revocation_registry_pool_rollover_works_for_not_published_standby() {
+        let mut pool = _pool();
+        pool.add(RevocationRegistryId("rev_reg_3".to_string()));
+        pool.set_published(&RevocationRegistryId("rev_reg_3".to_string()));
+
+        assert_eq!(Some(RevocationRegistryId("rev_reg_3".to_string())), pool.rollover());
+        assert_eq!(RevocationRegistryPoolEntryState::Standby, pool.registries[1].state);
+
+        assert_eq!(None, pool.rollover());
+        assert_eq!(RevocationRegistryId("rev_reg_3".to_string()), pool.active().unwrap().rev_reg_id);
+    }
+
+    #[test]
+    fn revocation_registry_pool_set_published_works() {
+        let mut pool = _pool();
+
+        assert!(pool.set_published(&RevocationRegistryId("rev_reg_1".to_string())));
+        assert!(pool.registries[0].published);
+        assert!(!pool.registries[1].published);
+        assert!(!pool.set_published(&RevocationRegistryId("rev_reg_3".to_string())));
+    }
+}
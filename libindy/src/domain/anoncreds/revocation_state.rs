@@ -1,8 +1,10 @@
 use ursa::cl::{Witness, RevocationRegistry};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use indy_api_types::validation::Validatable;
 
+use super::revocation_registry_definition::RevocationRegistryId;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RevocationState {
     pub witness: Witness,
@@ -20,3 +22,39 @@ impl Validatable for RevocationState {
 }
 
 pub type RevocationStates = HashMap<String, HashMap<u64, RevocationState>>;
+
+/// Number of the latest revocation states kept in the cache of each credential.
+pub const MAX_CACHED_REVOCATION_STATES: usize = 10;
+
+/// Revocation states of the credential cached in the prover wallet by timestamp.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CredentialRevocationStates {
+    pub rev_reg_id: RevocationRegistryId,
+    pub states: BTreeMap<u64, RevocationState>,
+}
+
+impl CredentialRevocationStates {
+    pub fn new(rev_reg_id: RevocationRegistryId) -> CredentialRevocationStates {
+        CredentialRevocationStates {
+            rev_reg_id,
+            states: BTreeMap::new(),
+        }
+    }
+
+    pub fn latest(&self) -> Option<&RevocationState> {
+        self.states.values().next_back()
+    }
+
+    /// The oldest states are dropped when there are more than `MAX_CACHED_REVOCATION_STATES` of them.
+    pub fn add(&mut self, state: RevocationState) {
+        self.states.insert(state.timestamp, state);
+
+        while self.states.len() > MAX_CACHED_REVOCATION_STATES {
+            let oldest = match self.states.keys().next() {
+                Some(timestamp) => *timestamp,
+                None => break
+            };
+            self.states.remove(&oldest);
+        }
+    }
+}
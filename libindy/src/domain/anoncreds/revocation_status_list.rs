@@ -0,0 +1,43 @@
+use std::collections::BTreeSet;
+
+use super::revocation_registry_definition::{IssuanceType, RevocationRegistryId};
+
+/// Status of indexes of the revocation registry tracked by the issuer wallet.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevocationStatusList {
+    pub rev_reg_id: RevocationRegistryId,
+    pub issuance_type: IssuanceType,
+    pub max_cred_num: u32,
+    pub issued_count: u32,
+    pub issued: BTreeSet<u32>,
+    pub revoked: BTreeSet<u32>,
+    /// Base64 encoded bitmap of `max_cred_num` bits. Bit `idx - 1` (most significant bit first) is set if `idx` is revoked.
+    pub status_list: String,
+}
+
+pub fn revocation_status_bitmap(max_cred_num: u32, revoked: &BTreeSet<u32>) -> Vec<u8> {
+    let mut bitmap = vec![0u8; ((max_cred_num + 7) / 8) as usize];
+
+    for idx in revoked.iter().filter(|idx| **idx >= 1 && **idx <= max_cred_num) {
+        let bit = (idx - 1) as usize;
+        bitmap[bit / 8] |= 0x80 >> (bit % 8);
+    }
+
+    bitmap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revocation_status_bitmap_works() {
+        let revoked: BTreeSet<u32> = vec![1, 8, 10].into_iter().collect();
+        assert_eq!(vec![0b1000_0001, 0b0100_0000], revocation_status_bitmap(10, &revoked));
+    }
+
+    #[test]
+    fn revocation_status_bitmap_works_for_nothing_revoked() {
+        assert_eq!(vec![0u8; 13], revocation_status_bitmap(100, &BTreeSet::new()));
+    }
+}
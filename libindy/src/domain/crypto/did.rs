@@ -4,6 +4,11 @@ use rust_base58::FromBase58;
 use indy_api_types::validation::Validatable;
 use crate::utils::qualifier;
 
+pub const DEFAULT_DID_METHOD: &str = "sov";
+pub const INDY_DID_METHOD: &str = "indy";
+// Fragment that references the first key of DID Document of ledger DID
+pub const DEFAULT_DID_KEY_FRAGMENT: &str = "key-1";
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DidMethod(pub String);
 
@@ -185,3 +190,127 @@ impl From<TemporaryDid> for Did {
         }
     }
 }
+
+/// Dated history of verkeys of DID (my or their) stored under DID as record id.
+/// Keys are ordered by the time they came into effect, the last one is the current key.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DidKeyHistory {
+    pub keys: Vec<DidKeyHistoryEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidKeyHistoryEntry {
+    pub verkey: String,
+    // None if the time key came into effect is unknown (only the first key can have it)
+    pub valid_from: Option<u64>,
+}
+
+impl DidKeyHistory {
+    pub fn new(verkey: &str, valid_from: u64) -> DidKeyHistory {
+        let mut history = DidKeyHistory::default();
+        history.push(verkey, valid_from);
+        history
+    }
+
+    /// History of DID stored before key history was introduced, so the time its key came into effect is unknown.
+    pub fn new_with_unknown_start(verkey: &str) -> DidKeyHistory {
+        DidKeyHistory {
+            keys: vec![DidKeyHistoryEntry { verkey: verkey.to_string(), valid_from: None }],
+        }
+    }
+
+    /// Appends key that came into effect at `valid_from` unless it is already the current one.
+    /// Returns true if history has been changed.
+    pub fn push(&mut self, verkey: &str, valid_from: u64) -> bool {
+        let valid_from = match self.keys.last() {
+            Some(current) if current.verkey == verkey => return false,
+            // Keep history ordered even if clock went backwards
+            Some(current) => current.valid_from.map_or(valid_from, |current_from| ::std::cmp::max(current_from, valid_from)),
+            None => valid_from
+        };
+
+        self.keys.push(DidKeyHistoryEntry { verkey: verkey.to_string(), valid_from: Some(valid_from) });
+        true
+    }
+
+    /// Key in effect at `timestamp` or None if history starts later or the key in effect has unknown start.
+    pub fn key_at(&self, timestamp: u64) -> Option<&str> {
+        self.keys.iter()
+            .rev()
+            .find(|entry| entry.valid_from.map_or(true, |valid_from| valid_from <= timestamp))
+            .and_then(|entry| entry.valid_from.map(|_| entry.verkey.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERKEY_1: &str = "GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa";
+    const VERKEY_2: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+
+    #[test]
+    fn did_key_history_push_works() {
+        let mut history = DidKeyHistory::new(VERKEY_1, 100);
+        assert!(history.push(VERKEY_2, 200));
+        assert_eq!(vec![DidKeyHistoryEntry { verkey: VERKEY_1.to_string(), valid_from: Some(100) },
+                        DidKeyHistoryEntry { verkey: VERKEY_2.to_string(), valid_from: Some(200) }],
+                   history.keys);
+    }
+
+    #[test]
+    fn did_key_history_push_works_for_current_key() {
+        let mut history = DidKeyHistory::new(VERKEY_1, 100);
+        assert!(!history.push(VERKEY_1, 200));
+        assert_eq!(1, history.keys.len());
+    }
+
+    #[test]
+    fn did_key_history_push_keeps_order_for_earlier_time() {
+        let mut history = DidKeyHistory::new(VERKEY_1, 100);
+        history.push(VERKEY_2, 50);
+        assert_eq!(Some(100), history.keys[1].valid_from);
+    }
+
+    #[test]
+    fn did_key_history_key_at_works() {
+        let mut history = DidKeyHistory::new(VERKEY_1, 100);
+        history.push(VERKEY_2, 200);
+
+        assert_eq!(None, history.key_at(99));
+        assert_eq!(Some(VERKEY_1), history.key_at(100));
+        assert_eq!(Some(VERKEY_1), history.key_at(199));
+        assert_eq!(Some(VERKEY_2), history.key_at(200));
+        assert_eq!(Some(VERKEY_2), history.key_at(1000));
+    }
+
+    #[test]
+    fn did_key_history_push_works_for_unknown_start() {
+        let mut history = DidKeyHistory::new_with_unknown_start(VERKEY_1);
+        assert!(history.push(VERKEY_2, 200));
+        assert_eq!(vec![DidKeyHistoryEntry { verkey: VERKEY_1.to_string(), valid_from: None },
+                        DidKeyHistoryEntry { verkey: VERKEY_2.to_string(), valid_from: Some(200) }],
+                   history.keys);
+    }
+
+    // None makes `indy_key_for_did_at` fetch the key from the ledger
+    #[test]
+    fn did_key_history_key_at_works_for_unknown_start() {
+        let mut history = DidKeyHistory::new_with_unknown_start(VERKEY_1);
+        assert_eq!(None, history.key_at(100));
+
+        history.push(VERKEY_2, 200);
+
+        assert_eq!(None, history.key_at(0));
+        assert_eq!(None, history.key_at(199));
+        assert_eq!(Some(VERKEY_2), history.key_at(200));
+        assert_eq!(Some(VERKEY_2), history.key_at(1000));
+    }
+
+    #[test]
+    fn did_key_history_deserialize_works_for_known_start() {
+        let history: DidKeyHistory = serde_json::from_str(&format!(r#"{{"keys":[{{"verkey":"{}","validFrom":100}}]}}"#, VERKEY_1)).unwrap();
+        assert_eq!(Some(100), history.keys[0].valid_from);
+    }
+}
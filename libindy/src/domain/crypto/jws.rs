@@ -0,0 +1,97 @@
+use indy_api_types::validation::Validatable;
+
+use super::did::{DidValue, DEFAULT_DID_KEY_FRAGMENT, DEFAULT_DID_METHOD};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum JwsSerialization {
+    Compact,
+    Json,
+}
+
+impl Default for JwsSerialization {
+    fn default() -> Self {
+        JwsSerialization::Compact
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct JwsOptions {
+    #[serde(default)]
+    pub serialization: JwsSerialization,
+    #[serde(default)]
+    pub detached: bool,
+    pub did: Option<DidValue>,
+}
+
+impl JwsOptions {
+    /// DID URL of signing key: unqualified DIDs are qualified with `sov` method
+    /// and DIDs without fragment reference the first key of DID Document.
+    pub fn kid(&self) -> Option<String> {
+        self.did.as_ref().map(|did| {
+            let did = if did.is_fully_qualified() { did.clone() } else { did.qualify(DEFAULT_DID_METHOD) };
+
+            if did.0.contains('#') {
+                did.0
+            } else {
+                format!("{}#{}", did.0, DEFAULT_DID_KEY_FRAGMENT)
+            }
+        })
+    }
+}
+
+impl Validatable for JwsOptions {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(ref did) = self.did {
+            did.validate()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _options(did: Option<&str>) -> JwsOptions {
+        JwsOptions {
+            did: did.map(|did| DidValue(did.to_string())),
+            ..JwsOptions::default()
+        }
+    }
+
+    #[test]
+    fn jws_options_deserialize_works_for_empty() {
+        let options: JwsOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(JwsSerialization::Compact, options.serialization);
+        assert!(!options.detached);
+        assert!(options.did.is_none());
+    }
+
+    #[test]
+    fn jws_options_deserialize_works_for_json_serialization() {
+        let options: JwsOptions = serde_json::from_str(r#"{"serialization": "json", "detached": true}"#).unwrap();
+        assert_eq!(JwsSerialization::Json, options.serialization);
+        assert!(options.detached);
+    }
+
+    #[test]
+    fn jws_options_kid_works_for_unqualified_did() {
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1", _options(Some("NcYxiDXkpYi6ov5FcYDi1e")).kid().unwrap());
+    }
+
+    #[test]
+    fn jws_options_kid_works_for_did_url() {
+        assert_eq!("did:peer:NcYxiDXkpYi6ov5FcYDi1e#sign", _options(Some("did:peer:NcYxiDXkpYi6ov5FcYDi1e#sign")).kid().unwrap());
+    }
+
+    #[test]
+    fn jws_options_kid_works_without_did() {
+        assert!(_options(None).kid().is_none());
+    }
+
+    #[test]
+    fn jws_options_validate_not_works_for_invalid_did() {
+        assert!(_options(Some("invalid_base58_did")).validate().is_err());
+    }
+}
@@ -38,4 +38,88 @@ pub struct UnpackMessage {
     pub recipient_verkey: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sender_verkey: Option<String>
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct JweV2 {
+    pub protected: String,
+    pub recipients: Vec<RecipientV2>,
+    pub iv: String,
+    pub ciphertext: String,
+    pub tag: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct RecipientV2 {
+    pub header: RecipientHeaderV2,
+    pub encrypted_key: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct RecipientHeaderV2 {
+    pub kid: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct ProtectedV2 {
+    pub typ: String,
+    pub alg: String,
+    pub enc: String,
+    pub epk: Jwk,
+    pub apv: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skid: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apu: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub x: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct Jws {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub payload: String,
+    pub signatures: Vec<JwsSignature>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct JwsSignature {
+    pub protected: String,
+    pub header: JwsHeader,
+    pub signature: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct JwsHeader {
+    pub kid: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct JwsProtected {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typ: Option<String>,
+    pub alg: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct UnpackMessageV2 {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_kid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_kid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signer_kid: Option<String>
+}
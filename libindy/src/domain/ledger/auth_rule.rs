@@ -236,6 +236,37 @@ pub struct AuthRule {
     pub constraint: Constraint,
 }
 
+/**
+   The signer of a transaction checked against auth rule constraints
+    # parameters
+   role - The role of the signer on the ledger (null for common USER).
+   is_owner - The flag specifying if the signer is an owner of the transaction (false by default).
+   off_ledger - The flag specifying if the signer DID isn't present on the ledger (false by default).
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthRuleSigner {
+    pub role: Option<String>,
+    #[serde(default)]
+    pub is_owner: bool,
+    #[serde(default)]
+    pub off_ledger: bool,
+}
+
+/**
+   The result of a check of signers against auth rule constraints
+    # parameters
+   allowed - Whether the signers satisfy the constraint.
+   explanation - Human readable explanation of the constraint evaluation.
+   fees - Fees aliases set in the metadata of satisfied constraints.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct AuthRuleCheckResult {
+    pub allowed: bool,
+    pub explanation: String,
+    pub fees: Vec<String>,
+}
+
 #[derive(Serialize, PartialEq, Debug)]
 pub struct AuthRulesOperation {
     #[serde(rename = "type")]
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use indy_api_types::validation::Validatable;
 
 use super::constants::{GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML, DISABLE_ALL_TXN_AUTHR_AGRMTS};
+use super::request::TxnAuthrAgrmtAcceptanceData;
+use super::response::{GetReplyResultV0, ReplyType};
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct TxnAuthorAgreementOperation {
@@ -144,4 +146,35 @@ impl GetAcceptanceMechanismOperation {
             version,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GetTxnAuthorAgreementReplyResult {
+    GetTxnAuthorAgreementReplyResultV0(GetReplyResultV0<TxnAuthorAgreementData>)
+}
+
+impl ReplyType for GetTxnAuthorAgreementReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct TxnAuthorAgreementData {
+    pub text: String,
+    pub version: String,
+    pub digest: Option<String>,
+    pub ratification_ts: Option<u64>,
+    pub retirement_ts: Option<u64>,
+}
+
+/// Transaction author agreement acceptance registered on a pool handle.
+#[derive(Clone, Debug)]
+pub struct PoolTxnAuthorAgreementAcceptance {
+    pub acceptance: TxnAuthrAgrmtAcceptanceData,
+    /// Latest agreement read from the ledger on first write sent with this acceptance
+    pub ledger_taa: Option<TxnAuthorAgreementData>,
+    /// Time when the agreement was read from the ledger
+    pub ledger_taa_time: u64,
+}
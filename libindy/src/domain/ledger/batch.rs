@@ -0,0 +1,81 @@
+use indy_api_types::ErrorCode;
+use indy_api_types::errors::IndyError;
+use indy_api_types::validation::Validatable;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubmitBatchOptions {
+    // Number of requests of one batch that are sent to the pool without waiting for replies.
+    pub max_in_flight: Option<usize>,
+}
+
+impl Validatable for SubmitBatchOptions {
+    fn validate(&self) -> Result<(), String> {
+        if self.max_in_flight == Some(0) {
+            return Err(String::from("`max_in_flight` must be greater than 0"));
+        }
+        Ok(())
+    }
+}
+
+/// Result of single request of the batch: either reply received from the pool or error.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchRequestResult {
+    Reply(serde_json::Value),
+    Error(BatchRequestError),
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BatchRequestError {
+    pub code: i32,
+    pub message: String,
+}
+
+impl BatchRequestResult {
+    pub fn new(result: Result<String, IndyError>) -> BatchRequestResult {
+        match result {
+            Ok(reply) => {
+                let reply = serde_json::from_str(&reply)
+                    .unwrap_or_else(|_| serde_json::Value::String(reply));
+                BatchRequestResult::Reply(reply)
+            }
+            Err(err) => BatchRequestResult::Error(BatchRequestError {
+                code: ErrorCode::from(err.kind()) as i32,
+                message: err.to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indy_api_types::errors::{err_msg, IndyErrorKind};
+
+    use super::*;
+
+    #[test]
+    fn submit_batch_options_deserialize_works_for_empty() {
+        let options: SubmitBatchOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(None, options.max_in_flight);
+    }
+
+    #[test]
+    fn submit_batch_options_validate_not_works_for_zero_max_in_flight() {
+        let options: SubmitBatchOptions = serde_json::from_str(r#"{"max_in_flight": 0}"#).unwrap();
+        assert!(options.validate().is_err());
+    }
+
+    #[test]
+    fn batch_request_result_serialize_works_for_reply() {
+        let result = BatchRequestResult::new(Ok(r#"{"op":"REPLY"}"#.to_string()));
+        assert_eq!(json!({"reply": {"op": "REPLY"}}), serde_json::to_value(&result).unwrap());
+    }
+
+    #[test]
+    fn batch_request_result_serialize_works_for_error() {
+        let result = BatchRequestResult::new(Err(err_msg(IndyErrorKind::PoolTimeout, "Timeout")));
+        let result = serde_json::to_value(&result).unwrap();
+        assert_eq!(ErrorCode::PoolLedgerTimeout as i32 as i64, result["error"]["code"].as_i64().unwrap());
+        assert!(result["error"]["message"].as_str().unwrap().contains("Timeout"));
+    }
+}
@@ -0,0 +1,91 @@
+use super::response::ResponseMetadata;
+
+pub const DID_DOCUMENT_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const DID_DOCUMENT_CONTENT_TYPE: &str = "application/did+ld+json";
+pub const ED25519_VERIFICATION_KEY_TYPE: &str = "Ed25519VerificationKey2018";
+
+pub const SERVICE_TYPE_ENDPOINT: &str = "endpoint";
+pub const SERVICE_TYPE_DID_COMMUNICATION: &str = "did-communication";
+pub const SERVICE_TYPE_DIDCOMM_MESSAGING: &str = "DIDCommMessaging";
+pub const DIDCOMM_V2_ENDPOINT_TYPE: &str = "DIDComm";
+
+pub const DEFAULT_ENDPOINT_TYPES: &[&str] = &[SERVICE_TYPE_ENDPOINT, SERVICE_TYPE_DID_COMMUNICATION];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub service: Vec<DidService>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipient_keys: Vec<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub routing_keys: Vec<String>,
+}
+
+/// Endpoint ATTRIB in either legacy indy format {"ha": <ip:port>, "verkey": <transport key>}
+/// or did:sov method format {"endpoint": <url>, "routingKeys": [..], "types": [..]}
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointAttrib {
+    pub ha: Option<String>,
+    pub verkey: Option<String>,
+    pub endpoint: Option<String>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+    pub types: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct EndpointAttribData {
+    pub endpoint: EndpointAttrib,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+    pub content_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<ResponseMetadata>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResult {
+    pub did_document: DidDocument,
+    pub did_resolution_metadata: DidResolutionMetadata,
+}
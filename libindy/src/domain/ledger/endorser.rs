@@ -0,0 +1,107 @@
+use serde_json::Value;
+
+use indy_api_types::validation::Validatable;
+
+use super::super::crypto::did::ShortDidValue;
+
+pub const PENDING_TRANSACTION_VERSION: &str = "1.0";
+
+/// Request prepared and signed by an author who lacks permission to write it.
+/// Passed to the endorser who co-signs and submits it to the ledger.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingTransaction {
+    pub ver: String,
+    pub txn_type: String,
+    pub author: ShortDidValue,
+    pub endorser: ShortDidValue,
+    pub request: Value,
+}
+
+impl PendingTransaction {
+    pub fn new(author: ShortDidValue, endorser: ShortDidValue, request: Value) -> PendingTransaction {
+        PendingTransaction {
+            ver: PENDING_TRANSACTION_VERSION.to_string(),
+            txn_type: request["operation"]["type"].as_str().unwrap_or_default().to_string(),
+            author,
+            endorser,
+            request,
+        }
+    }
+}
+
+impl Validatable for PendingTransaction {
+    fn validate(&self) -> Result<(), String> {
+        if self.ver != PENDING_TRANSACTION_VERSION {
+            return Err(format!("Unsupported pending transaction version: {}", self.ver));
+        }
+
+        if self.request["operation"]["type"].as_str() != Some(self.txn_type.as_str()) {
+            return Err(String::from("Transaction type doesn't match type of the request"));
+        }
+
+        if self.request["identifier"].as_str() != Some(self.author.0.as_str()) {
+            return Err(String::from("Request isn't submitted by the author"));
+        }
+
+        if self.request["endorser"].as_str() != Some(self.endorser.0.as_str()) {
+            return Err(String::from("Request doesn't contain the endorser"));
+        }
+
+        if self.request["signatures"][&self.author.0].as_str().is_none() {
+            return Err(String::from("Request isn't signed by the author"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUTHOR: &str = "V4SGRU86Z58d6TV7PBUe6f";
+    const ENDORSER: &str = "VsKV7grR1BUE29mG2Fm2kX";
+
+    fn _request() -> Value {
+        json!({
+            "reqId": 1,
+            "identifier": AUTHOR,
+            "endorser": ENDORSER,
+            "operation": {"type": "101"},
+            "signatures": {AUTHOR: "signature"}
+        })
+    }
+
+    fn _pending_transaction(request: Value) -> PendingTransaction {
+        PendingTransaction::new(ShortDidValue(AUTHOR.to_string()), ShortDidValue(ENDORSER.to_string()), request)
+    }
+
+    #[test]
+    fn pending_transaction_validate_works() {
+        let pending_transaction = _pending_transaction(_request());
+        assert_eq!("101", pending_transaction.txn_type);
+        pending_transaction.validate().unwrap();
+    }
+
+    #[test]
+    fn pending_transaction_validate_not_works_for_other_endorser() {
+        let mut request = _request();
+        request["endorser"] = json!(AUTHOR);
+        assert!(_pending_transaction(request).validate().is_err());
+    }
+
+    #[test]
+    fn pending_transaction_validate_not_works_for_not_signed_by_author() {
+        let mut request = _request();
+        request["signatures"] = json!({ENDORSER: "signature"});
+        assert!(_pending_transaction(request).validate().is_err());
+    }
+
+    #[test]
+    fn pending_transaction_validate_not_works_for_changed_type() {
+        let mut pending_transaction = _pending_transaction(_request());
+        pending_transaction.txn_type = "1".to_string();
+        assert!(pending_transaction.validate().is_err());
+    }
+}
@@ -5,6 +5,9 @@ pub mod schema;
 pub mod cred_def;
 pub mod node;
 pub mod ddo;
+pub mod did_doc;
+pub mod batch;
+pub mod endorser;
 pub mod txn;
 pub mod pool;
 pub mod rev_reg_def;
@@ -15,3 +18,4 @@ pub mod constants;
 pub mod auth_rule;
 pub mod author_agreement;
 pub mod ledgers_freeze;
+pub mod rich_schema;
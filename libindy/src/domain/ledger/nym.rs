@@ -31,14 +31,21 @@ impl NymOperation {
 pub struct GetNymOperation {
     #[serde(rename = "type")]
     pub _type: String,
-    pub dest: ShortDidValue
+    pub dest: ShortDidValue,
+    #[serde(rename = "seqNo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>
 }
 
 impl GetNymOperation {
-    pub fn new(dest: ShortDidValue) -> GetNymOperation {
+    pub fn new(dest: ShortDidValue, seq_no: Option<i32>, timestamp: Option<u64>) -> GetNymOperation {
         GetNymOperation {
             _type: GET_NYM.to_string(),
-            dest
+            dest,
+            seq_no,
+            timestamp
         }
     }
 }
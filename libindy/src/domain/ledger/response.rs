@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Response {
@@ -86,4 +88,98 @@ pub struct ResponseMetadata {
     pub last_txn_time: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_seq_no: Option<u64>,
-}
\ No newline at end of file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub consensus: Option<ConsensusDetails>,
+}
+
+/// Outcome of a request sent to all nodes of the pool.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConsensusDetails {
+    /// Whether f+1 nodes agreed on the same reply
+    pub reached: bool,
+    pub f: usize,
+    pub nodes: BTreeMap<String, NodeResult>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeResult {
+    pub acked: bool,
+    pub status: NodeReplyStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub error_kind: Option<NodeErrorKind>,
+}
+
+impl Default for NodeResult {
+    fn default() -> Self {
+        NodeResult {
+            acked: false,
+            status: NodeReplyStatus::NoReply,
+            reason: None,
+            error_kind: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum NodeReplyStatus {
+    #[serde(rename = "REPLY")]
+    Reply,
+    #[serde(rename = "REQNACK")]
+    ReqNack,
+    #[serde(rename = "REJECT")]
+    Reject,
+    #[serde(rename = "TIMEOUT")]
+    Timeout,
+    #[serde(rename = "NO_REPLY")]
+    NoReply,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum NodeErrorKind {
+    AuthRule,
+    TaaMismatch,
+    InvalidSignature,
+    InvalidRequest,
+    Other,
+}
+
+impl NodeErrorKind {
+    /// Classifies the reason of REQNACK or REJECT returned by a node.
+    pub fn from_reason(reason: &str) -> NodeErrorKind {
+        let reason_lc = reason.to_lowercase();
+
+        if reason_lc.contains("txn author agreement") || reason_lc.contains("taaacceptance") {
+            NodeErrorKind::TaaMismatch
+        } else if reason.contains("UnauthorizedClientRequest") || reason_lc.contains("rule for this action") {
+            NodeErrorKind::AuthRule
+        } else if reason.contains("InsufficientCorrectSignatures") || reason.contains("CouldNotAuthenticate")
+            || reason_lc.contains("signature") {
+            NodeErrorKind::InvalidSignature
+        } else if reason.contains("InvalidClientRequest") || reason_lc.contains("validation error")
+            || reason_lc.contains("client request invalid") {
+            NodeErrorKind::InvalidRequest
+        } else {
+            NodeErrorKind::Other
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TxnInclusion {
+    pub seq_no: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger_id: Option<u64>,
+    pub ledger_size: u64,
+    pub root_hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_signature_timestamp: Option<u64>,
+}
@@ -0,0 +1,234 @@
+use serde_json::Value;
+
+use indy_api_types::validation::Validatable;
+
+use super::constants::{GET_RICH_SCHEMA_OBJECT_BY_ID, GET_RICH_SCHEMA_OBJECT_BY_METADATA, JSON_LD_CONTEXT, RICH_SCHEMA,
+                       RICH_SCHEMA_CRED_DEF, RICH_SCHEMA_ENCODING, RICH_SCHEMA_MAPPING, RICH_SCHEMA_PRES_DEF};
+use super::response::{GetReplyResultV0, ReplyType};
+
+/**
+   Type of an object of the rich schema transaction family
+    ctx - JSON-LD context
+    sch - rich schema
+    map - mapping
+    enc - encoding
+    cdf - credential definition
+    pdf - presentation definition
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+pub enum RichSchemaType {
+    #[serde(rename = "ctx")]
+    Context,
+    #[serde(rename = "sch")]
+    Schema,
+    #[serde(rename = "map")]
+    Mapping,
+    #[serde(rename = "enc")]
+    Encoding,
+    #[serde(rename = "cdf")]
+    CredDef,
+    #[serde(rename = "pdf")]
+    PresDef,
+}
+
+impl RichSchemaType {
+    pub fn txn_type(&self) -> &'static str {
+        match self {
+            RichSchemaType::Context => JSON_LD_CONTEXT,
+            RichSchemaType::Schema => RICH_SCHEMA,
+            RichSchemaType::Mapping => RICH_SCHEMA_MAPPING,
+            RichSchemaType::Encoding => RICH_SCHEMA_ENCODING,
+            RichSchemaType::CredDef => RICH_SCHEMA_CRED_DEF,
+            RichSchemaType::PresDef => RICH_SCHEMA_PRES_DEF,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchema {
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+}
+
+impl RichSchema {
+    pub fn new(id: String, content: String, rs_name: String, rs_version: String, rs_type: RichSchemaType, ver: String) -> RichSchema {
+        RichSchema {
+            id,
+            content,
+            rs_name,
+            rs_version,
+            rs_type,
+            ver,
+        }
+    }
+}
+
+impl Validatable for RichSchema {
+    fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err(String::from("Empty Rich Schema object id has been passed"));
+        }
+
+        if self.rs_name.is_empty() || self.rs_version.is_empty() {
+            return Err(String::from("Empty Rich Schema object name or version has been passed"));
+        }
+
+        let content: Value = serde_json::from_str(&self.content)
+            .map_err(|err| format!("Rich Schema object content is invalid json: {}", err))?;
+
+        if !content.is_object() {
+            return Err(String::from("Rich Schema object content must be a json object"));
+        }
+
+        match self.rs_type {
+            RichSchemaType::Context => {
+                if content["@context"].is_null() {
+                    return Err(String::from("JSON-LD context content must contain `@context` field"));
+                }
+            }
+            _ => {
+                if content["@id"].as_str() != Some(self.id.as_str()) {
+                    return Err(format!("Rich Schema object content `@id` must be equal to object id {}", self.id));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+}
+
+impl RichSchemaOperation {
+    pub fn new(rich_schema: RichSchema) -> RichSchemaOperation {
+        RichSchemaOperation {
+            _type: rich_schema.rs_type.txn_type().to_string(),
+            id: rich_schema.id,
+            content: rich_schema.content,
+            rs_name: rich_schema.rs_name,
+            rs_version: rich_schema.rs_version,
+            rs_type: rich_schema.rs_type,
+            ver: rich_schema.ver,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetRichSchemaObjectByIdOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+}
+
+impl GetRichSchemaObjectByIdOperation {
+    pub fn new(id: String) -> GetRichSchemaObjectByIdOperation {
+        GetRichSchemaObjectByIdOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_ID.to_string(),
+            id,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRichSchemaObjectByMetadataOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub rs_type: RichSchemaType,
+    pub rs_name: String,
+    pub rs_version: String,
+}
+
+impl GetRichSchemaObjectByMetadataOperation {
+    pub fn new(rs_type: RichSchemaType, rs_name: String, rs_version: String) -> GetRichSchemaObjectByMetadataOperation {
+        GetRichSchemaObjectByMetadataOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_METADATA.to_string(),
+            rs_type,
+            rs_name,
+            rs_version,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum GetRichSchemaObjectByIdReplyResult {
+    GetRichSchemaObjectByIdReplyResultV0(GetReplyResultV0<RichSchema>)
+}
+
+impl ReplyType for GetRichSchemaObjectByIdReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_RICH_SCHEMA_OBJECT_BY_ID
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum GetRichSchemaObjectByMetadataReplyResult {
+    GetRichSchemaObjectByMetadataReplyResultV0(GetReplyResultV0<RichSchema>)
+}
+
+impl ReplyType for GetRichSchemaObjectByMetadataReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_RICH_SCHEMA_OBJECT_BY_METADATA
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "did:sov:2sEEi28LsekEUg6Pbpx3c1";
+
+    fn _rich_schema(content: Value, rs_type: RichSchemaType) -> RichSchema {
+        RichSchema::new(ID.to_string(), content.to_string(), "name".to_string(), "1.0".to_string(), rs_type, "1".to_string())
+    }
+
+    #[test]
+    fn rich_schema_validate_works() {
+        _rich_schema(json!({"@id": ID, "@type": "rdfs:Class"}), RichSchemaType::Schema).validate().unwrap();
+        _rich_schema(json!({"@context": {"name": "http://schema.org/name"}}), RichSchemaType::Context).validate().unwrap();
+    }
+
+    #[test]
+    fn rich_schema_validate_not_works_for_invalid_content() {
+        let mut rich_schema = _rich_schema(json!({"@id": ID}), RichSchemaType::Schema);
+        rich_schema.content = "not json".to_string();
+        assert!(rich_schema.validate().is_err());
+    }
+
+    #[test]
+    fn rich_schema_validate_not_works_for_other_id_in_content() {
+        assert!(_rich_schema(json!({"@id": "did:sov:other"}), RichSchemaType::Mapping).validate().is_err());
+    }
+
+    #[test]
+    fn rich_schema_validate_not_works_for_context_without_context_field() {
+        assert!(_rich_schema(json!({"@id": ID}), RichSchemaType::Context).validate().is_err());
+    }
+
+    #[test]
+    fn rich_schema_operation_works() {
+        let operation = RichSchemaOperation::new(_rich_schema(json!({"@id": ID}), RichSchemaType::Encoding));
+        let operation = serde_json::to_value(&operation).unwrap();
+        assert_eq!(RICH_SCHEMA_ENCODING, operation["type"]);
+        assert_eq!("enc", operation["rsType"]);
+        assert_eq!("name", operation["rsName"]);
+    }
+}
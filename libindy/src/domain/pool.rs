@@ -1,3 +1,6 @@
+use serde::ser::{Serialize, Serializer};
+use serde::de::{Deserializer, Deserialize};
+
 use indy_api_types::validation::Validatable;
 
 pub const POOL_CON_ACTIVE_TO: i64 = 5;
@@ -35,6 +38,62 @@ pub struct PoolOpenConfig {
     pub number_read_nodes: u8,
     #[serde(default = "PoolOpenConfig::default_socks_proxy")]
     pub socks_proxy: String,
+    #[serde(default)]
+    pub transport: PoolTransport,
+    #[serde(default)]
+    pub read_cache_size: usize,
+    #[serde(default)]
+    pub mirror_ledgers: bool,
+}
+
+pub const ZMQ_TRANSPORT: &str = "zmq";
+pub const SIMULATED_TRANSPORT: &str = "simulated";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PoolTransport {
+    Zmq,
+    Simulated,
+    /// Transport registered with `indy_register_pool_transport`
+    Plugged(String),
+}
+
+impl PoolTransport {
+    pub fn name(&self) -> &str {
+        match self {
+            PoolTransport::Zmq => ZMQ_TRANSPORT,
+            PoolTransport::Simulated => SIMULATED_TRANSPORT,
+            PoolTransport::Plugged(name) => name,
+        }
+    }
+}
+
+impl Default for PoolTransport {
+    fn default() -> Self {
+        PoolTransport::Zmq
+    }
+}
+
+impl From<String> for PoolTransport {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            ZMQ_TRANSPORT => PoolTransport::Zmq,
+            SIMULATED_TRANSPORT => PoolTransport::Simulated,
+            _ => PoolTransport::Plugged(name),
+        }
+    }
+}
+
+impl Serialize for PoolTransport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer, {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for PoolTransport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de>, {
+        let name = String::deserialize(deserializer)?;
+        Ok(PoolTransport::from(name))
+    }
 }
 
 impl Validatable for PoolOpenConfig {
@@ -68,6 +127,9 @@ impl Default for PoolOpenConfig {
             preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
             number_read_nodes: PoolOpenConfig::default_number_read_nodes(),
             socks_proxy: PoolOpenConfig::default_socks_proxy(),
+            transport: PoolTransport::default(),
+            read_cache_size: 0,
+            mirror_ledgers: false,
         }
     }
 }
@@ -97,3 +159,65 @@ impl PoolOpenConfig {
 
     fn default_socks_proxy() -> String { String::new() }
 }
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeStatus {
+    /// Node answered to LEDGER_STATUS
+    Reachable,
+    /// Connection was established but node didn't answer in time
+    NoReply,
+    /// CurveZMQ handshake with the node failed
+    HandshakeFailed,
+    /// Connection to the node wasn't established
+    Unreachable,
+    /// Node transaction doesn't contain valid connection info
+    Invalid,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BlsKeyStatus {
+    Valid,
+    Missing,
+    Invalid,
+    InvalidProofOfPossession,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeDiagnostics {
+    pub alias: String,
+    pub address: Option<String>,
+    pub status: NodeStatus,
+    pub latency_ms: Option<u64>,
+    pub merkle_root: Option<String>,
+    pub ledger_size: Option<usize>,
+    pub bls_key: BlsKeyStatus,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolConsensusStatus {
+    /// f + 1 nodes confirmed the local copy of pool ledger
+    Synced,
+    /// f + 1 nodes agreed on the ledger longer than the local copy
+    CatchupNeeded,
+    /// Nodes replies weren't enough to make a decision
+    NotReached,
+    Failed,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolDiagnostics {
+    pub f: usize,
+    pub local_merkle_root: String,
+    pub local_ledger_size: usize,
+    pub consensus: PoolConsensusStatus,
+    pub consensus_merkle_root: Option<String>,
+    pub consensus_ledger_size: Option<usize>,
+    pub error: Option<String>,
+    pub nodes: Vec<NodeDiagnostics>,
+}
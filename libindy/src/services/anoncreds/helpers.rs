@@ -1,6 +1,6 @@
 use indy_api_types::errors::prelude::*;
 
-use crate::domain::anoncreds::credential::AttributeValues;
+use crate::domain::anoncreds::credential::{AttributeValues, CredentialValues as DomainCredentialValues, RawCredentialValues};
 use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, NonRevocedInterval};
 use ursa::cl::{issuer, verifier, CredentialSchema, NonCredentialSchema, MasterSecret, CredentialValues, SubProofRequest};
 
@@ -15,6 +15,10 @@ use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::CredentialRequest;
 use crate::domain::anoncreds::proof_request::ProofRequest;
 
+use ursa::bn::BigNumber;
+use sha2::Sha256;
+use sha2::digest::{FixedOutput, Update};
+
 use std::collections::{HashSet, HashMap};
 
 pub fn attr_common_view(attr: &str) -> String {
@@ -65,6 +69,63 @@ pub fn build_credential_values(credential_values: &HashMap<String, AttributeValu
     Ok(res)
 }
 
+/// Encodes raw attribute value according to the de-facto rule used by Indy based agents:
+/// 32-bit integers are encoded as themselves, other values as decimal SHA-256 hash of their UTF-8 bytes.
+pub fn encode_credential_attribute(raw: &str) -> IndyResult<String> {
+    if let Ok(val) = raw.parse::<i32>() {
+        return Ok(val.to_string());
+    }
+
+    let mut hasher = Sha256::default();
+    hasher.update(raw.as_bytes());
+    let hash = hasher.finalize_fixed();
+
+    let res = BigNumber::from_bytes(hash.as_slice())?.to_dec()?;
+
+    Ok(res)
+}
+
+/// Returns whether encoded attribute value matches the canonical encoding of the raw one.
+pub fn is_canonical_attribute_encoding(raw: &str, encoded: &str) -> IndyResult<bool> {
+    let canonical = encode_credential_attribute(raw)?;
+    let res = BigNumber::from_dec(&canonical)? == BigNumber::from_dec(encoded)?;
+    Ok(res)
+}
+
+pub fn encode_credential_values(cred_values: &RawCredentialValues) -> IndyResult<DomainCredentialValues> {
+    trace!("encode_credential_values >>> cred_values: {:?}", secret!(cred_values));
+
+    let mut res = HashMap::new();
+    for (attr, values) in cred_values.0.iter() {
+        let encoded = match values.encoded {
+            Some(ref encoded) => encoded.clone(),
+            None => encode_credential_attribute(&values.raw)?
+        };
+        res.insert(attr.clone(), AttributeValues { raw: values.raw.clone(), encoded });
+    }
+
+    trace!("encode_credential_values <<<");
+
+    Ok(DomainCredentialValues(res))
+}
+
+pub fn encode_credential_attributes(attributes: &HashMap<String, String>) -> IndyResult<DomainCredentialValues> {
+    trace!("encode_credential_attributes >>> attributes: {:?}", secret!(attributes));
+
+    if attributes.is_empty() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Empty list of attributes has been passed"));
+    }
+
+    let mut res = HashMap::new();
+    for (attr, raw) in attributes.iter() {
+        res.insert(attr.clone(), AttributeValues { raw: raw.clone(), encoded: encode_credential_attribute(raw)? });
+    }
+
+    trace!("encode_credential_attributes <<<");
+
+    Ok(DomainCredentialValues(res))
+}
+
 pub fn build_sub_proof_request(attrs_for_credential: &[AttributeInfo],
                                predicates_for_credential: &[PredicateInfo]) -> IndyResult<SubProofRequest> {
     trace!("build_sub_proof_request >>> attrs_for_credential: {:?}, predicates_for_credential: {:?}", attrs_for_credential, predicates_for_credential);
@@ -187,6 +248,44 @@ mod tests {
         assert_eq!(None, res);
     }
 
+    mod encode_credential_attribute {
+        use super::*;
+
+        #[test]
+        fn encode_credential_attribute_works_for_integer() {
+            assert_eq!("28", encode_credential_attribute("28").unwrap());
+            assert_eq!("-5", encode_credential_attribute("-5").unwrap());
+            assert_eq!("1", encode_credential_attribute("00000001").unwrap());
+        }
+
+        #[test]
+        fn encode_credential_attribute_works_for_string() {
+            assert_eq!("68086943237164982734333428280784300550565381723532936263016368251445461241953",
+                       encode_credential_attribute("101 Wilson Lane").unwrap());
+        }
+
+        #[test]
+        fn encode_credential_attribute_works_for_integer_out_of_range() {
+            let res = encode_credential_attribute("4294967296").unwrap();
+            assert_ne!("4294967296", res);
+        }
+
+        #[test]
+        fn encode_credential_values_works_for_raw_only() {
+            let cred_values: RawCredentialValues = serde_json::from_str(r#"{"age":{"raw":"28"},"sex":{"raw":"male","encoded":"123"}}"#).unwrap();
+            let res = encode_credential_values(&cred_values).unwrap();
+            assert_eq!("28", res.0["age"].encoded);
+            assert_eq!("123", res.0["sex"].encoded);
+        }
+
+        #[test]
+        fn is_canonical_attribute_encoding_works() {
+            assert!(is_canonical_attribute_encoding("28", "28").unwrap());
+            assert!(is_canonical_attribute_encoding("male", "5944657099558967239210949258394887428692050081607692519917050011144233115103").unwrap());
+            assert!(!is_canonical_attribute_encoding("Alex", "1139481716457488690172217916278103335").unwrap());
+        }
+    }
+
     mod to_unqualified {
         use super::*;
 
@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1, CredentialDefinitionId};
-use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo};
+use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo, ProofVerificationDetails, NonCanonicalAttribute};
 use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequestPayload, NonRevocedInterval};
 use crate::domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryId};
@@ -43,7 +43,7 @@ impl Verifier {
                   schemas: &HashMap<SchemaId, SchemaV1>,
                   cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
                   rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
-                  rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
+                  rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<ProofVerificationDetails> {
         trace!("verify >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
                full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
 
@@ -58,7 +58,7 @@ impl Verifier {
                                                        &received_self_attested_attrs,
                                                        &received_predicates)?;
 
-        Verifier::_verify_revealed_attribute_values(&proof_req, &full_proof)?;
+        let non_canonical_attrs = Verifier::_verify_revealed_attribute_values(&proof_req, &full_proof)?;
 
         Verifier::_verify_requested_restrictions(&proof_req,
                                                  &full_proof.requested_proof,
@@ -124,9 +124,11 @@ impl Verifier {
 
         let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
 
-        trace!("verify <<< valid: {:?}", valid);
+        let details = ProofVerificationDetails { valid, non_canonical_attrs };
 
-        Ok(valid)
+        trace!("verify <<< details: {:?}", details);
+
+        Ok(details)
     }
 
     pub fn generate_nonce(&self) -> IndyResult<Nonce> {
@@ -323,7 +325,9 @@ impl Verifier {
     }
 
     fn _verify_revealed_attribute_values(proof_req: &ProofRequestPayload,
-                                         proof: &Proof) -> IndyResult<()> {
+                                         proof: &Proof) -> IndyResult<Vec<NonCanonicalAttribute>> {
+        let mut non_canonical_attrs = Vec::new();
+
         for (attr_referent, attr_info) in proof.requested_proof.revealed_attrs.iter() {
 
             let attr_name = proof_req.requested_attributes.get(attr_referent)
@@ -332,6 +336,7 @@ impl Verifier {
                 .name.as_ref()
                 .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
             Verifier::_verify_revealed_attribute_value(attr_name.as_str(), proof, &attr_info)?;
+            non_canonical_attrs.extend(Verifier::_check_revealed_attribute_encoding(attr_referent, attr_name, attr_info)?);
         }
 
         for (attr_referent, attr_infos) in proof.requested_proof.revealed_attr_groups.iter() {
@@ -347,14 +352,16 @@ impl Verifier {
             for attr_name in attr_names {
                 let attr_info = &attr_infos.values.get(attr_name)
                     .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))?;
-                Verifier::_verify_revealed_attribute_value(attr_name, proof, &RevealedAttributeInfo {
+                let attr_info = RevealedAttributeInfo {
                     sub_proof_index: attr_infos.sub_proof_index,
                     raw: attr_info.raw.clone(),
                     encoded: attr_info.encoded.clone()
-                })?;
+                };
+                Verifier::_verify_revealed_attribute_value(attr_name, proof, &attr_info)?;
+                non_canonical_attrs.extend(Verifier::_check_revealed_attribute_encoding(attr_referent, attr_name, &attr_info)?);
             }
         }
-        Ok(())
+        Ok(non_canonical_attrs)
     }
 
     fn _verify_revealed_attribute_value(attr_name: &str,
@@ -380,6 +387,23 @@ impl Verifier {
         Ok(())
     }
 
+    /// Values issued with non-canonical encoding are still accepted as there are credentials
+    /// issued by agents using their own encodings, so they are only reported to the verifier.
+    fn _check_revealed_attribute_encoding(attr_referent: &str,
+                                          attr_name: &str,
+                                          attr_info: &RevealedAttributeInfo) -> IndyResult<Option<NonCanonicalAttribute>> {
+        if is_canonical_attribute_encoding(&attr_info.raw, &attr_info.encoded)? {
+            return Ok(None);
+        }
+
+        Ok(Some(NonCanonicalAttribute {
+            referent: attr_referent.to_string(),
+            name: attr_name.to_string(),
+            raw: attr_info.raw.clone(),
+            encoded: attr_info.encoded.clone(),
+        }))
+    }
+
     fn _verify_requested_restrictions(proof_req: &ProofRequestPayload,
                                       requested_proof: &RequestedProof,
                                       received_revealed_attrs: &HashMap<String, Identifier>,
@@ -969,4 +993,36 @@ mod tests {
         Verifier::_validate_timestamp(&_received(), "referent_2", &None, &Some(_interval())).unwrap_err();
         Verifier::_validate_timestamp(&_received(), "referent_3", &None, &Some(_interval())).unwrap_err();
     }
+
+    fn _revealed_attr(raw: &str, encoded: &str) -> RevealedAttributeInfo {
+        RevealedAttributeInfo { sub_proof_index: 0, raw: raw.to_string(), encoded: encoded.to_string() }
+    }
+
+    #[test]
+    fn check_revealed_attribute_encoding_works() {
+        assert_eq!(None, Verifier::_check_revealed_attribute_encoding("attr1_referent", "age", &_revealed_attr("28", "28")).unwrap());
+        assert_eq!(None, Verifier::_check_revealed_attribute_encoding("attr2_referent", "sex", &_revealed_attr("male", "5944657099558967239210949258394887428692050081607692519917050011144233115103")).unwrap());
+    }
+
+    #[test]
+    fn check_revealed_attribute_encoding_works_for_custom_encoding() {
+        let non_canonical_attr = Verifier::_check_revealed_attribute_encoding("attr1_referent", "name", &_revealed_attr("Alex", "1139481716457488690172217916278103335")).unwrap();
+        assert_eq!(Some(NonCanonicalAttribute {
+            referent: "attr1_referent".to_string(),
+            name: "name".to_string(),
+            raw: "Alex".to_string(),
+            encoded: "1139481716457488690172217916278103335".to_string(),
+        }), non_canonical_attr);
+    }
+
+    #[test]
+    fn check_revealed_attribute_encoding_works_for_changed_integer() {
+        let non_canonical_attr = Verifier::_check_revealed_attribute_encoding("attr1_referent", "age", &_revealed_attr("28", "18")).unwrap();
+        assert_eq!(Some(NonCanonicalAttribute {
+            referent: "attr1_referent".to_string(),
+            name: "age".to_string(),
+            raw: "28".to_string(),
+            encoded: "18".to_string(),
+        }), non_canonical_attr);
+    }
 }
@@ -0,0 +1,193 @@
+use indy_api_types::errors::prelude::*;
+use openssl::bn::{BigNum, BigNumContext};
+use openssl::ec::{EcGroup, EcKey, EcPoint, PointConversionForm};
+use openssl::ecdsa::EcdsaSig;
+use openssl::nid::Nid;
+use openssl::sha::sha256;
+
+use super::SignatureType;
+
+// Size of each of the r and s components of the signature
+const SCALAR_BYTES: usize = 32;
+
+pub struct EcdsaSignatureType {
+    curve: Nid,
+}
+
+impl EcdsaSignatureType {
+    pub fn secp256k1() -> EcdsaSignatureType {
+        EcdsaSignatureType { curve: Nid::SECP256K1 }
+    }
+
+    pub fn p256() -> EcdsaSignatureType {
+        EcdsaSignatureType { curve: Nid::X9_62_PRIME256V1 }
+    }
+
+    fn group(&self) -> IndyResult<EcGroup> {
+        Ok(EcGroup::from_curve_name(self.curve)?)
+    }
+
+    fn public_key(&self, group: &EcGroup, vk: &[u8]) -> IndyResult<EcKey<openssl::pkey::Public>> {
+        let mut ctx = BigNumContext::new()?;
+
+        let point = EcPoint::from_bytes(group, vk, &mut ctx)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid ECDSA verkey")?;
+
+        EcKey::from_public_key(group, &point)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid ECDSA verkey")
+    }
+
+    fn _to_padded_bytes(num: &BigNum) -> Vec<u8> {
+        let bytes = num.to_vec();
+        let mut res = vec![0u8; SCALAR_BYTES.saturating_sub(bytes.len())];
+        res.extend_from_slice(&bytes);
+        res
+    }
+}
+
+impl SignatureType for EcdsaSignatureType {
+    fn create_key(&self, seed: Option<&[u8]>) -> IndyResult<(Vec<u8>, Vec<u8>)> {
+        let group = self.group()?;
+        let mut ctx = BigNumContext::new()?;
+
+        let sk = match seed {
+            Some(seed) => {
+                let mut order = BigNum::new()?;
+                group.order(&mut order, &mut ctx)?;
+
+                // Seed is hashed and reduced to get a valid scalar for the curve
+                let digest = BigNum::from_slice(&sha256(seed))?;
+                let mut sk = BigNum::new()?;
+                sk.nnmod(&digest, &order, &mut ctx)?;
+                sk
+            }
+            None => EcKey::generate(&group)?.private_key().to_owned()?
+        };
+
+        let mut pk = EcPoint::new(&group)?;
+        pk.mul_generator(&group, &sk, &ctx)?;
+
+        let vk = pk.to_bytes(&group, PointConversionForm::COMPRESSED, &mut ctx)?;
+        let sk = EcdsaSignatureType::_to_padded_bytes(&sk);
+
+        Ok((vk, sk))
+    }
+
+    fn validate_key(&self, vk: &[u8]) -> IndyResult<()> {
+        let group = self.group()?;
+        self.public_key(&group, vk)?;
+        Ok(())
+    }
+
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+        let group = self.group()?;
+        let ctx = BigNumContext::new()?;
+
+        let sk = BigNum::from_slice(sk)?;
+        let mut pk = EcPoint::new(&group)?;
+        pk.mul_generator(&group, &sk, &ctx)?;
+
+        let key = EcKey::from_private_components(&group, &sk, &pk)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid ECDSA signkey")?;
+
+        let signature = EcdsaSig::sign(&sha256(doc), &key)?;
+
+        // Signature is encoded as fixed size r || s the same way as in JOSE
+        let mut res = EcdsaSignatureType::_to_padded_bytes(&signature.r().to_owned()?);
+        res.extend(EcdsaSignatureType::_to_padded_bytes(&signature.s().to_owned()?));
+
+        Ok(res)
+    }
+
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> IndyResult<bool> {
+        if signature.len() != SCALAR_BYTES * 2 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Invalid ECDSA signature length: {}, expected: {}", signature.len(), SCALAR_BYTES * 2)));
+        }
+
+        let group = self.group()?;
+        let key = self.public_key(&group, vk)?;
+
+        let r = BigNum::from_slice(&signature[..SCALAR_BYTES])?;
+        let s = BigNum::from_slice(&signature[SCALAR_BYTES..])?;
+        let signature = EcdsaSig::from_private_components(r, s)?;
+
+        Ok(signature.verify(&sha256(doc), &key)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn types() -> Vec<EcdsaSignatureType> {
+        vec![EcdsaSignatureType::secp256k1(), EcdsaSignatureType::p256()]
+    }
+
+    #[test]
+    fn create_key_works() {
+        for signature_type in types() {
+            let (vk, sk) = signature_type.create_key(None).unwrap();
+            assert_eq!(33, vk.len());
+            assert_eq!(32, sk.len());
+        }
+    }
+
+    #[test]
+    fn create_key_works_for_seed() {
+        let seed = b"00000000000000000000000000000My1";
+
+        for signature_type in types() {
+            let (vk1, sk1) = signature_type.create_key(Some(seed)).unwrap();
+            let (vk2, sk2) = signature_type.create_key(Some(seed)).unwrap();
+            assert_eq!(vk1, vk2);
+            assert_eq!(sk1, sk2);
+        }
+    }
+
+    #[test]
+    fn sign_verify_works() {
+        let doc = b"Hello World";
+
+        for signature_type in types() {
+            let (vk, sk) = signature_type.create_key(None).unwrap();
+            let signature = signature_type.sign(&sk, doc).unwrap();
+            assert_eq!(64, signature.len());
+            assert!(signature_type.verify(&vk, doc, &signature).unwrap());
+            assert!(!signature_type.verify(&vk, b"Other message", &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn verify_works_for_other_key() {
+        let doc = b"Hello World";
+
+        for signature_type in types() {
+            let (_, sk) = signature_type.create_key(None).unwrap();
+            let (other_vk, _) = signature_type.create_key(None).unwrap();
+            let signature = signature_type.sign(&sk, doc).unwrap();
+            assert!(!signature_type.verify(&other_vk, doc, &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn verify_fails_for_invalid_signature_length() {
+        let signature_type = EcdsaSignatureType::p256();
+        let (vk, _) = signature_type.create_key(None).unwrap();
+
+        let res = signature_type.verify(&vk, b"Hello World", &[1, 2, 3]);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn validate_key_works() {
+        let secp256k1 = EcdsaSignatureType::secp256k1();
+        let p256 = EcdsaSignatureType::p256();
+
+        let (vk, _) = secp256k1.create_key(None).unwrap();
+        secp256k1.validate_key(&vk).unwrap();
+
+        assert_kind!(IndyErrorKind::InvalidStructure, secp256k1.validate_key(&[2u8; 10]));
+        assert_kind!(IndyErrorKind::InvalidStructure, p256.validate_key(&[5u8; 33]));
+    }
+}
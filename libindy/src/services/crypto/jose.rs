@@ -0,0 +1,198 @@
+use indy_api_types::errors::prelude::*;
+use openssl::aes::{AesKey, unwrap_key, wrap_key};
+use openssl::hash::MessageDigest;
+use openssl::memcmp;
+use openssl::pkey::PKey;
+use openssl::sha::Sha256;
+use openssl::sign::Signer;
+use openssl::symm::{Cipher, decrypt, encrypt};
+
+pub const A256CBC_HS512_KEY_BYTES: usize = 64;
+pub const A256CBC_HS512_IV_BYTES: usize = 16;
+pub const A256KW_KEY_BYTES: usize = 32;
+
+const A256CBC_HS512_TAG_BYTES: usize = 32;
+const KEY_WRAP_OVERHEAD_BYTES: usize = 8;
+
+/// Concat KDF as defined in RFC 7518 section 4.6.2. The tag is appended to SuppPubInfo
+/// for ECDH-1PU key wrapping modes as defined in draft-madden-jose-ecdh-1pu-04.
+pub fn concat_kdf(z: &[u8], alg: &str, apu: &[u8], apv: &[u8], key_len: usize, tag: Option<&[u8]>) -> IndyResult<Vec<u8>> {
+    if key_len > 32 {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported length of derived key: {}", key_len)));
+    }
+
+    let mut hasher = Sha256::new();
+
+    // Single round is enough for keys up to 256 bits
+    hasher.update(&1u32.to_be_bytes());
+    hasher.update(z);
+    _update_with_length_prefixed(&mut hasher, alg.as_bytes());
+    _update_with_length_prefixed(&mut hasher, apu);
+    _update_with_length_prefixed(&mut hasher, apv);
+    hasher.update(&((key_len * 8) as u32).to_be_bytes());
+
+    if let Some(tag) = tag {
+        _update_with_length_prefixed(&mut hasher, tag);
+    }
+
+    Ok(hasher.finish()[..key_len].to_vec())
+}
+
+fn _update_with_length_prefixed(hasher: &mut Sha256, data: &[u8]) {
+    hasher.update(&(data.len() as u32).to_be_bytes());
+    hasher.update(data);
+}
+
+/// AES Key Wrap as defined in RFC 3394
+pub fn aes_key_wrap(kek: &[u8], cek: &[u8]) -> IndyResult<Vec<u8>> {
+    let kek = AesKey::new_encrypt(kek)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Invalid key encryption key"))?;
+
+    let mut wrapped = vec![0u8; cek.len() + KEY_WRAP_OVERHEAD_BYTES];
+
+    wrap_key(&kek, None, &mut wrapped, cek)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Unable to wrap content encryption key"))?;
+
+    Ok(wrapped)
+}
+
+pub fn aes_key_unwrap(kek: &[u8], wrapped: &[u8]) -> IndyResult<Vec<u8>> {
+    if wrapped.len() < KEY_WRAP_OVERHEAD_BYTES * 2 {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Wrapped content encryption key is too short"));
+    }
+
+    let kek = AesKey::new_decrypt(kek)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Invalid key encryption key"))?;
+
+    let mut cek = vec![0u8; wrapped.len() - KEY_WRAP_OVERHEAD_BYTES];
+
+    unwrap_key(&kek, None, &mut cek, wrapped)
+        .map_err(|_| err_msg(IndyErrorKind::UnknownCrypto, "Unable to unwrap content encryption key"))?;
+
+    Ok(cek)
+}
+
+/// AES_256_CBC_HMAC_SHA_512 authenticated encryption as defined in RFC 7518 section 5.2.5
+pub fn a256cbc_hs512_encrypt(cek: &[u8], iv: &[u8], plaintext: &[u8], aad: &[u8]) -> IndyResult<(Vec<u8>, Vec<u8>)> {
+    let (mac_key, enc_key) = _split_a256cbc_hs512_key(cek)?;
+
+    let ciphertext = encrypt(Cipher::aes_256_cbc(), enc_key, Some(iv), plaintext)?;
+    let tag = _a256cbc_hs512_tag(mac_key, iv, &ciphertext, aad)?;
+
+    Ok((ciphertext, tag))
+}
+
+pub fn a256cbc_hs512_decrypt(cek: &[u8], iv: &[u8], ciphertext: &[u8], aad: &[u8], tag: &[u8]) -> IndyResult<Vec<u8>> {
+    let (mac_key, enc_key) = _split_a256cbc_hs512_key(cek)?;
+
+    let expected_tag = _a256cbc_hs512_tag(mac_key, iv, ciphertext, aad)?;
+
+    if tag.len() != expected_tag.len() || !memcmp::eq(tag, &expected_tag) {
+        return Err(err_msg(IndyErrorKind::UnknownCrypto, "Authentication tag of encrypted message is invalid"));
+    }
+
+    decrypt(Cipher::aes_256_cbc(), enc_key, Some(iv), ciphertext)
+        .to_indy(IndyErrorKind::UnknownCrypto, "Unable to decrypt message")
+}
+
+fn _split_a256cbc_hs512_key(cek: &[u8]) -> IndyResult<(&[u8], &[u8])> {
+    if cek.len() != A256CBC_HS512_KEY_BYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid length of content encryption key: {}", cek.len())));
+    }
+
+    Ok(cek.split_at(A256CBC_HS512_KEY_BYTES / 2))
+}
+
+fn _a256cbc_hs512_tag(mac_key: &[u8], iv: &[u8], ciphertext: &[u8], aad: &[u8]) -> IndyResult<Vec<u8>> {
+    let mac_key = PKey::hmac(mac_key)?;
+    let mut signer = Signer::new(MessageDigest::sha512(), &mac_key)?;
+
+    signer.update(aad)?;
+    signer.update(iv)?;
+    signer.update(ciphertext)?;
+    signer.update(&((aad.len() * 8) as u64).to_be_bytes())?;
+
+    let mut tag = signer.sign_to_vec()?;
+    tag.truncate(A256CBC_HS512_TAG_BYTES);
+
+    Ok(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concat_kdf_works() {
+        // RFC 7518 Appendix C
+        let z = [158, 86, 217, 29, 129, 113, 53, 211, 114, 131, 66, 131, 191, 132, 38, 156,
+            251, 49, 110, 163, 218, 128, 106, 72, 246, 218, 167, 121, 140, 254, 144, 196];
+
+        let key = concat_kdf(&z, "A128GCM", b"Alice", b"Bob", 16, None).unwrap();
+
+        assert_eq!(vec![86, 170, 141, 234, 248, 35, 109, 32, 92, 34, 40, 205, 113, 167, 16, 26], key);
+    }
+
+    #[test]
+    fn concat_kdf_works_for_tag() {
+        let z = [1u8; 64];
+
+        let key = concat_kdf(&z, "ECDH-1PU+A256KW", b"Alice", b"Bob", A256KW_KEY_BYTES, None).unwrap();
+        let key_with_tag = concat_kdf(&z, "ECDH-1PU+A256KW", b"Alice", b"Bob", A256KW_KEY_BYTES, Some(&[2u8; 32])).unwrap();
+
+        assert_eq!(A256KW_KEY_BYTES, key_with_tag.len());
+        assert_ne!(key, key_with_tag);
+    }
+
+    #[test]
+    fn aes_key_wrap_works() {
+        // RFC 3394 section 4.6
+        let kek: Vec<u8> = (0u8..32).collect();
+        let cek = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+
+        let wrapped = aes_key_wrap(&kek, &cek).unwrap();
+
+        assert_eq!(vec![0x28, 0xC9, 0xF4, 0x04, 0xC4, 0xB8, 0x10, 0xF4, 0xCB, 0xCC, 0xB3, 0x5C, 0xFB, 0x87, 0xF8, 0x26,
+                        0x3F, 0x57, 0x86, 0xE2, 0xD8, 0x0E, 0xD3, 0x26, 0xCB, 0xC7, 0xF0, 0xE7, 0x1A, 0x99, 0xF4, 0x3B,
+                        0xFB, 0x98, 0x8B, 0x9B, 0x7A, 0x02, 0xDD, 0x21], wrapped);
+
+        assert_eq!(cek.to_vec(), aes_key_unwrap(&kek, &wrapped).unwrap());
+    }
+
+    #[test]
+    fn aes_key_unwrap_not_works_for_other_kek() {
+        let wrapped = aes_key_wrap(&[1u8; 32], &[3u8; 64]).unwrap();
+        assert_kind!(IndyErrorKind::UnknownCrypto, aes_key_unwrap(&[2u8; 32], &wrapped));
+    }
+
+    #[test]
+    fn a256cbc_hs512_encrypt_decrypt_works() {
+        let cek = [7u8; A256CBC_HS512_KEY_BYTES];
+        let iv = [8u8; A256CBC_HS512_IV_BYTES];
+        let plaintext = b"Hello World";
+
+        let (ciphertext, tag) = a256cbc_hs512_encrypt(&cek, &iv, plaintext, b"aad").unwrap();
+        assert_eq!(32, tag.len());
+
+        let decrypted = a256cbc_hs512_decrypt(&cek, &iv, &ciphertext, b"aad", &tag).unwrap();
+        assert_eq!(plaintext.to_vec(), decrypted);
+    }
+
+    #[test]
+    fn a256cbc_hs512_decrypt_not_works_for_other_aad() {
+        let cek = [7u8; A256CBC_HS512_KEY_BYTES];
+        let iv = [8u8; A256CBC_HS512_IV_BYTES];
+
+        let (ciphertext, tag) = a256cbc_hs512_encrypt(&cek, &iv, b"Hello World", b"aad").unwrap();
+
+        let res = a256cbc_hs512_decrypt(&cek, &iv, &ciphertext, b"other aad", &tag);
+        assert_kind!(IndyErrorKind::UnknownCrypto, res);
+    }
+
+    #[test]
+    fn a256cbc_hs512_encrypt_not_works_for_invalid_key_len() {
+        let res = a256cbc_hs512_encrypt(&[7u8; 32], &[8u8; A256CBC_HS512_IV_BYTES], b"Hello World", b"aad");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+}
@@ -1,5 +1,6 @@
 extern crate hex;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::str;
 
@@ -14,13 +15,20 @@ use indy_utils::crypto::chacha20poly1305_ietf::gen_nonce_and_encrypt_detached;
 use indy_utils::crypto::ed25519_sign;
 use crate::utils::crypto::verkey_builder::{build_full_verkey, split_verkey, verkey_get_cryptoname};
 
+use self::ecdsa::EcdsaSignatureType;
 use self::ed25519::ED25519CryptoType;
+use self::plugged::PluggedKeyBackend;
 use self::hex::FromHex;
 use rust_base58::{FromBase58, ToBase58};
 
+mod ecdsa;
 mod ed25519;
+pub mod jose;
+pub mod plugged;
 
 pub const DEFAULT_CRYPTO_TYPE: &str = "ed25519";
+pub const SECP256K1_CRYPTO_TYPE: &str = "secp256k1";
+pub const P256_CRYPTO_TYPE: &str = "p256";
 
 //TODO fix this crypto trait so it matches the functions below
 //TODO create a second crypto trait for additional functions
@@ -36,8 +44,18 @@ trait CryptoType {
     fn crypto_box_seal_open(&self, vk: &ed25519_sign::PublicKey, sk: &ed25519_sign::SecretKey, doc: &[u8]) -> IndyResult<Vec<u8>>;
 }
 
+// Crypto types that can be used only for signing and verification
+trait SignatureType {
+    fn create_key(&self, seed: Option<&[u8]>) -> IndyResult<(Vec<u8>, Vec<u8>)>;
+    fn validate_key(&self, vk: &[u8]) -> IndyResult<()>;
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>>;
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> IndyResult<bool>;
+}
+
 pub struct CryptoService {
-    crypto_types: HashMap<&'static str, Box<dyn CryptoType>>
+    crypto_types: HashMap<&'static str, Box<dyn CryptoType>>,
+    signature_types: HashMap<&'static str, Box<dyn SignatureType>>,
+    key_backends: RefCell<HashMap<String, PluggedKeyBackend>>
 }
 
 impl CryptoService {
@@ -45,11 +63,37 @@ impl CryptoService {
         let mut crypto_types: HashMap<&str, Box<dyn CryptoType>> = HashMap::new();
         crypto_types.insert(DEFAULT_CRYPTO_TYPE, Box::new(ED25519CryptoType::new()));
 
+        let mut signature_types: HashMap<&str, Box<dyn SignatureType>> = HashMap::new();
+        signature_types.insert(SECP256K1_CRYPTO_TYPE, Box::new(EcdsaSignatureType::secp256k1()));
+        signature_types.insert(P256_CRYPTO_TYPE, Box::new(EcdsaSignatureType::p256()));
+
         CryptoService {
-            crypto_types
+            crypto_types,
+            signature_types,
+            key_backends: RefCell::new(HashMap::new())
         }
     }
 
+    pub fn register_key_backend(&self, crypto_type_name: &str, key_backend: PluggedKeyBackend) -> IndyResult<()> {
+        trace!("register_key_backend >>> crypto_type_name: {:?}", crypto_type_name);
+
+        if self.crypto_types.contains_key(crypto_type_name) || self.signature_types.contains_key(crypto_type_name) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Built-in crypto type can't be overridden: {}", crypto_type_name)));
+        }
+
+        let mut key_backends = self.key_backends.borrow_mut();
+
+        if key_backends.contains_key(crypto_type_name) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Key backend already registered for crypto type: {}", crypto_type_name)));
+        }
+
+        key_backends.insert(crypto_type_name.to_string(), key_backend);
+
+        trace!("register_key_backend <<<");
+
+        Ok(())
+    }
+
     pub fn create_key(&self, key_info: &KeyInfo) -> IndyResult<Key> {
         trace!("create_key >>> key_info: {:?}", secret!(key_info));
 
@@ -58,6 +102,29 @@ impl CryptoService {
             .map(String::as_str)
             .unwrap_or(DEFAULT_CRYPTO_TYPE);
 
+        if let Some(key_backend) = self.key_backends.borrow().get(crypto_type_name) {
+            let vk = key_backend.create_key(key_info)?;
+            let _ = vk.from_base58()?;
+
+            // Secret part of the key never leaves the backend
+            let key = Key::new(format!("{}:{}", vk, crypto_type_name), String::new());
+
+            trace!("create_key <<< key: {:?}", key);
+
+            return Ok(key);
+        }
+
+        if let Some(signature_type) = self.signature_types.get(crypto_type_name) {
+            let seed = self.convert_seed(key_info.seed.as_ref().map(String::as_ref))?;
+            let (vk, sk) = signature_type.create_key(seed.as_ref().map(|seed| &seed[..]))?;
+
+            let key = Key::new(format!("{}:{}", vk.to_base58(), crypto_type_name), sk.to_base58());
+
+            trace!("create_key <<< key: {:?}", key);
+
+            return Ok(key);
+        }
+
         if !self.crypto_types.contains_key(crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("KeyInfo contains unknown crypto: {}", crypto_type_name)));
         }
@@ -88,6 +155,32 @@ impl CryptoService {
             .map(String::as_str)
             .unwrap_or(DEFAULT_CRYPTO_TYPE);
 
+        if let Some(key_backend) = self.key_backends.borrow().get(crypto_type_name) {
+            let key_info = KeyInfo { seed: my_did_info.seed.clone(), crypto_type: Some(crypto_type_name.to_string()) };
+            let vk = key_backend.create_key(&key_info)?;
+            let did = CryptoService::_build_did(my_did_info, &vk.from_base58()?)?;
+
+            let vk = format!("{}:{}", vk, crypto_type_name);
+            let did = (Did::new(did, vk.clone()), Key::new(vk, String::new()));
+
+            trace!("create_my_did <<< did: {:?}", did);
+
+            return Ok(did);
+        }
+
+        if let Some(signature_type) = self.signature_types.get(crypto_type_name) {
+            let seed = self.convert_seed(my_did_info.seed.as_ref().map(String::as_ref))?;
+            let (vk, sk) = signature_type.create_key(seed.as_ref().map(|seed| &seed[..]))?;
+            let did = CryptoService::_build_did(my_did_info, &vk)?;
+
+            let vk = format!("{}:{}", vk.to_base58(), crypto_type_name);
+            let did = (Did::new(did, vk.clone()), Key::new(vk, sk.to_base58()));
+
+            trace!("create_my_did <<< did: {:?}", did);
+
+            return Ok(did);
+        }
+
         if !self.crypto_types.contains_key(crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("MyDidInfo contains unknown crypto: {}", crypto_type_name)));
         }
@@ -96,13 +189,7 @@ impl CryptoService {
 
         let seed = self.convert_seed(my_did_info.seed.as_ref().map(String::as_ref))?;
         let (vk, sk) = crypto_type.create_key(seed.as_ref())?;
-        let did = match my_did_info.did {
-            Some(ref did) => did.clone(),
-            _ if my_did_info.cid == Some(true) =>
-                DidValue::new(&vk[..].to_vec().to_base58(), my_did_info.method_name.as_ref().map(|method| method.0.as_str())),
-            _ =>
-                DidValue::new(&vk[0..16].to_vec().to_base58(), my_did_info.method_name.as_ref().map(|method| method.0.as_str()))
-        };
+        let did = CryptoService::_build_did(my_did_info, &vk[..])?;
 
         let mut vk = vk[..].to_base58();
         let sk = sk[..].to_base58();
@@ -119,6 +206,22 @@ impl CryptoService {
         Ok(did)
     }
 
+    fn _build_did(my_did_info: &MyDidInfo, vk: &[u8]) -> IndyResult<DidValue> {
+        if vk.len() < 16 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Verkey is too short to build DID from it"));
+        }
+
+        let did = match my_did_info.did {
+            Some(ref did) => did.clone(),
+            _ if my_did_info.cid == Some(true) =>
+                DidValue::new(&vk.to_base58(), my_did_info.method_name.as_ref().map(|method| method.0.as_str())),
+            _ =>
+                DidValue::new(&vk[0..16].to_base58(), my_did_info.method_name.as_ref().map(|method| method.0.as_str()))
+        };
+
+        Ok(did)
+    }
+
     pub fn create_their_did(&self, their_did_info: &TheirDidInfo) -> IndyResult<TheirDid> {
         trace!("create_their_did >>> their_did_info: {:?}", their_did_info);
 
@@ -142,6 +245,22 @@ impl CryptoService {
 
         let crypto_type_name = verkey_get_cryptoname(&my_key.verkey);
 
+        if let Some(key_backend) = self.key_backends.borrow().get(crypto_type_name) {
+            let signature = key_backend.sign(&my_key.verkey, doc)?;
+
+            trace!("sign <<< signature: {:?}", signature);
+
+            return Ok(signature);
+        }
+
+        if let Some(signature_type) = self.signature_types.get(crypto_type_name) {
+            let signature = signature_type.sign(&my_key.signkey.from_base58()?, doc)?;
+
+            trace!("sign <<< signature: {:?}", signature);
+
+            return Ok(signature);
+        }
+
         if !self.crypto_types.contains_key(crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Trying to sign message with unknown crypto: {}", crypto_type_name)));
         }
@@ -159,8 +278,24 @@ impl CryptoService {
     pub fn verify(&self, their_vk: &str, msg: &[u8], signature: &[u8]) -> IndyResult<bool> {
         trace!("verify >>> their_vk: {:?}, msg: {:?}, signature: {:?}", their_vk, msg, signature);
 
+        if let Some(key_backend) = self.key_backends.borrow().get(verkey_get_cryptoname(their_vk)) {
+            let valid = key_backend.verify(their_vk, msg, signature)?;
+
+            trace!("verify <<< valid: {:?}", valid);
+
+            return Ok(valid);
+        }
+
         let (their_vk, crypto_type_name) = split_verkey(their_vk);
 
+        if let Some(signature_type) = self.signature_types.get(crypto_type_name) {
+            let valid = signature_type.verify(&their_vk.from_base58()?, msg, signature)?;
+
+            trace!("verify <<< valid: {:?}", valid);
+
+            return Ok(valid);
+        }
+
         if !self.crypto_types.contains_key(crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Trying to verify message with unknown crypto: {}", crypto_type_name)));
         }
@@ -198,6 +333,19 @@ impl CryptoService {
 
         let crypto_type_name = verkey_get_cryptoname(&my_key.verkey);
 
+        self._check_not_signature_type(crypto_type_name, "crypto_box")?;
+        self._check_not_signature_type(verkey_get_cryptoname(their_vk), "crypto_box")?;
+
+        if let Some(key_backend) = self.key_backends.borrow().get(crypto_type_name) {
+            // Backend is responsible for checking compatibility with their key
+            let nonce = ed25519_box::gen_nonce()[..].to_vec();
+            let encrypted_doc = key_backend.crypto_box(&my_key.verkey, their_vk, doc, &nonce)?;
+
+            trace!("crypto_box <<< encrypted_doc: {:?}, nonce: {:?}", encrypted_doc, nonce);
+
+            return Ok((encrypted_doc, nonce));
+        }
+
         let (their_vk, their_crypto_type_name) = split_verkey(their_vk);
 
         if !self.crypto_types.contains_key(&crypto_type_name) {
@@ -231,8 +379,13 @@ impl CryptoService {
 
         let crypto_type_name = verkey_get_cryptoname(&my_key.verkey);
 
+        self._check_not_key_backend(crypto_type_name, "crypto_box_open")?;
+        self._check_not_signature_type(crypto_type_name, "crypto_box_open")?;
+
         let (their_vk, their_crypto_type_name) = split_verkey(their_vk);
 
+        self._check_not_signature_type(their_crypto_type_name, "crypto_box_open")?;
+
         if !self.crypto_types.contains_key(&crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto,
                                format!("Trying to crypto_box_open message with unknown crypto: {}", crypto_type_name)));
@@ -264,6 +417,8 @@ impl CryptoService {
 
         let (their_vk, crypto_type_name) = split_verkey(their_vk);
 
+        self._check_not_signature_type(crypto_type_name, "crypto_box_seal")?;
+
         if !self.crypto_types.contains_key(&crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Trying to encrypt sealed message with unknown crypto: {}", crypto_type_name)));
         }
@@ -284,6 +439,9 @@ impl CryptoService {
 
         let (my_vk, crypto_type_name) = split_verkey(&my_key.verkey);
 
+        self._check_not_key_backend(crypto_type_name, "crypto_box_seal_open")?;
+        self._check_not_signature_type(crypto_type_name, "crypto_box_seal_open")?;
+
         if !self.crypto_types.contains_key(&crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto,
                                format!("Trying to crypto_box_open sealed message with unknown crypto: {}", crypto_type_name)));
@@ -301,6 +459,61 @@ impl CryptoService {
         Ok(decrypted_doc)
     }
 
+    fn _check_not_key_backend(&self, crypto_type_name: &str, operation: &str) -> IndyResult<()> {
+        if self.key_backends.borrow().contains_key(crypto_type_name) {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Key backend for crypto {} doesn't support {} operation", crypto_type_name, operation)));
+        }
+
+        Ok(())
+    }
+
+    fn _check_not_signature_type(&self, crypto_type_name: &str, operation: &str) -> IndyResult<()> {
+        if self.signature_types.contains_key(crypto_type_name) {
+            // TODO: Support encryption for these types with ECDH-ES key agreement
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Crypto {} supports only signing and verification, {} operation isn't supported", crypto_type_name, operation)));
+        }
+
+        Ok(())
+    }
+
+    pub fn convert_to_x25519_public_key(&self, vk: &str) -> IndyResult<ed25519_box::PublicKey> {
+        trace!("convert_to_x25519_public_key >>> vk: {:?}", vk);
+
+        let (vk, crypto_type_name) = split_verkey(vk);
+
+        if crypto_type_name != DEFAULT_CRYPTO_TYPE {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Trying to use key with crypto {} for X25519 key agreement", crypto_type_name)));
+        }
+
+        let vk = ed25519_sign::PublicKey::from_slice(vk.from_base58()?.as_slice())?;
+        let res = ed25519_sign::vk_to_curve25519(&vk)?;
+
+        trace!("convert_to_x25519_public_key <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn convert_to_x25519_secret_key(&self, my_key: &Key) -> IndyResult<ed25519_box::SecretKey> {
+        trace!("convert_to_x25519_secret_key >>> my_key: {:?}", my_key);
+
+        let crypto_type_name = verkey_get_cryptoname(&my_key.verkey);
+
+        if crypto_type_name != DEFAULT_CRYPTO_TYPE {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Trying to use key with crypto {} for X25519 key agreement", crypto_type_name)));
+        }
+
+        let sk = ed25519_sign::SecretKey::from_slice(my_key.signkey.as_str().from_base58()?.as_slice())?;
+        let res = ed25519_sign::sk_to_curve25519(&sk)?;
+
+        trace!("convert_to_x25519_secret_key <<<");
+
+        Ok(res)
+    }
+
     pub fn convert_seed(&self, seed: Option<&str>) -> IndyResult<Option<ed25519_sign::Seed>> {
         trace!("convert_seed >>> seed: {:?}", secret!(seed));
 
@@ -347,6 +560,22 @@ impl CryptoService {
 
         let (vk, crypto_type_name) = split_verkey(vk);
 
+        if self.key_backends.borrow().contains_key(crypto_type_name) {
+            let _ = vk.from_base58()?;
+
+            trace!("validate_key <<<");
+
+            return Ok(());
+        }
+
+        if let Some(signature_type) = self.signature_types.get(crypto_type_name) {
+            signature_type.validate_key(&vk.from_base58()?)?;
+
+            trace!("validate_key <<<");
+
+            return Ok(());
+        }
+
         if !self.crypto_types.contains_key(&crypto_type_name) {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Trying to use key with unknown crypto: {}", crypto_type_name)));
         }
@@ -824,4 +1053,335 @@ mod tests {
             .decrypt_ciphertext(&expected_ciphertext, &iv_encoded, &tag, bad_aad, &cek);
         assert!(expected_error.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn register_key_backend_works() {
+        let service = CryptoService::new();
+        service.register_key_backend(mock_key_backend::CRYPTO_TYPE, mock_key_backend::backend()).unwrap();
+    }
+
+    #[test]
+    fn register_key_backend_not_works_for_builtin_crypto_type() {
+        let service = CryptoService::new();
+        let res = service.register_key_backend(DEFAULT_CRYPTO_TYPE, mock_key_backend::backend());
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn register_key_backend_not_works_for_twice() {
+        let service = CryptoService::new();
+        service.register_key_backend(mock_key_backend::CRYPTO_TYPE, mock_key_backend::backend()).unwrap();
+        let res = service.register_key_backend(mock_key_backend::CRYPTO_TYPE, mock_key_backend::backend());
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn create_key_works_for_key_backend() {
+        let service = _service_with_mock_key_backend();
+        let key_info = KeyInfo { seed: None, crypto_type: Some(mock_key_backend::CRYPTO_TYPE.to_string()) };
+
+        let key = service.create_key(&key_info).unwrap();
+        assert!(key.verkey.ends_with(":mock"));
+        assert!(key.signkey.is_empty());
+    }
+
+    #[test]
+    fn create_my_did_works_for_key_backend() {
+        let service = _service_with_mock_key_backend();
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: Some(mock_key_backend::CRYPTO_TYPE.to_string()), method_name: None };
+
+        let (did, key) = service.create_my_did(&did_info).unwrap();
+        assert_eq!(did.verkey, key.verkey);
+        assert!(key.verkey.ends_with(":mock"));
+        assert!(key.signkey.is_empty());
+    }
+
+    #[test]
+    fn sign_verify_works_for_key_backend() {
+        let service = _service_with_mock_key_backend();
+        let key_info = KeyInfo { seed: None, crypto_type: Some(mock_key_backend::CRYPTO_TYPE.to_string()) };
+        let key = service.create_key(&key_info).unwrap();
+        let msg = "some message".as_bytes();
+
+        let signature = service.sign(&key, msg).unwrap();
+        assert!(service.verify(&key.verkey, msg, &signature).unwrap());
+
+        // Mock backend keeps ed25519 keys, so signature can be checked with built-in crypto as well
+        let (vk, _) = split_verkey(&key.verkey);
+        assert!(service.verify(vk, msg, &signature).unwrap());
+        assert!(!service.verify(&key.verkey, "other message".as_bytes(), &signature).unwrap());
+    }
+
+    #[test]
+    fn crypto_box_works_for_key_backend() {
+        let service = _service_with_mock_key_backend();
+        let key_info = KeyInfo { seed: None, crypto_type: Some(mock_key_backend::CRYPTO_TYPE.to_string()) };
+        let key = service.create_key(&key_info).unwrap();
+        let their_key = service.create_key(&KeyInfo { seed: None, crypto_type: None }).unwrap();
+        let msg = "some message".as_bytes();
+
+        let (encrypted, nonce) = service.crypto_box(&key, &their_key.verkey, msg).unwrap();
+
+        let (vk, _) = split_verkey(&key.verkey);
+        let decrypted = service.crypto_box_open(&their_key, vk, &encrypted, &nonce).unwrap();
+        assert_eq!(msg.to_vec(), decrypted);
+    }
+
+    #[test]
+    fn crypto_box_not_works_for_key_backend_without_crypto_box_handler() {
+        let service = CryptoService::new();
+        service.register_key_backend(mock_key_backend::CRYPTO_TYPE, mock_key_backend::backend_without_crypto_box()).unwrap();
+        let key_info = KeyInfo { seed: None, crypto_type: Some(mock_key_backend::CRYPTO_TYPE.to_string()) };
+        let key = service.create_key(&key_info).unwrap();
+        let their_key = service.create_key(&KeyInfo { seed: None, crypto_type: None }).unwrap();
+
+        let res = service.crypto_box(&key, &their_key.verkey, "msg".as_bytes());
+        assert_kind!(IndyErrorKind::UnknownCrypto, res);
+    }
+
+    #[test]
+    fn crypto_box_seal_open_not_works_for_key_backend() {
+        let service = _service_with_mock_key_backend();
+        let key_info = KeyInfo { seed: None, crypto_type: Some(mock_key_backend::CRYPTO_TYPE.to_string()) };
+        let key = service.create_key(&key_info).unwrap();
+
+        let res = service.crypto_box_seal_open(&key, "msg".as_bytes());
+        assert_kind!(IndyErrorKind::UnknownCrypto, res);
+    }
+
+    #[test]
+    fn validate_key_works_for_key_backend() {
+        let service = _service_with_mock_key_backend();
+        let key_info = KeyInfo { seed: None, crypto_type: Some(mock_key_backend::CRYPTO_TYPE.to_string()) };
+        let key = service.create_key(&key_info).unwrap();
+
+        service.validate_key(&key.verkey).unwrap();
+    }
+
+    #[test]
+    fn create_key_works_for_signature_types() {
+        let service = CryptoService::new();
+
+        for crypto_type in &[SECP256K1_CRYPTO_TYPE, P256_CRYPTO_TYPE] {
+            let key_info = KeyInfo { seed: None, crypto_type: Some(crypto_type.to_string()) };
+            let key = service.create_key(&key_info).unwrap();
+
+            assert!(key.verkey.ends_with(&format!(":{}", crypto_type)));
+            service.validate_key(&key.verkey).unwrap();
+        }
+    }
+
+    #[test]
+    fn create_key_works_for_signature_type_and_seed() {
+        let service = CryptoService::new();
+        let key_info = KeyInfo { seed: Some("00000000000000000000000000000My1".to_string()), crypto_type: Some(P256_CRYPTO_TYPE.to_string()) };
+
+        let key1 = service.create_key(&key_info).unwrap();
+        let key2 = service.create_key(&key_info).unwrap();
+        assert_eq!(key1.verkey, key2.verkey);
+    }
+
+    #[test]
+    fn create_my_did_works_for_signature_type() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: Some(SECP256K1_CRYPTO_TYPE.to_string()), method_name: None };
+
+        let (did, key) = service.create_my_did(&did_info).unwrap();
+        assert_eq!(did.verkey, key.verkey);
+        assert!(key.verkey.ends_with(":secp256k1"));
+    }
+
+    #[test]
+    fn sign_verify_works_for_signature_types() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+
+        for crypto_type in &[SECP256K1_CRYPTO_TYPE, P256_CRYPTO_TYPE] {
+            let key_info = KeyInfo { seed: None, crypto_type: Some(crypto_type.to_string()) };
+            let key = service.create_key(&key_info).unwrap();
+
+            let signature = service.sign(&key, msg).unwrap();
+            assert!(service.verify(&key.verkey, msg, &signature).unwrap());
+            assert!(!service.verify(&key.verkey, "other message".as_bytes(), &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn encryption_not_works_for_signature_types() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: Some(P256_CRYPTO_TYPE.to_string()) }).unwrap();
+        let ed25519_key = service.create_key(&KeyInfo { seed: None, crypto_type: None }).unwrap();
+        let msg = "msg".as_bytes();
+
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.crypto_box(&key, &ed25519_key.verkey, msg));
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.crypto_box(&ed25519_key, &key.verkey, msg));
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.crypto_box_seal(&key.verkey, msg));
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.crypto_box_seal_open(&key, msg));
+    }
+
+    #[test]
+    fn convert_to_x25519_keys_works() {
+        let service = CryptoService::new();
+        let alice_key = service.create_key(&KeyInfo { seed: None, crypto_type: None }).unwrap();
+        let bob_key = service.create_key(&KeyInfo { seed: None, crypto_type: None }).unwrap();
+
+        let alice_shared_secret = ed25519_box::scalarmult(&service.convert_to_x25519_secret_key(&alice_key).unwrap(),
+                                                          &service.convert_to_x25519_public_key(&bob_key.verkey).unwrap()).unwrap();
+        let bob_shared_secret = ed25519_box::scalarmult(&service.convert_to_x25519_secret_key(&bob_key).unwrap(),
+                                                        &service.convert_to_x25519_public_key(&alice_key.verkey).unwrap()).unwrap();
+
+        assert_eq!(alice_shared_secret, bob_shared_secret);
+    }
+
+    #[test]
+    fn convert_to_x25519_keys_not_works_for_other_crypto_types() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo { seed: None, crypto_type: Some(SECP256K1_CRYPTO_TYPE.to_string()) }).unwrap();
+
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.convert_to_x25519_public_key(&key.verkey));
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.convert_to_x25519_secret_key(&key));
+    }
+
+    #[test]
+    fn register_key_backend_not_works_for_signature_type() {
+        let service = CryptoService::new();
+        let res = service.register_key_backend(SECP256K1_CRYPTO_TYPE, mock_key_backend::backend());
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    fn _service_with_mock_key_backend() -> CryptoService {
+        let service = CryptoService::new();
+        service.register_key_backend(mock_key_backend::CRYPTO_TYPE, mock_key_backend::backend()).unwrap();
+        service
+    }
+
+    mod mock_key_backend {
+        use std::ffi::{CStr, CString};
+        use std::slice;
+        use std::sync::Mutex;
+
+        use libc::c_char;
+
+        use indy_api_types::{ErrorCode, IndyHandle};
+        use indy_utils::sequence;
+
+        use super::*;
+
+        pub const CRYPTO_TYPE: &str = "mock";
+
+        lazy_static! {
+            static ref KEYS: Mutex<HashMap<String, ed25519_sign::SecretKey>> = Default::default();
+            static ref BUFFERS: Mutex<HashMap<IndyHandle, Vec<u8>>> = Default::default();
+        }
+
+        pub fn backend() -> PluggedKeyBackend {
+            PluggedKeyBackend::new(create_key, sign, verify, Some(crypto_box), free_buffer)
+        }
+
+        pub fn backend_without_crypto_box() -> PluggedKeyBackend {
+            PluggedKeyBackend::new(create_key, sign, verify, None, free_buffer)
+        }
+
+        fn _store_buffer(buffer: Vec<u8>, buffer_handle_p: *mut IndyHandle) -> (*const u8, u32) {
+            let handle = sequence::get_next_id();
+            let (ptr, len) = (buffer.as_ptr(), buffer.len() as u32);
+            BUFFERS.lock().unwrap().insert(handle, buffer);
+            unsafe { *buffer_handle_p = handle; }
+            (ptr, len)
+        }
+
+        extern fn create_key(_key_info_json: *const c_char,
+                             verkey_p: *mut *const c_char,
+                             verkey_handle_p: *mut IndyHandle) -> ErrorCode {
+            let (vk, sk) = ed25519_sign::create_key_pair_for_signature(None).unwrap();
+            let vk = vk[..].to_base58();
+            KEYS.lock().unwrap().insert(vk.clone(), sk);
+
+            let (ptr, _) = _store_buffer(CString::new(vk).unwrap().into_bytes_with_nul(), verkey_handle_p);
+            unsafe { *verkey_p = ptr as *const c_char; }
+            ErrorCode::Success
+        }
+
+        extern fn sign(verkey: *const c_char,
+                       message_raw: *const u8,
+                       message_len: u32,
+                       signature_raw_p: *mut *const u8,
+                       signature_len_p: *mut u32,
+                       signature_handle_p: *mut IndyHandle) -> ErrorCode {
+            let verkey = unsafe { CStr::from_ptr(verkey) }.to_str().unwrap();
+            let message = unsafe { slice::from_raw_parts(message_raw, message_len as usize) };
+
+            let keys = KEYS.lock().unwrap();
+            let sk = match keys.get(split_verkey(verkey).0) {
+                Some(sk) => sk,
+                None => return ErrorCode::WalletItemNotFound
+            };
+
+            let signature = ed25519_sign::sign(sk, message).unwrap()[..].to_vec();
+            let (ptr, len) = _store_buffer(signature, signature_handle_p);
+            unsafe {
+                *signature_raw_p = ptr;
+                *signature_len_p = len;
+            }
+            ErrorCode::Success
+        }
+
+        extern fn verify(verkey: *const c_char,
+                         message_raw: *const u8,
+                         message_len: u32,
+                         signature_raw: *const u8,
+                         signature_len: u32,
+                         valid_p: *mut bool) -> ErrorCode {
+            let verkey = unsafe { CStr::from_ptr(verkey) }.to_str().unwrap();
+            let message = unsafe { slice::from_raw_parts(message_raw, message_len as usize) };
+            let signature = unsafe { slice::from_raw_parts(signature_raw, signature_len as usize) };
+
+            let vk = ed25519_sign::PublicKey::from_slice(&split_verkey(verkey).0.from_base58().unwrap()).unwrap();
+            let signature = ed25519_sign::Signature::from_slice(signature).unwrap();
+
+            unsafe { *valid_p = ed25519_sign::verify(&vk, message, &signature).unwrap(); }
+            ErrorCode::Success
+        }
+
+        extern fn crypto_box(my_verkey: *const c_char,
+                             their_verkey: *const c_char,
+                             message_raw: *const u8,
+                             message_len: u32,
+                             nonce_raw: *const u8,
+                             nonce_len: u32,
+                             encrypted_raw_p: *mut *const u8,
+                             encrypted_len_p: *mut u32,
+                             encrypted_handle_p: *mut IndyHandle) -> ErrorCode {
+            let my_verkey = unsafe { CStr::from_ptr(my_verkey) }.to_str().unwrap();
+            let their_verkey = unsafe { CStr::from_ptr(their_verkey) }.to_str().unwrap();
+            let message = unsafe { slice::from_raw_parts(message_raw, message_len as usize) };
+            let nonce = unsafe { slice::from_raw_parts(nonce_raw, nonce_len as usize) };
+
+            let keys = KEYS.lock().unwrap();
+            let sk = match keys.get(split_verkey(my_verkey).0) {
+                Some(sk) => sk,
+                None => return ErrorCode::WalletItemNotFound
+            };
+            let their_vk = ed25519_sign::PublicKey::from_slice(&split_verkey(their_verkey).0.from_base58().unwrap()).unwrap();
+
+            let encrypted = ed25519_box::encrypt(&ed25519_sign::sk_to_curve25519(sk).unwrap(),
+                                                 &ed25519_sign::vk_to_curve25519(&their_vk).unwrap(),
+                                                 message,
+                                                 &ed25519_box::Nonce::from_slice(nonce).unwrap()).unwrap();
+
+            let (ptr, len) = _store_buffer(encrypted, encrypted_handle_p);
+            unsafe {
+                *encrypted_raw_p = ptr;
+                *encrypted_len_p = len;
+            }
+            ErrorCode::Success
+        }
+
+        extern fn free_buffer(buffer_handle: IndyHandle) -> ErrorCode {
+            match BUFFERS.lock().unwrap().remove(&buffer_handle) {
+                Some(_) => ErrorCode::Success,
+                None => ErrorCode::CommonInvalidState
+            }
+        }
+    }
+}
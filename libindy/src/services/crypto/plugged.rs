@@ -0,0 +1,163 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::slice;
+
+use libc::c_char;
+use serde_json;
+
+use indy_api_types::{ErrorCode, IndyHandle};
+use indy_api_types::errors::prelude::*;
+use crate::api::crypto::{KeyBackendCreateKey, KeyBackendCryptoBox, KeyBackendFreeBuffer, KeyBackendSign, KeyBackendVerify};
+use crate::domain::crypto::key::KeyInfo;
+
+struct BufferGuard {
+    buffer_handle: IndyHandle,
+    free_buffer: KeyBackendFreeBuffer,
+}
+
+impl BufferGuard {
+    fn new(buffer_handle: IndyHandle, free_buffer: KeyBackendFreeBuffer) -> BufferGuard {
+        BufferGuard { buffer_handle, free_buffer }
+    }
+}
+
+impl Drop for BufferGuard {
+    fn drop(&mut self) {
+        let err = (self.free_buffer)(self.buffer_handle);
+        if err != ErrorCode::Success {
+            warn!("Unable to free key backend buffer {}: {:?}", self.buffer_handle, err);
+        }
+    }
+}
+
+pub struct PluggedKeyBackend {
+    create_key_handler: KeyBackendCreateKey,
+    sign_handler: KeyBackendSign,
+    verify_handler: KeyBackendVerify,
+    crypto_box_handler: Option<KeyBackendCryptoBox>,
+    free_buffer_handler: KeyBackendFreeBuffer,
+}
+
+impl PluggedKeyBackend {
+    pub fn new(create_key_handler: KeyBackendCreateKey,
+               sign_handler: KeyBackendSign,
+               verify_handler: KeyBackendVerify,
+               crypto_box_handler: Option<KeyBackendCryptoBox>,
+               free_buffer_handler: KeyBackendFreeBuffer) -> PluggedKeyBackend {
+        PluggedKeyBackend {
+            create_key_handler,
+            sign_handler,
+            verify_handler,
+            crypto_box_handler,
+            free_buffer_handler,
+        }
+    }
+
+    pub fn create_key(&self, key_info: &KeyInfo) -> IndyResult<String> {
+        let key_info_json = serde_json::to_string(key_info)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize KeyInfo")?;
+        let key_info_json = CString::new(key_info_json)?;
+
+        let mut verkey_ptr: *const c_char = ptr::null();
+        let mut verkey_handle: IndyHandle = -1;
+
+        let err = (self.create_key_handler)(key_info_json.as_ptr(), &mut verkey_ptr, &mut verkey_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        let _verkey_guard = BufferGuard::new(verkey_handle, self.free_buffer_handler);
+
+        if verkey_ptr.is_null() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Key backend returned empty verkey"));
+        }
+
+        let verkey = unsafe { CStr::from_ptr(verkey_ptr) }
+            .to_str()
+            .to_indy(IndyErrorKind::InvalidState, "Key backend returned verkey with non-utf8 symbols")?
+            .to_string();
+
+        Ok(verkey)
+    }
+
+    pub fn sign(&self, verkey: &str, doc: &[u8]) -> IndyResult<Vec<u8>> {
+        let verkey = CString::new(verkey)?;
+
+        let mut signature_ptr: *const u8 = ptr::null();
+        let mut signature_len: u32 = 0;
+        let mut signature_handle: IndyHandle = -1;
+
+        let err = (self.sign_handler)(verkey.as_ptr(),
+                                      doc.as_ptr(),
+                                      doc.len() as u32,
+                                      &mut signature_ptr,
+                                      &mut signature_len,
+                                      &mut signature_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        let _signature_guard = BufferGuard::new(signature_handle, self.free_buffer_handler);
+
+        PluggedKeyBackend::_copy_buffer(signature_ptr, signature_len, "signature")
+    }
+
+    pub fn verify(&self, verkey: &str, doc: &[u8], signature: &[u8]) -> IndyResult<bool> {
+        let verkey = CString::new(verkey)?;
+
+        let mut valid = false;
+
+        let err = (self.verify_handler)(verkey.as_ptr(),
+                                        doc.as_ptr(),
+                                        doc.len() as u32,
+                                        signature.as_ptr(),
+                                        signature.len() as u32,
+                                        &mut valid);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(valid)
+    }
+
+    pub fn crypto_box(&self, my_verkey: &str, their_verkey: &str, doc: &[u8], nonce: &[u8]) -> IndyResult<Vec<u8>> {
+        let crypto_box_handler = self.crypto_box_handler
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownCrypto, "Key backend doesn't support authenticated encryption"))?;
+
+        let my_verkey = CString::new(my_verkey)?;
+        let their_verkey = CString::new(their_verkey)?;
+
+        let mut encrypted_ptr: *const u8 = ptr::null();
+        let mut encrypted_len: u32 = 0;
+        let mut encrypted_handle: IndyHandle = -1;
+
+        let err = crypto_box_handler(my_verkey.as_ptr(),
+                                     their_verkey.as_ptr(),
+                                     doc.as_ptr(),
+                                     doc.len() as u32,
+                                     nonce.as_ptr(),
+                                     nonce.len() as u32,
+                                     &mut encrypted_ptr,
+                                     &mut encrypted_len,
+                                     &mut encrypted_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        let _encrypted_guard = BufferGuard::new(encrypted_handle, self.free_buffer_handler);
+
+        PluggedKeyBackend::_copy_buffer(encrypted_ptr, encrypted_len, "encrypted message")
+    }
+
+    fn _copy_buffer(ptr: *const u8, len: u32, name: &str) -> IndyResult<Vec<u8>> {
+        if ptr.is_null() || len == 0 {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("Key backend returned empty {}", name)));
+        }
+
+        Ok(unsafe { slice::from_raw_parts(ptr, len as usize) }.to_vec())
+    }
+}
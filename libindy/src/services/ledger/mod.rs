@@ -10,17 +10,20 @@ use crate::domain::anoncreds::revocation_registry::RevocationRegistry;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::schema::{Schema, SchemaV1, SchemaId};
-use crate::domain::crypto::did::DidValue;
-use crate::domain::ledger::attrib::{AttribOperation, GetAttribOperation};
-use crate::domain::ledger::constants::{GET_VALIDATOR_INFO, POOL_RESTART, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
+use crate::domain::crypto::did::{DidValue, DEFAULT_DID_KEY_FRAGMENT, DEFAULT_DID_METHOD, INDY_DID_METHOD};
+use crate::domain::ledger::attrib::{AttribOperation, GetAttribOperation, GetAttrReplyResult};
+use crate::domain::ledger::constants::{GET_RICH_SCHEMA_OBJECT_BY_METADATA, GET_VALIDATOR_INFO, POOL_RESTART, NYM, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
 use crate::domain::ledger::cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult};
 use crate::domain::ledger::ddo::GetDdoOperation;
+use crate::domain::ledger::endorser::PendingTransaction;
+use crate::domain::ledger::did_doc::*;
 use crate::domain::ledger::node::{NodeOperation, NodeOperationData};
 use crate::domain::ledger::nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymData, NymOperation};
 use crate::domain::ledger::pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, Schedule};
 use crate::domain::ledger::request::{TxnAuthrAgrmtAcceptanceData, Request};
 use crate::domain::ledger::response::{Message, Reply, ReplyType};
 use crate::domain::ledger::rev_reg::{GetRevocRegDeltaReplyResult, GetRevocRegReplyResult, GetRevRegDeltaOperation, GetRevRegOperation, RevRegEntryOperation};
+use crate::domain::ledger::rich_schema::*;
 use crate::domain::ledger::rev_reg_def::{GetRevocRegDefReplyResult, GetRevRegDefOperation, RevRegDefOperation};
 use crate::domain::ledger::schema::{GetSchemaOperation, GetSchemaOperationData, GetSchemaReplyResult, SchemaOperation, SchemaOperationData};
 use crate::domain::ledger::txn::{GetTxnOperation, LedgerType};
@@ -29,7 +32,10 @@ use crate::domain::ledger::auth_rule::*;
 use crate::domain::ledger::author_agreement::*;
 use crate::domain::ledger::ledgers_freeze::{LedgersFreezeOperation, GetFrozenLedgersOperation};
 use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
 use indy_utils::crypto::hash::hash as openssl_hash;
+use crate::services::pool::parse_response_metadata;
+use crate::utils::crypto::verkey_builder::build_full_verkey;
 
 pub mod merkletree;
 
@@ -84,8 +90,9 @@ impl LedgerService {
     }
 
     #[logfn(Info)]
-    pub fn build_get_nym_request(&self, identifier: Option<&DidValue>, dest: &DidValue) -> IndyResult<String> {
-        build_result!(GetNymOperation, identifier, dest.to_short())
+    pub fn build_get_nym_request(&self, identifier: Option<&DidValue>, dest: &DidValue,
+                                 seq_no: Option<i32>, timestamp: Option<u64>) -> IndyResult<String> {
+        build_result!(GetNymOperation, identifier, dest.to_short(), seq_no, timestamp)
     }
 
     #[logfn(Info)]
@@ -121,11 +128,157 @@ impl LedgerService {
         Ok(res)
     }
 
+    /// Returns full verkey of DID from GET_NYM response expanding abbreviated one.
+    #[logfn(Info)]
+    pub fn parse_get_nym_verkey(&self, get_nym_response: &str) -> IndyResult<String> {
+        let nym_data: NymData = serde_json::from_str(&self.parse_get_nym_response(get_nym_response)?)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize NYM data")?;
+
+        build_full_verkey(&nym_data.did.0, nym_data.verkey.as_ref().map(String::as_str))
+    }
+
     #[logfn(Info)]
     pub fn build_get_ddo_request(&self, identifier: Option<&DidValue>, dest: &DidValue) -> IndyResult<String> {
         build_result!(GetDdoOperation, identifier, dest.to_short())
     }
 
+    /// Returns id of DID Document and DID to be looked up on the ledger.
+    /// Unqualified DIDs are resolved as did:sov, did:indy DIDs may contain namespace of the ledger.
+    #[logfn(Info)]
+    pub fn parse_resolvable_did(&self, did: &DidValue) -> IndyResult<(String, DidValue)> {
+        let method = did.get_method();
+
+        match method.as_ref().map(String::as_str) {
+            None => Ok((did.qualify(DEFAULT_DID_METHOD).0, did.clone())),
+            Some(DEFAULT_DID_METHOD) | Some(INDY_DID_METHOD) => {
+                let unqualified = did.to_unqualified().0;
+                let dest = unqualified.rsplit(':').next().unwrap_or_default().to_string();
+                Ok((did.0.clone(), DidValue(dest)))
+            }
+            Some(method) => Err(err_msg(IndyErrorKind::InvalidStructure,
+                                        format!("DID method {} can't be resolved from the ledger", method)))
+        }
+    }
+
+    #[logfn(Info)]
+    pub fn build_did_document(&self, did: &DidValue, get_nym_response: &str, get_attrib_response: Option<&str>) -> IndyResult<String> {
+        let (id, _) = self.parse_resolvable_did(did)?;
+
+        let verkey = self.parse_get_nym_verkey(get_nym_response)?;
+        let nym_metadata = parse_response_metadata(get_nym_response)?;
+
+        let key_id = format!("{}#{}", id, DEFAULT_DID_KEY_FRAGMENT);
+
+        let (service, endpoint_metadata) = match get_attrib_response {
+            Some(get_attrib_response) => match self._parse_endpoint_attrib(get_attrib_response)? {
+                Some(endpoint) => (LedgerService::_build_did_services(&id, &key_id, endpoint),
+                                   Some(parse_response_metadata(get_attrib_response)?)),
+                None => (Vec::new(), None)
+            },
+            None => (Vec::new(), None)
+        };
+
+        let did_document = DidDocument {
+            context: vec![DID_DOCUMENT_CONTEXT.to_string()],
+            id: id.clone(),
+            verification_method: vec![VerificationMethod {
+                id: key_id.clone(),
+                type_: ED25519_VERIFICATION_KEY_TYPE.to_string(),
+                controller: id,
+                public_key_base58: verkey,
+            }],
+            authentication: vec![key_id.clone()],
+            assertion_method: vec![key_id],
+            service,
+        };
+
+        let res = DidResolutionResult {
+            did_document,
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: DID_DOCUMENT_CONTENT_TYPE.to_string(),
+                seq_no: nym_metadata.seq_no,
+                txn_time: nym_metadata.txn_time,
+                endpoint: endpoint_metadata,
+            },
+        };
+
+        serde_json::to_string(&res)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize DID resolution result")
+    }
+
+    fn _parse_endpoint_attrib(&self, get_attrib_response: &str) -> IndyResult<Option<EndpointAttrib>> {
+        // Reply without data doesn't correspond to the structure so it is reported as not found
+        let reply: Reply<GetAttrReplyResult> = match LedgerService::parse_response(get_attrib_response) {
+            Ok(reply) => reply,
+            Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => return Ok(None),
+            Err(err) => return Err(err)
+        };
+
+        let raw = match reply.result() {
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => res.data,
+            GetAttrReplyResult::GetAttrReplyResultV1(res) => res.txn.data.raw
+        };
+
+        let attrib_data: EndpointAttribData = serde_json::from_str(&raw)
+            .to_indy(IndyErrorKind::InvalidState, "Invalid endpoint ATTRIB json")?;
+
+        Ok(Some(attrib_data.endpoint))
+    }
+
+    fn _build_did_services(id: &str, key_id: &str, endpoint: EndpointAttrib) -> Vec<DidService> {
+        // Legacy indy endpoint has host address and transport key only
+        if let (Some(ha), None) = (endpoint.ha.as_ref(), endpoint.endpoint.as_ref()) {
+            return vec![DidService {
+                id: format!("{}#{}", id, SERVICE_TYPE_DID_COMMUNICATION),
+                type_: SERVICE_TYPE_DID_COMMUNICATION.to_string(),
+                service_endpoint: ha.to_string(),
+                priority: Some(0),
+                recipient_keys: vec![endpoint.verkey.unwrap_or_else(|| key_id.to_string())],
+                routing_keys: Vec::new(),
+            }];
+        }
+
+        let service_endpoint = match endpoint.endpoint {
+            Some(service_endpoint) => service_endpoint,
+            None => return Vec::new()
+        };
+
+        let routing_keys = endpoint.routing_keys;
+        let types = endpoint.types
+            .unwrap_or_else(|| DEFAULT_ENDPOINT_TYPES.iter().map(|type_| type_.to_string()).collect());
+
+        types
+            .iter()
+            .filter_map(|type_| match type_.as_str() {
+                SERVICE_TYPE_ENDPOINT => Some(DidService {
+                    id: format!("{}#{}", id, SERVICE_TYPE_ENDPOINT),
+                    type_: SERVICE_TYPE_ENDPOINT.to_string(),
+                    service_endpoint: service_endpoint.clone(),
+                    priority: None,
+                    recipient_keys: Vec::new(),
+                    routing_keys: Vec::new(),
+                }),
+                SERVICE_TYPE_DID_COMMUNICATION => Some(DidService {
+                    id: format!("{}#{}", id, SERVICE_TYPE_DID_COMMUNICATION),
+                    type_: SERVICE_TYPE_DID_COMMUNICATION.to_string(),
+                    service_endpoint: service_endpoint.clone(),
+                    priority: Some(0),
+                    recipient_keys: vec![key_id.to_string()],
+                    routing_keys: routing_keys.clone(),
+                }),
+                DIDCOMM_V2_ENDPOINT_TYPE => Some(DidService {
+                    id: format!("{}#didcomm-1", id),
+                    type_: SERVICE_TYPE_DIDCOMM_MESSAGING.to_string(),
+                    service_endpoint: service_endpoint.clone(),
+                    priority: None,
+                    recipient_keys: Vec::new(),
+                    routing_keys: routing_keys.clone(),
+                }),
+                _ => None
+            })
+            .collect()
+    }
+
     #[logfn(Info)]
     pub fn build_attrib_request(&self, identifier: &DidValue, dest: &DidValue, hash: Option<&str>,
                                 raw: Option<&serde_json::Value>, enc: Option<&str>) -> IndyResult<String> {
@@ -405,6 +558,61 @@ impl LedgerService {
         build_result!(GetFrozenLedgersOperation, Some(submitter_did))
     }
 
+    #[logfn(Info)]
+    pub fn build_rich_schema_request(&self, identifier: &DidValue, id: &str, content: &str, rs_name: &str,
+                                     rs_version: &str, rs_type: &str, ver: &str) -> IndyResult<String> {
+        let rs_type = LedgerService::_parse_rich_schema_type(rs_type)?;
+
+        let rich_schema = RichSchema::new(id.to_string(), content.to_string(), rs_name.to_string(),
+                                          rs_version.to_string(), rs_type, ver.to_string());
+
+        rich_schema.validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        build_result!(RichSchemaOperation, Some(identifier), rich_schema)
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_rich_schema_object_by_id_request(&self, identifier: Option<&DidValue>, id: &str) -> IndyResult<String> {
+        build_result!(GetRichSchemaObjectByIdOperation, identifier, id.to_string())
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_rich_schema_object_by_metadata_request(&self, identifier: Option<&DidValue>, rs_type: &str,
+                                                            rs_name: &str, rs_version: &str) -> IndyResult<String> {
+        let rs_type = LedgerService::_parse_rich_schema_type(rs_type)?;
+
+        build_result!(GetRichSchemaObjectByMetadataOperation, identifier, rs_type, rs_name.to_string(), rs_version.to_string())
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_rich_schema_object_response(&self, response: &str) -> IndyResult<String> {
+        let message: serde_json::Value = serde_json::from_str(&response)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        let rich_schema = match message["result"]["type"].as_str() {
+            Some(GET_RICH_SCHEMA_OBJECT_BY_METADATA) =>
+                match LedgerService::parse_response::<GetRichSchemaObjectByMetadataReplyResult>(response)?.result() {
+                    GetRichSchemaObjectByMetadataReplyResult::GetRichSchemaObjectByMetadataReplyResultV0(res) => res.data
+                },
+            _ =>
+                match LedgerService::parse_response::<GetRichSchemaObjectByIdReplyResult>(response)?.result() {
+                    GetRichSchemaObjectByIdReplyResult::GetRichSchemaObjectByIdReplyResultV0(res) => res.data
+                }
+        };
+
+        let rich_schema = rich_schema
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Rich Schema object not found on the ledger"))?;
+
+        serde_json::to_string(&rich_schema)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Rich Schema object")
+    }
+
+    fn _parse_rich_schema_type(rs_type: &str) -> IndyResult<RichSchemaType> {
+        serde_json::from_str::<RichSchemaType>(&format!("\"{}\"", rs_type))
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Cannot parse Rich Schema object type: {}", err)))
+    }
+
     #[logfn(Info)]
     pub fn build_auth_rules_request(&self, submitter_did: &DidValue, rules: AuthRules) -> IndyResult<String> {
         build_result!(AuthRulesOperation, Some(submitter_did), rules)
@@ -552,6 +760,207 @@ impl LedgerService {
         Ok(())
     }
 
+    pub fn parse_get_txn_author_agreement_response(&self, response: &str) -> IndyResult<TxnAuthorAgreementData> {
+        trace!("parse_get_txn_author_agreement_response >>> response: {:?}", response);
+
+        let reply: Reply<GetTxnAuthorAgreementReplyResult> = LedgerService::parse_response(response)?;
+
+        let res = match reply.result() {
+            GetTxnAuthorAgreementReplyResult::GetTxnAuthorAgreementReplyResultV0(res) => res.data
+                .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction author agreement not found on the ledger"))?
+        };
+
+        trace!("parse_get_txn_author_agreement_response <<< {:?}", res);
+
+        Ok(res)
+    }
+
+    /// Checks that accepted agreement is the one set on the ledger and it hasn't been retired yet.
+    pub fn check_txn_author_agreement_acceptance(&self, acceptance: &TxnAuthrAgrmtAcceptanceData, ledger_taa: &TxnAuthorAgreementData, cur_time: u64) -> IndyResult<()> {
+        let ledger_digest = match ledger_taa.digest {
+            Some(ref digest) => digest.clone(),
+            None => hex::encode(self._calculate_hash(&ledger_taa.text, &ledger_taa.version)?)
+        };
+
+        if !ledger_digest.eq_ignore_ascii_case(&acceptance.taa_digest) {
+            return Err(IndyError::from_msg(IndyErrorKind::InvalidTransaction,
+                                           format!("Accepted transaction author agreement {:?} doesn't match agreement {:?} of version {:?} set on the ledger",
+                                                   acceptance.taa_digest, ledger_digest, ledger_taa.version)));
+        }
+
+        match ledger_taa.retirement_ts {
+            Some(retirement_ts) if retirement_ts <= cur_time =>
+                Err(IndyError::from_msg(IndyErrorKind::InvalidTransaction,
+                                        format!("Transaction author agreement of version {:?} has been retired at {}", ledger_taa.version, retirement_ts))),
+            _ => Ok(())
+        }
+    }
+
+    /// Checks that the pending transaction signed by its author and endorser is allowed by auth rules set on the ledger.
+    pub fn check_endorsement(&self, pending_transaction: &PendingTransaction, author_role: Option<&str>, author_on_ledger: bool,
+                             endorser_role: Option<&str>, auth_rules: &[AuthRule]) -> IndyResult<()> {
+        // the ledger accepts endorsement only from the DID with one of these roles regardless of auth rules
+        if !endorser_role.map(|role| [TRUSTEE, STEWARD, ENDORSER].contains(&role)).unwrap_or(false) {
+            return Err(IndyError::from_msg(IndyErrorKind::InvalidTransaction,
+                                           format!("Endorser must have one of TRUSTEE, STEWARD or ENDORSER roles, but has {:?}", endorser_role)));
+        }
+
+        let (field, new_value) = match pending_transaction.txn_type.as_str() {
+            NYM => ("role", pending_transaction.request["operation"]["role"].as_str().unwrap_or("")),
+            _ => ("*", "*")
+        };
+
+        let signers = [
+            AuthRuleSigner { role: author_role.map(String::from), is_owner: true, off_ledger: !author_on_ledger },
+            AuthRuleSigner { role: endorser_role.map(String::from), is_owner: false, off_ledger: false },
+        ];
+
+        let result = self.check_auth_rule(auth_rules, &pending_transaction.txn_type, "ADD", field, None, Some(new_value), &signers)?;
+
+        if !result.allowed {
+            return Err(IndyError::from_msg(IndyErrorKind::InvalidTransaction,
+                                           format!("Auth rules don't allow to endorse transaction of type {}: {}", pending_transaction.txn_type, result.explanation)));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_auth_rule(&self, auth_rules: &[AuthRule], txn_type: &str, action: &str, field: &str,
+                           old_value: Option<&str>, new_value: Option<&str>, signers: &[AuthRuleSigner]) -> IndyResult<AuthRuleCheckResult> {
+        let txn_type = txn_name_to_code(&txn_type)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported `txn_type`: {}", txn_type)))?;
+
+        let action = serde_json::from_str::<AuthAction>(&format!("\"{}\"", action))
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Cannot parse auth action: {}", err)))?;
+
+        let rule = LedgerService::_find_auth_rule(auth_rules, txn_type, &action, field, old_value, new_value)
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound,
+                                   format!("Auth rule for {:?} of field {} of transaction type {} not found", action, field, txn_type)))?;
+
+        let signers = signers.iter()
+            .map(|signer| {
+                let role = LedgerService::_role_code(signer.role.as_ref().map(String::as_str))?;
+                Ok(AuthRuleSigner { role: role.map(String::from), ..signer.clone() })
+            })
+            .collect::<IndyResult<Vec<AuthRuleSigner>>>()?;
+
+        Ok(LedgerService::_check_constraint(&rule.constraint, &signers))
+    }
+
+    fn _find_auth_rule<'a>(auth_rules: &'a [AuthRule], txn_type: &str, action: &AuthAction, field: &str,
+                           old_value: Option<&str>, new_value: Option<&str>) -> Option<&'a AuthRule> {
+        let action = match action {
+            AuthAction::ADD => "ADD",
+            AuthAction::EDIT => "EDIT",
+        };
+
+        let is_wildcard = |value: &Option<String>| value.as_ref().map(|value| value == "*").unwrap_or(false);
+        let matches = |expected: &Option<String>, value: Option<&str>| is_wildcard(expected) || expected.as_ref().map(String::as_str) == value;
+
+        auth_rules.iter()
+            .filter(|rule| rule.auth_type == txn_type && rule.auth_action == action)
+            .filter(|rule| rule.field == "*" || rule.field == field)
+            .filter(|rule| action == "ADD" || matches(&rule.old_value, old_value))
+            .filter(|rule| matches(&rule.new_value, new_value))
+            // the ledger applies the most specific rule if there are rules with wildcards
+            .min_by_key(|rule| [rule.field == "*", is_wildcard(&rule.old_value), is_wildcard(&rule.new_value)].iter().filter(|wildcard| **wildcard).count())
+    }
+
+    fn _check_constraint(constraint: &Constraint, signers: &[AuthRuleSigner]) -> AuthRuleCheckResult {
+        match constraint {
+            Constraint::RoleConstraint(constraint) => {
+                let role = constraint.role.as_ref().map(String::as_str).filter(|role| !role.is_empty());
+
+                let provided = signers.iter()
+                    .filter(|signer| role == Some("*") || signer.role.as_ref().map(String::as_str) == role)
+                    .filter(|signer| !constraint.need_to_be_owner || signer.is_owner)
+                    .filter(|signer| !signer.off_ledger || constraint.off_ledger_signature)
+                    .count();
+
+                let allowed = provided >= constraint.sig_count as usize;
+
+                let explanation = if constraint.sig_count == 0 {
+                    String::from("no signatures required")
+                } else {
+                    format!("{} signature(s) of {}{} required, {} provided",
+                            constraint.sig_count,
+                            LedgerService::_role_name(role),
+                            if constraint.need_to_be_owner { " being an owner" } else { "" },
+                            provided)
+                };
+
+                let fees = constraint.metadata.as_ref()
+                    .and_then(|metadata| metadata["fees"].as_str())
+                    .filter(|_| allowed)
+                    .map(|fees| vec![fees.to_string()])
+                    .unwrap_or_default();
+
+                AuthRuleCheckResult { allowed, explanation, fees }
+            }
+            Constraint::OrConstraint(constraint) => {
+                let mut results: Vec<AuthRuleCheckResult> = constraint.auth_constraints.iter()
+                    .map(|constraint| LedgerService::_check_constraint(constraint, signers))
+                    .collect();
+
+                match results.iter().position(|result| result.allowed) {
+                    Some(position) => results.swap_remove(position),
+                    None => AuthRuleCheckResult {
+                        allowed: false,
+                        explanation: LedgerService::_join_explanations(&results, " OR "),
+                        fees: Vec::new(),
+                    }
+                }
+            }
+            Constraint::AndConstraint(constraint) => {
+                let results: Vec<AuthRuleCheckResult> = constraint.auth_constraints.iter()
+                    .map(|constraint| LedgerService::_check_constraint(constraint, signers))
+                    .collect();
+
+                let allowed = results.iter().all(|result| result.allowed);
+
+                AuthRuleCheckResult {
+                    allowed,
+                    explanation: LedgerService::_join_explanations(&results, " AND "),
+                    fees: if allowed { results.into_iter().flat_map(|result| result.fees).collect() } else { Vec::new() },
+                }
+            }
+            Constraint::ForbiddenConstraint(_) => AuthRuleCheckResult {
+                allowed: false,
+                explanation: String::from("action is forbidden"),
+                fees: Vec::new(),
+            }
+        }
+    }
+
+    fn _join_explanations(results: &[AuthRuleCheckResult], separator: &str) -> String {
+        let explanations: Vec<&str> = results.iter().map(|result| result.explanation.as_str()).collect();
+        format!("({})", explanations.join(separator))
+    }
+
+    fn _role_code(role: Option<&str>) -> IndyResult<Option<&str>> {
+        match role {
+            None | Some("") => Ok(None),
+            Some("TRUSTEE") => Ok(Some(TRUSTEE)),
+            Some("STEWARD") => Ok(Some(STEWARD)),
+            Some("TRUST_ANCHOR") | Some("ENDORSER") => Ok(Some(ENDORSER)),
+            Some("NETWORK_MONITOR") => Ok(Some(NETWORK_MONITOR)),
+            Some(role) if ROLES.contains(&role) => Ok(Some(role)),
+            Some(role) => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))
+        }
+    }
+
+    fn _role_name(role: Option<&str>) -> &str {
+        match role {
+            None => "IDENTITY_OWNER",
+            Some("*") => "any role",
+            Some(TRUSTEE) => "TRUSTEE",
+            Some(STEWARD) => "STEWARD",
+            Some(ENDORSER) => "ENDORSER",
+            Some(NETWORK_MONITOR) => "NETWORK_MONITOR",
+            Some(role) => role,
+        }
+    }
+
     pub fn parse_get_auth_rule_response(&self, response: &str) -> IndyResult<Vec<AuthRule>> {
         trace!("parse_get_auth_rule_response >>> response: {:?}", response);
 
@@ -639,7 +1048,35 @@ mod tests {
             "dest": DEST
         });
 
-        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest()).unwrap();
+        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest(), None, None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_nym_request_works_for_timestamp() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_NYM,
+            "dest": DEST,
+            "timestamp": 1_600_000_000
+        });
+
+        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest(), None, Some(1_600_000_000)).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_nym_request_works_for_seq_no() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_NYM,
+            "dest": DEST,
+            "seqNo": 5
+        });
+
+        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest(), Some(5), None).unwrap();
         check_request(&request, expected_result);
     }
 
@@ -1128,6 +1565,427 @@ mod tests {
         }
     }
 
+    mod endorsement {
+        use crate::domain::crypto::did::ShortDidValue;
+
+        use super::*;
+
+        fn _pending_transaction() -> PendingTransaction {
+            PendingTransaction::new(ShortDidValue("V4SGRU86Z58d6TV7PBUe6f".to_string()),
+                                    ShortDidValue("VsKV7grR1BUE29mG2Fm2kX".to_string()),
+                                    json!({"operation": {"type": SCHEMA}}))
+        }
+
+        fn _auth_rule(auth_type: &str, constraint: Constraint) -> AuthRule {
+            AuthRule {
+                auth_type: auth_type.to_string(),
+                auth_action: "ADD".to_string(),
+                field: "*".to_string(),
+                old_value: None,
+                new_value: Some("*".to_string()),
+                constraint,
+            }
+        }
+
+        fn _role_constraint(role: &str) -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count: 1,
+                metadata: None,
+                role: Some(role.to_string()),
+                need_to_be_owner: false,
+                off_ledger_signature: false,
+            })
+        }
+
+        fn _schema_rules() -> Vec<AuthRule> {
+            vec![
+                _auth_rule(NYM, _role_constraint(ENDORSER)),
+                _auth_rule(SCHEMA, Constraint::OrConstraint(CombinationConstraint {
+                    auth_constraints: vec![_role_constraint(TRUSTEE), _role_constraint(ENDORSER)]
+                })),
+            ]
+        }
+
+        #[test]
+        fn check_endorsement_works() {
+            let ledger_service = LedgerService::new();
+            ledger_service.check_endorsement(&_pending_transaction(), None, true, Some(ENDORSER), &_schema_rules()).unwrap();
+        }
+
+        #[test]
+        fn check_endorsement_works_for_author_allowed_by_rules() {
+            let ledger_service = LedgerService::new();
+            ledger_service.check_endorsement(&_pending_transaction(), Some(TRUSTEE), true, Some(STEWARD), &_schema_rules()).unwrap();
+        }
+
+        #[test]
+        fn check_endorsement_works_for_off_ledger_author() {
+            let ledger_service = LedgerService::new();
+            let rules = vec![_auth_rule(SCHEMA, Constraint::AndConstraint(CombinationConstraint {
+                auth_constraints: vec![
+                    Constraint::RoleConstraint(RoleConstraint {
+                        sig_count: 1,
+                        metadata: None,
+                        role: Some("*".to_string()),
+                        need_to_be_owner: true,
+                        off_ledger_signature: false,
+                    }),
+                    _role_constraint(ENDORSER),
+                ]
+            }))];
+
+            let res = ledger_service.check_endorsement(&_pending_transaction(), None, false, Some(ENDORSER), &rules);
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+
+            ledger_service.check_endorsement(&_pending_transaction(), None, true, Some(ENDORSER), &rules).unwrap();
+        }
+
+        #[test]
+        fn check_endorsement_works_for_endorser_without_role() {
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.check_endorsement(&_pending_transaction(), Some(TRUSTEE), true, None, &_schema_rules());
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn check_endorsement_works_for_role_not_allowed_by_rules() {
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.check_endorsement(&_pending_transaction(), None, true, Some(STEWARD), &_schema_rules());
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn check_endorsement_works_for_nym_role_rule() {
+            let ledger_service = LedgerService::new();
+            let pending_transaction = PendingTransaction::new(ShortDidValue("V4SGRU86Z58d6TV7PBUe6f".to_string()),
+                                                              ShortDidValue("VsKV7grR1BUE29mG2Fm2kX".to_string()),
+                                                              json!({"operation": {"type": NYM, "role": TRUSTEE}}));
+            let rules = vec![
+                AuthRule { field: "role".to_string(), new_value: Some("".to_string()), ..._auth_rule(NYM, _role_constraint(ENDORSER)) },
+                AuthRule { field: "role".to_string(), new_value: Some(TRUSTEE.to_string()), ..._auth_rule(NYM, _role_constraint(TRUSTEE)) },
+            ];
+
+            let res = ledger_service.check_endorsement(&pending_transaction, None, true, Some(ENDORSER), &rules);
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+
+            ledger_service.check_endorsement(&pending_transaction, Some(TRUSTEE), true, Some(ENDORSER), &rules).unwrap();
+        }
+
+        #[test]
+        fn check_endorsement_works_for_forbidden_action() {
+            let ledger_service = LedgerService::new();
+            let rules = vec![_auth_rule(SCHEMA, Constraint::ForbiddenConstraint(ForbiddenConstraint {}))];
+            let res = ledger_service.check_endorsement(&_pending_transaction(), Some(TRUSTEE), true, Some(TRUSTEE), &rules);
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn check_endorsement_works_for_no_rules() {
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.check_endorsement(&_pending_transaction(), None, true, Some(TRUSTEE), &[]);
+            assert_eq!(IndyErrorKind::LedgerItemNotFound, res.unwrap_err().kind());
+        }
+    }
+
+    mod rich_schema {
+        use super::*;
+
+        const ID: &str = "did:sov:2sEEi28LsekEUg6Pbpx3c1";
+
+        fn _content() -> String {
+            json!({"@id": ID, "@type": "rdfs:Class"}).to_string()
+        }
+
+        #[test]
+        fn build_rich_schema_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": RICH_SCHEMA,
+                "id": ID,
+                "content": _content(),
+                "rsName": "name",
+                "rsVersion": "1.0",
+                "rsType": "sch",
+                "ver": "1"
+            });
+
+            let request = ledger_service.build_rich_schema_request(&identifier(), ID, &_content(), "name", "1.0", "sch", "1").unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_context() {
+            let ledger_service = LedgerService::new();
+
+            let content = json!({"@context": {"name": "http://schema.org/name"}}).to_string();
+            let request = ledger_service.build_rich_schema_request(&identifier(), ID, &content, "name", "1.0", "ctx", "1").unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(JSON_LD_CONTEXT, request["operation"]["type"]);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_invalid_type() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_rich_schema_request(&identifier(), ID, &_content(), "name", "1.0", "other", "1");
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_invalid_content() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_rich_schema_request(&identifier(), ID, "{}", "name", "1.0", "sch", "1");
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_id_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_RICH_SCHEMA_OBJECT_BY_ID,
+                "id": ID
+            });
+
+            let request = ledger_service.build_get_rich_schema_object_by_id_request(Some(&identifier()), ID).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_metadata_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "map",
+                "rsName": "name",
+                "rsVersion": "1.0"
+            });
+
+            let request = ledger_service.build_get_rich_schema_object_by_metadata_request(Some(&identifier()), "map", "name", "1.0").unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                    "seqNo": 10,
+                    "txnTime": 1514214795,
+                    "data": {
+                        "id": ID,
+                        "content": _content(),
+                        "rsName": "name",
+                        "rsVersion": "1.0",
+                        "rsType": "sch",
+                        "ver": "1",
+                        "from": IDENTIFIER
+                    }
+                }
+            }).to_string();
+
+            let rich_schema = ledger_service.parse_get_rich_schema_object_response(&response).unwrap();
+            let rich_schema: RichSchema = serde_json::from_str(&rich_schema).unwrap();
+            assert_eq!(RichSchema::new(ID.to_string(), _content(), "name".to_string(), "1.0".to_string(), RichSchemaType::Schema, "1".to_string()),
+                       rich_schema);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_RICH_SCHEMA_OBJECT_BY_ID, "seqNo": null, "txnTime": null, "data": null}
+            }).to_string();
+
+            let res = ledger_service.parse_get_rich_schema_object_response(&response);
+            assert_eq!(IndyErrorKind::LedgerItemNotFound, res.unwrap_err().kind());
+        }
+    }
+
+    mod check_auth_rule {
+        use super::*;
+
+        fn _role_constraint(role: &str, sig_count: u32, need_to_be_owner: bool) -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count,
+                metadata: Some(json!({"fees": format!("fees_{}", role)})),
+                role: Some(role.to_string()),
+                need_to_be_owner,
+                off_ledger_signature: false,
+            })
+        }
+
+        fn _signer(role: Option<&str>, is_owner: bool) -> AuthRuleSigner {
+            AuthRuleSigner { role: role.map(String::from), is_owner, off_ledger: false }
+        }
+
+        fn _auth_rules() -> Vec<AuthRule> {
+            vec![
+                AuthRule {
+                    auth_type: NYM.to_string(),
+                    auth_action: "ADD".to_string(),
+                    field: "role".to_string(),
+                    old_value: None,
+                    new_value: Some(TRUSTEE.to_string()),
+                    constraint: _role_constraint(TRUSTEE, 2, false),
+                },
+                AuthRule {
+                    auth_type: NYM.to_string(),
+                    auth_action: "ADD".to_string(),
+                    field: "role".to_string(),
+                    old_value: None,
+                    new_value: Some("*".to_string()),
+                    constraint: _role_constraint(TRUSTEE, 1, false),
+                },
+                AuthRule {
+                    auth_type: NYM.to_string(),
+                    auth_action: "EDIT".to_string(),
+                    field: "verkey".to_string(),
+                    old_value: Some("*".to_string()),
+                    new_value: Some("*".to_string()),
+                    constraint: _role_constraint("", 1, true),
+                },
+                AuthRule {
+                    auth_type: SCHEMA.to_string(),
+                    auth_action: "ADD".to_string(),
+                    field: "*".to_string(),
+                    old_value: None,
+                    new_value: Some("*".to_string()),
+                    constraint: Constraint::OrConstraint(CombinationConstraint {
+                        auth_constraints: vec![_role_constraint(TRUSTEE, 1, false), _role_constraint(ENDORSER, 1, false)]
+                    }),
+                },
+                AuthRule {
+                    auth_type: CRED_DEF.to_string(),
+                    auth_action: "ADD".to_string(),
+                    field: "*".to_string(),
+                    old_value: None,
+                    new_value: Some("*".to_string()),
+                    constraint: Constraint::AndConstraint(CombinationConstraint {
+                        auth_constraints: vec![_role_constraint(TRUSTEE, 1, false), _role_constraint(STEWARD, 1, false)]
+                    }),
+                },
+                AuthRule {
+                    auth_type: POOL_UPGRADE.to_string(),
+                    auth_action: "ADD".to_string(),
+                    field: "*".to_string(),
+                    old_value: None,
+                    new_value: Some("*".to_string()),
+                    constraint: Constraint::ForbiddenConstraint(ForbiddenConstraint {}),
+                },
+            ]
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_role_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), "NYM", "ADD", "role", None, Some(ENDORSER),
+                                                     &[_signer(Some(TRUSTEE), false)]).unwrap();
+            assert!(res.allowed);
+            assert_eq!("1 signature(s) of TRUSTEE required, 1 provided", res.explanation);
+            assert_eq!(vec!["fees_0".to_string()], res.fees);
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_specific_rule_preferred() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), NYM, "ADD", "role", None, Some(TRUSTEE),
+                                                     &[_signer(Some("TRUSTEE"), false)]).unwrap();
+            assert!(!res.allowed);
+            assert_eq!("2 signature(s) of TRUSTEE required, 1 provided", res.explanation);
+            assert!(res.fees.is_empty());
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_need_to_be_owner() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), NYM, "EDIT", "verkey", Some("old"), Some("new"),
+                                                     &[_signer(None, false)]).unwrap();
+            assert!(!res.allowed);
+            assert_eq!("1 signature(s) of IDENTITY_OWNER being an owner required, 0 provided", res.explanation);
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), NYM, "EDIT", "verkey", Some("old"), Some("new"),
+                                                     &[_signer(None, true)]).unwrap();
+            assert!(res.allowed);
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_or_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), SCHEMA, "ADD", "*", None, Some("*"),
+                                                     &[_signer(Some(ENDORSER), false)]).unwrap();
+            assert!(res.allowed);
+            assert_eq!("1 signature(s) of ENDORSER required, 1 provided", res.explanation);
+            assert_eq!(vec!["fees_101".to_string()], res.fees);
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), SCHEMA, "ADD", "*", None, Some("*"),
+                                                     &[_signer(None, false)]).unwrap();
+            assert!(!res.allowed);
+            assert_eq!("(1 signature(s) of TRUSTEE required, 0 provided OR 1 signature(s) of ENDORSER required, 0 provided)", res.explanation);
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_and_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), CRED_DEF, "ADD", "*", None, Some("*"),
+                                                     &[_signer(Some(TRUSTEE), false)]).unwrap();
+            assert!(!res.allowed);
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), CRED_DEF, "ADD", "*", None, Some("*"),
+                                                     &[_signer(Some(TRUSTEE), false), _signer(Some(STEWARD), false)]).unwrap();
+            assert!(res.allowed);
+            assert_eq!(vec!["fees_0".to_string(), "fees_2".to_string()], res.fees);
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_forbidden_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), POOL_UPGRADE, "ADD", "action", None, Some("start"),
+                                                     &[_signer(Some(TRUSTEE), false)]).unwrap();
+            assert!(!res.allowed);
+            assert_eq!("action is forbidden", res.explanation);
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_rule_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), ATTRIB, "ADD", "*", None, Some("*"), &[]);
+            assert_eq!(IndyErrorKind::LedgerItemNotFound, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_invalid_action() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), NYM, "WRONG", "role", None, None, &[]);
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn check_auth_rule_works_for_invalid_signer_role() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.check_auth_rule(&_auth_rules(), NYM, "ADD", "role", None, Some(ENDORSER),
+                                                     &[_signer(Some("OWNER"), false)]);
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+    }
+
     mod author_agreement {
         use super::*;
 
@@ -1209,6 +2067,72 @@ mod tests {
             aml
         }
 
+        fn _ledger_taa(retirement_ts: Option<u64>) -> TxnAuthorAgreementData {
+            TxnAuthorAgreementData {
+                text: TEXT.to_string(),
+                version: VERSION.to_string(),
+                digest: None,
+                ratification_ts: Some(12345),
+                retirement_ts,
+            }
+        }
+
+        fn _acceptance(text: &str) -> TxnAuthrAgrmtAcceptanceData {
+            LedgerService::new().prepare_acceptance_data(Some(text), Some(VERSION), None, "on_file", 123379200).unwrap()
+        }
+
+        #[test]
+        fn parse_get_txn_author_agreement_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN_AUTHR_AGRMT,
+                    "reqId": 1,
+                    "data": {"text": TEXT, "version": VERSION, "digest": "abcd", "ratification_ts": 12345}
+                }
+            }).to_string();
+
+            let taa = ledger_service.parse_get_txn_author_agreement_response(&response).unwrap();
+            assert_eq!(TEXT, taa.text);
+            assert_eq!(Some("abcd".to_string()), taa.digest);
+            assert_eq!(None, taa.retirement_ts);
+        }
+
+        #[test]
+        fn parse_get_txn_author_agreement_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_TXN_AUTHR_AGRMT, "reqId": 1, "data": null}
+            }).to_string();
+
+            let res = ledger_service.parse_get_txn_author_agreement_response(&response);
+            assert_eq!(IndyErrorKind::LedgerItemNotFound, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn check_txn_author_agreement_acceptance_works() {
+            let ledger_service = LedgerService::new();
+            ledger_service.check_txn_author_agreement_acceptance(&_acceptance(TEXT), &_ledger_taa(Some(200)), 100).unwrap();
+        }
+
+        #[test]
+        fn check_txn_author_agreement_acceptance_works_for_other_agreement() {
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.check_txn_author_agreement_acceptance(&_acceptance("other agreement"), &_ledger_taa(None), 100);
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn check_txn_author_agreement_acceptance_works_for_retired_agreement() {
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.check_txn_author_agreement_acceptance(&_acceptance(TEXT), &_ledger_taa(Some(100)), 200);
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+        }
+
         #[test]
         fn build_acceptance_mechanisms_request() {
             let ledger_service = LedgerService::new();
@@ -1285,6 +2209,177 @@ mod tests {
         }
     }
 
+    mod did_document {
+        use super::*;
+
+        fn _get_nym_reply(verkey: Option<&str>) -> String {
+            let data = json!({"dest": DEST, "identifier": IDENTIFIER, "role": null, "verkey": verkey}).to_string();
+
+            json!({
+                "op": "REPLY",
+                "result": {"type": GET_NYM, "identifier": IDENTIFIER, "reqId": 1, "seqNo": 10, "txnTime": 1600000000, "dest": DEST, "data": data}
+            }).to_string()
+        }
+
+        fn _get_attrib_reply(endpoint: Option<serde_json::Value>) -> String {
+            let data = endpoint.map(|endpoint| json!({ "endpoint": endpoint }).to_string());
+
+            json!({
+                "op": "REPLY",
+                "result": {"type": GET_ATTR, "identifier": IDENTIFIER, "reqId": 2, "seqNo": 11, "txnTime": 1600000100, "dest": DEST, "raw": "endpoint", "data": data}
+            }).to_string()
+        }
+
+        fn _build_did_document(did: &str, get_nym_reply: &str, get_attrib_reply: Option<&str>) -> serde_json::Value {
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.build_did_document(&DidValue(did.to_string()), get_nym_reply, get_attrib_reply).unwrap();
+            serde_json::from_str(&res).unwrap()
+        }
+
+        #[test]
+        fn parse_resolvable_did_works() {
+            let ledger_service = LedgerService::new();
+
+            let (id, dest) = ledger_service.parse_resolvable_did(&DidValue(DEST.to_string())).unwrap();
+            assert_eq!(format!("did:sov:{}", DEST), id);
+            assert_eq!(DEST, dest.0);
+
+            let did = format!("did:indy:sovrin:staging:{}", DEST);
+            let (id, dest) = ledger_service.parse_resolvable_did(&DidValue(did.clone())).unwrap();
+            assert_eq!(did, id);
+            assert_eq!(DEST, dest.0);
+        }
+
+        #[test]
+        fn parse_get_nym_verkey_works() {
+            let ledger_service = LedgerService::new();
+            let verkey = ledger_service.parse_get_nym_verkey(&_get_nym_reply(Some(VERKEY))).unwrap();
+            assert_eq!(VERKEY, verkey);
+        }
+
+        #[test]
+        fn parse_get_nym_verkey_not_works_for_unknown_did() {
+            let ledger_service = LedgerService::new();
+
+            let get_nym_reply = json!({
+                "op": "REPLY",
+                "result": {"type": GET_NYM, "identifier": IDENTIFIER, "reqId": 1, "seqNo": null, "txnTime": null, "dest": DEST, "data": null}
+            }).to_string();
+
+            let res = ledger_service.parse_get_nym_verkey(&get_nym_reply);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_resolvable_did_not_works_for_other_method() {
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.parse_resolvable_did(&DidValue(format!("did:peer:{}", DEST)));
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_did_document_works_without_endpoint() {
+            let res = _build_did_document(DEST, &_get_nym_reply(Some(VERKEY)), Some(&_get_attrib_reply(None)));
+
+            let expected_document = json!({
+                "@context": ["https://www.w3.org/ns/did/v1"],
+                "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX",
+                "verificationMethod": [{
+                    "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1",
+                    "type": "Ed25519VerificationKey2018",
+                    "controller": "did:sov:VsKV7grR1BUE29mG2Fm2kX",
+                    "publicKeyBase58": VERKEY
+                }],
+                "authentication": ["did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1"],
+                "assertionMethod": ["did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1"]
+            });
+
+            assert_eq!(expected_document, res["didDocument"]);
+            assert_eq!(json!({"contentType": "application/did+ld+json", "seqNo": 10, "txnTime": 1600000000}), res["didResolutionMetadata"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_abbreviated_verkey() {
+            let res = _build_did_document(DEST, &_get_nym_reply(Some("~HYwqs2vrTc8Tn4uBV7NBTe")), None);
+            assert_eq!("GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa", res["didDocument"]["verificationMethod"][0]["publicKeyBase58"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_sov_endpoint() {
+            let endpoint = json!({"endpoint": "https://agent.example.com", "routingKeys": [VERKEY], "types": ["endpoint", "did-communication", "DIDComm"]});
+            let res = _build_did_document(DEST, &_get_nym_reply(Some(VERKEY)), Some(&_get_attrib_reply(Some(endpoint))));
+
+            let expected_services = json!([
+                {
+                    "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX#endpoint",
+                    "type": "endpoint",
+                    "serviceEndpoint": "https://agent.example.com"
+                },
+                {
+                    "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX#did-communication",
+                    "type": "did-communication",
+                    "serviceEndpoint": "https://agent.example.com",
+                    "priority": 0,
+                    "recipientKeys": ["did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1"],
+                    "routingKeys": [VERKEY]
+                },
+                {
+                    "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX#didcomm-1",
+                    "type": "DIDCommMessaging",
+                    "serviceEndpoint": "https://agent.example.com",
+                    "routingKeys": [VERKEY]
+                }
+            ]);
+
+            assert_eq!(expected_services, res["didDocument"]["service"]);
+            assert_eq!(11, res["didResolutionMetadata"]["endpoint"]["seqNo"]);
+            assert_eq!(1600000100, res["didResolutionMetadata"]["endpoint"]["txnTime"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_legacy_endpoint() {
+            let endpoint = json!({"ha": "127.0.0.1:5555", "verkey": VERKEY});
+            let did = format!("did:indy:sovrin:{}", DEST);
+            let res = _build_did_document(&did, &_get_nym_reply(Some(VERKEY)), Some(&_get_attrib_reply(Some(endpoint))));
+
+            let expected_services = json!([
+                {
+                    "id": "did:indy:sovrin:VsKV7grR1BUE29mG2Fm2kX#did-communication",
+                    "type": "did-communication",
+                    "serviceEndpoint": "127.0.0.1:5555",
+                    "priority": 0,
+                    "recipientKeys": [VERKEY]
+                }
+            ]);
+
+            assert_eq!(did, res["didDocument"]["id"]);
+            assert_eq!(expected_services, res["didDocument"]["service"]);
+        }
+
+        #[test]
+        fn build_did_document_not_works_for_unknown_did() {
+            let ledger_service = LedgerService::new();
+
+            let get_nym_reply = json!({
+                "op": "REPLY",
+                "result": {"type": GET_NYM, "identifier": IDENTIFIER, "reqId": 1, "seqNo": null, "txnTime": null, "dest": DEST, "data": null}
+            }).to_string();
+
+            let res = ledger_service.build_did_document(&dest(), &get_nym_reply, None);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn build_did_document_not_works_for_rejected_attrib_request() {
+            let ledger_service = LedgerService::new();
+
+            let get_attrib_reply = json!({"op": "REJECT", "reqId": 2, "reason": "some reason"}).to_string();
+
+            let res = ledger_service.build_did_document(&dest(), &_get_nym_reply(Some(VERKEY)), Some(&get_attrib_reply));
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+    }
+
     #[test]
     fn datetime_to_date() {
         assert_eq!(0, LedgerService::datetime_to_date_timestamp(0));
@@ -64,9 +64,36 @@ impl From<&IssuerCommand> for CommandMetric {
             IssuerCommand::CreateCredential(_, _, _, _, _, _, _) => {
                 CommandMetric::IssuerCommandCreateCredential
             }
+            IssuerCommand::CreateCredentialsBatch(_, _, _, _, _) => {
+                CommandMetric::IssuerCommandCreateCredentialsBatch
+            }
+            IssuerCommand::CreateCredentialsBatchContinue(_, _) => {
+                CommandMetric::IssuerCommandCreateCredentialsBatchContinue
+            }
+            IssuerCommand::CreateRevocationRegistryPool(_, _, _, _, _) => {
+                CommandMetric::IssuerCommandCreateRevocationRegistryPool
+            }
+            IssuerCommand::CreateCredentialFromRevocationRegistryPool(_, _, _, _, _) => {
+                CommandMetric::IssuerCommandCreateCredentialFromRevocationRegistryPool
+            }
+            IssuerCommand::GetRevocationRegistryPoolPendingRequests(_, _, _) => {
+                CommandMetric::IssuerCommandGetRevocationRegistryPoolPendingRequests
+            }
+            IssuerCommand::AddRevocationRegistryToPool(_, _, _) => {
+                CommandMetric::IssuerCommandAddRevocationRegistryToPool
+            }
+            IssuerCommand::ConfirmRevocationRegistryPublished(_, _, _) => {
+                CommandMetric::IssuerCommandConfirmRevocationRegistryPublished
+            }
             IssuerCommand::RevokeCredential(_, _, _, _, _) => {
                 CommandMetric::IssuerCommandRevokeCredential
             }
+            IssuerCommand::RevokeCredentials(_, _, _, _, _) => {
+                CommandMetric::IssuerCommandRevokeCredentials
+            }
+            IssuerCommand::GetRevocationStatusList(_, _, _) => {
+                CommandMetric::IssuerCommandGetRevocationStatusList
+            }
             IssuerCommand::MergeRevocationRegistryDeltas(_, _, _) => {
                 CommandMetric::IssuerCommandMergeRevocationRegistryDeltas
             }
@@ -95,6 +122,8 @@ impl From<&ProverCommand> for CommandMetric {
             ProverCommand::CreateProof(_, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateProof }
             ProverCommand::CreateRevocationState(_, _, _, _, _, _) => { CommandMetric::ProverCommandCreateRevocationState }
             ProverCommand::UpdateRevocationState(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandUpdateRevocationState }
+            ProverCommand::RefreshRevocationStates(_, _, _, _, _) => { CommandMetric::ProverCommandRefreshRevocationStates }
+            ProverCommand::RefreshRevocationStatesContinue(_, _) => { CommandMetric::ProverCommandRefreshRevocationStatesContinue }
         }
     }
 }
@@ -103,6 +132,7 @@ impl From<&VerifierCommand> for CommandMetric {
     fn from(cmd: &VerifierCommand) -> Self {
         match cmd {
             VerifierCommand::VerifyProof(_, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProof }
+            VerifierCommand::VerifyProofWithDetails(_, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProofWithDetails }
             VerifierCommand::GenerateNonce(_) => { CommandMetric::VerifierCommandGenerateNonce }
         }
     }
@@ -118,6 +148,7 @@ impl From<&Command> for CommandMetric {
                     AnoncredsCommand::Prover(cmd) => { cmd.into() }
                     AnoncredsCommand::Verifier(cmd) => { cmd.into() }
                     AnoncredsCommand::ToUnqualified(_, _) => { CommandMetric::AnoncredsCommandToUnqualified }
+                    AnoncredsCommand::EncodeCredentialAttributes(_, _) => { CommandMetric::AnoncredsCommandEncodeCredentialAttributes }
                 }
             }
             Command::BlobStorage(cmd) => {
@@ -128,24 +159,34 @@ impl From<&Command> for CommandMetric {
             }
             Command::Crypto(cmd) => {
                 match cmd {
+                    CryptoCommand::RegisterKeyBackend(_, _, _) => { CommandMetric::CryptoCommandRegisterKeyBackend }
                     CryptoCommand::CreateKey(_, _, _) => { CommandMetric::CryptoCommandCreateKey }
                     CryptoCommand::SetKeyMetadata(_, _, _, _) => { CommandMetric::CryptoCommandSetKeyMetadata }
                     CryptoCommand::GetKeyMetadata(_, _, _) => { CommandMetric::CryptoCommandGetKeyMetadata }
                     CryptoCommand::CryptoSign(_, _, _, _) => { CommandMetric::CryptoCommandCryptoSign }
                     CryptoCommand::CryptoVerify(_, _, _, _) => { CommandMetric::CryptoCommandCryptoVerify }
+                    CryptoCommand::CryptoSignJws(_, _, _, _, _) => { CommandMetric::CryptoCommandCryptoSignJws }
+                    CryptoCommand::CryptoVerifyJws(_, _, _, _) => { CommandMetric::CryptoCommandCryptoVerifyJws }
                     CryptoCommand::AuthenticatedEncrypt(_, _, _, _, _) => { CommandMetric::CryptoCommandAuthenticatedEncrypt }
                     CryptoCommand::AuthenticatedDecrypt(_, _, _, _) => { CommandMetric::CryptoCommandAuthenticatedDecrypt }
                     CryptoCommand::AnonymousEncrypt(_, _, _) => { CommandMetric::CryptoCommandAnonymousEncrypt }
                     CryptoCommand::AnonymousDecrypt(_, _, _, _) => { CommandMetric::CryptoCommandAnonymousDecrypt }
                     CryptoCommand::PackMessage(_, _, _, _, _) => { CommandMetric::CryptoCommandPackMessage }
                     CryptoCommand::UnpackMessage(_, _, _) => { CommandMetric::CryptoCommandUnpackMessage }
+                    CryptoCommand::PackMessageV2(_, _, _, _, _, _) => { CommandMetric::CryptoCommandPackMessageV2 }
+                    CryptoCommand::UnpackMessageV2(_, _, _) => { CommandMetric::CryptoCommandUnpackMessageV2 }
                 }
             }
             Command::Ledger(cmd) => {
                 match cmd {
                     LedgerCommand::SignAndSubmitRequest(_, _, _, _, _) => { CommandMetric::LedgerCommandSignAndSubmitRequest }
+                    LedgerCommand::SignAndSubmitRequestContinue(_, _, _, _, _, _) => { CommandMetric::LedgerCommandSignAndSubmitRequestContinue }
                     LedgerCommand::SubmitRequest(_, _, _) => { CommandMetric::LedgerCommandSubmitRequest }
                     LedgerCommand::SubmitAck(_, _) => { CommandMetric::LedgerCommandSubmitAck }
+                    LedgerCommand::SubmitRequestWithDetails(_, _, _) => { CommandMetric::LedgerCommandSubmitRequestWithDetails }
+                    LedgerCommand::SubmitConsensusDetails(_, _) => { CommandMetric::LedgerCommandSubmitConsensusDetails }
+                    LedgerCommand::SubmitRequestsBatch(_, _, _, _) => { CommandMetric::LedgerCommandSubmitRequestsBatch }
+                    LedgerCommand::SubmitRequestsBatchContinue(_, _, _) => { CommandMetric::LedgerCommandSubmitRequestsBatchContinue }
                     LedgerCommand::SubmitAction(_, _, _, _, _) => { CommandMetric::LedgerCommandSubmitAction }
                     LedgerCommand::SignRequest(_, _, _, _) => { CommandMetric::LedgerCommandSignRequest }
                     LedgerCommand::MultiSignRequest(_, _, _, _) => { CommandMetric::LedgerCommandMultiSignRequest }
@@ -177,9 +218,15 @@ impl From<&Command> for CommandMetric {
                     LedgerCommand::ParseGetRevocRegDeltaResponse(_, _) => { CommandMetric::LedgerCommandParseGetRevocRegDeltaResponse }
                     LedgerCommand::RegisterSPParser(_, _, _, _) => { CommandMetric::LedgerCommandRegisterSPParser }
                     LedgerCommand::GetResponseMetadata(_, _) => { CommandMetric::LedgerCommandGetResponseMetadata }
+                    LedgerCommand::VerifyTxnInclusion(_, _, _) => { CommandMetric::LedgerCommandVerifyTxnInclusion }
                     LedgerCommand::BuildAuthRuleRequest(_, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildAuthRuleRequest }
                     LedgerCommand::BuildAuthRulesRequest(_, _, _) => { CommandMetric::LedgerCommandBuildAuthRulesRequest }
                     LedgerCommand::BuildGetAuthRuleRequest(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetAuthRuleRequest }
+                    LedgerCommand::CheckAuthRule(_, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandCheckAuthRule }
+                    LedgerCommand::BuildRichSchemaRequest(_, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildRichSchemaRequest }
+                    LedgerCommand::BuildGetRichSchemaObjectByIdRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetRichSchemaObjectByIdRequest }
+                    LedgerCommand::BuildGetRichSchemaObjectByMetadataRequest(_, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetRichSchemaObjectByMetadataRequest }
+                    LedgerCommand::ParseGetRichSchemaObjectResponse(_, _) => { CommandMetric::LedgerCommandParseGetRichSchemaObjectResponse }
                     LedgerCommand::GetSchema(_, _, _, _) => { CommandMetric::LedgerCommandGetSchema }
                     LedgerCommand::GetSchemaContinue(_, _, _) => { CommandMetric::LedgerCommandGetSchemaContinue }
                     LedgerCommand::GetCredDef(_, _, _, _) => { CommandMetric::LedgerCommandGetCredDef }
@@ -190,6 +237,12 @@ impl From<&Command> for CommandMetric {
                     LedgerCommand::BuildAcceptanceMechanismRequests(_, _, _, _, _) => { CommandMetric::LedgerCommandBuildAcceptanceMechanismRequests }
                     LedgerCommand::BuildGetAcceptanceMechanismsRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildGetAcceptanceMechanismsRequest }
                     LedgerCommand::AppendTxnAuthorAgreementAcceptanceToRequest(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest }
+                    LedgerCommand::SetTxnAuthorAgreementAcceptanceForPool(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandSetTxnAuthorAgreementAcceptanceForPool }
+                    LedgerCommand::PrepareRequestForEndorser(_, _, _, _, _) => { CommandMetric::LedgerCommandPrepareRequestForEndorser }
+                    LedgerCommand::EndorsePendingTransaction(_, _, _, _, _) => { CommandMetric::LedgerCommandEndorsePendingTransaction }
+                    LedgerCommand::EndorsePendingTransactionNymContinue(_, _, _, _, _, _) => { CommandMetric::LedgerCommandEndorsePendingTransactionNymContinue }
+                    LedgerCommand::EndorsePendingTransactionAuthorNymContinue(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandEndorsePendingTransactionAuthorNymContinue }
+                    LedgerCommand::EndorsePendingTransactionAuthRulesContinue(_, _, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandEndorsePendingTransactionAuthRulesContinue }
                     LedgerCommand::AppendRequestEndorser(_, _, _) => { CommandMetric::LedgerCommandAppendRequestEndorser }
                     LedgerCommand::BuildGetFrozenLedgersRequest(_,_,) => { CommandMetric::LedgerCommandBuildGetFrozenLedgersRequest }
                     LedgerCommand::BuildLedgersFreezeRequest(_,_,_,) => { CommandMetric::LedgerCommandBuildLedgersFreezeRequest }
@@ -207,6 +260,11 @@ impl From<&Command> for CommandMetric {
                     PoolCommand::Refresh(_, _) => { CommandMetric::PoolCommandRefresh }
                     PoolCommand::RefreshAck(_, _) => { CommandMetric::PoolCommandRefreshAck }
                     PoolCommand::SetProtocolVersion(_, _) => { CommandMetric::PoolCommandSetProtocolVersion }
+                    PoolCommand::GetLocalTxn(_, _, _, _) => { CommandMetric::PoolCommandGetLocalTxn }
+                    PoolCommand::GetLocalTxnsByType(_, _, _, _, _, _) => { CommandMetric::PoolCommandGetLocalTxnsByType }
+                    PoolCommand::Diagnose(_, _, _) => { CommandMetric::PoolCommandDiagnose }
+                    PoolCommand::DiagnoseAck(_, _) => { CommandMetric::PoolCommandDiagnoseAck }
+                    PoolCommand::RegisterTransport(_, _, _) => { CommandMetric::PoolCommandRegisterTransport }
                 }
             }
             Command::Did(cmd) => {
@@ -219,19 +277,25 @@ impl From<&Command> for CommandMetric {
                     DidCommand::ListMyDidsWithMeta(_, _) => { CommandMetric::DidCommandListMyDidsWithMeta }
                     DidCommand::KeyForDid(_, _, _, _) => { CommandMetric::DidCommandKeyForDid }
                     DidCommand::KeyForLocalDid(_, _, _) => { CommandMetric::DidCommandKeyForLocalDid }
+                    DidCommand::KeyForDidAt(_, _, _, _, _) => { CommandMetric::DidCommandKeyForDidAt }
                     DidCommand::SetEndpointForDid(_, _, _, _) => { CommandMetric::DidCommandSetEndpointForDid }
                     DidCommand::GetEndpointForDid(_, _, _, _) => { CommandMetric::DidCommandGetEndpointForDid }
+                    DidCommand::ResolveDid(_, _, _) => { CommandMetric::DidCommandResolveDid }
                     DidCommand::SetDidMetadata(_, _, _, _) => { CommandMetric::DidCommandSetDidMetadata }
                     DidCommand::GetDidMetadata(_, _, _) => { CommandMetric::DidCommandGetDidMetadata }
                     DidCommand::AbbreviateVerkey(_, _, _) => { CommandMetric::DidCommandAbbreviateVerkey }
                     DidCommand::GetNymAck(_, _, _, _) => { CommandMetric::DidCommandGetNymAck }
                     DidCommand::GetAttribAck(_, _, _) => { CommandMetric::DidCommandGetAttribAck }
+                    DidCommand::ResolveDidGetNymAck(_, _, _, _) => { CommandMetric::DidCommandResolveDidGetNymAck }
+                    DidCommand::ResolveDidGetAttribAck(_, _, _, _) => { CommandMetric::DidCommandResolveDidGetAttribAck }
+                    DidCommand::KeyForDidAtGetNymAck(_, _) => { CommandMetric::DidCommandKeyForDidAtGetNymAck }
                     DidCommand::QualifyDid(_, _, _, _) => { CommandMetric::DidCommandQualifyDid }
                 }
             }
             Command::Wallet(cmd) => {
                 match cmd {
                     WalletCommand::RegisterWalletType(_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _) => { CommandMetric::WalletCommandRegisterWalletType }
+                    WalletCommand::RegisterWalletTransactions(_, _, _, _, _) => { CommandMetric::WalletCommandRegisterWalletTransactions }
                     WalletCommand::Create(_, _, _) => { CommandMetric::WalletCommandCreate }
                     WalletCommand::CreateContinue(_, _, _, _, _) => { CommandMetric::WalletCommandCreateContinue }
                     WalletCommand::Open(_, _, _) => { CommandMetric::WalletCommandOpen }
@@ -243,6 +307,12 @@ impl From<&Command> for CommandMetric {
                     WalletCommand::ExportContinue(_, _, _, _, _) => { CommandMetric::WalletCommandExportContinue }
                     WalletCommand::Import(_, _, _, _) => { CommandMetric::WalletCommandImport }
                     WalletCommand::ImportContinue(_, _, _, _, _) => { CommandMetric::WalletCommandImportContinue }
+                    WalletCommand::ImportPlaintextContinue(_, _, _, _, _) => { CommandMetric::WalletCommandImportPlaintextContinue }
+                    WalletCommand::RotateKey(_, _, _) => { CommandMetric::WalletCommandRotateKey }
+                    WalletCommand::RotateKeyContinue(_, _, _, _) => { CommandMetric::WalletCommandRotateKeyContinue }
+                    WalletCommand::BeginTransaction(_, _) => { CommandMetric::WalletCommandBeginTransaction }
+                    WalletCommand::CommitTransaction(_, _) => { CommandMetric::WalletCommandCommitTransaction }
+                    WalletCommand::RollbackTransaction(_, _) => { CommandMetric::WalletCommandRollbackTransaction }
                     WalletCommand::GenerateKey(_, _) => { CommandMetric::WalletCommandGenerateKey }
                     WalletCommand::DeriveKey(_, _) => { CommandMetric::WalletCommandDeriveKey }
                 }
@@ -339,7 +409,16 @@ pub enum CommandMetric {
     IssuerCommandCreateAndStoreRevocationRegistry,
     IssuerCommandCreateCredentialOffer,
     IssuerCommandCreateCredential,
+    IssuerCommandCreateCredentialsBatch,
+    IssuerCommandCreateCredentialsBatchContinue,
+    IssuerCommandCreateRevocationRegistryPool,
+    IssuerCommandCreateCredentialFromRevocationRegistryPool,
+    IssuerCommandGetRevocationRegistryPoolPendingRequests,
+    IssuerCommandAddRevocationRegistryToPool,
+    IssuerCommandConfirmRevocationRegistryPublished,
     IssuerCommandRevokeCredential,
+    IssuerCommandRevokeCredentials,
+    IssuerCommandGetRevocationStatusList,
     IssuerCommandMergeRevocationRegistryDeltas,
     // ProverCommand
     ProverCommandCreateMasterSecret,
@@ -360,30 +439,44 @@ pub enum CommandMetric {
     ProverCommandCreateProof,
     ProverCommandCreateRevocationState,
     ProverCommandUpdateRevocationState,
+    ProverCommandRefreshRevocationStates,
+    ProverCommandRefreshRevocationStatesContinue,
     // VerifierCommand
     VerifierCommandVerifyProof,
+    VerifierCommandVerifyProofWithDetails,
     VerifierCommandGenerateNonce,
     // AnoncredsCommand
     AnoncredsCommandToUnqualified,
+    AnoncredsCommandEncodeCredentialAttributes,
     // BlobStorage
     BlobStorageCommandOpenReader,
     BlobStorageCommandOpenWriter,
     // CryptoCommand
+    CryptoCommandRegisterKeyBackend,
     CryptoCommandCreateKey,
     CryptoCommandSetKeyMetadata,
     CryptoCommandGetKeyMetadata,
     CryptoCommandCryptoSign,
     CryptoCommandCryptoVerify,
+    CryptoCommandCryptoSignJws,
+    CryptoCommandCryptoVerifyJws,
     CryptoCommandAuthenticatedEncrypt,
     CryptoCommandAuthenticatedDecrypt,
     CryptoCommandAnonymousEncrypt,
     CryptoCommandAnonymousDecrypt,
     CryptoCommandPackMessage,
     CryptoCommandUnpackMessage,
+    CryptoCommandPackMessageV2,
+    CryptoCommandUnpackMessageV2,
     LedgerCommandSignAndSubmitRequest,
+    LedgerCommandSignAndSubmitRequestContinue,
     // LedgerCommand
     LedgerCommandSubmitRequest,
     LedgerCommandSubmitAck,
+    LedgerCommandSubmitRequestWithDetails,
+    LedgerCommandSubmitConsensusDetails,
+    LedgerCommandSubmitRequestsBatch,
+    LedgerCommandSubmitRequestsBatchContinue,
     LedgerCommandSubmitAction,
     LedgerCommandSignRequest,
     LedgerCommandMultiSignRequest,
@@ -415,9 +508,15 @@ pub enum CommandMetric {
     LedgerCommandParseGetRevocRegDeltaResponse,
     LedgerCommandRegisterSPParser,
     LedgerCommandGetResponseMetadata,
+    LedgerCommandVerifyTxnInclusion,
     LedgerCommandBuildAuthRuleRequest,
     LedgerCommandBuildAuthRulesRequest,
     LedgerCommandBuildGetAuthRuleRequest,
+    LedgerCommandCheckAuthRule,
+    LedgerCommandBuildRichSchemaRequest,
+    LedgerCommandBuildGetRichSchemaObjectByIdRequest,
+    LedgerCommandBuildGetRichSchemaObjectByMetadataRequest,
+    LedgerCommandParseGetRichSchemaObjectResponse,
     LedgerCommandGetSchema,
     LedgerCommandGetSchemaContinue,
     LedgerCommandGetCredDef,
@@ -428,6 +527,12 @@ pub enum CommandMetric {
     LedgerCommandBuildAcceptanceMechanismRequests,
     LedgerCommandBuildGetAcceptanceMechanismsRequest,
     LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest,
+    LedgerCommandSetTxnAuthorAgreementAcceptanceForPool,
+    LedgerCommandPrepareRequestForEndorser,
+    LedgerCommandEndorsePendingTransaction,
+    LedgerCommandEndorsePendingTransactionNymContinue,
+    LedgerCommandEndorsePendingTransactionAuthorNymContinue,
+    LedgerCommandEndorsePendingTransactionAuthRulesContinue,
     LedgerCommandAppendRequestEndorser,
     LedgerCommandBuildGetFrozenLedgersRequest,
     LedgerCommandBuildLedgersFreezeRequest,
@@ -442,6 +547,11 @@ pub enum CommandMetric {
     PoolCommandRefresh,
     PoolCommandRefreshAck,
     PoolCommandSetProtocolVersion,
+    PoolCommandGetLocalTxn,
+    PoolCommandGetLocalTxnsByType,
+    PoolCommandDiagnose,
+    PoolCommandDiagnoseAck,
+    PoolCommandRegisterTransport,
     // DidCommand
     DidCommandCreateAndStoreMyDid,
     DidCommandReplaceKeysStart,
@@ -451,16 +561,22 @@ pub enum CommandMetric {
     DidCommandListMyDidsWithMeta,
     DidCommandKeyForDid,
     DidCommandKeyForLocalDid,
+    DidCommandKeyForDidAt,
     DidCommandSetEndpointForDid,
     DidCommandGetEndpointForDid,
+    DidCommandResolveDid,
     DidCommandSetDidMetadata,
     DidCommandGetDidMetadata,
     DidCommandAbbreviateVerkey,
     DidCommandGetNymAck,
     DidCommandGetAttribAck,
+    DidCommandResolveDidGetNymAck,
+    DidCommandResolveDidGetAttribAck,
+    DidCommandKeyForDidAtGetNymAck,
     DidCommandQualifyDid,
     // WalletCommand
     WalletCommandRegisterWalletType,
+    WalletCommandRegisterWalletTransactions,
     WalletCommandCreate,
     WalletCommandCreateContinue,
     WalletCommandOpen,
@@ -472,6 +588,12 @@ pub enum CommandMetric {
     WalletCommandExportContinue,
     WalletCommandImport,
     WalletCommandImportContinue,
+    WalletCommandImportPlaintextContinue,
+    WalletCommandRotateKey,
+    WalletCommandRotateKeyContinue,
+    WalletCommandBeginTransaction,
+    WalletCommandCommitTransaction,
+    WalletCommandRollbackTransaction,
     WalletCommandGenerateKey,
     WalletCommandDeriveKey,
     // PairwiseCommand
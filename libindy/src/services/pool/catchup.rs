@@ -21,7 +21,7 @@ pub enum CatchupProgress {
     InProgress,
 }
 
-pub fn build_catchup_req(merkle: &MerkleTree, target_mt_size: usize) -> IndyResult<Option<(String, String)>> {
+pub fn build_catchup_req(merkle: &MerkleTree, target_mt_size: usize, ledger_id: usize) -> IndyResult<Option<(String, String)>> {
     if merkle.count() >= target_mt_size  {
         warn!("No transactions to catch up!");
         return Ok(None);
@@ -30,7 +30,7 @@ pub fn build_catchup_req(merkle: &MerkleTree, target_mt_size: usize) -> IndyResu
     let seq_no_end = target_mt_size;
 
     let cr = CatchupReq {
-        ledgerId: 0,
+        ledgerId: ledger_id,
         seqNoStart: seq_no_start,
         seqNoEnd: seq_no_end,
         catchupTill: target_mt_size,
@@ -50,6 +50,25 @@ pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Opt
                                        node_cnt: usize,
                                        f: usize,
                                        pool_name: &str) -> IndyResult<CatchupProgress> {
+    match get_nodes_consensus(nodes_votes, node_cnt, f) {
+        Ok(Some(target)) => try_to_catch_up(&target, merkle_tree).or_else(|err| {
+            if merkle_tree_factory::drop_cache(pool_name).is_ok() {
+                let merkle_tree = merkle_tree_factory::create(pool_name)?;
+                try_to_catch_up(&target, &merkle_tree)
+            } else {
+                Err(err)
+            }
+        }),
+        Ok(None) => Ok(CatchupProgress::InProgress),
+        Err(err) => _try_to_restart_catch_up(pool_name, err)
+    }
+}
+
+/// Returns ledger status f + 1 nodes agreed on or `None` if there are not enough replies yet.
+/// Fails if consensus can't be reached anymore. Doesn't touch the local copy of the ledger.
+pub fn get_nodes_consensus(nodes_votes: &HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
+                           node_cnt: usize,
+                           f: usize) -> IndyResult<Option<(String, usize, Option<Vec<String>>)>> {
     let (votes, timeout_votes): (HashMap<&(String, usize, Option<Vec<String>>), usize>, HashMap<&(String, usize, Option<Vec<String>>), usize>) =
         nodes_votes
             .iter()
@@ -65,40 +84,32 @@ pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Opt
 
     if let Some((most_popular_not_timeout_vote, votes_cnt)) = most_popular_not_timeout {
         if *votes_cnt == f + 1 {
-            return _try_to_catch_up(most_popular_not_timeout_vote, merkle_tree).or_else(|err| {
-                if merkle_tree_factory::drop_cache(pool_name).is_ok() {
-                    let merkle_tree = merkle_tree_factory::create(pool_name)?;
-                    _try_to_catch_up(most_popular_not_timeout_vote, &merkle_tree)
-                } else {
-                    Err(err)
-                }
-            });
+            return Ok(Some((*most_popular_not_timeout_vote).clone()));
         } else {
-            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f, pool_name);
+            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f);
         }
     } else if let Some((_, votes_cnt)) = timeout_votes {
         if *votes_cnt == node_cnt - f {
-            return _try_to_restart_catch_up(pool_name, err_msg(IndyErrorKind::PoolTimeout, "Pool timeout"));
+            return Err(err_msg(IndyErrorKind::PoolTimeout, "Pool timeout"));
         } else {
-            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f, pool_name);
+            return _if_consensus_reachable(nodes_votes, node_cnt, *votes_cnt, f);
         }
     }
-    Ok(CatchupProgress::InProgress)
+    Ok(None)
 }
 
 fn _if_consensus_reachable(nodes_votes: &HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
                            node_cnt: usize,
                            votes_cnt: usize,
-                           f: usize,
-                           pool_name: &str) -> IndyResult<CatchupProgress> {
+                           f: usize) -> IndyResult<Option<(String, usize, Option<Vec<String>>)>> {
     let reps_cnt: usize = nodes_votes.values().map(HashSet::len).sum();
     let positive_votes_cnt = votes_cnt + (node_cnt - reps_cnt);
     let is_consensus_not_reachable = positive_votes_cnt < node_cnt - f;
     if is_consensus_not_reachable {
         //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-        _try_to_restart_catch_up(pool_name, err_msg(IndyErrorKind::PoolTimeout, "No consensus possible"))
+        Err(err_msg(IndyErrorKind::PoolTimeout, "No consensus possible"))
     } else {
-        Ok(CatchupProgress::InProgress)
+        Ok(None)
     }
 }
 
@@ -112,7 +123,7 @@ fn _try_to_restart_catch_up(pool_name: &str, err: IndyError) -> IndyResult<Catch
     }
 }
 
-fn _try_to_catch_up(ledger_status: &(String, usize, Option<Vec<String>>), merkle_tree: &MerkleTree) -> IndyResult<CatchupProgress> {
+pub fn try_to_catch_up(ledger_status: &(String, usize, Option<Vec<String>>), merkle_tree: &MerkleTree) -> IndyResult<CatchupProgress> {
     let &(ref target_mt_root, target_mt_size, ref hashes) = ledger_status;
     let cur_mt_size = merkle_tree.count();
     let cur_mt_hash = merkle_tree.root_hash().to_base58();
@@ -0,0 +1,429 @@
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use byteorder::{ByteOrder, LittleEndian};
+use rust_base58::{FromBase58, ToBase58};
+use serde_json;
+
+use crate::domain::pool::{BlsKeyStatus, NodeDiagnostics, NodeStatus, PoolConsensusStatus, PoolDiagnostics, PoolOpenConfig};
+use indy_api_types::errors::prelude::*;
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::pool::catchup::{CatchupProgress, get_nodes_consensus, try_to_catch_up};
+use crate::services::pool::ledger_mirror::POOL_LEDGER_ID;
+use crate::services::pool::{merkle_tree_factory, pool};
+use crate::services::pool::request_handler::DEFAULT_GENERATOR;
+use crate::services::pool::types::{Message, NodeTransactionV1, RemoteNode};
+
+use super::ursa::bls::{Bls, Generator, ProofOfPossession};
+use super::zmq;
+
+// Socket monitor events (see zmq_socket_monitor in zmq.h)
+const ZMQ_EVENT_CONNECTED: u16 = 0x0001;
+const ZMQ_EVENT_HANDSHAKE_FAILED_NO_DETAIL: u16 = 0x0800;
+const ZMQ_EVENT_HANDSHAKE_SUCCEEDED: u16 = 0x1000;
+const ZMQ_EVENT_HANDSHAKE_FAILED_PROTOCOL: u16 = 0x2000;
+const ZMQ_EVENT_HANDSHAKE_FAILED_AUTH: u16 = 0x4000;
+const ZMQ_EVENT_ALL: i32 = 0xFFFF;
+
+type Votes = HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>;
+
+struct NodeConnection {
+    idx: usize,
+    socket: zmq::Socket,
+    monitor: zmq::Socket,
+    sent_at: Instant,
+    connected: bool,
+    handshake_failed: bool,
+}
+
+impl NodeConnection {
+    fn process_monitor_events(&mut self) {
+        while let Ok(frames) = self.monitor.recv_multipart(zmq::DONTWAIT) {
+            if frames.is_empty() || frames[0].len() < 2 {
+                continue;
+            }
+
+            match LittleEndian::read_u16(&frames[0][0..2]) {
+                ZMQ_EVENT_CONNECTED | ZMQ_EVENT_HANDSHAKE_SUCCEEDED => self.connected = true,
+                ZMQ_EVENT_HANDSHAKE_FAILED_NO_DETAIL | ZMQ_EVENT_HANDSHAKE_FAILED_PROTOCOL | ZMQ_EVENT_HANDSHAKE_FAILED_AUTH => {
+                    self.connected = true;
+                    self.handshake_failed = true;
+                }
+                _ => ()
+            }
+        }
+    }
+
+    /// Returns merkle root, ledger size and consistency proof reported by the node.
+    fn recv_ledger_status(&self) -> Option<(String, usize, Option<Vec<String>>)> {
+        while let Ok(Ok(msg)) = self.socket.recv_string(zmq::DONTWAIT) {
+            match Message::from_raw_str(&msg) {
+                Ok(Message::LedgerStatus(ls)) if ls.ledgerId as usize == POOL_LEDGER_ID =>
+                    return Some((ls.merkleRoot, ls.txnSeqNo, None)),
+                Ok(Message::ConsistencyProof(cp)) if cp.ledgerId == POOL_LEDGER_ID =>
+                    return Some((cp.newMerkleRoot, cp.seqNoEnd, Some(cp.hashes))),
+                _ => trace!("diagnose: skip message {:?}", msg)
+            }
+        }
+        None
+    }
+}
+
+/// Checks nodes of the pool without opening it.
+///
+/// LEDGER_STATUS of the local copy of pool ledger is sent to every validator node.
+/// Answers are voted in the same way as on opening the pool, so the report shows
+/// what `indy_open_pool_ledger` would do together with per node connection problems.
+pub fn diagnose(pool_name: &str, config: &PoolOpenConfig) -> IndyResult<PoolDiagnostics> {
+    let merkle = merkle_tree_factory::create(pool_name)?;
+    let node_txns = merkle_tree_factory::build_node_state(&merkle)?;
+
+    let generator = DEFAULT_GENERATOR.from_base58()
+        .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Invalid BLS generator"))?;
+    let generator = Generator::from_bytes(&generator)?;
+
+    let mut node_txns: Vec<&NodeTransactionV1> = node_txns.values().collect();
+    node_txns.sort_by(|a, b| a.txn.data.data.alias.cmp(&b.txn.data.data.alias));
+
+    let mut nodes: Vec<NodeDiagnostics> = Vec::new();
+    let mut remotes: Vec<(usize, RemoteNode)> = Vec::new();
+
+    for txn in node_txns {
+        let mut node = NodeDiagnostics {
+            alias: txn.txn.data.data.alias.clone(),
+            address: None,
+            status: NodeStatus::Unreachable,
+            latency_ms: None,
+            merkle_root: None,
+            ledger_size: None,
+            bls_key: _check_bls_key(txn, &generator),
+            error: None,
+        };
+
+        match pool::parse_node_remote(txn) {
+            Ok(remote) => {
+                node.address = Some(remote.zaddr.clone());
+                remotes.push((nodes.len(), remote));
+            }
+            Err(err) => {
+                node.status = NodeStatus::Invalid;
+                node.error = Some(err.to_string());
+            }
+        }
+
+        nodes.push(node);
+    }
+
+    let node_cnt = remotes.len();
+    let f = pool::get_f(node_cnt);
+
+    let ledger_status = serde_json::to_string(&Message::LedgerStatus(pool::ledger_status(&merkle)))
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus")?;
+
+    let ctx = zmq::Context::new();
+    let key_pair = zmq::CurveKeyPair::new()?;
+
+    let mut connections: Vec<NodeConnection> = Vec::new();
+
+    for (idx, remote) in remotes {
+        match _connect(&ctx, &key_pair, &remote, idx, &config.socks_proxy, &ledger_status) {
+            Ok(connection) => connections.push(connection),
+            Err(err) => nodes[idx].error = Some(err.to_string()),
+        }
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(config.timeout as u64);
+
+    let mut votes: Votes = HashMap::new();
+    let mut progress: Option<IndyResult<CatchupProgress>> = None;
+
+    while connections.iter().any(|conn| nodes[conn.idx].status != NodeStatus::Reachable) {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+
+        let readable: Vec<(bool, bool)> = {
+            let mut poll_items: Vec<zmq::PollItem> = connections.iter()
+                .flat_map(|conn| vec![conn.socket.as_poll_item(zmq::POLLIN), conn.monitor.as_poll_item(zmq::POLLIN)])
+                .collect();
+
+            zmq::poll(&mut poll_items, (deadline - now).as_millis() as i64)?;
+
+            poll_items.chunks(2).map(|items| (items[0].is_readable(), items[1].is_readable())).collect()
+        };
+
+        for (conn, (socket_readable, monitor_readable)) in connections.iter_mut().zip(readable) {
+            if monitor_readable {
+                conn.process_monitor_events();
+            }
+
+            if !socket_readable || nodes[conn.idx].status == NodeStatus::Reachable {
+                continue;
+            }
+
+            if let Some((merkle_root, ledger_size, hashes)) = conn.recv_ledger_status() {
+                let node = &mut nodes[conn.idx];
+                node.status = NodeStatus::Reachable;
+                node.latency_ms = Some(conn.sent_at.elapsed().as_millis() as u64);
+                node.merkle_root = Some(merkle_root.clone());
+                node.ledger_size = Some(ledger_size);
+
+                _add_vote(&mut votes, (merkle_root, ledger_size, hashes), &node.alias);
+                _check_consensus(&mut progress, &votes, &merkle, node_cnt, f);
+            }
+        }
+    }
+
+    for conn in connections.iter_mut() {
+        conn.process_monitor_events();
+
+        let node = &mut nodes[conn.idx];
+        if node.status == NodeStatus::Reachable {
+            continue;
+        }
+
+        node.status = if conn.handshake_failed {
+            NodeStatus::HandshakeFailed
+        } else if conn.connected {
+            NodeStatus::NoReply
+        } else {
+            NodeStatus::Unreachable
+        };
+    }
+
+    for node in nodes.iter().filter(|node| node.address.is_some() && node.status != NodeStatus::Reachable) {
+        _add_vote(&mut votes, ("timeout".to_string(), 0, None), &node.alias);
+        _check_consensus(&mut progress, &votes, &merkle, node_cnt, f);
+    }
+
+    let (consensus, consensus_merkle_root, consensus_ledger_size, error) = match progress {
+        Some(Ok(CatchupProgress::NotNeeded(_))) =>
+            (PoolConsensusStatus::Synced, Some(merkle.root_hash().to_base58()), Some(merkle.count()), None),
+        Some(Ok(CatchupProgress::ShouldBeStarted(target_mt_root, target_mt_size, _))) =>
+            (PoolConsensusStatus::CatchupNeeded, Some(target_mt_root.to_base58()), Some(target_mt_size), None),
+        Some(Ok(CatchupProgress::InProgress)) | Some(Ok(CatchupProgress::Restart(_))) | None => (PoolConsensusStatus::NotReached, None, None, None),
+        Some(Err(err)) => (PoolConsensusStatus::Failed, None, None, Some(err.to_string())),
+    };
+
+    Ok(PoolDiagnostics {
+        f,
+        local_merkle_root: merkle.root_hash().to_base58(),
+        local_ledger_size: merkle.count(),
+        consensus,
+        consensus_merkle_root,
+        consensus_ledger_size,
+        error,
+        nodes,
+    })
+}
+
+fn _connect(ctx: &zmq::Context, key_pair: &zmq::CurveKeyPair, remote: &RemoteNode, idx: usize,
+            socks_proxy: &str, ledger_status: &str) -> IndyResult<NodeConnection> {
+    let socket = remote.connect(ctx, key_pair, socks_proxy.to_string())?;
+
+    let monitor_addr = format!("inproc://diagnose_monitor_{}", idx);
+    socket.monitor(&monitor_addr, ZMQ_EVENT_ALL)?;
+
+    let monitor = ctx.socket(zmq::SocketType::PAIR)?;
+    monitor.connect(&monitor_addr)?;
+
+    socket.send(ledger_status, zmq::DONTWAIT)?;
+
+    Ok(NodeConnection {
+        idx,
+        socket,
+        monitor,
+        sent_at: Instant::now(),
+        connected: false,
+        handshake_failed: false,
+    })
+}
+
+fn _add_vote(votes: &mut Votes, key: (String, usize, Option<Vec<String>>), node_alias: &str) {
+    votes.entry(key)
+        .or_insert_with(HashSet::new)
+        .insert(node_alias.to_string());
+}
+
+/// Diagnostics only reports the consensus, local copy of pool ledger is left intact.
+fn _check_consensus(progress: &mut Option<IndyResult<CatchupProgress>>, votes: &Votes, merkle: &MerkleTree,
+                    node_cnt: usize, f: usize) {
+    if progress.is_some() {
+        return;
+    }
+
+    match get_nodes_consensus(votes, node_cnt, f) {
+        Ok(Some(target)) => *progress = Some(try_to_catch_up(&target, merkle)),
+        Ok(None) => (),
+        Err(err) => *progress = Some(Err(err))
+    }
+}
+
+/// Proof of possession is checked only if it's present in the node transaction.
+fn _check_bls_key(txn: &NodeTransactionV1, generator: &Generator) -> BlsKeyStatus {
+    let verkey = match pool::parse_node_bls_key(txn) {
+        Ok(Some(verkey)) => verkey,
+        Ok(None) => return BlsKeyStatus::Missing,
+        Err(_) => return BlsKeyStatus::Invalid,
+    };
+
+    let pop = match txn.txn.data.data.blskey_pop {
+        Some(ref pop) => pop,
+        None => return BlsKeyStatus::Valid,
+    };
+
+    let is_pop_valid = pop.from_base58().ok()
+        .and_then(|pop| ProofOfPossession::from_bytes(&pop).ok())
+        .and_then(|pop| Bls::verify_proof_of_posession(&pop, &verkey, generator).ok())
+        .unwrap_or(false);
+
+    if is_pop_valid {
+        BlsKeyStatus::Valid
+    } else {
+        BlsKeyStatus::InvalidProofOfPossession
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::thread;
+
+    use crate::domain::ledger::request::ProtocolVersion;
+    use crate::services::pool::tests::nodes_emulator;
+    use crate::utils::test;
+
+    use super::*;
+
+    fn _create_pool(pool_name: &str, txns: &[NodeTransactionV1]) {
+        ProtocolVersion::set(2);
+
+        let mut f = test::test_pool_create_poolfile(pool_name);
+        for txn in txns {
+            writeln!(f, "{}", serde_json::to_string(txn).unwrap()).unwrap();
+        }
+    }
+
+    fn _config(timeout: i64) -> PoolOpenConfig {
+        PoolOpenConfig { timeout, ..PoolOpenConfig::default() }
+    }
+
+    fn _genesis_txn(idx: usize) -> NodeTransactionV1 {
+        serde_json::from_str(&test::gen_txns()[idx]).unwrap()
+    }
+
+    #[test]
+    fn diagnose_works_for_reachable_node() {
+        let pool_name = "diagnose_works_for_reachable_node";
+        test::cleanup_storage(pool_name);
+
+        let mut txn = nodes_emulator::node();
+        let node_socket = nodes_emulator::start(&mut txn);
+        _create_pool(pool_name, &[txn]);
+
+        let diagnose_thread = thread::spawn(move || diagnose(pool_name, &_config(5)));
+
+        assert_eq!(1, node_socket.poll(zmq::POLLIN, 5_000).unwrap());
+        let msg = node_socket.recv_multipart(zmq::DONTWAIT).unwrap();
+        // node with the same ledger answers by the same LEDGER_STATUS
+        node_socket.send_multipart(&[msg[0].as_slice(), msg[1].as_slice()], zmq::DONTWAIT).unwrap();
+
+        let diagnostics = diagnose_thread.join().unwrap().unwrap();
+
+        assert_eq!(0, diagnostics.f);
+        assert_eq!(PoolConsensusStatus::Synced, diagnostics.consensus);
+        assert_eq!(Some(1), diagnostics.consensus_ledger_size);
+
+        let node = &diagnostics.nodes[0];
+        assert_eq!(NodeStatus::Reachable, node.status);
+        assert_eq!(BlsKeyStatus::Valid, node.bls_key);
+        assert_eq!(Some(1), node.ledger_size);
+        assert_eq!(Some(diagnostics.local_merkle_root.clone()), node.merkle_root);
+        assert!(node.latency_ms.is_some());
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn diagnose_works_for_unreachable_node() {
+        let pool_name = "diagnose_works_for_unreachable_node";
+        test::cleanup_storage(pool_name);
+
+        let mut txn = nodes_emulator::node();
+        txn.txn.data.data.client_port = Some(1);
+        _create_pool(pool_name, &[txn]);
+
+        let diagnostics = diagnose(pool_name, &_config(1)).unwrap();
+
+        assert_eq!(NodeStatus::Unreachable, diagnostics.nodes[0].status);
+        assert_eq!(None, diagnostics.nodes[0].ledger_size);
+        assert_eq!(PoolConsensusStatus::Failed, diagnostics.consensus);
+        assert!(diagnostics.error.is_some());
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn diagnose_works_for_unreachable_node_without_dropping_cache() {
+        let pool_name = "diagnose_works_for_unreachable_node_without_dropping_cache";
+        test::cleanup_storage(pool_name);
+
+        let mut txn = nodes_emulator::node();
+        txn.txn.data.data.client_port = Some(1);
+        _create_pool(pool_name, &[txn]);
+        merkle_tree_factory::dump_new_txns(pool_name, &[]).unwrap();
+
+        let diagnostics = diagnose(pool_name, &_config(1)).unwrap();
+
+        assert_eq!(PoolConsensusStatus::Failed, diagnostics.consensus);
+        merkle_tree_factory::drop_cache(pool_name).unwrap();
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn diagnose_works_for_invalid_node() {
+        let pool_name = "diagnose_works_for_invalid_node";
+        test::cleanup_storage(pool_name);
+
+        let mut txn = nodes_emulator::node();
+        txn.txn.data.data.services = None;
+        _create_pool(pool_name, &[txn]);
+
+        let diagnostics = diagnose(pool_name, &_config(1)).unwrap();
+
+        assert_eq!(NodeStatus::Invalid, diagnostics.nodes[0].status);
+        assert_eq!(None, diagnostics.nodes[0].address);
+        assert!(diagnostics.nodes[0].error.is_some());
+        assert_eq!(PoolConsensusStatus::NotReached, diagnostics.consensus);
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn diagnose_works_for_not_created_pool() {
+        let pool_name = "diagnose_works_for_not_created_pool";
+        test::cleanup_storage(pool_name);
+
+        let res = diagnose(pool_name, &_config(1));
+        assert_eq!(IndyErrorKind::PoolNotCreated, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn check_bls_key_works() {
+        let generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+
+        assert_eq!(BlsKeyStatus::Valid, _check_bls_key(&_genesis_txn(0), &generator));
+
+        let mut txn = _genesis_txn(0);
+        txn.txn.data.data.blskey_pop = _genesis_txn(1).txn.data.data.blskey_pop;
+        assert_eq!(BlsKeyStatus::InvalidProofOfPossession, _check_bls_key(&txn, &generator));
+
+        txn.txn.data.data.blskey = Some("invalid".to_string());
+        assert_eq!(BlsKeyStatus::Invalid, _check_bls_key(&txn, &generator));
+
+        txn.txn.data.data.blskey = None;
+        assert_eq!(BlsKeyStatus::Missing, _check_bls_key(&txn, &generator));
+    }
+}
@@ -7,7 +7,7 @@ use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::{PoolService, types:: *};
 use indy_api_types::CommandHandle;
 
-pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
+pub const REQUESTS_FOR_STATE_PROOFS: [&str; 13] = [
     constants::GET_NYM,
     constants::GET_TXN_AUTHR_AGRMT,
     constants::GET_TXN_AUTHR_AGRMT_AML,
@@ -19,6 +19,8 @@ pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
     constants::GET_REVOC_REG_DELTA,
     constants::GET_AUTH_RULE,
     constants::GET_TXN,
+    constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+    constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
 ];
 
 const REQUEST_FOR_FULL: [&str; 2] = [
@@ -27,7 +29,8 @@ const REQUEST_FOR_FULL: [&str; 2] = [
 ];
 
 
-pub const REQUESTS_FOR_STATE_PROOFS_IN_THE_PAST: [&str; 5] = [
+pub const REQUESTS_FOR_STATE_PROOFS_IN_THE_PAST: [&str; 6] = [
+    constants::GET_NYM,
     constants::GET_REVOC_REG,
     constants::GET_REVOC_REG_DELTA,
     constants::GET_TXN_AUTHR_AGRMT,
@@ -112,6 +115,11 @@ pub enum PoolEvent {
         String, //req_id
         String, //node alias
     ),
+    StateProofVerified(
+        String, //req_id
+        String, //reply
+        u64, //last signed time
+    ),
 }
 
 #[derive(Clone, Debug)]
@@ -271,6 +279,14 @@ fn _parse_timestamp_from_req_for_builtin_sp(req: &SJsonValue, op: &str) -> (Opti
     }
 
     match op {
+        constants::GET_NYM => {
+            match (req["operation"]["timestamp"].as_u64(), req["operation"]["seqNo"].as_u64()) {
+                (Some(timestamp), _) => (None, Some(timestamp)),
+                // State at the given transaction is requested, so any reply time is fresh enough
+                (None, Some(_)) => (None, Some(0)),
+                (None, None) => (None, None)
+            }
+        }
         constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML => {
             (None, req["operation"]["timestamp"].as_u64())
         }
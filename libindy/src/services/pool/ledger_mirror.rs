@@ -0,0 +1,569 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json;
+
+use indy_api_types::errors::prelude::*;
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, try_to_catch_up};
+use crate::services::pool::events::{NetworkerEvent, RequestEvent};
+use crate::services::pool::merkle_tree_factory;
+use crate::services::pool::networker::Networker;
+use crate::services::pool::types::{CatchupRep, LedgerStatus, Message};
+use crate::domain::ledger::request::ProtocolVersion;
+use rust_base58::ToBase58;
+
+pub const POOL_LEDGER_ID: usize = 0;
+pub const DOMAIN_LEDGER_ID: usize = 1;
+pub const CONFIG_LEDGER_ID: usize = 2;
+
+const MIRRORED_LEDGERS: [usize; 2] = [DOMAIN_LEDGER_ID, CONFIG_LEDGER_ID];
+
+/// Interval (in seconds) to check the pool for the transactions written after the last catchup.
+const REFRESH_INTERVAL: u64 = 60;
+
+lazy_static! {
+    static ref LOCAL_LEDGERS: Mutex<HashMap<(String, usize), Arc<MerkleTree>>> = Mutex::new(HashMap::new());
+}
+
+/// Returns locally stored copy of the ledger.
+///
+/// Mirrored ledgers are loaded from the file once and then kept in memory
+/// and updated by the mirror of the opened pool. POOL ledger is read from the pool cache.
+pub fn get_local_ledger(pool_name: &str, ledger_id: usize) -> IndyResult<Arc<MerkleTree>> {
+    if ledger_id == POOL_LEDGER_ID {
+        return merkle_tree_factory::load_local_ledger(pool_name, ledger_id).map(Arc::new);
+    }
+
+    let mut local_ledgers = LOCAL_LEDGERS.lock().unwrap();
+    let key = (pool_name.to_string(), ledger_id);
+
+    if let Some(merkle) = local_ledgers.get(&key) {
+        return Ok(merkle.clone());
+    }
+
+    let merkle = Arc::new(merkle_tree_factory::load_local_ledger(pool_name, ledger_id)?);
+    local_ledgers.insert(key, merkle.clone());
+    Ok(merkle)
+}
+
+/// Forgets in-memory copies of the ledgers of the deleted pool.
+pub fn forget_local_ledgers(pool_name: &str) {
+    LOCAL_LEDGERS.lock().unwrap().retain(|&(ref name, _), _| name != pool_name);
+}
+
+fn _publish_local_ledger(pool_name: &str, ledger_id: usize, merkle: Arc<MerkleTree>) {
+    LOCAL_LEDGERS.lock().unwrap().insert((pool_name.to_string(), ledger_id), merkle);
+}
+
+/// Keeps verified local copies of DOMAIN and CONFIG ledgers.
+///
+/// Mirroring is started when pool becomes active and uses the same flow as POOL ledger catchup:
+/// LEDGER_STATUS is sent to all nodes, the target is accepted when f + 1 nodes agree on it
+/// and consistency proofs are valid, then CATCHUP_REQ is sent to one node and the received
+/// transactions are checked against the target merkle root before persisting.
+///
+/// While pool is active the same flow is repeated every `refresh_interval` to append new transactions.
+/// If the local copy is not consistent with the pool it is dropped and mirrored from scratch.
+pub struct LedgerMirror {
+    pool_name: String,
+    enabled: bool,
+    started: bool,
+    refresh_interval: Duration,
+    ledgers: Vec<MirroredLedger>,
+}
+
+struct MirroredLedger {
+    pool_name: String,
+    ledger_id: usize,
+    merkle: Arc<MerkleTree>,
+    state: MirrorState,
+}
+
+enum MirrorState {
+    GettingTarget {
+        req_id: String,
+        votes: HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
+        timeout_nodes: HashSet<String>,
+    },
+    Catchup {
+        req_id: String,
+        target_mt_root: Vec<u8>,
+        target_mt_size: usize,
+    },
+    Finished(Instant),
+}
+
+impl LedgerMirror {
+    pub fn new(pool_name: &str, enabled: bool) -> LedgerMirror {
+        LedgerMirror {
+            pool_name: pool_name.to_string(),
+            enabled,
+            started: false,
+            refresh_interval: Duration::from_secs(REFRESH_INTERVAL),
+            ledgers: Vec::new(),
+        }
+    }
+
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Starts mirroring if it's enabled and wasn't started since pool became active.
+    /// Ledgers mirrored more than `refresh_interval` ago are caught up again.
+    pub fn ensure_started<T: Networker>(&mut self, networker: &RefCell<T>, timeout: i64) {
+        if !self.enabled {
+            return;
+        }
+
+        if self.started {
+            let refresh_interval = self.refresh_interval;
+            for ledger in self.ledgers.iter_mut() {
+                ledger.refresh(networker, refresh_interval, timeout);
+            }
+            return;
+        }
+        self.started = true;
+
+        let pool_name = self.pool_name.clone();
+
+        self.ledgers = MIRRORED_LEDGERS.iter()
+            .filter_map(|&ledger_id| {
+                let merkle = merkle_tree_factory::create_ledger_mirror(&pool_name, ledger_id)
+                    .or_else(|err| {
+                        warn!("Can't restore local mirror of ledger {}: {:?}", ledger_id, err);
+                        merkle_tree_factory::drop_ledger_mirror(&pool_name, ledger_id)
+                            .map(|_| MerkleTree::default())
+                    })
+                    .ok()?;
+
+                MirroredLedger::start(&pool_name, ledger_id, merkle, networker, timeout)
+                    .map_err(|err| warn!("Can't start mirroring of ledger {}: {:?}", ledger_id, err))
+                    .ok()
+            })
+            .collect();
+    }
+
+    /// Called when pool leaves active state. Mirroring will be restarted on the next activation.
+    pub fn reset(&mut self) {
+        self.started = false;
+        self.ledgers.clear();
+    }
+
+    /// Returns true if event belongs to ledger mirroring.
+    pub fn process_event<T: Networker>(&mut self, event: &RequestEvent, networker: &RefCell<T>,
+                                       f: usize, nodes_cnt: usize, timeout: i64) -> bool {
+        let ledger = match *event {
+            RequestEvent::LedgerStatus(ref ls, Some(_), _) => self._get_ledger(ls.ledgerId as usize),
+            RequestEvent::ConsistencyProof(ref cp, _) => self._get_ledger(cp.ledgerId),
+            RequestEvent::CatchupRep(ref cr, _) => self._get_ledger(cr.ledgerId),
+            RequestEvent::Timeout(ref req_id, _) => self.ledgers.iter_mut().find(|ledger| ledger.req_id() == Some(req_id)),
+            _ => None
+        };
+
+        match ledger {
+            Some(ledger) => {
+                ledger.process_event(event, networker, f, nodes_cnt, timeout);
+                true
+            }
+            None => false
+        }
+    }
+
+    fn _get_ledger(&mut self, ledger_id: usize) -> Option<&mut MirroredLedger> {
+        if ledger_id == POOL_LEDGER_ID {
+            return None;
+        }
+        self.ledgers.iter_mut().find(|ledger| ledger.ledger_id == ledger_id)
+    }
+}
+
+impl MirroredLedger {
+    fn start<T: Networker>(pool_name: &str, ledger_id: usize, merkle: MerkleTree, networker: &RefCell<T>, timeout: i64) -> IndyResult<MirroredLedger> {
+        let mut ledger = MirroredLedger {
+            pool_name: pool_name.to_string(),
+            ledger_id,
+            merkle: Arc::new(MerkleTree::default()),
+            state: MirrorState::Finished(Instant::now()),
+        };
+
+        ledger._set_merkle(merkle);
+        ledger.state = ledger._request_target(networker, timeout)?;
+
+        Ok(ledger)
+    }
+
+    fn refresh<T: Networker>(&mut self, networker: &RefCell<T>, refresh_interval: Duration, timeout: i64) {
+        match self.state {
+            MirrorState::Finished(finished_at) if finished_at.elapsed() >= refresh_interval => {}
+            _ => return
+        }
+
+        self.state = self._request_target(networker, timeout)
+            .unwrap_or_else(|err| {
+                warn!("Can't refresh mirror of ledger {}: {:?}", self.ledger_id, err);
+                MirrorState::Finished(Instant::now())
+            });
+    }
+
+    fn req_id(&self) -> Option<&String> {
+        match self.state {
+            MirrorState::GettingTarget { ref req_id, .. } | MirrorState::Catchup { ref req_id, .. } => Some(req_id),
+            MirrorState::Finished(_) => None
+        }
+    }
+
+    fn process_event<T: Networker>(&mut self, event: &RequestEvent, networker: &RefCell<T>,
+                                   f: usize, nodes_cnt: usize, timeout: i64) {
+        let state = ::std::mem::replace(&mut self.state, MirrorState::Finished(Instant::now()));
+
+        self.state = match (state, event) {
+            (MirrorState::GettingTarget { req_id, mut votes, timeout_nodes }, &RequestEvent::LedgerStatus(ref ls, Some(ref node_alias), _)) => {
+                votes.entry((ls.merkleRoot.clone(), ls.txnSeqNo, None)).or_insert_with(HashSet::new).insert(node_alias.clone());
+                self._check_target(req_id, votes, timeout_nodes, node_alias, networker, f, nodes_cnt, timeout)
+            }
+            (MirrorState::GettingTarget { req_id, mut votes, timeout_nodes }, &RequestEvent::ConsistencyProof(ref cp, ref node_alias)) => {
+                votes.entry((cp.newMerkleRoot.clone(), cp.seqNoEnd, Some(cp.hashes.clone()))).or_insert_with(HashSet::new).insert(node_alias.clone());
+                self._check_target(req_id, votes, timeout_nodes, node_alias, networker, f, nodes_cnt, timeout)
+            }
+            (MirrorState::GettingTarget { req_id, votes, mut timeout_nodes }, &RequestEvent::Timeout(_, ref node_alias)) => {
+                timeout_nodes.insert(node_alias.clone());
+                self._check_target(req_id, votes, timeout_nodes, node_alias, networker, f, nodes_cnt, timeout)
+            }
+            (MirrorState::Catchup { req_id, target_mt_root, target_mt_size }, &RequestEvent::CatchupRep(ref cr, ref node_alias)) => {
+                match self._process_catchup_reply(cr.clone(), &target_mt_root, target_mt_size) {
+                    Ok(()) => {
+                        trace!("ledger {} is mirrored, size {}", self.ledger_id, self.merkle.count());
+                        networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+                        MirrorState::Finished(Instant::now())
+                    }
+                    Err(err) => {
+                        warn!("Invalid catchup reply for ledger {} from node {}: {:?}", self.ledger_id, node_alias, err);
+                        networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+                        networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), Some(node_alias.clone()))));
+                        MirrorState::Catchup { req_id, target_mt_root, target_mt_size }
+                    }
+                }
+            }
+            (MirrorState::Catchup { req_id, target_mt_root, target_mt_size }, &RequestEvent::Timeout(_, ref node_alias)) => {
+                networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+                networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), Some(node_alias.clone()))));
+                MirrorState::Catchup { req_id, target_mt_root, target_mt_size }
+            }
+            (state, _) => state
+        };
+    }
+
+    fn _request_target<T: Networker>(&self, networker: &RefCell<T>, timeout: i64) -> IndyResult<MirrorState> {
+        let protocol_version = ProtocolVersion::get();
+
+        let ls = LedgerStatus {
+            txnSeqNo: self.merkle.count(),
+            merkleRoot: self.merkle.root_hash().as_slice().to_base58(),
+            ledgerId: self.ledger_id as u8,
+            ppSeqNo: None,
+            viewNo: None,
+            protocolVersion: if protocol_version > 1 { Some(protocol_version) } else { None },
+        };
+
+        let req_id = format!("mirror_{}_{}", self.ledger_id, ls.merkleRoot);
+        let msg = serde_json::to_string(&Message::LedgerStatus(ls))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus")?;
+
+        trace!("request target of ledger {}, local size {}", self.ledger_id, self.merkle.count());
+        networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id.clone(), timeout, None)));
+
+        Ok(MirrorState::GettingTarget {
+            req_id,
+            votes: HashMap::new(),
+            timeout_nodes: HashSet::new(),
+        })
+    }
+
+    fn _check_target<T: Networker>(&mut self,
+                                   req_id: String,
+                                   votes: HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
+                                   timeout_nodes: HashSet<String>,
+                                   node_alias: &str,
+                                   networker: &RefCell<T>,
+                                   f: usize, nodes_cnt: usize, timeout: i64) -> MirrorState {
+        let target = votes.iter()
+            .find(|(_, nodes)| nodes.len() > f)
+            .map(|(target, _)| target.clone());
+
+        let target = match target {
+            Some(target) => target,
+            None => {
+                let replies_cnt = timeout_nodes.len() + votes.values().map(HashSet::len).sum::<usize>();
+                return if replies_cnt < nodes_cnt {
+                    networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), Some(node_alias.to_string()))));
+                    MirrorState::GettingTarget { req_id, votes, timeout_nodes }
+                } else {
+                    warn!("No consensus on the state of ledger {} -- mirroring is postponed", self.ledger_id);
+                    networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+                    MirrorState::Finished(Instant::now())
+                };
+            }
+        };
+
+        networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+
+        match try_to_catch_up(&target, &self.merkle) {
+            Ok(CatchupProgress::ShouldBeStarted(target_mt_root, target_mt_size, _)) => {
+                match build_catchup_req(&self.merkle, target_mt_size, self.ledger_id) {
+                    Ok(Some((req_id, req_json))) => {
+                        let req_id = format!("mirror_{}_{}", self.ledger_id, req_id);
+                        networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id.clone(), timeout)));
+                        MirrorState::Catchup { req_id, target_mt_root, target_mt_size }
+                    }
+                    Ok(None) => MirrorState::Finished(Instant::now()),
+                    Err(err) => {
+                        warn!("Can't build catchup request for ledger {}: {:?}", self.ledger_id, err);
+                        MirrorState::Finished(Instant::now())
+                    }
+                }
+            }
+            Ok(_) => {
+                trace!("ledger {} mirror is up to date", self.ledger_id);
+                MirrorState::Finished(Instant::now())
+            }
+            Err(err) => {
+                warn!("Local mirror of ledger {} is not consistent with the pool: {:?}", self.ledger_id, err);
+                self._resync(networker, timeout)
+            }
+        }
+    }
+
+    fn _resync<T: Networker>(&mut self, networker: &RefCell<T>, timeout: i64) -> MirrorState {
+        if let Err(err) = merkle_tree_factory::drop_ledger_mirror(&self.pool_name, self.ledger_id) {
+            warn!("Can't drop local mirror of ledger {}: {:?}", self.ledger_id, err);
+            return MirrorState::Finished(Instant::now());
+        }
+
+        self._set_merkle(MerkleTree::default());
+
+        self._request_target(networker, timeout)
+            .unwrap_or_else(|err| {
+                warn!("Can't resync mirror of ledger {}: {:?}", self.ledger_id, err);
+                MirrorState::Finished(Instant::now())
+            })
+    }
+
+    fn _process_catchup_reply(&mut self, mut rep: CatchupRep, target_mt_root: &Vec<u8>, target_mt_size: usize) -> IndyResult<()> {
+        let mut txns = vec![];
+        let mut merkle = (*self.merkle).clone();
+
+        while !rep.txns.is_empty() {
+            let key = rep.min_tx()?;
+            let txn = rep.txns.remove(&key.to_string()).unwrap();
+
+            let txn = rmp_serde::to_vec_named(&txn)
+                .to_indy(IndyErrorKind::InvalidStructure, "Invalid transaction -- can not transform to bytes")?;
+
+            merkle.append(txn.clone())?;
+            txns.push(txn);
+        }
+
+        check_cons_proofs(&merkle, &rep.consProof, target_mt_root, target_mt_size)?;
+        merkle_tree_factory::dump_ledger_mirror_txns(&self.pool_name, self.ledger_id, &txns)?;
+
+        self._set_merkle(merkle);
+        Ok(())
+    }
+
+    fn _set_merkle(&mut self, merkle: MerkleTree) {
+        self.merkle = Arc::new(merkle);
+        _publish_local_ledger(&self.pool_name, self.ledger_id, self.merkle.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::services::pool::networker::MockNetworker;
+    use crate::services::pool::types::ConsistencyProof;
+    use crate::utils::test;
+
+    const NODES: [&str; 4] = ["n1", "n2", "n3", "n4"];
+
+    fn _txn(seq_no: usize) -> serde_json::Value {
+        json!({"txn": {"type": "1", "data": {"dest": format!("dest{}", seq_no)}}, "txnMetadata": {"seqNo": seq_no}})
+    }
+
+    fn _target(txns_cnt: usize) -> MerkleTree {
+        let txns = (1..=txns_cnt).map(|seq_no| rmp_serde::to_vec_named(&_txn(seq_no)).unwrap()).collect();
+        MerkleTree::from_vec(txns).unwrap()
+    }
+
+    fn _ledger_status(merkle: &MerkleTree, ledger_id: usize) -> LedgerStatus {
+        LedgerStatus {
+            txnSeqNo: merkle.count(),
+            merkleRoot: merkle.root_hash().to_base58(),
+            ledgerId: ledger_id as u8,
+            ppSeqNo: None,
+            viewNo: None,
+            protocolVersion: None,
+        }
+    }
+
+    fn _networker() -> RefCell<MockNetworker> {
+        RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))
+    }
+
+    #[test]
+    fn ledger_mirror_works_for_disabled() {
+        let networker = _networker();
+        let mut mirror = LedgerMirror::new("ledger_mirror_works_for_disabled", false);
+        mirror.ensure_started(&networker, 0);
+
+        assert!(networker.borrow().events.is_empty());
+        assert!(!mirror.process_event(&RequestEvent::LedgerStatus(_ledger_status(&_target(1), DOMAIN_LEDGER_ID), Some(NODES[0].to_string()), None),
+                                      &networker, 1, NODES.len(), 0));
+    }
+
+    #[test]
+    fn ledger_mirror_works() {
+        test::cleanup_storage("ledger_mirror_works");
+
+        let networker = _networker();
+        let mut mirror = LedgerMirror::new("ledger_mirror_works", true);
+        mirror.ensure_started(&networker, 0);
+
+        assert_eq!(MIRRORED_LEDGERS.len(), networker.borrow().events.len());
+        assert_match!(Some(NetworkerEvent::SendAllRequest(_, _, _, None)), networker.borrow().events[0]);
+
+        let target = _target(3);
+        for node in &NODES[..2] {
+            assert!(mirror.process_event(&RequestEvent::LedgerStatus(_ledger_status(&target, DOMAIN_LEDGER_ID), Some(node.to_string()), None),
+                                         &networker, 1, NODES.len(), 0));
+        }
+        assert_match!(Some(NetworkerEvent::SendOneRequest(_, _, _)), networker.borrow().events.last().unwrap());
+
+        let txns = (1..=3).map(|seq_no| (seq_no.to_string(), _txn(seq_no))).collect();
+        assert!(mirror.process_event(&RequestEvent::CatchupRep(CatchupRep { ledgerId: DOMAIN_LEDGER_ID, consProof: vec![], txns }, NODES[0].to_string()),
+                                     &networker, 1, NODES.len(), 0));
+
+        let mirrored = merkle_tree_factory::create_ledger_mirror("ledger_mirror_works", DOMAIN_LEDGER_ID).unwrap();
+        assert_eq!(target.root_hash(), mirrored.root_hash());
+
+        let local = get_local_ledger("ledger_mirror_works", DOMAIN_LEDGER_ID).unwrap();
+        assert_eq!(target.root_hash(), local.root_hash());
+
+        forget_local_ledgers("ledger_mirror_works");
+        test::cleanup_storage("ledger_mirror_works");
+    }
+
+    #[test]
+    fn ledger_mirror_works_for_new_txns() {
+        test::cleanup_storage("ledger_mirror_works_for_new_txns");
+
+        let networker = _networker();
+        let mut mirror = LedgerMirror::new("ledger_mirror_works_for_new_txns", true)
+            .with_refresh_interval(Duration::from_secs(0));
+        mirror.ensure_started(&networker, 0);
+
+        for seq_no in 1..=2 {
+            for node in &NODES[..2] {
+                mirror.process_event(&RequestEvent::LedgerStatus(_ledger_status(&_target(seq_no), DOMAIN_LEDGER_ID), Some(node.to_string()), None),
+                                     &networker, 1, NODES.len(), 0);
+            }
+
+            let txns = vec![(seq_no.to_string(), _txn(seq_no))].into_iter().collect();
+            mirror.process_event(&RequestEvent::CatchupRep(CatchupRep { ledgerId: DOMAIN_LEDGER_ID, consProof: vec![], txns }, NODES[0].to_string()),
+                                 &networker, 1, NODES.len(), 0);
+
+            let events_cnt = networker.borrow().events.len();
+            mirror.ensure_started(&networker, 0);
+            assert_match!(Some(NetworkerEvent::SendAllRequest(_, _, _, None)), networker.borrow().events[events_cnt]);
+        }
+
+        let local = get_local_ledger("ledger_mirror_works_for_new_txns", DOMAIN_LEDGER_ID).unwrap();
+        assert_eq!(_target(2).root_hash(), local.root_hash());
+
+        forget_local_ledgers("ledger_mirror_works_for_new_txns");
+        test::cleanup_storage("ledger_mirror_works_for_new_txns");
+    }
+
+    #[test]
+    fn ledger_mirror_works_for_inconsistent_local_copy() {
+        test::cleanup_storage("ledger_mirror_works_for_inconsistent_local_copy");
+
+        let txns: Vec<Vec<u8>> = (2..=4).map(|seq_no| rmp_serde::to_vec_named(&_txn(seq_no)).unwrap()).collect();
+        merkle_tree_factory::dump_ledger_mirror_txns("ledger_mirror_works_for_inconsistent_local_copy", DOMAIN_LEDGER_ID, &txns).unwrap();
+
+        let networker = _networker();
+        let mut mirror = LedgerMirror::new("ledger_mirror_works_for_inconsistent_local_copy", true);
+        mirror.ensure_started(&networker, 0);
+
+        let target = _target(3);
+        for node in &NODES[..2] {
+            mirror.process_event(&RequestEvent::LedgerStatus(_ledger_status(&target, DOMAIN_LEDGER_ID), Some(node.to_string()), None),
+                                 &networker, 1, NODES.len(), 0);
+        }
+
+        assert_eq!(0, merkle_tree_factory::create_ledger_mirror("ledger_mirror_works_for_inconsistent_local_copy", DOMAIN_LEDGER_ID).unwrap().count());
+        assert_match!(Some(NetworkerEvent::SendAllRequest(_, _, _, None)), networker.borrow().events.last().unwrap());
+
+        for node in &NODES[..2] {
+            mirror.process_event(&RequestEvent::LedgerStatus(_ledger_status(&target, DOMAIN_LEDGER_ID), Some(node.to_string()), None),
+                                 &networker, 1, NODES.len(), 0);
+        }
+        assert_match!(Some(NetworkerEvent::SendOneRequest(_, _, _)), networker.borrow().events.last().unwrap());
+
+        let txns = (1..=3).map(|seq_no| (seq_no.to_string(), _txn(seq_no))).collect();
+        mirror.process_event(&RequestEvent::CatchupRep(CatchupRep { ledgerId: DOMAIN_LEDGER_ID, consProof: vec![], txns }, NODES[0].to_string()),
+                             &networker, 1, NODES.len(), 0);
+
+        let mirrored = merkle_tree_factory::create_ledger_mirror("ledger_mirror_works_for_inconsistent_local_copy", DOMAIN_LEDGER_ID).unwrap();
+        assert_eq!(target.root_hash(), mirrored.root_hash());
+
+        forget_local_ledgers("ledger_mirror_works_for_inconsistent_local_copy");
+        test::cleanup_storage("ledger_mirror_works_for_inconsistent_local_copy");
+    }
+
+    #[test]
+    fn ledger_mirror_works_for_invalid_catchup_reply() {
+        test::cleanup_storage("ledger_mirror_works_for_invalid_catchup_reply");
+
+        let networker = _networker();
+        let mut mirror = LedgerMirror::new("ledger_mirror_works_for_invalid_catchup_reply", true);
+        mirror.ensure_started(&networker, 0);
+
+        let target = _target(2);
+        for node in &NODES[..2] {
+            mirror.process_event(&RequestEvent::ConsistencyProof(ConsistencyProof {
+                seqNoEnd: target.count(),
+                seqNoStart: 0,
+                ledgerId: DOMAIN_LEDGER_ID,
+                hashes: vec![],
+                oldMerkleRoot: MerkleTree::default().root_hash().to_base58(),
+                newMerkleRoot: target.root_hash().to_base58(),
+            }, node.to_string()), &networker, 1, NODES.len(), 0);
+        }
+
+        let txns = vec![("1".to_string(), _txn(1)), ("2".to_string(), _txn(5))].into_iter().collect();
+        mirror.process_event(&RequestEvent::CatchupRep(CatchupRep { ledgerId: DOMAIN_LEDGER_ID, consProof: vec![], txns }, NODES[0].to_string()),
+                             &networker, 1, NODES.len(), 0);
+
+        assert_eq!(0, merkle_tree_factory::create_ledger_mirror("ledger_mirror_works_for_invalid_catchup_reply", DOMAIN_LEDGER_ID).unwrap().count());
+        let events = &networker.borrow().events;
+        assert_match!(Some(NetworkerEvent::Resend(_, _)), events[events.len() - 2]);
+
+        forget_local_ledgers("ledger_mirror_works_for_invalid_catchup_reply");
+        test::cleanup_storage("ledger_mirror_works_for_invalid_catchup_reply");
+    }
+
+    #[test]
+    fn ledger_mirror_ignores_pool_ledger_events() {
+        let networker = _networker();
+        let mut mirror = LedgerMirror::new("ledger_mirror_ignores_pool_ledger_events", true);
+        mirror.ensure_started(&networker, 0);
+
+        assert!(!mirror.process_event(&RequestEvent::LedgerStatus(_ledger_status(&_target(1), POOL_LEDGER_ID), Some(NODES[0].to_string()), None),
+                                      &networker, 1, NODES.len(), 0));
+    }
+}
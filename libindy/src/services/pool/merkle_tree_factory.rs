@@ -9,6 +9,7 @@ use serde_json::Value as SJsonValue;
 use crate::domain::ledger::request::ProtocolVersion;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::pool::ledger_mirror::POOL_LEDGER_ID;
 use crate::services::pool::types::{NodeTransaction, NodeTransactionV0, NodeTransactionV1};
 use crate::utils::environment;
 
@@ -232,6 +233,63 @@ pub fn from_file(txn_file: &str) -> IndyResult<MerkleTree> {
     _from_genesis(&PathBuf::from(txn_file))
 }
 
+/// Restores merkle tree of the local mirror of DOMAIN, CONFIG or other non-pool ledger.
+/// Returns empty tree if nothing was mirrored yet.
+pub fn create_ledger_mirror(pool_name: &str, ledger_id: usize) -> IndyResult<MerkleTree> {
+    let p = get_ledger_mirror_path(pool_name, ledger_id, false);
+
+    if !p.exists() {
+        trace!("No local mirror of ledger {} for pool {}", ledger_id, pool_name);
+        return MerkleTree::from_vec(Vec::new());
+    }
+
+    _from_cache(&p)
+}
+
+pub fn dump_ledger_mirror_txns(pool_name: &str, ledger_id: usize, txns: &[Vec<u8>]) -> IndyResult<()> {
+    let p = get_ledger_mirror_path(pool_name, ledger_id, true);
+
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(p)
+        .to_indy(IndyErrorKind::IOError, "Can't open ledger mirror file")?;
+
+    _dump_vec_to_file(txns, &mut file)
+}
+
+pub fn drop_ledger_mirror(pool_name: &str, ledger_id: usize) -> IndyResult<()> {
+    let p = get_ledger_mirror_path(pool_name, ledger_id, false);
+    if p.exists() {
+        warn!("Local mirror of ledger {} is invalid -- dropping it!", ledger_id);
+        fs::remove_file(p)
+            .to_indy(IndyErrorKind::IOError, "Can't drop ledger mirror file")?;
+    }
+    Ok(())
+}
+
+/// Returns locally stored copy of the ledger: POOL ledger cache or mirror of other ledger.
+pub fn load_local_ledger(pool_name: &str, ledger_id: usize) -> IndyResult<MerkleTree> {
+    if !environment::pool_path(pool_name).exists() {
+        return Err(err_msg(IndyErrorKind::PoolNotCreated, format!("Pool is not created for name: {:?}", pool_name)));
+    }
+
+    if ledger_id == POOL_LEDGER_ID {
+        create(pool_name)
+    } else {
+        create_ledger_mirror(pool_name, ledger_id)
+    }
+}
+
+pub fn decode_txn(txn: &[u8]) -> IndyResult<SJsonValue> {
+    rmp_serde::decode::from_slice(txn)
+        .to_indy(IndyErrorKind::InvalidState, "Local ledger contains invalid transaction")
+}
+
+fn get_ledger_mirror_path(pool_name: &str, ledger_id: usize, create_dir: bool) -> PathBuf {
+    get_pool_stored_path_base(pool_name, create_dir, &format!("ledger_{}", ledger_id), "btxn")
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -402,4 +460,33 @@ mod tests {
 
         test::cleanup_storage("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version");
     }
+
+    #[test]
+    fn ledger_mirror_dump_and_create_works() {
+        test::cleanup_storage("ledger_mirror_dump_and_create_works");
+
+        let txns = vec![
+            rmp_serde::to_vec_named(&json!({"txn": {"type": "1"}, "txnMetadata": {"seqNo": 1}})).unwrap(),
+            rmp_serde::to_vec_named(&json!({"txn": {"type": "101"}, "txnMetadata": {"seqNo": 2}})).unwrap(),
+        ];
+
+        assert_eq!(0, create_ledger_mirror("ledger_mirror_dump_and_create_works", 1).unwrap().count());
+
+        dump_ledger_mirror_txns("ledger_mirror_dump_and_create_works", 1, &txns[..1]).unwrap();
+        dump_ledger_mirror_txns("ledger_mirror_dump_and_create_works", 1, &txns[1..]).unwrap();
+
+        let merkle = create_ledger_mirror("ledger_mirror_dump_and_create_works", 1).unwrap();
+        assert_eq!(2, merkle.count());
+
+        let decoded = merkle.into_iter().map(|txn| decode_txn(&txn).unwrap()).collect::<Vec<SJsonValue>>();
+        assert_eq!("101", decoded[1]["txn"]["type"]);
+
+        assert_eq!(0, create_ledger_mirror("ledger_mirror_dump_and_create_works", 2).unwrap().count());
+
+        drop_ledger_mirror("ledger_mirror_dump_and_create_works", 1).unwrap();
+        assert_eq!(0, create_ledger_mirror("ledger_mirror_dump_and_create_works", 1).unwrap().count());
+
+        test::cleanup_storage("ledger_mirror_dump_and_create_works");
+    }
+
 }
\ No newline at end of file
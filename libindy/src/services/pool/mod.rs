@@ -13,46 +13,68 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::Write;
 use std::sync::Mutex;
+use std::thread;
 
 use serde_json;
 use serde::de::DeserializeOwned;
 
 use crate::api::ledger::{CustomFree, CustomTransactionParser};
+use crate::commands::{Command, CommandExecutor};
+use crate::commands::pool::PoolCommand;
 use crate::domain::{
-    pool::{PoolConfig, PoolOpenConfig},
+    pool::{PoolConfig, PoolOpenConfig, PoolTransport},
+    ledger::author_agreement::{PoolTxnAuthorAgreementAcceptance, TxnAuthorAgreementData},
+    ledger::request::TxnAuthrAgrmtAcceptanceData,
     ledger::response::{
         Message,
+        ConsensusDetails,
         Reply,
-        ResponseMetadata
+        ResponseMetadata,
+        TxnInclusion
     }
 };
 use indy_api_types::errors::*;
+use crate::services::pool::networker::{Networker, TransportNetworker, ZMQNetworker};
+use crate::services::pool::plugged_transport::{PluggedTransport, PluggedTransportType};
 use crate::services::pool::pool::{Pool, ZMQPool};
+use crate::services::pool::request_handler::{DEFAULT_GENERATOR, RequestHandlerImpl};
+use crate::services::pool::simulated::SimulatedPool;
 use crate::utils::environment;
 use crate::services::pool::events::{COMMAND_EXIT, COMMAND_CONNECT, COMMAND_REFRESH};
 use indy_api_types::{CommandHandle, PoolHandle};
 use indy_utils::{next_command_handle, next_pool_handle};
-use ursa::bls::VerKey;
+use rust_base58::FromBase58;
+use ursa::bls::{Generator, VerKey};
 
 mod catchup;
 mod commander;
+mod diagnostics;
 mod events;
+mod ledger_mirror;
 mod merkle_tree_factory;
 mod networker;
 mod pool;
+pub mod plugged_transport;
+pub mod read_cache;
 mod request_handler;
+mod simulated;
 mod state_proof;
 mod types;
 
 lazy_static! {
     static ref REGISTERED_SP_PARSERS: Mutex<HashMap<String, (CustomTransactionParser, CustomFree)>> = Mutex::new(HashMap::new());
+    static ref REGISTERED_TRANSPORTS: Mutex<HashMap<String, PluggedTransportType>> = Mutex::new(HashMap::new());
 }
 
 type Nodes = HashMap<String, Option<VerKey>>;
 
+/// Time (in seconds) to use transaction author agreement read from the ledger before reading it again.
+const LEDGER_TAA_TIMEOUT: u64 = 600;
+
 pub struct PoolService {
     open_pools: RefCell<HashMap<PoolHandle, ZMQPool>>,
     pending_pools: RefCell<HashMap<PoolHandle, ZMQPool>>,
+    taa_acceptances: RefCell<HashMap<PoolHandle, PoolTxnAuthorAgreementAcceptance>>,
 }
 
 impl PoolService {
@@ -60,6 +82,7 @@ impl PoolService {
         PoolService {
             open_pools: RefCell::new(HashMap::new()),
             pending_pools: RefCell::new(HashMap::new()),
+            taa_acceptances: RefCell::new(HashMap::new()),
         }
     }
 
@@ -140,7 +163,10 @@ impl PoolService {
         let path = environment::pool_path(name);
 
         fs::remove_dir_all(path)
-            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")
+            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")?;
+
+        ledger_mirror::forget_local_ledgers(name);
+        Ok(())
     }
 
     pub fn open(&self, name: &str, config: Option<PoolOpenConfig>) -> IndyResult<PoolHandle> {
@@ -153,8 +179,21 @@ impl PoolService {
 
         let config = config.unwrap_or_default();
 
+        match config.transport {
+            PoolTransport::Zmq => self.open_with_networker::<ZMQNetworker>(name, config),
+            PoolTransport::Simulated => self.open_with_networker::<TransportNetworker<SimulatedPool>>(name, config),
+            PoolTransport::Plugged(ref transport_name) => {
+                if PoolService::get_transport(transport_name).is_none() {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown pool transport: {}", transport_name)));
+                }
+                self.open_with_networker::<TransportNetworker<PluggedTransport>>(name, config)
+            }
+        }
+    }
+
+    pub fn open_with_networker<S: Networker + 'static>(&self, name: &str, config: PoolOpenConfig) -> IndyResult<PoolHandle> {
         let pool_handle: PoolHandle = next_pool_handle();
-        let mut new_pool = Pool::new(name, pool_handle, config);
+        let mut new_pool: Pool<S, RequestHandlerImpl<S>> = Pool::new(name, pool_handle, config);
 
         let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets(&format!("pool_{}", name));
 
@@ -214,6 +253,29 @@ impl PoolService {
         parsers.get(txn_type).map(Clone::clone)
     }
 
+    pub fn register_transport(name: &str, transport_type: PluggedTransportType) -> IndyResult<()> {
+        if let PoolTransport::Zmq | PoolTransport::Simulated = PoolTransport::from(name.to_string()) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Try to override built-in pool transport {}", name)));
+        }
+
+        let mut transports = REGISTERED_TRANSPORTS.lock().unwrap();
+
+        if transports.contains_key(name) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Pool transport {} is already registered", name)));
+        }
+
+        transports.insert(name.to_owned(), transport_type);
+
+        Ok(())
+    }
+
+    pub fn get_transport(name: &str) -> Option<PluggedTransportType> {
+        let transports = REGISTERED_TRANSPORTS.lock().unwrap();
+        transports.get(name).cloned()
+    }
+
     pub fn close(&self, handle: PoolHandle) -> IndyResult<CommandHandle> {
         let cmd_id: CommandHandle = next_command_handle();
 
@@ -224,6 +286,8 @@ impl PoolService {
             None => return Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
         }
 
+        self.taa_acceptances.try_borrow_mut()?.remove(&handle);
+
         Ok(cmd_id)
     }
 
@@ -244,6 +308,14 @@ impl PoolService {
         }
     }
 
+    /// Returns maximum number of requests sent over single connection of the opened pool.
+    pub fn get_conn_limit(&self, handle: PoolHandle) -> IndyResult<usize> {
+        self.open_pools.try_borrow()?
+            .get(&handle)
+            .map(|pool| pool.pool.get_conn_limit())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))
+    }
+
     pub fn list(&self) -> IndyResult<Vec<serde_json::Value>> {
         let mut pool = Vec::new();
         let pool_home_path = environment::pool_home_path();
@@ -260,6 +332,130 @@ impl PoolService {
 
         Ok(pool)
     }
+
+    /// Starts diagnostics of the pool nodes in a separate thread.
+    /// Result is sent back to the pool command executor as `PoolCommand::DiagnoseAck`.
+    pub fn diagnose(&self, name: &str, config: Option<PoolOpenConfig>) -> IndyResult<CommandHandle> {
+        let config = config.unwrap_or_default();
+
+        if config.transport != PoolTransport::Zmq {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Pool diagnostics are supported only for `zmq` transport"));
+        }
+
+        if !environment::pool_path(name).exists() {
+            return Err(err_msg(IndyErrorKind::PoolNotCreated, format!("Pool is not created for name: {:?}", name)));
+        }
+
+        let cmd_id: CommandHandle = next_command_handle();
+        let name = name.to_string();
+
+        thread::spawn(move || {
+            let res = diagnostics::diagnose(&name, &config);
+            CommandExecutor::instance().send(Command::Pool(PoolCommand::DiagnoseAck(cmd_id, res))).unwrap();
+        });
+
+        Ok(cmd_id)
+    }
+
+    /// Returns transaction with given sequence number from the local copy of the ledger.
+    /// POOL ledger is always stored locally, other ledgers are stored if pool was opened with `mirror_ledgers`.
+    pub fn get_local_txn(&self, name: &str, ledger_id: usize, seq_no: usize) -> IndyResult<serde_json::Value> {
+        let merkle = ledger_mirror::get_local_ledger(name, ledger_id)?;
+
+        if seq_no == 0 || seq_no > merkle.count() {
+            return Err(err_msg(IndyErrorKind::LedgerItemNotFound,
+                               format!("Transaction {} not found in local copy of ledger {}, local size: {}", seq_no, ledger_id, merkle.count())));
+        }
+
+        let txn = merkle.iter().nth(seq_no - 1)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Local ledger is shorter than its merkle tree"))?;
+
+        merkle_tree_factory::decode_txn(txn)
+    }
+
+    /// Returns up to `count` transactions of given type starting from `from_seq_no` from the local copy of the ledger.
+    pub fn get_local_txns_by_type(&self, name: &str, ledger_id: usize, txn_type: &str, from_seq_no: usize, count: usize) -> IndyResult<Vec<serde_json::Value>> {
+        let merkle = ledger_mirror::get_local_ledger(name, ledger_id)?;
+
+        let mut txns = Vec::new();
+
+        for txn in merkle.iter().skip(from_seq_no.max(1) - 1) {
+            if txns.len() >= count {
+                break;
+            }
+
+            let txn = merkle_tree_factory::decode_txn(txn)?;
+
+            let type_ = txn["txn"]["type"].as_str().or_else(|| txn["type"].as_str());
+            if type_ == Some(txn_type) {
+                txns.push(txn);
+            }
+        }
+
+        Ok(txns)
+    }
+
+    /// Verifies audit path of GET_TXN reply against the ledger root multi-signed by nodes of the opened pool.
+    pub fn verify_txn_inclusion(&self, handle: PoolHandle, response: &str) -> IndyResult<TxnInclusion> {
+        let pool_name = self.open_pools.try_borrow()?
+            .get(&handle)
+            .map(|pool| pool.pool.get_name().to_string())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))?;
+
+        let message: Message<serde_json::Value> = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Cannot deserialize transaction Response")?;
+
+        let result = _handle_response_message_type(message)?.result();
+
+        let (nodes, f) = pool::get_nodes_bls_keys(&pool_name)?;
+
+        let generator = DEFAULT_GENERATOR.from_base58()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Invalid BLS generator"))?;
+        let generator = Generator::from_bytes(&generator)?;
+
+        state_proof::verify_txn_inclusion(&result, &nodes, f, &generator)
+    }
+
+    /// Registers transaction author agreement acceptance to be appended to writes sent through the pool.
+    /// Replaces previously registered acceptance together with agreement cached from the ledger.
+    pub fn set_taa_acceptance(&self, handle: PoolHandle, acceptance: TxnAuthrAgrmtAcceptanceData) -> IndyResult<()> {
+        if !self.open_pools.try_borrow()?.contains_key(&handle) {
+            return Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)));
+        }
+
+        self.taa_acceptances.try_borrow_mut()?
+            .insert(handle, PoolTxnAuthorAgreementAcceptance { acceptance, ledger_taa: None, ledger_taa_time: 0 });
+
+        Ok(())
+    }
+
+    /// Returns acceptance registered on the pool.
+    /// Agreement cached from the ledger is omitted if it was read more than `LEDGER_TAA_TIMEOUT` seconds ago.
+    pub fn get_taa_acceptance(&self, handle: PoolHandle, cur_time: u64) -> Option<PoolTxnAuthorAgreementAcceptance> {
+        self.taa_acceptances.borrow().get(&handle)
+            .cloned()
+            .map(|mut taa_acceptance| {
+                if cur_time >= taa_acceptance.ledger_taa_time + LEDGER_TAA_TIMEOUT {
+                    taa_acceptance.ledger_taa = None;
+                }
+                taa_acceptance
+            })
+    }
+
+    /// Caches agreement read from the ledger for acceptance registered on the pool.
+    pub fn set_ledger_taa(&self, handle: PoolHandle, ledger_taa: TxnAuthorAgreementData, cur_time: u64) {
+        if let Some(taa_acceptance) = self.taa_acceptances.borrow_mut().get_mut(&handle) {
+            taa_acceptance.ledger_taa = Some(ledger_taa);
+            taa_acceptance.ledger_taa_time = cur_time;
+        }
+    }
+
+    /// Drops agreement cached from the ledger, so it will be read again on the next write.
+    pub fn forget_ledger_taa(&self, handle: PoolHandle) {
+        if let Some(taa_acceptance) = self.taa_acceptances.borrow_mut().get_mut(&handle) {
+            taa_acceptance.ledger_taa = None;
+        }
+    }
 }
 
 lazy_static! {
@@ -274,7 +470,32 @@ pub fn set_freshness_threshold(threshold: u64) {
 
 pub fn parse_response_metadata(response: &str) -> IndyResult<ResponseMetadata> {
     trace!("indy::services::pool::parse_response_metadata << response: {}", response);
-    let message: Message<serde_json::Value> = serde_json::from_str(response)
+    let response: serde_json::Value = serde_json::from_str(response)
+        .to_indy(IndyErrorKind::InvalidTransaction, "Cannot deserialize transaction Response")?;
+
+    let response_metadata = match response.get("consensus") {
+        // result of submit with details: node reply is put into "reply" field
+        Some(consensus) if response.get("reply").is_some() => {
+            let consensus: Option<ConsensusDetails> = serde_json::from_value(consensus.clone())
+                .to_indy(IndyErrorKind::InvalidTransaction, "Cannot deserialize consensus details")?;
+
+            let mut response_metadata = match response["reply"]["op"].as_str() {
+                Some("REPLY") => _parse_response_metadata(response["reply"].clone())?,
+                _ => ResponseMetadata { seq_no: None, txn_time: None, last_txn_time: None, last_seq_no: None, consensus: None }
+            };
+            response_metadata.consensus = consensus;
+            response_metadata
+        }
+        _ => _parse_response_metadata(response)?
+    };
+
+    trace!("indy::services::pool::parse_response_metadata >> response_metadata: {:?}", response_metadata);
+
+    Ok(response_metadata)
+}
+
+fn _parse_response_metadata(response: serde_json::Value) -> IndyResult<ResponseMetadata> {
+    let message: Message<serde_json::Value> = serde_json::from_value(response)
         .to_indy(IndyErrorKind::InvalidTransaction, "Cannot deserialize transaction Response")?;
 
     let response_object: Reply<serde_json::Value> = _handle_response_message_type(message)?;
@@ -286,8 +507,6 @@ pub fn parse_response_metadata(response: &str) -> IndyResult<ResponseMetadata> {
         ver=> return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Unsupported transaction response version: {:?}", ver)))
     };
 
-    trace!("indy::services::pool::parse_response_metadata >> response_metadata: {:?}", response_metadata);
-
     Ok(response_metadata)
 }
 
@@ -313,6 +532,7 @@ fn _parse_transaction_metadata_v0(message: &serde_json::Value) -> ResponseMetada
         txn_time: message["txnTime"].as_u64(),
         last_txn_time: message["state_proof"]["multi_signature"]["value"]["timestamp"].as_u64(),
         last_seq_no: None,
+        consensus: None,
     }
 }
 
@@ -322,6 +542,7 @@ fn _parse_transaction_metadata_v1(message: &serde_json::Value) -> ResponseMetada
         txn_time: message["txnMetadata"]["txnTime"].as_u64(),
         last_txn_time: message["multiSignature"]["signedState"]["stateMetadata"]["timestamp"].as_u64(),
         last_seq_no: None,
+        consensus: None,
     }
 }
 
@@ -352,12 +573,16 @@ mod tests {
         ProtocolVersion::set(version);
     }
 
+    fn _zmq_pool(name: &str, pool_id: PoolHandle) -> Pool<ZMQNetworker, RequestHandlerImpl<ZMQNetworker>> {
+        Pool::new(name, pool_id, PoolOpenConfig::default())
+    }
+
     mod pool_service {
         use std::path;
 
         use libc::c_char;
 
-        use indy_api_types::{ErrorCode, INVALID_POOL_HANDLE};
+        use indy_api_types::{ErrorCode, IndyHandle, INVALID_POOL_HANDLE};
 
         use super::*;
 
@@ -384,7 +609,7 @@ mod tests {
             let ps = PoolService::new();
             let pool_id = next_pool_handle();
             let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("pool_service_close_works");
-            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(Pool::new("", pool_id, PoolOpenConfig::default()), send_cmd_sock));
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(_zmq_pool("", pool_id), send_cmd_sock));
             let cmd_id = ps.close(pool_id).unwrap();
             let recv = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
             assert_eq!(recv.len(), 3);
@@ -399,7 +624,7 @@ mod tests {
             let ps = PoolService::new();
             let pool_id = next_pool_handle();
             let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("pool_service_refresh_works");
-            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(Pool::new("", pool_id, PoolOpenConfig::default()), send_cmd_sock));
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(_zmq_pool("", pool_id), send_cmd_sock));
             let cmd_id = ps.refresh(pool_id).unwrap();
             let recv = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
             assert_eq!(recv.len(), 3);
@@ -422,6 +647,42 @@ mod tests {
             test::cleanup_storage("pool_service_delete_works");
         }
 
+        #[test]
+        fn pool_service_get_local_txn_works_for_pool_ledger() {
+            test::cleanup_storage("pool_service_get_local_txn_works_for_pool_ledger");
+            _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+            let mut f = test::test_pool_create_poolfile("pool_service_get_local_txn_works_for_pool_ledger");
+            writeln!(f, "{}", test::gen_txns().join("\n")).unwrap();
+
+            let ps = PoolService::new();
+
+            let txn = ps.get_local_txn("pool_service_get_local_txn_works_for_pool_ledger", 0, 2).unwrap();
+            assert_eq!("Node2", txn["txn"]["data"]["data"]["alias"]);
+
+            let res = ps.get_local_txn("pool_service_get_local_txn_works_for_pool_ledger", 0, 5);
+            assert_eq!(IndyErrorKind::LedgerItemNotFound, res.unwrap_err().kind());
+
+            let res = ps.get_local_txn("pool_service_get_local_txn_works_for_pool_ledger", 1, 1);
+            assert_eq!(IndyErrorKind::LedgerItemNotFound, res.unwrap_err().kind());
+
+            let txns = ps.get_local_txns_by_type("pool_service_get_local_txn_works_for_pool_ledger", 0, "0", 2, 2).unwrap();
+            assert_eq!(2, txns.len());
+            assert_eq!(2, txns[0]["txnMetadata"]["seqNo"]);
+
+            test::cleanup_storage("pool_service_get_local_txn_works_for_pool_ledger");
+        }
+
+        #[test]
+        fn pool_service_get_local_txn_works_for_not_created_pool() {
+            test::cleanup_storage("pool_service_get_local_txn_works_for_not_created_pool");
+
+            let ps = PoolService::new();
+
+            let res = ps.get_local_txn("pool_service_get_local_txn_works_for_not_created_pool", 0, 1);
+            assert_eq!(IndyErrorKind::PoolNotCreated, res.unwrap_err().kind());
+        }
+
         #[test]
         fn pool_service_delete_works_for_opened() {
             test::cleanup_storage("pool_service_delete_works_for_opened");
@@ -432,7 +693,7 @@ mod tests {
             let path: path::PathBuf = environment::pool_path(pool_name);
             let pool_id = next_pool_handle();
 
-            let pool = Pool::new(pool_name, pool_id, PoolOpenConfig::default());
+            let pool = _zmq_pool(pool_name, pool_id);
             ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
 
             fs::create_dir_all(path.as_path()).unwrap();
@@ -451,7 +712,7 @@ mod tests {
             let name = "test";
             let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("pool_send_tx_works");
             let pool_id = next_pool_handle();
-            let pool = Pool::new(name, pool_id, PoolOpenConfig::default());
+            let pool = _zmq_pool(name, pool_id);
             let ps = PoolService::new();
             ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
             let test_data = "str_instead_of_tx_json";
@@ -468,7 +729,7 @@ mod tests {
             let send_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR).unwrap();
 
             let pool_id = next_pool_handle();
-            let pool = Pool::new(name, pool_id, PoolOpenConfig::default());
+            let pool = _zmq_pool(name, pool_id);
             let ps = PoolService::new();
             ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
             let res = ps.send_tx(pool_id, "test_data");
@@ -489,7 +750,7 @@ mod tests {
 
             let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("pool_send_action_works");
             let pool_id = next_pool_handle();
-            let pool = Pool::new("pool_send_action_works", pool_id, PoolOpenConfig::default());
+            let pool = _zmq_pool("pool_send_action_works", pool_id);
             let ps = PoolService::new();
             ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
             let test_data = "str_instead_of_tx_json";
@@ -540,6 +801,46 @@ mod tests {
             PoolService::get_sp_parser("test").unwrap();
         }
 
+        #[test]
+        fn pool_register_transport_works() {
+            test::cleanup_storage("pool_register_transport_works");
+            PoolService::register_transport("pool_register_transport_works", _plugged_transport_type()).unwrap();
+            assert!(PoolService::get_transport("pool_register_transport_works").is_some());
+        }
+
+        #[test]
+        fn pool_register_transport_works_for_built_in_name() {
+            test::cleanup_storage("pool_register_transport_works_for_built_in_name");
+            let res = PoolService::register_transport("zmq", _plugged_transport_type());
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_register_transport_works_for_twice() {
+            test::cleanup_storage("pool_register_transport_works_for_twice");
+            PoolService::register_transport("pool_register_transport_works_for_twice", _plugged_transport_type()).unwrap();
+            let res = PoolService::register_transport("pool_register_transport_works_for_twice", _plugged_transport_type());
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_open_works_for_unknown_transport() {
+            test::cleanup_storage("pool_open_works_for_unknown_transport");
+            let ps = PoolService::new();
+            let config = PoolOpenConfig { transport: PoolTransport::Plugged("unknown".to_string()), ..PoolOpenConfig::default() };
+            let res = ps.open("pool_open_works_for_unknown_transport", Some(config));
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        fn _plugged_transport_type() -> PluggedTransportType {
+            extern fn open(_pool_name: *const c_char, _transport_handle_p: *mut IndyHandle) -> ErrorCode { ErrorCode::Success }
+            extern fn send(_transport_handle: IndyHandle, _node_alias: *const c_char, _node_address: *const c_char, _msg: *const c_char) -> ErrorCode { ErrorCode::Success }
+            extern fn recv(_transport_handle: IndyHandle, _node_alias_p: *mut *const c_char, _msg_p: *mut *const c_char, _buffer_handle_p: *mut IndyHandle) -> ErrorCode { ErrorCode::Success }
+            extern fn free_buffer(_buffer_handle: IndyHandle) -> ErrorCode { ErrorCode::Success }
+            extern fn close(_transport_handle: IndyHandle) -> ErrorCode { ErrorCode::Success }
+            PluggedTransportType::new(open, send, recv, free_buffer, close)
+        }
+
         #[test]
         fn pool_get_sp_parser_works_for_invalid_name() {
             test::cleanup_storage("pool_get_sp_parser_works_for_invalid_name");
@@ -553,7 +854,7 @@ mod tests {
             let ps = PoolService::new();
             let (send_cmd_sock, _recv_cmd_sock) = pool_create_pair_of_sockets("pool_add_open_pool_works");
             let pool_id = next_pool_handle();
-            let pool = Pool::new("pool_add_open_pool_works", pool_id, PoolOpenConfig::default());
+            let pool = _zmq_pool("pool_add_open_pool_works", pool_id);
             ps.pending_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
             assert_match!(Ok(_pool_id), ps.add_open_pool(pool_id));
         }
@@ -565,6 +866,108 @@ mod tests {
             let res = ps.add_open_pool(INVALID_POOL_HANDLE);
             assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
         }
+
+        #[test]
+        pub fn pool_service_set_taa_acceptance_works() {
+            test::cleanup_storage("pool_service_set_taa_acceptance_works");
+            let ps = PoolService::new();
+            let (send_cmd_sock, _recv_cmd_sock) = pool_create_pair_of_sockets("pool_service_set_taa_acceptance_works");
+            let pool_id = next_pool_handle();
+            let pool = _zmq_pool("pool_service_set_taa_acceptance_works", pool_id);
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
+
+            let acceptance = TxnAuthrAgrmtAcceptanceData { mechanism: "on_file".to_string(), taa_digest: "abcd".to_string(), time: 123379200 };
+            ps.set_taa_acceptance(pool_id, acceptance.clone()).unwrap();
+
+            let ledger_taa = TxnAuthorAgreementData {
+                text: "text".to_string(),
+                version: "1.0".to_string(),
+                digest: Some("abcd".to_string()),
+                ratification_ts: None,
+                retirement_ts: None,
+            };
+            ps.set_ledger_taa(pool_id, ledger_taa.clone(), 1000);
+
+            let taa_acceptance = ps.get_taa_acceptance(pool_id, 1000).unwrap();
+            assert_eq!(acceptance, taa_acceptance.acceptance);
+            assert_eq!(Some(ledger_taa), taa_acceptance.ledger_taa);
+
+            ps.set_taa_acceptance(pool_id, acceptance).unwrap();
+            assert_eq!(None, ps.get_taa_acceptance(pool_id, 1000).unwrap().ledger_taa);
+        }
+
+        #[test]
+        pub fn pool_service_get_taa_acceptance_works_for_expired_and_forgotten_ledger_taa() {
+            test::cleanup_storage("pool_service_get_taa_acceptance_works_for_expired_and_forgotten_ledger_taa");
+            let ps = PoolService::new();
+            let (send_cmd_sock, _recv_cmd_sock) = pool_create_pair_of_sockets("pool_service_get_taa_acceptance_works_for_expired_and_forgotten_ledger_taa");
+            let pool_id = next_pool_handle();
+            let pool = _zmq_pool("pool_service_get_taa_acceptance_works_for_expired_and_forgotten_ledger_taa", pool_id);
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
+
+            let acceptance = TxnAuthrAgrmtAcceptanceData { mechanism: "on_file".to_string(), taa_digest: "abcd".to_string(), time: 123379200 };
+            ps.set_taa_acceptance(pool_id, acceptance).unwrap();
+
+            let ledger_taa = TxnAuthorAgreementData {
+                text: "text".to_string(),
+                version: "1.0".to_string(),
+                digest: Some("abcd".to_string()),
+                ratification_ts: None,
+                retirement_ts: None,
+            };
+            ps.set_ledger_taa(pool_id, ledger_taa, 1000);
+
+            assert!(ps.get_taa_acceptance(pool_id, 1000 + LEDGER_TAA_TIMEOUT - 1).unwrap().ledger_taa.is_some());
+            assert_eq!(None, ps.get_taa_acceptance(pool_id, 1000 + LEDGER_TAA_TIMEOUT).unwrap().ledger_taa);
+
+            ps.forget_ledger_taa(pool_id);
+            assert_eq!(None, ps.get_taa_acceptance(pool_id, 1000).unwrap().ledger_taa);
+        }
+
+        #[test]
+        pub fn pool_service_set_taa_acceptance_works_for_invalid_handle() {
+            test::cleanup_storage("pool_service_set_taa_acceptance_works_for_invalid_handle");
+            let ps = PoolService::new();
+            let acceptance = TxnAuthrAgrmtAcceptanceData { mechanism: "on_file".to_string(), taa_digest: "abcd".to_string(), time: 123379200 };
+            let res = ps.set_taa_acceptance(INVALID_POOL_HANDLE, acceptance);
+            assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
+        }
+    }
+
+    #[test]
+    fn parse_response_metadata_works_for_response_with_details() {
+        let response = json!({
+            "reply": {"op": "REPLY", "result": {"ver": "1", "txnMetadata": {"seqNo": 10, "txnTime": 1000}}},
+            "consensus": {
+                "reached": true,
+                "f": 0,
+                "nodes": {"n1": {"acked": true, "status": "REPLY"}}
+            }
+        }).to_string();
+
+        let response_metadata = parse_response_metadata(&response).unwrap();
+        assert_eq!(Some(10), response_metadata.seq_no);
+        assert_eq!(Some(1000), response_metadata.txn_time);
+
+        let consensus = response_metadata.consensus.unwrap();
+        assert!(consensus.reached);
+        assert!(consensus.nodes["n1"].acked);
+    }
+
+    #[test]
+    fn parse_response_metadata_works_for_response_with_details_without_reply() {
+        let response = json!({
+            "reply": null,
+            "consensus": {
+                "reached": false,
+                "f": 0,
+                "nodes": {"n1": {"acked": false, "status": "REJECT", "reason": "reject", "errorKind": "Other"}}
+            }
+        }).to_string();
+
+        let response_metadata = parse_response_metadata(&response).unwrap();
+        assert_eq!(None, response_metadata.seq_no);
+        assert!(!response_metadata.consensus.unwrap().reached);
     }
 
     #[test]
@@ -592,7 +995,7 @@ mod tests {
             file.write(&gen_txn.as_bytes()).unwrap();
 
             let pool_id = next_pool_handle();
-            let mut pool = Pool::new(pool_name, pool_id, PoolOpenConfig::default());
+            let mut pool = _zmq_pool(pool_name, pool_id);
             pool.work(recv_cmd_sock);
             ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
             thread::sleep(time::Duration::from_secs(1));
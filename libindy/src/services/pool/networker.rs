@@ -6,6 +6,7 @@ use rand::prelude::SliceRandom;
 use time::Tm;
 
 use indy_api_types::errors::prelude::*;
+use crate::domain::pool::{PoolOpenConfig, PoolTransport};
 use crate::services::pool::events::*;
 use crate::services::pool::types::*;
 use indy_utils::sequence;
@@ -17,11 +18,30 @@ use super::zmq::PollItem;
 use super::zmq::Socket as ZSocket;
 
 pub trait Networker {
-    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self;
+    fn open(pool_name: &str, config: &PoolOpenConfig) -> IndyResult<Self> where Self: Sized;
     fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent>;
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent>;
     fn get_timeout(&self) -> ((String, String), i64);
     fn get_poll_items(&self) -> Vec<PollItem>;
+    /// Whether replies are already buffered and can be fetched without polling sockets.
+    fn has_pending_events(&self) -> bool { false }
+    /// Max time (in ms) to wait on sockets before checking replies that aren't delivered through sockets.
+    fn get_poll_interval(&self) -> Option<i64> { None }
+}
+
+/// Message-level transport to the pool nodes.
+///
+/// Implementations don't deal with sockets polling, timeouts or resending:
+/// `TransportNetworker` keeps this bookkeeping and passes ready messages here.
+pub trait Transport {
+    fn open(pool_name: &str, transport: &PoolTransport) -> IndyResult<Self> where Self: Sized;
+    fn nodes_updated(&mut self, _nodes: &[RemoteNode]) {}
+    fn send(&mut self, node: &RemoteNode, msg: &str) -> IndyResult<()>;
+    /// Returns received replies as (reply, node alias) pairs.
+    fn recv(&mut self) -> Vec<(String, String)>;
+    fn has_pending(&mut self) -> bool;
+    /// Max time (in ms) between checks for the received replies if transport can't report them in `has_pending`.
+    fn poll_interval(&self) -> Option<i64> { None }
 }
 
 pub struct ZMQNetworker {
@@ -34,8 +54,8 @@ pub struct ZMQNetworker {
     socks_proxy: String,
 }
 
-impl Networker for ZMQNetworker {
-    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self {
+impl ZMQNetworker {
+    pub fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self {
         ZMQNetworker {
             req_id_mappings: HashMap::new(),
             pool_connections: BTreeMap::new(),
@@ -46,6 +66,12 @@ impl Networker for ZMQNetworker {
             socks_proxy,
         }
     }
+}
+
+impl Networker for ZMQNetworker {
+    fn open(_pool_name: &str, config: &PoolOpenConfig) -> IndyResult<Self> {
+        Ok(ZMQNetworker::new(config.conn_active_timeout, config.conn_limit, config.preordered_nodes.clone(), config.socks_proxy.clone()))
+    }
 
     fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent> {
         let mut cnt = 0;
@@ -343,7 +369,7 @@ impl PoolConnection {
 }
 
 impl RemoteNode {
-    fn connect(&self, ctx: &zmq::Context, key_pair: &zmq::CurveKeyPair, socks_proxy: String) -> IndyResult<ZSocket> {
+    pub(super) fn connect(&self, ctx: &zmq::Context, key_pair: &zmq::CurveKeyPair, socks_proxy: String) -> IndyResult<ZSocket> {
         let s = ctx.socket(zmq::SocketType::DEALER)?;
         s.set_identity(base64::encode(&key_pair.public_key).as_bytes())?;
         s.set_curve_secretkey(&key_pair.secret_key)?;
@@ -368,18 +394,165 @@ impl RemoteNode {
     }
 }
 
+pub struct TransportNetworker<T: Transport> {
+    transport: RefCell<T>,
+    nodes: Vec<RemoteNode>,
+    preordered_nodes: Vec<String>,
+    resend: HashMap<String, (usize, String)>,
+    timeouts: HashMap<(String, String), Tm>,
+}
+
+impl<T: Transport> TransportNetworker<T> {
+    pub fn new(transport: T, preordered_nodes: Vec<String>) -> Self {
+        TransportNetworker {
+            transport: RefCell::new(transport),
+            nodes: Vec::new(),
+            preordered_nodes,
+            resend: HashMap::new(),
+            timeouts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Transport> Networker for TransportNetworker<T> {
+    fn open(pool_name: &str, config: &PoolOpenConfig) -> IndyResult<Self> {
+        let transport = T::open(pool_name, &config.transport)?;
+        Ok(TransportNetworker::new(transport, config.preordered_nodes.clone()))
+    }
+
+    fn fetch_events(&self, _poll_items: &[PollItem]) -> Vec<PoolEvent> {
+        self.transport.borrow_mut().recv()
+            .into_iter()
+            .map(|(msg, node_alias)| PoolEvent::NodeReply(msg, node_alias))
+            .collect()
+    }
+
+    fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
+        let res = match pe {
+            Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout)) => {
+                let res = self._send_msg_to_one_node(0, req_id.clone(), msg.clone(), timeout);
+                self.resend.insert(req_id, (0, msg));
+                res
+            }
+            Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send)) => {
+                let mut res = Ok(());
+                for idx in 0..self.nodes.len() {
+                    if nodes_to_send.as_ref().map(|nodes| nodes.contains(&self.nodes[idx].name)).unwrap_or(true) {
+                        res = res.and(self._send_msg_to_one_node(idx, req_id.clone(), msg.clone(), timeout));
+                    }
+                }
+                res
+            }
+            Some(NetworkerEvent::Resend(req_id, timeout)) => {
+                let resend = if let Some(&mut (ref mut cnt, ref req)) = self.resend.get_mut(&req_id) {
+                    *cnt += 1;
+                    Some((*cnt % self.nodes.len(), req.clone()))
+                } else {
+                    error!("Unknown req_id for resending {}", req_id);
+                    None
+                };
+                match resend {
+                    Some((idx, req)) => self._send_msg_to_one_node(idx, req_id, req, timeout),
+                    None => Ok(())
+                }
+            }
+            Some(NetworkerEvent::NodesStateUpdated(mut nodes)) => {
+                trace!("TransportNetworker::process_event: nodes_updated {:?}", nodes);
+                nodes.shuffle(&mut thread_rng());
+                if !self.preordered_nodes.is_empty() {
+                    let preordered_nodes = &self.preordered_nodes;
+                    nodes.sort_by_key(|node: &RemoteNode| -> usize {
+                        preordered_nodes.iter()
+                            .position(|name| node.name.eq(name))
+                            .unwrap_or(usize::max_value())
+                    });
+                }
+                self.transport.borrow_mut().nodes_updated(&nodes);
+                self.nodes = nodes;
+                Ok(())
+            }
+            Some(NetworkerEvent::ExtendTimeout(req_id, node_alias, timeout)) => {
+                if let Some(tm) = self.timeouts.get_mut(&(req_id.clone(), node_alias.clone())) {
+                    *tm = time::now() + Duration::seconds(timeout);
+                } else {
+                    debug!("late REQACK for req_id {}, node {}", req_id, node_alias);
+                }
+                Ok(())
+            }
+            Some(NetworkerEvent::CleanTimeout(req_id, node_alias)) => {
+                match node_alias {
+                    Some(node_alias) => {
+                        self.timeouts.remove(&(req_id, node_alias));
+                    }
+                    None => {
+                        self.timeouts.retain(|&(ref req_id_timeout, _), _| req_id.ne(req_id_timeout));
+                        self.resend.remove(&req_id);
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(())
+        };
+
+        if let Err(err) = res {
+            error!("TransportNetworker::process_event: can't send message {:?}", err);
+        }
+
+        None
+    }
+
+    fn get_timeout(&self) -> ((String, String), i64) {
+        self.timeouts.iter()
+            .map(|(key, value)| (key, (*value - time::now()).num_milliseconds()))
+            .min_by(|&(_, ref val1), &(_, ref val2)| val1.cmp(&val2))
+            .map(|(&(ref req_id, ref node_alias), timeout)| ((req_id.to_string(), node_alias.to_string()), timeout))
+            .unwrap_or((("".to_string(), "".to_string()), ::std::i64::MAX))
+    }
+
+    fn get_poll_items(&self) -> Vec<PollItem> {
+        Vec::new()
+    }
+
+    fn has_pending_events(&self) -> bool {
+        self.transport.borrow_mut().has_pending()
+    }
+
+    fn get_poll_interval(&self) -> Option<i64> {
+        self.transport.borrow().poll_interval()
+    }
+}
+
+impl<T: Transport> TransportNetworker<T> {
+    fn _send_msg_to_one_node(&mut self, idx: usize, req_id: String, req: String, timeout: i64) -> IndyResult<()> {
+        trace!("TransportNetworker::_send_msg_to_one_node >> idx {}, req_id {}, req {}", idx, req_id, req);
+        let node = self.nodes.get(idx)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("No node with index {} to send request", idx)))?;
+        self.transport.borrow_mut().send(node, &req)?;
+        self.timeouts.insert((req_id, node.name.clone()), time::now() + Duration::seconds(timeout));
+        trace!("TransportNetworker::_send_msg_to_one_node <<");
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub struct MockNetworker {
     pub events: Vec<Option<NetworkerEvent>>,
 }
 
 #[cfg(test)]
-impl Networker for MockNetworker {
-    fn new(_active_timeout: i64, _conn_limit: usize, _preordered_nodes: Vec<String>, _socks_proxy: String) -> Self {
+impl MockNetworker {
+    pub fn new(_active_timeout: i64, _conn_limit: usize, _preordered_nodes: Vec<String>, _socks_proxy: String) -> Self {
         MockNetworker {
             events: Vec::new(),
         }
     }
+}
+
+#[cfg(test)]
+impl Networker for MockNetworker {
+    fn open(_pool_name: &str, config: &PoolOpenConfig) -> IndyResult<Self> {
+        Ok(MockNetworker::new(config.conn_active_timeout, config.conn_limit, config.preordered_nodes.clone(), config.socks_proxy.clone()))
+    }
 
     fn fetch_events(&self, _poll_items: &[zmq::PollItem]) -> Vec<PoolEvent> {
         unimplemented!()
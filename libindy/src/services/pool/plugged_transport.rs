@@ -0,0 +1,173 @@
+use std::collections::VecDeque;
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use libc::c_char;
+
+use indy_api_types::{ErrorCode, IndyHandle};
+use indy_api_types::errors::prelude::*;
+use crate::api::pool::{PoolTransportClose, PoolTransportFreeBuffer, PoolTransportOpen, PoolTransportRecv, PoolTransportSend};
+use crate::domain::pool::PoolTransport;
+use crate::services::pool::PoolService;
+use crate::services::pool::networker::Transport;
+use crate::services::pool::types::RemoteNode;
+
+/// Interval (in ms) to poll plugged transport for the received messages.
+const POLL_INTERVAL: i64 = 10;
+
+struct BufferGuard {
+    buffer_handle: IndyHandle,
+    free_buffer: PoolTransportFreeBuffer,
+}
+
+impl BufferGuard {
+    fn new(buffer_handle: IndyHandle, free_buffer: PoolTransportFreeBuffer) -> BufferGuard {
+        BufferGuard { buffer_handle, free_buffer }
+    }
+}
+
+impl Drop for BufferGuard {
+    fn drop(&mut self) {
+        let err = (self.free_buffer)(self.buffer_handle);
+        if err != ErrorCode::Success {
+            warn!("Unable to free pool transport buffer {}: {:?}", self.buffer_handle, err);
+        }
+    }
+}
+
+/// Handlers of the transport registered with `indy_register_pool_transport`.
+#[derive(Clone, Copy)]
+pub struct PluggedTransportType {
+    open_handler: PoolTransportOpen,
+    send_handler: PoolTransportSend,
+    recv_handler: PoolTransportRecv,
+    free_buffer_handler: PoolTransportFreeBuffer,
+    close_handler: PoolTransportClose,
+}
+
+impl PluggedTransportType {
+    pub fn new(open_handler: PoolTransportOpen,
+               send_handler: PoolTransportSend,
+               recv_handler: PoolTransportRecv,
+               free_buffer_handler: PoolTransportFreeBuffer,
+               close_handler: PoolTransportClose) -> PluggedTransportType {
+        PluggedTransportType {
+            open_handler,
+            send_handler,
+            recv_handler,
+            free_buffer_handler,
+            close_handler,
+        }
+    }
+}
+
+/// Connection of the registered transport opened for single pool.
+pub struct PluggedTransport {
+    transport_type: PluggedTransportType,
+    handle: IndyHandle,
+    received: VecDeque<(String, String)>,
+}
+
+impl Transport for PluggedTransport {
+    fn open(pool_name: &str, transport: &PoolTransport) -> IndyResult<Self> {
+        let transport_type = PoolService::get_transport(transport.name())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unknown pool transport: {}", transport.name())))?;
+
+        let pool_name = CString::new(pool_name)?;
+        let mut handle: IndyHandle = -1;
+
+        let err = (transport_type.open_handler)(pool_name.as_ptr(), &mut handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(PluggedTransport {
+            transport_type,
+            handle,
+            received: VecDeque::new(),
+        })
+    }
+
+    fn send(&mut self, node: &RemoteNode, msg: &str) -> IndyResult<()> {
+        let node_alias = CString::new(node.name.as_str())?;
+        let node_address = CString::new(node.zaddr.as_str())?;
+        let msg = CString::new(msg)?;
+
+        let err = (self.transport_type.send_handler)(self.handle, node_alias.as_ptr(), node_address.as_ptr(), msg.as_ptr());
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    fn recv(&mut self) -> Vec<(String, String)> {
+        self._receive();
+        self.received.drain(..).collect()
+    }
+
+    fn has_pending(&mut self) -> bool {
+        self._receive();
+        !self.received.is_empty()
+    }
+
+    fn poll_interval(&self) -> Option<i64> {
+        Some(POLL_INTERVAL)
+    }
+}
+
+impl PluggedTransport {
+    fn _receive(&mut self) {
+        loop {
+            match self._receive_one() {
+                Ok(Some(reply)) => self.received.push_back(reply),
+                Ok(None) => break,
+                Err(err) => {
+                    error!("PluggedTransport: can't receive message {:?}", err);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn _receive_one(&self) -> IndyResult<Option<(String, String)>> {
+        let mut node_alias_ptr: *const c_char = ptr::null();
+        let mut msg_ptr: *const c_char = ptr::null();
+        let mut buffer_handle: IndyHandle = -1;
+
+        let err = (self.transport_type.recv_handler)(self.handle, &mut node_alias_ptr, &mut msg_ptr, &mut buffer_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        if node_alias_ptr.is_null() || msg_ptr.is_null() {
+            return Ok(None);
+        }
+
+        let _buffer_guard = BufferGuard::new(buffer_handle, self.transport_type.free_buffer_handler);
+
+        let node_alias = PluggedTransport::_copy_str(node_alias_ptr, "node alias")?;
+        let msg = PluggedTransport::_copy_str(msg_ptr, "message")?;
+
+        Ok(Some((msg, node_alias)))
+    }
+
+    fn _copy_str(ptr: *const c_char, name: &str) -> IndyResult<String> {
+        unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .map(str::to_string)
+            .to_indy(IndyErrorKind::InvalidState, format!("Pool transport returned {} with non-utf8 symbols", name))
+    }
+}
+
+impl Drop for PluggedTransport {
+    fn drop(&mut self) {
+        let err = (self.transport_type.close_handler)(self.handle);
+        if err != ErrorCode::Success {
+            warn!("Unable to close pool transport {}: {:?}", self.handle, err);
+        }
+    }
+}
@@ -19,10 +19,12 @@ use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::commander::Commander;
 use crate::services::pool::events::*;
 use crate::services::pool::{merkle_tree_factory, Nodes};
-use crate::services::pool::networker::{Networker, ZMQNetworker};
-use crate::services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
+use crate::services::pool::ledger_mirror::LedgerMirror;
+use crate::services::pool::networker::Networker;
+use crate::services::pool::read_cache::ReadCache;
+use crate::services::pool::request_handler::RequestHandler;
 use rust_base58::{FromBase58, ToBase58};
-use crate::services::pool::types::{LedgerStatus, RemoteNode};
+use crate::services::pool::types::{LedgerStatus, NodeTransactionV1, RemoteNode};
 use indy_utils::crypto::ed25519_sign;
 
 use super::ursa::bls::VerKey;
@@ -35,6 +37,8 @@ struct PoolSM<T: Networker, R: RequestHandler<T>> {
     timeout: i64,
     extended_timeout: i64,
     number_read_nodes: u8,
+    read_cache: ReadCache,
+    ledger_mirror: LedgerMirror,
     state: PoolState<T, R>,
 }
 
@@ -92,14 +96,28 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
             timeout,
             extended_timeout,
             number_read_nodes,
+            read_cache: ReadCache::new(0),
+            ledger_mirror: LedgerMirror::new(pname, false),
             state: PoolState::Initialization(InitializationState {
                 networker
             }),
         }
     }
 
-    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, state: PoolState<T, R>) -> Self {
-        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, state }
+    /// Replaces disabled read cache the pool starts with.
+    pub fn with_read_cache(mut self, read_cache: ReadCache) -> Self {
+        self.read_cache = read_cache;
+        self
+    }
+
+    /// Replaces disabled ledger mirror the pool starts with.
+    pub fn with_ledger_mirror(mut self, ledger_mirror: LedgerMirror) -> Self {
+        self.ledger_mirror = ledger_mirror;
+        self
+    }
+
+    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, read_cache: ReadCache, ledger_mirror: LedgerMirror, state: PoolState<T, R>) -> Self {
+        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, read_cache, ledger_mirror, state }
     }
 }
 
@@ -268,7 +286,7 @@ impl<T: Networker> From<TerminatedState<T>> for ClosedState {
 
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
     pub fn handle_event(self, pe: PoolEvent) -> Self {
-        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes } = self;
+        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes, mut read_cache, mut ledger_mirror } = self;
         let state = match state {
             PoolState::Initialization(state) => match pe {
                 PoolEvent::CheckCache(cmd_id) => {
@@ -310,8 +328,8 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::CatchupRestart(merkle_tree) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
-                            let ls = _ledger_status(&merkle_tree);
+                            state.request_handler = R::new(state.networker.clone(), get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                            let ls = ledger_status(&merkle_tree);
                             state.request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle_tree))));
                             PoolState::GettingCatchupTarget(state)
                         } else {
@@ -321,7 +339,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                            let mut request_handler = R::new(state.networker.clone(), get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
                             request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
                             PoolState::SyncCatchup((request_handler, state).into())
                         } else {
@@ -382,11 +400,28 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::SendRequest(cmd_id, _, _, _) => {
                         trace!("received request to send");
                         let re: Option<RequestEvent> = pe.into();
+                        let cache_key = match re {
+                            Some(RequestEvent::CustomSingleRequest(ref msg, _, ref sp_key, timestamps)) if read_cache.is_enabled() =>
+                                ReadCache::key_for_request(msg, sp_key.as_ref().map(Vec::as_slice), timestamps),
+                            Some(RequestEvent::CustomConsensusRequest(_, _)) => {
+                                read_cache.clear();
+                                None
+                            }
+                            _ => None
+                        };
                         match re.as_ref().map(|r| r.get_req_id()) {
                             Some(req_id) => {
-                                let mut request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &[cmd_id], &state.nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
-                                request_handler.process_event(re);
-                                state.request_handlers.insert(req_id.to_string(), request_handler); //FIXME check already exists
+                                if let Some(reply) = cache_key.as_ref().and_then(|key| read_cache.get(key, &req_id)) {
+                                    trace!("reply for request {} is taken from read cache", req_id);
+                                    _send_submit_ack(cmd_id, Ok(reply));
+                                } else {
+                                    if let Some(key) = cache_key {
+                                        read_cache.track(&req_id, key);
+                                    }
+                                    let mut request_handler = R::new(state.networker.clone(), get_f(state.nodes.len()), &[cmd_id], &state.nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                                    request_handler.process_event(re);
+                                    state.request_handlers.insert(req_id.to_string(), request_handler); //FIXME check already exists
+                                }
                             }
                             None => {
                                 let res = Err(err_msg(IndyErrorKind::InvalidStructure, "Request id not found"));
@@ -398,16 +433,23 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::NodeReply(reply, node) => {
                         trace!("received reply from node {:?}: {:?}", node, reply);
                         let re: Option<RequestEvent> = pe.into();
+                        let is_mirror_event = re.as_ref()
+                            .map(|re| ledger_mirror.process_event(re, &state.networker, get_f(state.nodes.len()), state.nodes.len(), extended_timeout))
+                            .unwrap_or(false);
                         match re.as_ref().map(|r| r.get_req_id()) {
+                            _ if is_mirror_event => trace!("reply is processed by ledger mirror"),
                             Some(req_id) => {
                                 let remove = if let Some(rh) = state.request_handlers.get_mut(&req_id) {
-                                    rh.process_event(re);
+                                    if let Some(PoolEvent::StateProofVerified(req_id, reply, last_signed_time)) = rh.process_event(re) {
+                                        read_cache.put_verified(&req_id, reply, last_signed_time);
+                                    }
                                     rh.is_terminal()
                                 } else {
                                     false
                                 };
                                 if remove {
                                     state.request_handlers.remove(&req_id);
+                                    read_cache.forget(&req_id);
                                 }
                             }
                             None => warn!("Request id not found in Reply: {:?}", reply)
@@ -420,7 +462,8 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                             rh.process_event(pe.into());
                         } else if "".eq(&req_id) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::Timeout));
-                        } else {
+                        } else if !ledger_mirror.process_event(&RequestEvent::Timeout(req_id.clone(), node_alias.clone()), &state.networker,
+                                                                get_f(state.nodes.len()), state.nodes.len(), extended_timeout) {
                             warn!("Unexpected timeout: req_id {}, node_alias {}", req_id, node_alias)
                         }
                         PoolState::Active(state)
@@ -449,7 +492,17 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                 }
             }
         };
-        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, state)
+        let state = match state {
+            PoolState::Active(state) => {
+                ledger_mirror.ensure_started(&state.networker, extended_timeout);
+                PoolState::Active(state)
+            }
+            state => {
+                ledger_mirror.reset();
+                state
+            }
+        };
+        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, read_cache, ledger_mirror, state)
     }
 
     pub fn is_terminal(&self) -> bool {
@@ -469,13 +522,7 @@ pub struct Pool<S: Networker, R: RequestHandler<S>> {
     worker: Option<JoinHandle<()>>,
     name: String,
     id: PoolHandle,
-    timeout: i64,
-    extended_timeout: i64,
-    active_timeout: i64,
-    conn_limit: usize,
-    preordered_nodes: Vec<String>,
-    number_read_nodes: u8,
-    socks_proxy: String,
+    config: PoolOpenConfig,
 }
 
 impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
@@ -486,34 +533,23 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
             worker: None,
             name: name.to_string(),
             id,
-            timeout: config.timeout,
-            extended_timeout: config.extended_timeout,
-            active_timeout: config.conn_active_timeout,
-            conn_limit: config.conn_limit,
-            preordered_nodes: config.preordered_nodes,
-            number_read_nodes: config.number_read_nodes,
-            socks_proxy: config.socks_proxy,
+            config,
         }
     }
 
     pub fn work(&mut self, cmd_socket: zmq::Socket) {
         let name = self.name.as_str().to_string();
         let id = self.id;
-        let timeout = self.timeout;
-        let extended_timeout = self.extended_timeout;
-        let active_timeout = self.active_timeout;
-        let conn_limit = self.conn_limit;
-        let preordered_nodes = self.preordered_nodes.clone();
-        let number_read_nodes = self.number_read_nodes;
-        let socks_proxy = self.socks_proxy.clone();
+        let config = self.config.clone();
         self.worker = Some(thread::spawn(move || {
-            let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
-                                                                    timeout, extended_timeout,
-                                                                    active_timeout, conn_limit,
-                                                                    preordered_nodes,
-                                                                    number_read_nodes,
-                                                                    socks_proxy);
-            pool_thread.work();
+            let pool_thread: IndyResult<PoolThread<S, R>> = PoolThread::new(cmd_socket, name, id, config);
+            match pool_thread {
+                Ok(mut pool_thread) => pool_thread.work(),
+                Err(err) => {
+                    error!("Can't start pool {:?}: {:?}", id, err);
+                    CommandExecutor::instance().send(Command::Pool(PoolCommand::OpenAck(id, id, Err(err)))).unwrap();
+                }
+            }
         }));
     }
 
@@ -524,6 +560,10 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
     pub fn get_id(&self) -> PoolHandle {
         self.id
     }
+
+    pub fn get_conn_limit(&self) -> usize {
+        self.config.conn_limit
+    }
 }
 
 struct PoolThread<S: Networker, R: RequestHandler<S>> {
@@ -534,15 +574,16 @@ struct PoolThread<S: Networker, R: RequestHandler<S>> {
 }
 
 impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
-    pub fn new(cmd_socket: zmq::Socket, name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize,
-               preordered_nodes: Vec<String>, number_read_nodes: u8, socks_proxy: String) -> Self {
-        let networker = Rc::new(RefCell::new(S::new(active_timeout, conn_limit, preordered_nodes, socks_proxy)));
-        PoolThread {
-            pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, number_read_nodes)),
+    pub fn new(cmd_socket: zmq::Socket, name: String, id: PoolHandle, config: PoolOpenConfig) -> IndyResult<Self> {
+        let networker = Rc::new(RefCell::new(S::open(&name, &config)?));
+        Ok(PoolThread {
+            pool_sm: Some(PoolSM::new(networker.clone(), &name, id, config.timeout, config.extended_timeout, config.number_read_nodes)
+                .with_read_cache(ReadCache::new(config.read_cache_size))
+                .with_ledger_mirror(LedgerMirror::new(&name, config.mirror_ledgers))),
             events: VecDeque::new(),
             commander: Commander::new(cmd_socket),
             networker,
-        }
+        })
     }
 
     pub fn work(&mut self) {
@@ -580,11 +621,19 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
             let ((req_id, alias), timeout) = networker.get_timeout();
             //            trace!("next timeout: {:?}", timeout);
 
-            let poll_res = zmq::poll(&mut poll_items, ::std::cmp::max(timeout, 0))
+            let has_pending_events = networker.has_pending_events();
+            let timeout = ::std::cmp::max(timeout, 0);
+            let poll_timeout = if has_pending_events {
+                0
+            } else {
+                networker.get_poll_interval().map(|interval| ::std::cmp::min(interval, timeout)).unwrap_or(timeout)
+            };
+
+            let poll_res = zmq::poll(&mut poll_items, poll_timeout)
                 .map_err(map_err_err!())
                 .map_err(|_| unimplemented!() /* FIXME */).unwrap();
             //            trace!("poll_res: {:?}", poll_res);
-            if poll_res == 0 {
+            if poll_res == 0 && !has_pending_events && poll_timeout == timeout {
                 self.events.push_back(PoolEvent::Timeout(req_id, alias)); // TODO check duplicate ?
             }
             //            trace!("poll_items: {:?}", poll_items.len());
@@ -603,13 +652,21 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     }
 }
 
-fn _get_f(cnt: usize) -> usize {
+pub(super) fn get_f(cnt: usize) -> usize {
     if cnt < 4 {
         return 0;
     }
     (cnt - 1) / 3
 }
 
+/// Returns BLS keys of the nodes from the local copy of pool ledger and f of the pool.
+pub(super) fn get_nodes_bls_keys(pool_name: &str) -> IndyResult<(Nodes, usize)> {
+    let merkle = merkle_tree_factory::create(pool_name)?;
+    let (nodes, _) = _get_nodes_and_remotes(&merkle)?;
+    let f = get_f(nodes.len());
+    Ok((nodes, f))
+}
+
 fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<T>>(
     networker: Rc<RefCell<T>>,
     pool_name: &str,
@@ -632,13 +689,13 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
         }
     };
     networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-    let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
-    let ls = _ledger_status(&merkle);
+    let mut request_handler = R::new(networker.clone(), get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
+    let ls = ledger_status(&merkle);
     request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle))));
     Ok(request_handler)
 }
 
-fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus {
+pub(super) fn ledger_status(merkle: &MerkleTree) -> LedgerStatus {
     let protocol_version = ProtocolVersion::get();
 
     LedgerStatus {
@@ -655,49 +712,9 @@ fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(Nodes, Vec<RemoteN
     let nodes = merkle_tree_factory::build_node_state(merkle)?;
 
     Ok(nodes.iter().map(|(_, txn)| {
-        let node_alias = txn.txn.data.data.alias.clone();
-
-        let node_verkey = txn.txn.data.dest
-            .as_str()
-            .from_base58()
-            .map_err(Context::new)
-            .to_indy(IndyErrorKind::InvalidStructure, "Invalid field dest in genesis transaction")?;
-
-        let node_verkey = ed25519_sign::PublicKey::from_slice(&node_verkey)
-            .and_then(|vk| ed25519_sign::vk_to_curve25519(&vk))
-            .to_indy(IndyErrorKind::InvalidStructure, "Invalid field dest in genesis transaction")?;
-
-        if txn.txn.data.data.services.is_none() || !txn.txn.data.data.services.as_ref().unwrap().contains(&"VALIDATOR".to_string()) {
-            return Err(err_msg(IndyErrorKind::InvalidState, "Node is not a validator")); // FIXME: review error kind
-        }
-
-        let address = match (&txn.txn.data.data.client_ip, &txn.txn.data.data.client_port) {
-            (&Some(ref client_ip), &Some(ref client_port)) => format!("tcp://{}:{}", client_ip, client_port),
-            _ => return Err(err_msg(IndyErrorKind::InvalidState, "Client address not found")),
-        };
-
-        let remote = RemoteNode {
-            name: node_alias.clone(),
-            public_key: node_verkey[..].to_vec(),
-            // TODO:FIXME
-            zaddr: address,
-            is_blacklisted: false,
-        };
-
-        let verkey: Option<VerKey> = match txn.txn.data.data.blskey {
-            Some(ref blskey) => {
-                let key = blskey
-                    .as_str()
-                    .from_base58()
-                    .map_err(Context::new)
-                    .to_indy(IndyErrorKind::InvalidStructure, "Invalid field blskey in genesis transaction")?;
-
-                Some(VerKey::from_bytes(&key)
-                    .to_indy(IndyErrorKind::InvalidStructure, "Invalid field blskey in genesis transaction")?)
-            }
-            None => None,
-        };
-        Ok(((node_alias, verkey), remote))
+        let remote = parse_node_remote(txn)?;
+        let verkey = parse_node_bls_key(txn)?;
+        Ok(((remote.name.clone(), verkey), remote))
     }
     ).fold(
         (HashMap::new(), vec![]), |(mut map, mut vec), res| {
@@ -715,6 +732,52 @@ fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(Nodes, Vec<RemoteN
     ))
 }
 
+/// Builds connection info of the validator node from its pool ledger transaction.
+pub(super) fn parse_node_remote(txn: &NodeTransactionV1) -> IndyResult<RemoteNode> {
+    let node_verkey = txn.txn.data.dest
+        .as_str()
+        .from_base58()
+        .map_err(Context::new)
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid field dest in genesis transaction")?;
+
+    let node_verkey = ed25519_sign::PublicKey::from_slice(&node_verkey)
+        .and_then(|vk| ed25519_sign::vk_to_curve25519(&vk))
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid field dest in genesis transaction")?;
+
+    if txn.txn.data.data.services.is_none() || !txn.txn.data.data.services.as_ref().unwrap().contains(&"VALIDATOR".to_string()) {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Node is not a validator")); // FIXME: review error kind
+    }
+
+    let address = match (&txn.txn.data.data.client_ip, &txn.txn.data.data.client_port) {
+        (&Some(ref client_ip), &Some(ref client_port)) => format!("tcp://{}:{}", client_ip, client_port),
+        _ => return Err(err_msg(IndyErrorKind::InvalidState, "Client address not found")),
+    };
+
+    Ok(RemoteNode {
+        name: txn.txn.data.data.alias.clone(),
+        public_key: node_verkey[..].to_vec(),
+        // TODO:FIXME
+        zaddr: address,
+        is_blacklisted: false,
+    })
+}
+
+pub(super) fn parse_node_bls_key(txn: &NodeTransactionV1) -> IndyResult<Option<VerKey>> {
+    match txn.txn.data.data.blskey {
+        Some(ref blskey) => {
+            let key = blskey
+                .as_str()
+                .from_base58()
+                .map_err(Context::new)
+                .to_indy(IndyErrorKind::InvalidStructure, "Invalid field blskey in genesis transaction")?;
+
+            Ok(Some(VerKey::from_bytes(&key)
+                .to_indy(IndyErrorKind::InvalidStructure, "Invalid field blskey in genesis transaction")?))
+        }
+        None => Ok(None),
+    }
+}
+
 fn _close_pool_ack(cmd_id: CommandHandle) {
     let pc = PoolCommand::CloseAck(cmd_id, Ok(()));
     CommandExecutor::instance().send(Command::Pool(pc)).unwrap();
@@ -735,15 +798,40 @@ fn _send_open_refresh_ack(cmd_id: CommandHandle, id: PoolHandle, is_refresh: boo
     CommandExecutor::instance().send(Command::Pool(pc)).unwrap();
 }
 
+pub(super) trait PoolWorker {
+    fn get_name(&self) -> &str;
+    fn get_id(&self) -> PoolHandle;
+    fn get_conn_limit(&self) -> usize;
+    fn take_worker(&mut self) -> Option<JoinHandle<()>>;
+}
+
+impl<S: Networker, R: RequestHandler<S>> PoolWorker for Pool<S, R> {
+    fn get_name(&self) -> &str {
+        Pool::get_name(self)
+    }
+
+    fn get_id(&self) -> PoolHandle {
+        Pool::get_id(self)
+    }
+
+    fn get_conn_limit(&self) -> usize {
+        Pool::get_conn_limit(self)
+    }
+
+    fn take_worker(&mut self) -> Option<JoinHandle<()>> {
+        self.worker.take()
+    }
+}
+
 pub struct ZMQPool {
-    pub(super) pool: Pool<ZMQNetworker, RequestHandlerImpl<ZMQNetworker>>,
+    pub(super) pool: Box<dyn PoolWorker>,
     pub(super) cmd_socket: zmq::Socket,
 }
 
 impl ZMQPool {
-    pub fn new(pool: Pool<ZMQNetworker, RequestHandlerImpl<ZMQNetworker>>, cmd_socket: zmq::Socket) -> ZMQPool {
+    pub fn new<S: Networker + 'static, R: RequestHandler<S> + 'static>(pool: Pool<S, R>, cmd_socket: zmq::Socket) -> ZMQPool {
         ZMQPool {
-            pool,
+            pool: Box::new(pool),
             cmd_socket,
         }
     }
@@ -758,7 +846,7 @@ impl Drop for ZMQPool {
         }
 
         // Option worker type and this kludge is workaround for rust
-        if let Some(worker) = self.pool.worker.take() {
+        if let Some(worker) = self.pool.take_worker() {
             info!("Drop wait worker");
             worker.join().unwrap();
         }
@@ -879,6 +967,8 @@ mod tests {
                 timeout: 0,
                 extended_timeout: 0,
                 number_read_nodes: NUMBER_READ_NODES,
+                read_cache: ReadCache::new(0),
+                ledger_mirror: LedgerMirror::new("pool_wrapper_terminated_timeout_works", false),
             };
 
             let p = p.handle_event(PoolEvent::Timeout("".to_string(), "".to_string()));
@@ -1404,12 +1494,12 @@ mod tests {
         fn get_f_works() {
             test::cleanup_storage("get_f_works");
 
-            assert_eq!(_get_f(0), 0);
-            assert_eq!(_get_f(3), 0);
-            assert_eq!(_get_f(4), 1);
-            assert_eq!(_get_f(5), 1);
-            assert_eq!(_get_f(6), 1);
-            assert_eq!(_get_f(7), 2);
+            assert_eq!(get_f(0), 0);
+            assert_eq!(get_f(3), 0);
+            assert_eq!(get_f(4), 1);
+            assert_eq!(get_f(5), 1);
+            assert_eq!(get_f(6), 1);
+            assert_eq!(get_f(7), 2);
         }
     }
 }
@@ -0,0 +1,266 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json;
+use serde_json::Value as SJsonValue;
+
+use super::THRESHOLD;
+
+static READ_CACHE_HITS: AtomicUsize = AtomicUsize::new(0);
+static READ_CACHE_MISSES: AtomicUsize = AtomicUsize::new(0);
+
+/// (request type, state proof key)
+pub type ReadCacheKey = (String, Vec<u8>);
+
+/// Per-pool cache of read replies which passed state proof verification.
+///
+/// Entry is served only while the timestamp of its BLS multi-signature
+/// is within the freshness threshold, so cached data is never older than
+/// data accepted by a regular state proof check.
+pub struct ReadCache {
+    capacity: usize,
+    entries: HashMap<ReadCacheKey, CachedReply>,
+    order: VecDeque<ReadCacheKey>,
+    pending: HashMap<String, ReadCacheKey>,
+}
+
+struct CachedReply {
+    raw_msg: String,
+    last_signed_time: u64,
+}
+
+impl ReadCache {
+    pub fn new(capacity: usize) -> ReadCache {
+        ReadCache {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Builds cache key for a read request.
+    /// Requests for data in the past have timestamps or `seqNo` and aren't cached.
+    pub fn key_for_request(msg: &str, sp_key: Option<&[u8]>, timestamps: (Option<u64>, Option<u64>)) -> Option<ReadCacheKey> {
+        if timestamps != (None, None) {
+            return None;
+        }
+
+        let sp_key = sp_key?;
+        let req: SJsonValue = serde_json::from_str(msg).ok()?;
+
+        // Request types that don't check state proofs in the past can still read historical data
+        if !req["operation"]["seqNo"].is_null() || !req["operation"]["timestamp"].is_null() {
+            return None;
+        }
+
+        let type_ = req["operation"]["type"].as_str()?;
+
+        Some((type_.to_string(), sp_key.to_vec()))
+    }
+
+    /// Returns cached reply patched with `req_id` of the current request.
+    pub fn get(&mut self, key: &ReadCacheKey, req_id: &str) -> Option<String> {
+        let freshness_threshold = *THRESHOLD.lock().unwrap();
+        let cur_time = _get_cur_time();
+
+        let reply = match self.entries.get(key) {
+            Some(entry) if cur_time <= entry.last_signed_time + freshness_threshold => {
+                _patch_req_id(&entry.raw_msg, req_id)
+            }
+            Some(_) => {
+                trace!("ReadCache: entry for {:?} is outdated", key);
+                self._remove(key);
+                None
+            }
+            None => None
+        };
+
+        if reply.is_some() {
+            READ_CACHE_HITS.fetch_add(1, Ordering::Relaxed);
+        } else {
+            READ_CACHE_MISSES.fetch_add(1, Ordering::Relaxed);
+        }
+
+        reply
+    }
+
+    /// Remembers key of the sent request to store its reply after state proof verification.
+    pub fn track(&mut self, req_id: &str, key: ReadCacheKey) {
+        if self.is_enabled() {
+            self.pending.insert(req_id.to_string(), key);
+        }
+    }
+
+    pub fn forget(&mut self, req_id: &str) {
+        self.pending.remove(req_id);
+    }
+
+    /// Stores reply of the tracked request which passed state proof verification.
+    pub fn put_verified(&mut self, req_id: &str, raw_msg: String, last_signed_time: u64) {
+        if let Some(key) = self.pending.remove(req_id) {
+            self.put(key, raw_msg, last_signed_time);
+        }
+    }
+
+    fn put(&mut self, key: ReadCacheKey, raw_msg: String, last_signed_time: u64) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        if self.entries.insert(key.clone(), CachedReply { raw_msg, last_signed_time }).is_none() {
+            self.order.push_back(key);
+        }
+
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    /// Drops all entries. Called after write requests sent through the pool
+    /// as they can change any of cached values.
+    /// Replies of reads sent before are also not cached as they can be answered after the write.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.pending.clear();
+    }
+
+    fn _remove(&mut self, key: &ReadCacheKey) {
+        self.entries.remove(key);
+        self.order.retain(|cached| cached != key);
+    }
+}
+
+/// Returns (hits, misses) of read caches of all pools.
+pub fn read_cache_counters() -> (usize, usize) {
+    (READ_CACHE_HITS.load(Ordering::Relaxed), READ_CACHE_MISSES.load(Ordering::Relaxed))
+}
+
+fn _patch_req_id(raw_msg: &str, req_id: &str) -> Option<String> {
+    let mut msg: SJsonValue = serde_json::from_str(raw_msg).ok()?;
+    let req_id: u64 = req_id.parse().ok()?;
+
+    if msg["result"].is_object() {
+        msg["result"]["reqId"] = json!(req_id);
+    }
+
+    Some(msg.to_string())
+}
+
+fn _get_cur_time() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("Time has gone backwards").as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = r#"{"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX"}}"#;
+
+    fn _reply(timestamp: u64) -> String {
+        json!({
+            "op": "REPLY",
+            "result": {
+                "reqId": 1,
+                "data": "data",
+                "state_proof": {"multi_signature": {"value": {"timestamp": timestamp}}}
+            }
+        }).to_string()
+    }
+
+    fn _key() -> ReadCacheKey {
+        ReadCache::key_for_request(MESSAGE, Some(b"key"), (None, None)).unwrap()
+    }
+
+    #[test]
+    fn read_cache_key_for_request_works() {
+        assert_eq!(("105".to_string(), b"key".to_vec()), _key());
+        assert_eq!(None, ReadCache::key_for_request(MESSAGE, None, (None, None)));
+        assert_eq!(None, ReadCache::key_for_request(MESSAGE, Some(b"key"), (None, Some(100))));
+    }
+
+    #[test]
+    fn read_cache_key_for_request_works_for_historical_get_nym() {
+        let get_nym_by_seq_no = r#"{"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX","seqNo":10}}"#;
+        assert_eq!(None, ReadCache::key_for_request(get_nym_by_seq_no, Some(b"key"), (None, None)));
+
+        let get_nym_by_timestamp = r#"{"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX","timestamp":100}}"#;
+        assert_eq!(None, ReadCache::key_for_request(get_nym_by_timestamp, Some(b"key"), (None, None)));
+    }
+
+    #[test]
+    fn read_cache_get_works_for_fresh_entry() {
+        let mut cache = ReadCache::new(10);
+        cache.put(_key(), _reply(_get_cur_time()), _get_cur_time());
+
+        let reply: SJsonValue = serde_json::from_str(&cache.get(&_key(), "2").unwrap()).unwrap();
+        assert_eq!(2, reply["result"]["reqId"]);
+        assert_eq!("data", reply["result"]["data"]);
+    }
+
+    #[test]
+    fn read_cache_get_works_for_outdated_entry() {
+        let mut cache = ReadCache::new(10);
+        let timestamp = _get_cur_time() - 100_000;
+        cache.put(_key(), _reply(timestamp), timestamp);
+
+        assert_eq!(None, cache.get(&_key(), "2"));
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn read_cache_put_works_for_capacity_exceeded() {
+        let mut cache = ReadCache::new(1);
+        cache.put(("105".to_string(), b"key1".to_vec()), _reply(_get_cur_time()), _get_cur_time());
+        cache.put(("105".to_string(), b"key2".to_vec()), _reply(_get_cur_time()), _get_cur_time());
+
+        assert_eq!(None, cache.get(&("105".to_string(), b"key1".to_vec()), "2"));
+        assert!(cache.get(&("105".to_string(), b"key2".to_vec()), "2").is_some());
+    }
+
+    #[test]
+    fn read_cache_put_verified_works() {
+        let mut cache = ReadCache::new(10);
+        cache.track("1", _key());
+        cache.put_verified("1", _reply(_get_cur_time()), _get_cur_time());
+
+        assert!(cache.get(&_key(), "2").is_some());
+        assert!(cache.pending.is_empty());
+    }
+
+    #[test]
+    fn read_cache_put_verified_works_for_not_tracked_request() {
+        let mut cache = ReadCache::new(10);
+        cache.put_verified("1", _reply(_get_cur_time()), _get_cur_time());
+
+        assert_eq!(None, cache.get(&_key(), "2"));
+    }
+
+    #[test]
+    fn read_cache_clear_works() {
+        let mut cache = ReadCache::new(10);
+        cache.put(_key(), _reply(_get_cur_time()), _get_cur_time());
+        cache.clear();
+
+        assert_eq!(None, cache.get(&_key(), "2"));
+    }
+
+    #[test]
+    fn read_cache_clear_works_for_reply_received_after_write() {
+        let mut cache = ReadCache::new(10);
+        cache.track("1", _key());
+        cache.clear();
+        cache.put_verified("1", _reply(_get_cur_time()), _get_cur_time());
+
+        assert_eq!(None, cache.get(&_key(), "2"));
+        assert!(cache.pending.is_empty());
+    }
+}
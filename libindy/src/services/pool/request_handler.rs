@@ -14,9 +14,11 @@ use self::super::THRESHOLD;
 use crate::commands::Command;
 use crate::commands::CommandExecutor;
 use crate::commands::ledger::LedgerCommand;
+use crate::domain::ledger::response::{ConsensusDetails, NodeErrorKind, NodeReplyStatus, NodeResult};
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status};
+use crate::services::pool::ledger_mirror::POOL_LEDGER_ID;
 use crate::services::pool::events::NetworkerEvent;
 use crate::services::pool::events::PoolEvent;
 use crate::services::pool::events::RequestEvent;
@@ -123,6 +125,7 @@ struct ConsensusState<T: Networker> {
     denied_nodes: HashSet<String> /* FIXME should be map, may be merged with replies */,
     replies: HashMap<HashableValue, HashSet<String>>,
     timeout_nodes: HashSet<String>,
+    node_results: HashMap<String, NodeResult>,
     networker: Rc<RefCell<T>>,
 }
 
@@ -176,6 +179,7 @@ impl<T: Networker> From<StartState<T>> for ConsensusState<T> {
             denied_nodes: HashSet::new(),
             replies: HashMap::new(),
             timeout_nodes: HashSet::new(),
+            node_results: HashMap::new(),
             networker: state.networker.clone(),
         }
     }
@@ -264,7 +268,7 @@ impl<T: Networker> RequestSM<T> {
                         (RequestState::CatchupConsensus((merkle, state).into()), None)
                     }
                     RequestEvent::CatchupReq(merkle, target_mt_size, target_mt_root) => {
-                        match build_catchup_req(&merkle, target_mt_size) {
+                        match build_catchup_req(&merkle, target_mt_size, POOL_LEDGER_ID) {
                             Ok(Some((req_id, req_json))) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id.clone(), timeout)));
                                 (RequestState::CatchupSingle((merkle, state, target_mt_root, target_mt_size, req_id).into()), None)
@@ -325,6 +329,7 @@ impl<T: Networker> RequestSM<T> {
                 }
             }
             RequestState::Consensus(mut state) => {
+                state.record_node_result(&re);
                 match re {
                     RequestEvent::Reply(_, raw_msg, node_alias, req_id) |
                     RequestEvent::ReqNACK(_, raw_msg, node_alias, req_id) |
@@ -340,6 +345,7 @@ impl<T: Networker> RequestSM<T> {
                             };
 
                             if cnt > f {
+                                _send_consensus_details(&cmd_ids, state.details(true, f, &nodes));
                                 _send_ok_replies(&cmd_ids, &raw_msg);
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                                 (RequestState::finish(), None)
@@ -347,6 +353,7 @@ impl<T: Networker> RequestSM<T> {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
                                 (RequestState::Consensus(state), None)
                             } else {
+                                _send_consensus_details(&cmd_ids, state.details(false, f, &nodes));
                                 //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
                                 _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
@@ -355,6 +362,7 @@ impl<T: Networker> RequestSM<T> {
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
                             if state.denied_nodes.len() + state.replies.len() == nodes.len() {
+                                _send_consensus_details(&cmd_ids, state.details(false, f, &nodes));
                                 _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                                 (RequestState::finish(), None)
                             } else {
@@ -372,6 +380,7 @@ impl<T: Networker> RequestSM<T> {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
                             (RequestState::Consensus(state), None)
                         } else {
+                            _send_consensus_details(&cmd_ids, state.details(false, f, &nodes));
                             //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
                             _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
@@ -406,11 +415,14 @@ impl<T: Networker> RequestSM<T> {
                                 )
                             };
 
-                            if cnt > f
-                                || _check_state_proof(&result, f, &generator, &nodes, &raw_msg, state.sp_key.as_ref().map(Vec::as_slice), state.timestamps, last_write_time) {
-                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+                            let is_sp_verified = cnt <= f
+                                && _check_state_proof(&result, f, &generator, &nodes, &raw_msg, state.sp_key.as_ref().map(Vec::as_slice), state.timestamps, last_write_time);
+
+                            if cnt > f || is_sp_verified {
+                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), None)));
                                 _send_ok_replies(&cmd_ids, if cnt > f { &soonest } else { &raw_msg });
-                                (RequestState::finish(), None)
+                                let pe = if is_sp_verified { Some(PoolEvent::StateProofVerified(req_id, raw_msg, last_write_time)) } else { None };
+                                (RequestState::finish(), pe)
                             } else {
                                 (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
                             }
@@ -436,13 +448,13 @@ impl<T: Networker> RequestSM<T> {
             }
             RequestState::CatchupConsensus(state) => {
                 match re {
-                    RequestEvent::LedgerStatus(ls, Some(node_alias), _) => {
+                    RequestEvent::LedgerStatus(ls, Some(node_alias), _) if ls.ledgerId as usize == POOL_LEDGER_ID => {
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
                             ls.merkleRoot.clone(), ls.txnSeqNo, None,
                             node_alias, ls.merkleRoot, f, &nodes, &pool_name)
                     }
-                    RequestEvent::ConsistencyProof(cp, node_alias) => {
+                    RequestEvent::ConsistencyProof(cp, node_alias) if cp.ledgerId == POOL_LEDGER_ID => {
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
                             cp.newMerkleRoot, cp.seqNoEnd, Some(cp.hashes),
@@ -464,7 +476,7 @@ impl<T: Networker> RequestSM<T> {
             }
             RequestState::CatchupSingle(state) => {
                 match re {
-                    RequestEvent::CatchupRep(mut cr, node_alias) => {
+                    RequestEvent::CatchupRep(mut cr, node_alias) if cr.ledgerId == POOL_LEDGER_ID => {
                         match _process_catchup_reply(&mut cr, &state.merkle_tree, &state.target_mt_root, state.target_mt_size, &pool_name) {
                             Ok(merkle) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), None)));
@@ -675,6 +687,42 @@ impl<T: Networker> ConsensusState<T> {
         let max_no = self.replies.values().map(|set| set.len()).max().unwrap_or(0);
         max_no + total_nodes_cnt - rep_no - self.timeout_nodes.len() - self.denied_nodes.len() > f
     }
+
+    fn record_node_result(&mut self, re: &RequestEvent) {
+        let (node_alias, status, raw_msg) = match *re {
+            RequestEvent::ReqACK(_, _, ref node_alias, _) => {
+                self.node_results.entry(node_alias.clone()).or_insert_with(NodeResult::default).acked = true;
+                return;
+            }
+            RequestEvent::Reply(_, ref raw_msg, ref node_alias, _) => (node_alias, NodeReplyStatus::Reply, Some(raw_msg)),
+            RequestEvent::ReqNACK(_, ref raw_msg, ref node_alias, _) => (node_alias, NodeReplyStatus::ReqNack, Some(raw_msg)),
+            RequestEvent::Reject(_, ref raw_msg, ref node_alias, _) => (node_alias, NodeReplyStatus::Reject, Some(raw_msg)),
+            RequestEvent::Timeout(_, ref node_alias) => (node_alias, NodeReplyStatus::Timeout, None),
+            _ => return
+        };
+
+        let node_result = self.node_results.entry(node_alias.clone()).or_insert_with(NodeResult::default);
+        node_result.status = status;
+
+        if status == NodeReplyStatus::ReqNack || status == NodeReplyStatus::Reject {
+            node_result.reason = raw_msg
+                .and_then(|raw_msg| serde_json::from_str::<SJsonValue>(raw_msg).ok())
+                .and_then(|msg| msg["reason"].as_str().or_else(|| msg["result"]["reason"].as_str()).map(String::from));
+            node_result.error_kind = Some(node_result.reason.as_ref()
+                .map(|reason| NodeErrorKind::from_reason(reason))
+                .unwrap_or(NodeErrorKind::Other));
+        }
+    }
+
+    fn details(&self, reached: bool, f: usize, nodes: &Nodes) -> ConsensusDetails {
+        ConsensusDetails {
+            reached,
+            f,
+            nodes: nodes.keys()
+                .map(|alias| (alias.clone(), self.node_results.get(alias).cloned().unwrap_or_default()))
+                .collect(),
+        }
+    }
 }
 
 fn _parse_nack(denied_nodes: &mut HashSet<String>, f: usize, raw_msg: &str, cmd_ids: &[CommandHandle], node_alias: &str) -> bool {
@@ -711,6 +759,15 @@ fn _send_ok_replies(cmd_ids: &[CommandHandle], msg: &str) {
     _send_replies(cmd_ids, Ok(msg.to_string()))
 }
 
+fn _send_consensus_details(cmd_ids: &[CommandHandle], details: ConsensusDetails) {
+    cmd_ids.iter().for_each(|id| {
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::SubmitConsensusDetails(*id, details.clone()))
+        ).unwrap();
+    });
+}
+
 fn _finish_request(cmd_ids: &[CommandHandle]) {
     _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTerminated, "Pool is terminated")))
 }
@@ -1207,6 +1264,44 @@ pub mod tests {
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_consensus_state_records_node_results() {
+            let mut request_handler = _request_handler("request_handler_consensus_state_records_node_results", 1, 4);
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::ReqACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::Reject(Response::default(),
+                                                                    r#"{"op":"REJECT","reason":"client request invalid: UnauthorizedClientRequest('Not enough TRUSTEE signatures',)"}"#.to_string(),
+                                                                    NODE_2.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), NACK_REPLY.to_string(), NODE_3.to_string(), REQ_ID.to_string())));
+
+            let request_wrapper = request_handler.request_wrapper.unwrap();
+            let details = match request_wrapper.state {
+                RequestState::Consensus(ref state) => state.details(false, 1, &request_wrapper.nodes),
+                _ => panic!("unexpected state")
+            };
+
+            assert!(!details.reached);
+            assert_eq!(4, details.nodes.len());
+
+            let node_1 = &details.nodes[NODE];
+            assert!(node_1.acked);
+            assert_eq!(NodeReplyStatus::Reply, node_1.status);
+            assert_eq!(None, node_1.reason);
+
+            let node_2 = &details.nodes[NODE_2];
+            assert!(!node_2.acked);
+            assert_eq!(NodeReplyStatus::Reject, node_2.status);
+            assert_eq!(Some(NodeErrorKind::AuthRule), node_2.error_kind);
+
+            let node_3 = &details.nodes[NODE_3];
+            assert_eq!(NodeReplyStatus::ReqNack, node_3.status);
+            assert_eq!(Some("reqnack".to_string()), node_3.reason);
+            assert_eq!(Some(NodeErrorKind::Other), node_3.error_kind);
+
+            assert_eq!(NodeReplyStatus::NoReply, details.nodes[NODE_4].status);
+        }
+
         #[test]
         fn request_handler_process_other_event_from_consensus_state_works() {
             let mut request_handler = _request_handler("request_handler_process_other_event_from_consensus_state_works", 1, 4);
@@ -0,0 +1,672 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rust_base58::ToBase58;
+use serde_json;
+use serde_json::Value as SJsonValue;
+use ursa::bls::{Bls, MultiSignature, SignKey, Signature};
+
+use crate::domain::ledger::constants;
+use crate::domain::pool::PoolTransport;
+use indy_api_types::errors::prelude::*;
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::ledger::merkletree::proof::{Lemma, Positioned};
+use crate::services::pool::events::REQUESTS_FOR_STATE_PROOFS;
+use crate::services::pool::networker::Transport;
+use crate::services::pool::state_proof;
+use crate::services::pool::types::RemoteNode;
+use indy_utils::crypto::hash::hash as openssl_hash;
+
+const DOMAIN_LEDGER_ID: u64 = 1;
+
+/// In-process pool that answers requests from in-memory ledger instead of Indy Nodes.
+///
+/// Pool ledger is taken as is from the client side, so LEDGER_STATUS for it is echoed back.
+/// All write requests are ordered into in-memory ledger owned by the opened pool,
+/// so written data is visible only through this pool handle and is lost when the pool is closed.
+/// Read replies contain state proofs signed by BLS keys derived from node aliases (see `node_bls_sign_key`).
+pub struct SimulatedPool {
+    nodes: Vec<String>,
+    ledger: SimulatedLedger,
+    replies: VecDeque<(String, String)>,
+}
+
+#[derive(Default)]
+struct SimulatedLedger {
+    txns: Vec<SJsonValue>,
+    merkle: MerkleTree,
+    state: BTreeMap<Vec<u8>, StateEntry>,
+    replies: HashMap<(String, u64), String>,
+}
+
+struct StateEntry {
+    data: SJsonValue,
+    seq_no: u64,
+    txn_time: u64,
+    proof_value: String,
+}
+
+/// Returns BLS sign key of the simulated node with given alias.
+/// Genesis transactions for the simulated pool must contain corresponded `blskey` values.
+pub fn node_bls_sign_key(alias: &str) -> IndyResult<SignKey> {
+    let seed = openssl_hash(alias.as_bytes())?;
+    Ok(SignKey::new(Some(&seed))?)
+}
+
+impl Transport for SimulatedPool {
+    fn open(_pool_name: &str, _transport: &PoolTransport) -> IndyResult<Self> {
+        Ok(SimulatedPool::new())
+    }
+
+    fn nodes_updated(&mut self, nodes: &[RemoteNode]) {
+        let mut nodes: Vec<String> = nodes.iter()
+            .map(|node| node.name.clone())
+            .collect();
+        nodes.sort();
+
+        self.nodes = nodes;
+    }
+
+    fn send(&mut self, node: &RemoteNode, msg: &str) -> IndyResult<()> {
+        trace!("SimulatedPool::send >> node: {}, msg: {}", node.name, msg);
+
+        let msg: SJsonValue = serde_json::from_str(msg)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid message sent to simulated pool")?;
+
+        let replies = match msg["op"].as_str() {
+            Some("LEDGER_STATUS") => vec![self._ledger_status(msg)?],
+            Some("CATCHUP_REQ") => vec![self._catchup_rep(&msg)?],
+            _ => self._request(&msg)?,
+        };
+
+        for reply in replies {
+            self.replies.push_back((reply, node.name.clone()));
+        }
+
+        trace!("SimulatedPool::send <<");
+        Ok(())
+    }
+
+    fn recv(&mut self) -> Vec<(String, String)> {
+        self.replies.drain(..).collect()
+    }
+
+    fn has_pending(&mut self) -> bool {
+        !self.replies.is_empty()
+    }
+}
+
+impl SimulatedPool {
+    fn new() -> Self {
+        SimulatedPool {
+            nodes: Vec::new(),
+            ledger: SimulatedLedger::default(),
+            replies: VecDeque::new(),
+        }
+    }
+
+    fn _ledger_status(&self, mut msg: SJsonValue) -> IndyResult<String> {
+        if msg["ledgerId"].as_u64() == Some(DOMAIN_LEDGER_ID) {
+            msg["txnSeqNo"] = json!(self.ledger.merkle.count());
+            msg["merkleRoot"] = json!(self.ledger.merkle.root_hash().to_base58());
+        }
+
+        Ok(msg.to_string())
+    }
+
+    fn _catchup_rep(&self, msg: &SJsonValue) -> IndyResult<String> {
+        let ledger_id = msg["ledgerId"].as_u64().unwrap_or(0);
+        let seq_no_start = msg["seqNoStart"].as_u64().unwrap_or(0) as usize;
+        let seq_no_end = msg["seqNoEnd"].as_u64().unwrap_or(0) as usize;
+
+        let mut txns = serde_json::Map::new();
+
+        if ledger_id == DOMAIN_LEDGER_ID {
+            for seq_no in seq_no_start.max(1)..=seq_no_end.min(self.ledger.txns.len()) {
+                txns.insert(seq_no.to_string(), self.ledger.txns[seq_no - 1].clone());
+            }
+        }
+
+        Ok(json!({
+            "op": "CATCHUP_REP",
+            "ledgerId": ledger_id,
+            "consProof": [],
+            "txns": txns,
+        }).to_string())
+    }
+
+    fn _request(&mut self, msg: &SJsonValue) -> IndyResult<Vec<String>> {
+        let (identifier, req_id) = match (msg["identifier"].as_str(), msg["reqId"].as_u64()) {
+            (Some(identifier), Some(req_id)) => (identifier.to_string(), req_id),
+            _ => {
+                warn!("SimulatedPool: request without identifier or reqId is ignored");
+                return Ok(vec![]);
+            }
+        };
+
+        let type_ = msg["operation"]["type"].as_str().unwrap_or("").to_string();
+        let is_write = !(REQUESTS_FOR_STATE_PROOFS.contains(&type_.as_str())
+            || type_ == constants::GET_VALIDATOR_INFO
+            || type_ == constants::GET_FROZEN_LEDGERS);
+
+        let ledger = &mut self.ledger;
+
+        let key = (identifier.clone(), req_id);
+
+        let reply = match ledger.replies.get(&key) {
+            Some(reply) => reply.clone(),
+            None => {
+                let reply = if is_write {
+                    ledger.write(msg)?
+                } else {
+                    ledger.read(msg, &type_, &self.nodes)?
+                };
+                ledger.replies.insert(key, reply.clone());
+                reply
+            }
+        };
+
+        if is_write {
+            let ack = json!({"op": "REQACK", "identifier": identifier, "reqId": req_id}).to_string();
+            Ok(vec![ack, reply])
+        } else {
+            Ok(vec![reply])
+        }
+    }
+}
+
+impl SimulatedLedger {
+    fn write(&mut self, msg: &SJsonValue) -> IndyResult<String> {
+        if msg["signature"].is_null() && msg["signatures"].is_null() {
+            return Ok(json!({
+                "op": "REQNACK",
+                "identifier": msg["identifier"],
+                "reqId": msg["reqId"],
+                "reason": "client request invalid: MissingSignature()",
+            }).to_string());
+        }
+
+        let seq_no = self.txns.len() as u64 + 1;
+        let txn_time = _get_cur_time();
+
+        let mut data = msg["operation"].clone();
+        let type_ = data.as_object_mut().and_then(|op| op.remove("type")).unwrap_or(SJsonValue::Null);
+
+        let mut metadata = json!({
+            "reqId": msg["reqId"],
+            "from": msg["identifier"],
+        });
+        for field in &["endorser", "taaAcceptance"] {
+            if !msg[*field].is_null() {
+                metadata[*field] = msg[*field].clone();
+            }
+        }
+
+        let req_signature = match msg["signature"].as_str() {
+            Some(signature) => json!({
+                "type": "ED25519",
+                "values": [{"from": msg["identifier"], "value": signature}],
+            }),
+            None => json!({
+                "type": "ED25519",
+                "values": msg["signatures"].as_object()
+                    .map(|signatures| signatures.iter()
+                        .map(|(from, value)| json!({"from": from, "value": value}))
+                        .collect::<Vec<SJsonValue>>())
+                    .unwrap_or_default(),
+            }),
+        };
+
+        let txn = json!({
+            "txn": {
+                "type": type_,
+                "protocolVersion": msg["protocolVersion"],
+                "data": data,
+                "metadata": metadata,
+            },
+            "txnMetadata": {
+                "seqNo": seq_no,
+                "txnTime": txn_time,
+            },
+            "reqSignature": req_signature,
+            "ver": "1",
+        });
+
+        let leaf = _txn_leaf(&txn, seq_no)?;
+        self.merkle.append(leaf)?;
+        self.txns.push(txn.clone());
+        self._update_state(msg, seq_no, txn_time)?;
+
+        let mut result = txn;
+        result["rootHash"] = json!(self.merkle.root_hash().to_base58());
+        result["auditPath"] = json!(self._audit_path(seq_no)?);
+
+        Ok(json!({"op": "REPLY", "result": result}).to_string())
+    }
+
+    fn read(&self, msg: &SJsonValue, type_: &str, nodes: &[String]) -> IndyResult<String> {
+        let mut result = json!({
+            "type": type_,
+            "identifier": msg["identifier"],
+            "reqId": msg["reqId"],
+            "data": SJsonValue::Null,
+            "seqNo": SJsonValue::Null,
+            "txnTime": SJsonValue::Null,
+        });
+
+        if let Some(operation) = msg["operation"].as_object() {
+            for (field, value) in operation.iter().filter(|&(field, _)| field != "type") {
+                result[field] = value.clone();
+            }
+            result["data"] = SJsonValue::Null;
+        }
+
+        if type_ == constants::GET_TXN {
+            let seq_no = msg["operation"]["data"].as_u64().unwrap_or(0);
+            if seq_no >= 1 && seq_no <= self.txns.len() as u64 {
+                let mut data = self.txns[seq_no as usize - 1].clone();
+                data["rootHash"] = json!(self.merkle.root_hash().to_base58());
+                data["auditPath"] = json!(self._audit_path(seq_no)?);
+                data["ledgerSize"] = json!(self.txns.len());
+
+                result["seqNo"] = json!(seq_no);
+                result["data"] = data;
+                result["state_proof"] = json!({
+                    "multi_signature": self._multi_signature(nodes)?,
+                });
+            }
+        } else if let Some(sp_key) = state_proof::parse_key_from_request_for_builtin_sp(msg) {
+            if let Some(entry) = self.state.get(&sp_key) {
+                result["data"] = entry.data.clone();
+                result["seqNo"] = json!(entry.seq_no);
+                result["txnTime"] = json!(entry.txn_time);
+            }
+
+            let (root_hash, proof_nodes) = state_proof::build_state_proof(&self._state_kvs());
+            result["state_proof"] = json!({
+                "root_hash": root_hash.to_base58(),
+                "proof_nodes": proof_nodes,
+                "multi_signature": self._multi_signature(nodes)?,
+            });
+        }
+
+        Ok(json!({"op": "REPLY", "result": result}).to_string())
+    }
+
+    fn _update_state(&mut self, msg: &SJsonValue, seq_no: u64, txn_time: u64) -> IndyResult<()> {
+        let operation = &msg["operation"];
+        let identifier = &msg["identifier"];
+
+        let (get_operation, data) = match operation["type"].as_str() {
+            Some(constants::NYM) => {
+                let get_operation = json!({"type": constants::GET_NYM, "dest": operation["dest"]});
+
+                let mut nym = self._get_state_entry(&get_operation)
+                    .and_then(|entry| entry.data.as_str().and_then(|data| serde_json::from_str::<SJsonValue>(data).ok()))
+                    .unwrap_or_else(|| json!({
+                        "dest": operation["dest"],
+                        "identifier": identifier,
+                        "role": SJsonValue::Null,
+                        "verkey": SJsonValue::Null,
+                    }));
+
+                if let Some(verkey) = operation.get("verkey") {
+                    nym["verkey"] = verkey.clone();
+                }
+                if let Some(role) = operation.get("role") {
+                    nym["role"] = if role.as_str() == Some(constants::ROLE_REMOVE) { SJsonValue::Null } else { role.clone() };
+                }
+                nym["seqNo"] = json!(seq_no);
+                nym["txnTime"] = json!(txn_time);
+
+                (get_operation, SJsonValue::String(nym.to_string()))
+            }
+            Some(constants::ATTRIB) => {
+                let raw_name = operation["raw"].as_str()
+                    .and_then(|raw| serde_json::from_str::<SJsonValue>(raw).ok())
+                    .and_then(|raw| raw.as_object().and_then(|raw| raw.keys().next().cloned()));
+
+                match (raw_name, operation["hash"].as_str(), operation["enc"].as_str()) {
+                    (Some(raw_name), _, _) =>
+                        (json!({"type": constants::GET_ATTR, "dest": operation["dest"], "raw": raw_name}), operation["raw"].clone()),
+                    (None, Some(hash), _) =>
+                        (json!({"type": constants::GET_ATTR, "dest": operation["dest"], "hash": hash}), json!(hash)),
+                    (None, None, Some(enc)) =>
+                        (json!({"type": constants::GET_ATTR, "dest": operation["dest"], "enc": enc}), json!(enc)),
+                    _ => return Ok(())
+                }
+            }
+            Some(constants::SCHEMA) => {
+                let get_operation = json!({
+                    "type": constants::GET_SCHEMA,
+                    "dest": identifier,
+                    "data": {"name": operation["data"]["name"], "version": operation["data"]["version"]},
+                });
+                (get_operation, operation["data"].clone())
+            }
+            Some(constants::CRED_DEF) => {
+                let get_operation = json!({
+                    "type": constants::GET_CRED_DEF,
+                    "origin": identifier,
+                    "ref": operation["ref"],
+                    "signature_type": operation["signature_type"],
+                    "tag": operation["tag"],
+                });
+                (get_operation, operation["data"].clone())
+            }
+            Some(constants::REVOC_REG_DEF) => {
+                let mut data = operation.clone();
+                if let Some(data) = data.as_object_mut() {
+                    data.remove("type");
+                }
+                (json!({"type": constants::GET_REVOC_REG_DEF, "id": operation["id"]}), data)
+            }
+            Some(constants::JSON_LD_CONTEXT) | Some(constants::RICH_SCHEMA) | Some(constants::RICH_SCHEMA_ENCODING) |
+            Some(constants::RICH_SCHEMA_MAPPING) | Some(constants::RICH_SCHEMA_CRED_DEF) | Some(constants::RICH_SCHEMA_PRES_DEF) => {
+                let mut data = operation.clone();
+                if let Some(data) = data.as_object_mut() {
+                    data.remove("type");
+                }
+                data["from"] = identifier.clone();
+
+                let get_by_metadata_operation = json!({
+                    "type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                    "rsType": operation["rsType"],
+                    "rsName": operation["rsName"],
+                    "rsVersion": operation["rsVersion"],
+                });
+                self._insert_state_entry(&get_by_metadata_operation, data.clone(), seq_no, txn_time)?;
+
+                (json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID, "id": operation["id"]}), data)
+            }
+            _ => return Ok(())
+        };
+
+        self._insert_state_entry(&get_operation, data, seq_no, txn_time)
+    }
+
+    fn _insert_state_entry(&mut self, get_operation: &SJsonValue, data: SJsonValue, seq_no: u64, txn_time: u64) -> IndyResult<()> {
+        let get_type = get_operation["type"].as_str().unwrap_or("").to_string();
+        let sp_key = unwrap_opt_or_return!(state_proof::parse_key_from_request_for_builtin_sp(&json!({"operation": get_operation})), Ok(()));
+
+        let get_result = json!({"type": get_type, "data": data, "seqNo": seq_no, "txnTime": txn_time});
+        let proof_value = state_proof::build_proof_value_for_reply(&get_result, &get_type, &sp_key)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Can't build state value for simulated pool"))?;
+
+        self.state.insert(sp_key, StateEntry { data, seq_no, txn_time, proof_value });
+        Ok(())
+    }
+
+    fn _get_state_entry(&self, get_operation: &SJsonValue) -> Option<&StateEntry> {
+        state_proof::parse_key_from_request_for_builtin_sp(&json!({"operation": get_operation}))
+            .and_then(|sp_key| self.state.get(&sp_key))
+    }
+
+    fn _state_kvs(&self) -> Vec<(Vec<u8>, Vec<u8>)> {
+        self.state.iter()
+            .map(|(key, entry)| (key.clone(), entry.proof_value.as_bytes().to_vec()))
+            .collect()
+    }
+
+    fn _audit_path(&self, seq_no: u64) -> IndyResult<Vec<String>> {
+        let leaf = _txn_leaf(&self.txns[seq_no as usize - 1], seq_no)?;
+        let proof = self.merkle.gen_proof(leaf)?
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Transaction not found in simulated ledger"))?;
+
+        let mut path = Vec::new();
+        let mut lemma: Option<&Lemma> = Some(&proof.lemma);
+        while let Some(cur) = lemma {
+            match cur.sibling_hash {
+                Some(Positioned::Left(ref hash)) | Some(Positioned::Right(ref hash)) => path.push(hash.to_base58()),
+                None => ()
+            }
+            lemma = cur.sub_lemma.as_ref().map(|sub| sub.as_ref());
+        }
+        path.reverse();
+
+        Ok(path)
+    }
+
+    fn _multi_signature(&self, nodes: &[String]) -> IndyResult<SJsonValue> {
+        let (state_root_hash, _) = state_proof::build_state_proof(&self._state_kvs());
+        let (pool_state_root_hash, _) = state_proof::build_state_proof(&[]);
+
+        let value = json!({
+            "ledger_id": DOMAIN_LEDGER_ID,
+            "pool_state_root_hash": pool_state_root_hash.to_base58(),
+            "state_root_hash": state_root_hash.to_base58(),
+            "timestamp": _get_cur_time(),
+            "txn_root_hash": self.merkle.root_hash().to_base58(),
+        });
+
+        let message = rmp_serde::to_vec_named(&value)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize multi signature value")?;
+
+        let mut signatures: Vec<Signature> = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            signatures.push(Bls::sign(&message, &node_bls_sign_key(node)?)?);
+        }
+
+        let signature = MultiSignature::new(&signatures.iter().collect::<Vec<&Signature>>())?;
+
+        Ok(json!({
+            "signature": signature.as_bytes().to_base58(),
+            "participants": nodes,
+            "value": value,
+        }))
+    }
+}
+
+fn _txn_leaf(txn: &SJsonValue, seq_no: u64) -> IndyResult<Vec<u8>> {
+    let get_txn_result = json!({"type": constants::GET_TXN, "data": txn});
+    let value = state_proof::build_proof_value_for_reply(&get_txn_result, constants::GET_TXN, seq_no.to_string().as_bytes())
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Can't build audit value for simulated ledger"))?;
+
+    let value: SJsonValue = serde_json::from_str(&value)
+        .to_indy(IndyErrorKind::InvalidState, "Can't parse audit value for simulated ledger")?;
+
+    rmp_serde::to_vec(&value)
+        .to_indy(IndyErrorKind::InvalidState, "Can't serialize transaction for simulated ledger")
+}
+
+fn _get_cur_time() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("Time has gone backwards").as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::pool::networker::{Networker, TransportNetworker};
+    use crate::services::pool::events::{NetworkerEvent, PoolEvent};
+    use crate::services::pool::request_handler::DEFAULT_GENERATOR;
+    use crate::services::pool::Nodes;
+    use rust_base58::FromBase58;
+    use ursa::bls::{Generator, VerKey};
+
+    const IDENTIFIER: &str = "V4SGRU86Z58d6TV7PBUe6f";
+    const DEST: &str = "VsKV7grR1BUE29mG2Fm2kX";
+
+    fn _remote_node(name: &str) -> RemoteNode {
+        RemoteNode {
+            name: name.to_string(),
+            public_key: vec![],
+            zaddr: format!("tcp://127.0.0.1:{}", name.len()),
+            is_blacklisted: false,
+        }
+    }
+
+    fn _simulated_pool(network: &str) -> SimulatedPool {
+        let mut pool = SimulatedPool::new();
+        pool.nodes_updated(&[_remote_node(&format!("{}_1", network)), _remote_node(&format!("{}_2", network))]);
+        pool
+    }
+
+    fn _send(pool: &mut SimulatedPool, msg: SJsonValue) -> Vec<SJsonValue> {
+        let node = _remote_node("Node1");
+        pool.send(&node, &msg.to_string()).unwrap();
+        pool.recv().into_iter()
+            .map(|(reply, _)| serde_json::from_str(&reply).unwrap())
+            .collect()
+    }
+
+    fn _nym_request(req_id: u64) -> SJsonValue {
+        json!({
+            "reqId": req_id,
+            "identifier": IDENTIFIER,
+            "operation": {"type": constants::NYM, "dest": DEST, "verkey": "~CoRER63DVYnWZtK8uAzNbx"},
+            "protocolVersion": 2,
+            "signature": "signature",
+        })
+    }
+
+    #[test]
+    fn simulated_pool_write_works() {
+        let mut pool = _simulated_pool("simulated_pool_write_works");
+
+        let replies = _send(&mut pool, _nym_request(1));
+
+        assert_eq!(2, replies.len());
+        assert_eq!("REQACK", replies[0]["op"]);
+        assert_eq!("REPLY", replies[1]["op"]);
+        assert_eq!(1, replies[1]["result"]["txnMetadata"]["seqNo"]);
+        assert_eq!(DEST, replies[1]["result"]["txn"]["data"]["dest"]);
+    }
+
+    #[test]
+    fn simulated_pool_write_works_for_missed_signature() {
+        let mut pool = _simulated_pool("simulated_pool_write_works_for_missed_signature");
+
+        let mut request = _nym_request(1);
+        request.as_object_mut().unwrap().remove("signature");
+
+        let replies = _send(&mut pool, request);
+        assert_eq!("REQNACK", replies[1]["op"]);
+    }
+
+    #[test]
+    fn simulated_pool_read_works_with_state_proof() {
+        let mut pool = _simulated_pool("simulated_pool_read_works_with_state_proof");
+        _send(&mut pool, _nym_request(1));
+
+        let get_nym = json!({
+            "reqId": 2,
+            "identifier": IDENTIFIER,
+            "operation": {"type": constants::GET_NYM, "dest": DEST},
+            "protocolVersion": 2,
+        });
+        let replies = _send(&mut pool, get_nym.clone());
+        let result = &replies[0]["result"];
+
+        let data: SJsonValue = serde_json::from_str(result["data"].as_str().unwrap()).unwrap();
+        assert_eq!("~CoRER63DVYnWZtK8uAzNbx", data["verkey"]);
+
+        let sp_key = state_proof::parse_key_from_request_for_builtin_sp(&get_nym).unwrap();
+        let parsed_sps = state_proof::parse_generic_reply_for_proof_checking(result, &replies[0].to_string(), Some(&sp_key)).unwrap();
+        assert_eq!(1, parsed_sps.len());
+        assert_eq!(result["state_proof"]["root_hash"], parsed_sps[0].root_hash);
+    }
+
+    #[test]
+    fn simulated_pool_read_works_for_rich_schema_object() {
+        let mut pool = _simulated_pool("simulated_pool_read_works_for_rich_schema_object");
+
+        let id = "did:sov:2sEEi28LsekEUg6Pbpx3c1";
+        _send(&mut pool, json!({
+            "reqId": 1,
+            "identifier": IDENTIFIER,
+            "operation": {
+                "type": constants::RICH_SCHEMA,
+                "id": id,
+                "content": json!({"@id": id}).to_string(),
+                "rsName": "name",
+                "rsVersion": "1.0",
+                "rsType": "sch",
+                "ver": "1"
+            },
+            "protocolVersion": 2,
+            "signature": "signature",
+        }));
+
+        let get_by_metadata = json!({
+            "reqId": 2,
+            "identifier": IDENTIFIER,
+            "operation": {"type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, "rsType": "sch", "rsName": "name", "rsVersion": "1.0"},
+            "protocolVersion": 2,
+        });
+        let replies = _send(&mut pool, get_by_metadata.clone());
+        let result = &replies[0]["result"];
+        assert_eq!(id, result["data"]["id"]);
+        assert_eq!(IDENTIFIER, result["data"]["from"]);
+
+        let sp_key = state_proof::parse_key_from_request_for_builtin_sp(&get_by_metadata).unwrap();
+        let parsed_sps = state_proof::parse_generic_reply_for_proof_checking(result, &replies[0].to_string(), Some(&sp_key)).unwrap();
+        assert_eq!(1, parsed_sps.len());
+        assert_eq!(result["state_proof"]["root_hash"], parsed_sps[0].root_hash);
+    }
+
+    #[test]
+    fn simulated_pool_get_txn_reply_passes_inclusion_check() {
+        let mut pool = _simulated_pool("simulated_pool_get_txn_reply_passes_inclusion_check");
+        _send(&mut pool, _nym_request(1));
+        _send(&mut pool, _nym_request(2));
+
+        let get_txn = json!({
+            "reqId": 3,
+            "identifier": IDENTIFIER,
+            "operation": {"type": constants::GET_TXN, "data": 1, "ledgerId": 1},
+            "protocolVersion": 2,
+        });
+        let replies = _send(&mut pool, get_txn);
+
+        let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+        let nodes: Nodes = pool.nodes.iter()
+            .map(|alias| (alias.clone(), Some(VerKey::new(&gen, &node_bls_sign_key(alias).unwrap()).unwrap())))
+            .collect();
+
+        let inclusion = state_proof::verify_txn_inclusion(&replies[0]["result"], &nodes, 0, &gen).unwrap();
+        assert_eq!(1, inclusion.seq_no);
+        assert_eq!(2, inclusion.ledger_size);
+        assert_eq!(Some(DOMAIN_LEDGER_ID), inclusion.ledger_id);
+
+        let mut tampered = replies[0]["result"].clone();
+        tampered["data"]["txn"]["data"]["verkey"] = json!("~NcYxiDXkpYi6ov5FcYDi1e");
+        let res = state_proof::verify_txn_inclusion(&tampered, &nodes, 0, &gen);
+        assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn simulated_pool_ledger_status_works_for_domain_ledger() {
+        let mut pool = _simulated_pool("simulated_pool_ledger_status_works_for_domain_ledger");
+        _send(&mut pool, _nym_request(1));
+
+        let replies = _send(&mut pool, json!({"op": "LEDGER_STATUS", "txnSeqNo": 0, "merkleRoot": "", "ledgerId": 1, "ppSeqNo": null, "viewNo": null}));
+        assert_eq!(1, replies[0]["txnSeqNo"]);
+    }
+
+    #[test]
+    fn simulated_pool_ledger_is_not_shared_between_pools() {
+        let mut pool = _simulated_pool("simulated_pool_ledger_is_not_shared_between_pools");
+        _send(&mut pool, _nym_request(1));
+
+        let mut other_pool = _simulated_pool("simulated_pool_ledger_is_not_shared_between_pools");
+        let replies = _send(&mut other_pool, _nym_request(2));
+        assert_eq!(1, replies[1]["result"]["txnMetadata"]["seqNo"]);
+
+        let replies = _send(&mut other_pool, json!({"op": "LEDGER_STATUS", "txnSeqNo": 0, "merkleRoot": "", "ledgerId": 1, "ppSeqNo": null, "viewNo": null}));
+        assert_eq!(1, replies[0]["txnSeqNo"]);
+    }
+
+    #[test]
+    fn transport_networker_fetches_simulated_replies() {
+        let mut networker: TransportNetworker<SimulatedPool> = TransportNetworker::new(SimulatedPool::new(), vec![]);
+        networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![_remote_node("transport_networker_1"), _remote_node("transport_networker_2")])));
+
+        networker.process_event(Some(NetworkerEvent::SendAllRequest(_nym_request(1).to_string(), "1".to_string(), 10, None)));
+
+        assert!(networker.has_pending_events());
+        let events = networker.fetch_events(&[]);
+        assert_eq!(4, events.len());
+        assert_match!(PoolEvent::NodeReply(_, _), events[0]);
+        assert!(!networker.has_pending_events());
+    }
+}
@@ -12,7 +12,7 @@ use serde_json;
 use serde_json::Value as SJsonValue;
 
 use indy_api_types::ErrorCode;
-use crate::domain::ledger::{constants, request::ProtocolVersion};
+use crate::domain::ledger::{constants, request::ProtocolVersion, response::TxnInclusion};
 use indy_api_types::errors::prelude::*;
 use crate::services::pool::events::{REQUESTS_FOR_STATE_PROOFS, REQUESTS_FOR_MULTI_STATE_PROOFS};
 use indy_utils::crypto::hash::hash as openssl_hash;
@@ -145,6 +145,79 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
     true
 }
 
+/// Checks that transaction from GET_TXN reply is included into the ledger:
+/// audit path must lead from the transaction to the root hash multi-signed by the pool nodes.
+pub fn verify_txn_inclusion(json_msg: &SJsonValue, nodes: &Nodes, f: usize, gen: &Generator) -> IndyResult<TxnInclusion> {
+    trace!("verify_txn_inclusion: >>> json_msg: {:?}", json_msg);
+
+    if json_msg["type"].as_str() != Some(constants::GET_TXN) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Response is not a GET_TXN reply"));
+    }
+
+    let (data, parsed_data) = _parse_reply_data(json_msg)
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid data field in GET_TXN reply"))?;
+
+    if parsed_data.is_null() {
+        return Err(err_msg(IndyErrorKind::LedgerItemNotFound, "GET_TXN reply doesn't contain transaction"));
+    }
+
+    let seq_no = parsed_data["txnMetadata"]["seqNo"].as_u64()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Transaction in GET_TXN reply has no seqNo"))?;
+
+    let multi_signature = &json_msg["state_proof"]["multi_signature"];
+    if multi_signature.is_null() {
+        return Err(err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN reply has no multi signature"));
+    }
+
+    let parsed_sp = _parse_reply_for_sp(json_msg, data.as_ref().map(String::as_str), &parsed_data, constants::GET_TXN, seq_no.to_string().as_bytes())
+        .map_err(|err| err_msg(IndyErrorKind::InvalidTransaction, format!("Can't parse audit path of GET_TXN reply: {}", err)))?;
+
+    let root_hash = parsed_sp.root_hash.clone();
+
+    let ledger_size = match parsed_sp.kvs_to_verify {
+        KeyValuesInSP::Simple(KeyValueSimpleData { verification_type: KeyValueSimpleDataVerificationType::MerkleTree(length), .. }) => length,
+        _ => return Err(err_msg(IndyErrorKind::InvalidState, "Unexpected proof type for GET_TXN reply"))
+    };
+
+    if seq_no == 0 || seq_no > ledger_size {
+        return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction {} is out of ledger of size {}", seq_no, ledger_size)));
+    }
+
+    if !verify_parsed_sp(vec![parsed_sp], nodes, f, gen) {
+        return Err(err_msg(IndyErrorKind::InvalidTransaction, "Audit path of GET_TXN reply is not valid for the multi signed ledger root"));
+    }
+
+    let res = TxnInclusion {
+        seq_no,
+        txn_time: parsed_data["txnMetadata"]["txnTime"].as_u64(),
+        ledger_id: multi_signature["value"]["ledger_id"].as_u64(),
+        ledger_size,
+        root_hash,
+        multi_signature_timestamp: multi_signature["value"]["timestamp"].as_u64(),
+    };
+
+    trace!("verify_txn_inclusion: <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+/// Calculates the value that a state proof for the given reply is expected to contain.
+pub fn build_proof_value_for_reply(json_msg: &SJsonValue, type_: &str, sp_key: &[u8]) -> Option<String> {
+    let (data, parsed_data) = _parse_reply_data(json_msg)?;
+    _parse_reply_for_proof_value(json_msg, data.as_ref().map(String::as_str), &parsed_data, type_, sp_key)
+        .map_err(|err| trace!("build_proof_value_for_reply: <<< {:?}", err))
+        .ok()?
+}
+
+/// Builds Patricia Merkle Trie for the given key-values.
+/// Returns the root hash and the proof nodes in the form they are sent by Nodes.
+pub fn build_state_proof(kvs: &[(Vec<u8>, Vec<u8>)]) -> (Vec<u8>, String) {
+    let (root, mut nodes) = Node::build_trie(kvs);
+    let root_hash = root.get_hash().to_vec();
+    nodes.push(root);
+    (root_hash, base64::encode(&rlp::encode_list(&nodes)))
+}
+
 #[logfn(Trace)]
 pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Vec<u8>> {
     let type_ = json_msg["operation"]["type"].as_str()?;
@@ -259,6 +332,28 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 "3:latest".to_owned()
             }
         }
+        constants::GET_RICH_SCHEMA_OBJECT_BY_ID => {
+            if let Some(id) = json_msg["id"].as_str() {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_RICH_SCHEMA_OBJECT_BY_ID id {:?}", id);
+                id.to_string()
+            } else {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_RICH_SCHEMA_OBJECT_BY_ID No key suffix");
+                return None;
+            }
+        }
+        constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
+            //{RS_TYPE}:{RS_NAME}:{RS_VERSION}
+            if let (Some(rs_type), Some(rs_name), Some(rs_version)) = (json_msg["rsType"].as_str(),
+                                                                       json_msg["rsName"].as_str(),
+                                                                       json_msg["rsVersion"].as_str()) {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_RICH_SCHEMA_OBJECT_BY_METADATA rs_type {:?}, rs_name {:?}, rs_version {:?}",
+                       rs_type, rs_name, rs_version);
+                format!("{}:{}:{}", rs_type, rs_name, rs_version)
+            } else {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_RICH_SCHEMA_OBJECT_BY_METADATA No key suffix");
+                return None;
+            }
+        }
         constants::GET_TXN => {
             if let Some(seq_no) = json_msg["data"].as_u64() {
                 format!("{}", seq_no)
@@ -283,7 +378,8 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 return None;
             }
         }
-        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE => {
+        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE |
+        constants::GET_RICH_SCHEMA_OBJECT_BY_ID | constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
             Vec::new()
         }
         constants::GET_REVOC_REG_DEF => {
@@ -317,37 +413,7 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str, key: &[u8]) -
 
     assert!(REQUESTS_FOR_STATE_PROOFS.contains(&type_));
 
-    // TODO: FIXME: It is a workaround for Node's problem. Node returns some transactions as strings and some as objects.
-    // If node returns marshaled json it can contain spaces and it can cause invalid hash.
-    // So we have to save the original string too.
-    // See https://jira.hyperledger.org/browse/INDY-699
-    let (data, parsed_data): (Option<String>, SJsonValue) = match json_msg["data"] {
-        SJsonValue::Null => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is null");
-            (None, SJsonValue::Null)
-        }
-        SJsonValue::String(ref str) => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is string");
-            if let Ok(parsed_data) = serde_json::from_str(str) {
-                (Some(str.to_owned()), parsed_data)
-            } else {
-                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid json");
-                return None;
-            }
-        }
-        SJsonValue::Object(ref map) => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is object");
-            (Some(json_msg["data"].to_string()), SJsonValue::from(map.clone()))
-        }
-        SJsonValue::Array(ref array) => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is array");
-            (Some(json_msg["data"].to_string()), SJsonValue::from(array.clone()))
-        }
-        _ => {
-            trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid type");
-            return None;
-        }
-    };
+    let (data, parsed_data) = _parse_reply_data(json_msg)?;
 
     trace!("TransactionHandler::parse_reply_for_builtin_sp: data: {:?}, parsed_data: {:?}", data, parsed_data);
 
@@ -383,6 +449,40 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str, key: &[u8]) -
     Some(state_proofs)
 }
 
+fn _parse_reply_data(json_msg: &SJsonValue) -> Option<(Option<String>, SJsonValue)> {
+    // TODO: FIXME: It is a workaround for Node's problem. Node returns some transactions as strings and some as objects.
+    // If node returns marshaled json it can contain spaces and it can cause invalid hash.
+    // So we have to save the original string too.
+    // See https://jira.hyperledger.org/browse/INDY-699
+    match json_msg["data"] {
+        SJsonValue::Null => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is null");
+            Some((None, SJsonValue::Null))
+        }
+        SJsonValue::String(ref str) => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is string");
+            if let Ok(parsed_data) = serde_json::from_str(str) {
+                Some((Some(str.to_owned()), parsed_data))
+            } else {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid json");
+                None
+            }
+        }
+        SJsonValue::Object(ref map) => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is object");
+            Some((Some(json_msg["data"].to_string()), SJsonValue::from(map.clone())))
+        }
+        SJsonValue::Array(ref array) => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: Data is array");
+            Some((Some(json_msg["data"].to_string()), SJsonValue::from(array.clone())))
+        }
+        _ => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Data field is invalid type");
+            None
+        }
+    }
+}
+
 fn _parse_reply_for_sp(json_msg: &SJsonValue, data: Option<&str>, parsed_data: &SJsonValue, xtype: &str, sp_key: &[u8]) -> Result<ParsedSP, String> {
     trace!("TransactionHandler::_parse_reply_for_sp: data: {:?}, parsed_data: {:?}", data, parsed_data);
 
@@ -816,9 +916,18 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
             constants::GET_ATTR => {
                 value["val"] = SJsonValue::String(hex::encode(openssl_hash(data.as_bytes()).map_err(|err| err.to_string())?));
             }
-            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML => {
+            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML |
+            constants::GET_RICH_SCHEMA_OBJECT_BY_ID => {
                 value["val"] = parsed_data.clone();
             }
+            // state by metadata key contains only id of the object
+            constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
+                if let Some(id) = parsed_data["id"].as_str() {
+                    value = SJsonValue::String(id.to_string());
+                } else {
+                    return Err("Invalid data for GET_RICH_SCHEMA_OBJECT_BY_METADATA".to_string());
+                }
+            }
             constants::GET_AUTH_RULE => {
                 let constraint = parsed_data
                     .as_array()
@@ -1734,4 +1843,90 @@ mod tests {
                            }),
                    }));
     }
+
+    #[test]
+    fn state_proof_build_state_proof_works() {
+        let kvs = vec![
+            (b"abcdefgh1".to_vec(), b"3630".to_vec()),
+            (b"abcdefgh2".to_vec(), b"3631".to_vec()),
+            (b"abcdefgz".to_vec(), b"3632".to_vec()),
+            (b"xyz".to_vec(), b"3633".to_vec()),
+        ];
+
+        let (root_hash, proof_nodes) = build_state_proof(&kvs);
+        let proofs_rlp = base64::decode(&proof_nodes).unwrap();
+
+        for (key, value) in kvs.iter() {
+            assert!(_verify_proof(&proofs_rlp, &root_hash, key, Some(std::str::from_utf8(value).unwrap())));
+        }
+        assert!(_verify_proof(&proofs_rlp, &root_hash, b"abcdefgh3", None));
+        assert!(!_verify_proof(&proofs_rlp, &root_hash, b"xyz", Some("3634")));
+    }
+
+    #[test]
+    fn parse_key_from_request_for_builtin_sp_works_for_get_rich_schema_object() {
+        let by_id = json!({"operation": {"type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID, "id": "did:sov:2sEEi28LsekEUg6Pbpx3c1"}});
+        assert_eq!(b"did:sov:2sEEi28LsekEUg6Pbpx3c1".to_vec(), parse_key_from_request_for_builtin_sp(&by_id).unwrap());
+
+        let by_metadata = json!({"operation": {
+            "type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+            "rsType": "sch",
+            "rsName": "name",
+            "rsVersion": "1.0"
+        }});
+        assert_eq!(b"sch:name:1.0".to_vec(), parse_key_from_request_for_builtin_sp(&by_metadata).unwrap());
+
+        let by_metadata = json!({"operation": {"type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, "rsType": "sch"}});
+        assert_eq!(None, parse_key_from_request_for_builtin_sp(&by_metadata));
+    }
+
+    #[test]
+    fn build_proof_value_for_reply_works_for_get_rich_schema_object() {
+        let data = json!({"id": "did:sov:2sEEi28LsekEUg6Pbpx3c1", "rsType": "sch", "rsName": "name", "rsVersion": "1.0"});
+
+        let reply = json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID, "data": data, "seqNo": 1, "txnTime": 2});
+        let value = build_proof_value_for_reply(&reply, constants::GET_RICH_SCHEMA_OBJECT_BY_ID, b"did:sov:2sEEi28LsekEUg6Pbpx3c1").unwrap();
+        assert_eq!(json!({"lsn": 1, "lut": 2, "val": data}), serde_json::from_str::<SJsonValue>(&value).unwrap());
+
+        let reply = json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, "data": data, "seqNo": 1, "txnTime": 2});
+        let value = build_proof_value_for_reply(&reply, constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, b"sch:name:1.0").unwrap();
+        assert_eq!("did:sov:2sEEi28LsekEUg6Pbpx3c1", value);
+    }
+
+    fn _default_generator() -> Generator {
+        Generator::from_bytes(&crate::services::pool::request_handler::DEFAULT_GENERATOR.from_base58().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works_for_not_get_txn_reply() {
+        let json_msg = json!({"type": constants::GET_NYM, "data": null});
+
+        let res = verify_txn_inclusion(&json_msg, &HashMap::new(), 0, &_default_generator());
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works_for_not_found_txn() {
+        let json_msg = json!({"type": constants::GET_TXN, "data": null});
+
+        let res = verify_txn_inclusion(&json_msg, &HashMap::new(), 0, &_default_generator());
+        assert_eq!(IndyErrorKind::LedgerItemNotFound, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works_for_no_multi_signature() {
+        let json_msg = json!({
+            "type": constants::GET_TXN,
+            "data": {
+                "auditPath": ["1", "2"],
+                "ledgerSize": 2,
+                "rootHash": "123",
+                "txn": {"test1": "test2", "seqNo": 2},
+                "txnMetadata": {"seqNo": 2},
+            },
+        });
+
+        let res = verify_txn_inclusion(&json_msg, &HashMap::new(), 0, &_default_generator());
+        assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+    }
 }
@@ -372,6 +372,97 @@ impl Node {
         }
     }
 
+    /// Builds Patricia Merkle Trie over given key-values.
+    /// Returns root node and all nodes referenced by hash so they can be sent as proof nodes.
+    pub fn build_trie(kvs: &[(Vec<u8>, Vec<u8>)]) -> (Node, Vec<Node>) {
+        let mut db: Vec<Node> = Vec::new();
+
+        if kvs.is_empty() {
+            return (Node::Blank, db);
+        }
+
+        let mut entries: Vec<(Vec<u8>, &[u8])> = kvs.iter()
+            .map(|(key, value)| (Node::path_to_nibbles(key), value.as_slice()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries.dedup_by(|a, b| a.0 == b.0);
+
+        let root = Node::_build_trie(&entries, 0, &mut db);
+        (root, db)
+    }
+
+    fn _build_trie(entries: &[(Vec<u8>, &[u8])], depth: usize, db: &mut Vec<Node>) -> Node {
+        if entries.len() == 1 {
+            let (ref path, value) = entries[0];
+            return Node::Leaf(Leaf {
+                path: Node::_encode_path(&path[depth..], true),
+                value: Node::_encode_value(value),
+            });
+        }
+
+        let first = &entries[0].0;
+        let mut prefix_len = 0;
+        while entries.iter().all(|(path, _)| path.len() > depth + prefix_len && path[depth + prefix_len] == first[depth + prefix_len]) {
+            prefix_len += 1;
+        }
+
+        if prefix_len > 0 {
+            let next = Node::_build_trie(entries, depth + prefix_len, db);
+            return Node::Extension(Extension {
+                path: Node::_encode_path(&first[depth..depth + prefix_len], false),
+                next: Box::new(Node::_hash_ref(next, db)),
+            });
+        }
+
+        let mut nodes: [Option<Box<Node>>; Node::RADIX] = [None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None];
+        let mut value = None;
+        let mut start = 0;
+
+        if entries[0].0.len() == depth {
+            value = Some(Node::_encode_value(entries[0].1));
+            start = 1;
+        }
+
+        while start < entries.len() {
+            let nibble = entries[start].0[depth];
+            let end = start + entries[start..].iter().take_while(|(path, _)| path[depth] == nibble).count();
+            let next = Node::_build_trie(&entries[start..end], depth + 1, db);
+            nodes[nibble as usize] = Some(Box::new(Node::_hash_ref(next, db)));
+            start = end;
+        }
+
+        Node::Full(FullNode { nodes, value })
+    }
+
+    fn _hash_ref(node: Node, db: &mut Vec<Node>) -> Node {
+        let hash = node.get_hash().to_vec();
+        db.push(node);
+        Node::Hash(hash)
+    }
+
+    fn _encode_path(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+        let mut flags = if is_leaf { Node::IS_LEAF_MASK } else { 0x00 };
+        let mut encoded: Vec<u8> = Vec::with_capacity(nibbles.len() / 2 + 1);
+
+        let rest = if nibbles.len() % 2 == 1 {
+            flags |= Node::IS_PATH_ODD_MASK | nibbles[0];
+            &nibbles[1..]
+        } else {
+            nibbles
+        };
+
+        encoded.push(flags);
+        for pair in rest.chunks(2) {
+            encoded.push((pair[0] << 4) + pair[1]);
+        }
+
+        encoded
+    }
+
+    fn _encode_value(value: &[u8]) -> Vec<u8> {
+        rlp::encode_list::<Vec<u8>, Vec<u8>>(&[value.to_vec()]).to_vec()
+    }
+
     fn path_to_nibbles(path: &[u8]) -> Vec<u8> {
         let mut nibble_path: Vec<u8> = Vec::with_capacity(2*path.len());
 
@@ -0,0 +1,147 @@
+use indy_api_types::errors::prelude::*;
+use rust_base58::{FromBase58, ToBase58};
+
+use crate::services::crypto::{DEFAULT_CRYPTO_TYPE, P256_CRYPTO_TYPE, SECP256K1_CRYPTO_TYPE};
+use crate::utils::crypto::verkey_builder::split_verkey;
+
+pub const DID_KEY_PREFIX: &str = "did:key:";
+
+// Multibase prefix of base58-btc encoding
+const MULTIBASE_BASE58_BTC: char = 'z';
+
+// Multicodec prefixes of supported public keys
+const ED25519_PUB_MULTICODEC: &[u8] = &[0xed, 0x01];
+const X25519_PUB_MULTICODEC: &[u8] = &[0xec, 0x01];
+const SECP256K1_PUB_MULTICODEC: &[u8] = &[0xe7, 0x01];
+const P256_PUB_MULTICODEC: &[u8] = &[0x80, 0x24];
+
+const MULTICODECS: &[(&str, &[u8])] = &[
+    (DEFAULT_CRYPTO_TYPE, ED25519_PUB_MULTICODEC),
+    (SECP256K1_CRYPTO_TYPE, SECP256K1_PUB_MULTICODEC),
+    (P256_CRYPTO_TYPE, P256_PUB_MULTICODEC),
+];
+
+/// Builds `did:key` DID URL that identifies verkey used for signing: did:key:<key>#<key>
+pub fn verkey_to_kid(verkey: &str) -> IndyResult<String> {
+    let (vk, crypto_type) = split_verkey(verkey);
+
+    let multicodec = MULTICODECS
+        .iter()
+        .find(|(name, _)| *name == crypto_type)
+        .map(|(_, multicodec)| multicodec)
+        .ok_or_else(|| err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Crypto type {} can't be represented as did:key", crypto_type)))?;
+
+    let key = _encode_multibase(multicodec, &vk.from_base58()?);
+
+    Ok(format!("{}{}#{}", DID_KEY_PREFIX, key, key))
+}
+
+/// Builds `did:key` DID URL that identifies X25519 key agreement key derived
+/// from ed25519 verkey: did:key:<ed25519 key>#<x25519 key>
+pub fn verkey_to_key_agreement_kid(verkey: &str, x25519_pk: &[u8]) -> IndyResult<String> {
+    let (vk, crypto_type) = split_verkey(verkey);
+
+    if crypto_type != DEFAULT_CRYPTO_TYPE {
+        return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                           format!("Key agreement isn't supported for crypto type {}", crypto_type)));
+    }
+
+    Ok(format!("{}{}#{}",
+               DID_KEY_PREFIX,
+               _encode_multibase(ED25519_PUB_MULTICODEC, &vk.from_base58()?),
+               _encode_multibase(X25519_PUB_MULTICODEC, x25519_pk)))
+}
+
+/// Resolves key id to verkey. Key id can be either verkey itself or `did:key` DID URL.
+pub fn kid_to_verkey(kid: &str) -> IndyResult<String> {
+    if !kid.starts_with("did:") {
+        return Ok(kid.to_string());
+    }
+
+    if !kid.starts_with(DID_KEY_PREFIX) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Only did:key DID URLs and verkeys are supported as key id: {}", kid)));
+    }
+
+    // Fragment references either the same key or key agreement key derived from it
+    let key = kid[DID_KEY_PREFIX.len()..].split('#').next().unwrap_or_default();
+
+    if !key.starts_with(MULTIBASE_BASE58_BTC) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Unsupported multibase encoding of did:key: {}", kid)));
+    }
+
+    let bytes = key[1..].from_base58()?;
+
+    let (crypto_type, multicodec) = MULTICODECS
+        .iter()
+        .find(|(_, multicodec)| bytes.starts_with(multicodec))
+        .ok_or_else(|| err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Unsupported key type of did:key: {}", kid)))?;
+
+    let vk = bytes[multicodec.len()..].to_base58();
+
+    let verkey = if *crypto_type == DEFAULT_CRYPTO_TYPE {
+        vk
+    } else {
+        format!("{}:{}", vk, crypto_type)
+    };
+
+    Ok(verkey)
+}
+
+fn _encode_multibase(multicodec: &[u8], key: &[u8]) -> String {
+    let mut bytes = multicodec.to_vec();
+    bytes.extend_from_slice(key);
+    format!("{}{}", MULTIBASE_BASE58_BTC, bytes.to_base58())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERKEY: &str = "5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp";
+
+    #[test]
+    fn verkey_to_kid_works() {
+        let kid = verkey_to_kid(VERKEY).unwrap();
+        assert!(kid.starts_with("did:key:z6Mk"));
+        assert_eq!(VERKEY, kid_to_verkey(&kid).unwrap());
+    }
+
+    #[test]
+    fn verkey_to_kid_works_for_qualified_verkey() {
+        let verkey = format!("{}:{}", "28Pz8zGa2HNWzDDyhQ2m1aWNzZHD1S4jMVU8qtuWcVZDN", SECP256K1_CRYPTO_TYPE);
+        let kid = verkey_to_kid(&verkey).unwrap();
+        assert_eq!(verkey, kid_to_verkey(&kid).unwrap());
+    }
+
+    #[test]
+    fn verkey_to_kid_not_works_for_unknown_crypto_type() {
+        assert_kind!(IndyErrorKind::UnknownCrypto, verkey_to_kid(&format!("{}:unknown", VERKEY)));
+    }
+
+    #[test]
+    fn verkey_to_key_agreement_kid_works() {
+        let kid = verkey_to_key_agreement_kid(VERKEY, &[1u8; 32]).unwrap();
+        assert!(kid.contains("#z6LS"));
+        assert_eq!(VERKEY, kid_to_verkey(&kid).unwrap());
+    }
+
+    #[test]
+    fn kid_to_verkey_works_for_verkey() {
+        assert_eq!(VERKEY, kid_to_verkey(VERKEY).unwrap());
+    }
+
+    #[test]
+    fn kid_to_verkey_not_works_for_other_did_method() {
+        assert_kind!(IndyErrorKind::InvalidStructure, kid_to_verkey("did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1"));
+    }
+
+    #[test]
+    fn kid_to_verkey_not_works_for_x25519_did_key() {
+        let kid = format!("did:key:{}", _encode_multibase(X25519_PUB_MULTICODEC, &[1u8; 32]));
+        assert_kind!(IndyErrorKind::UnknownCrypto, kid_to_verkey(&kid));
+    }
+}
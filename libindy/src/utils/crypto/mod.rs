@@ -1,2 +1,3 @@
 pub mod verkey_builder;
 pub mod signature_serializer;
+pub mod did_key;
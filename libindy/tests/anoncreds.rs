@@ -90,6 +90,96 @@ mod high_cases {
         fn issuer_create_credential_works() {
             anoncreds::init_common_wallet();
         }
+
+        #[test]
+        fn issuer_create_credential_works_for_raw_only_values() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let credential_values_json = json!({
+                "sex": {"raw": "male"},
+                "name": {"raw": "Alex", "encoded": "1139481716457488690172217916278103335"},
+                "height": {"raw": "175"},
+                "age": {"raw": "28"}
+            }).to_string();
+
+            let (credential_json, _, _) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                              &credential_offer,
+                                                                              &credential_req,
+                                                                              &credential_values_json,
+                                                                              None,
+                                                                              None).unwrap();
+
+            let credential: serde_json::Value = serde_json::from_str(&credential_json).unwrap();
+            assert_eq!("5944657099558967239210949258394887428692050081607692519917050011144233115103", credential["values"]["sex"]["encoded"]);
+            assert_eq!("1139481716457488690172217916278103335", credential["values"]["name"]["encoded"]);
+            assert_eq!("175", credential["values"]["height"]["encoded"]);
+            assert_eq!("28", credential["values"]["age"]["encoded"]);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod issuer_create_credentials_batch {
+        use super::*;
+
+        #[test]
+        fn issuer_create_credentials_batch_works() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let cred_requests_json = json!([
+                {
+                    "cred_offer": serde_json::from_str::<serde_json::Value>(&credential_offer).unwrap(),
+                    "cred_req": serde_json::from_str::<serde_json::Value>(&credential_req).unwrap(),
+                    "cred_values": serde_json::from_str::<serde_json::Value>(&anoncreds::gvt_credential_values_json()).unwrap()
+                },
+                {
+                    "cred_offer": serde_json::from_str::<serde_json::Value>(&credential_offer).unwrap(),
+                    "cred_req": serde_json::from_str::<serde_json::Value>(&credential_req).unwrap(),
+                    "cred_values": serde_json::from_str::<serde_json::Value>(&anoncreds::gvt2_credential_values_json()).unwrap()
+                }
+            ]).to_string();
+
+            let (credentials_json, rev_reg_delta_json) = anoncreds::issuer_create_credentials_batch(wallet_handle,
+                                                                                                    &cred_requests_json,
+                                                                                                    None,
+                                                                                                    None).unwrap();
+            assert!(rev_reg_delta_json.is_none());
+
+            let credentials: serde_json::Value = serde_json::from_str(&credentials_json).unwrap();
+            let credentials = credentials.as_array().unwrap();
+            assert_eq!(2, credentials.len());
+            assert_eq!("Alex", credentials[0]["cred"]["values"]["name"]["raw"]);
+            assert_eq!("Alexander", credentials[1]["cred"]["values"]["name"]["raw"]);
+            assert!(credentials[0]["cred_revoc_id"].is_null());
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod encode_credential_attributes {
+        use super::*;
+
+        #[test]
+        fn encode_credential_attributes_works() {
+            let attributes_json = json!({
+                "sex": "male",
+                "address": "101 Wilson Lane",
+                "age": "28"
+            }).to_string();
+
+            let cred_values_json = anoncreds::encode_credential_attributes(&attributes_json).unwrap();
+            let cred_values: serde_json::Value = serde_json::from_str(&cred_values_json).unwrap();
+
+            assert_eq!(json!({
+                "sex": {"raw": "male", "encoded": "5944657099558967239210949258394887428692050081607692519917050011144233115103"},
+                "address": {"raw": "101 Wilson Lane", "encoded": "68086943237164982734333428280784300550565381723532936263016368251445461241953"},
+                "age": {"raw": "28", "encoded": "28"}
+            }), cred_values);
+        }
     }
 
     mod prover_store_credential {
@@ -3116,6 +3206,32 @@ mod high_cases {
             wallet::close_wallet(wallet_handle).unwrap();
         }
 
+        #[test]
+        fn prover_create_proof_works_for_cached_revocation_states_and_not_revocable_credential() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+                 }),
+                 "requested_predicates": json!({
+                    "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID })
+                 })
+            }).to_string();
+
+            anoncreds::prover_create_proof_with_cached_revocation_states(wallet_handle,
+                                                                         &anoncreds::proof_request_attr_and_predicate(),
+                                                                         &requested_credentials_json,
+                                                                         COMMON_MASTER_SECRET,
+                                                                         &anoncreds::schemas_for_proof(),
+                                                                         &anoncreds::cred_defs_for_proof()).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
         #[test]
         fn prover_create_proof_works_for_names() {
             anoncreds::init_common_wallet();
@@ -3319,6 +3435,28 @@ mod high_cases {
         }
     }
 
+    mod verifier_verify_proof_with_details {
+        use super::*;
+
+        #[test]
+        fn verifier_verify_proof_with_details_works_for_custom_encoding() {
+            let details_json = anoncreds::verifier_verify_proof_with_details(&anoncreds::proof_request_attr(),
+                                                                             &anoncreds::proof_json(),
+                                                                             &anoncreds::schemas_for_proof(),
+                                                                             &anoncreds::cred_defs_for_proof(),
+                                                                             "{}",
+                                                                             "{}").unwrap();
+            let details: serde_json::Value = serde_json::from_str(&details_json).unwrap();
+
+            assert_eq!(json!({
+                "valid": true,
+                "non_canonical_attrs": [
+                    {"referent": "attr1_referent", "name": "name", "raw": "Alex", "encoded": "1139481716457488690172217916278103335"}
+                ]
+            }), details);
+        }
+    }
+
     #[test]
     fn verifier_verify_proof_works_for_proof_with_names_in_different_credentials() {
         let other_proof_req_json = json!({
@@ -4237,6 +4375,178 @@ mod medium_cases {
         }
     }
 
+    mod issuer_create_credentials_batch {
+        use super::*;
+
+        #[test]
+        fn issuer_create_credentials_batch_works_for_empty_list() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_create_credentials_batch(wallet_handle, "[]", None, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_credentials_batch_works_for_different_credential_definitions() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let mut other_credential_offer: serde_json::Value = serde_json::from_str(&credential_offer).unwrap();
+            other_credential_offer["cred_def_id"] = json!(anoncreds::issuer_1_xyz_cred_def_id());
+
+            let cred_requests_json = json!([
+                {
+                    "cred_offer": serde_json::from_str::<serde_json::Value>(&credential_offer).unwrap(),
+                    "cred_req": serde_json::from_str::<serde_json::Value>(&credential_req).unwrap(),
+                    "cred_values": serde_json::from_str::<serde_json::Value>(&anoncreds::gvt_credential_values_json()).unwrap()
+                },
+                {
+                    "cred_offer": other_credential_offer,
+                    "cred_req": serde_json::from_str::<serde_json::Value>(&credential_req).unwrap(),
+                    "cred_values": serde_json::from_str::<serde_json::Value>(&anoncreds::gvt_credential_values_json()).unwrap()
+                }
+            ]).to_string();
+
+            let res = anoncreds::issuer_create_credentials_batch(wallet_handle, &cred_requests_json, None, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod issuer_create_revocation_registry_pool {
+        use super::*;
+
+        #[test]
+        fn issuer_create_revocation_registry_pool_works_for_invalid_config() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let config_json = json!({"max_cred_num": 5, "tails_writer_config": "tails"}).to_string();
+
+            let res = anoncreds::issuer_create_revocation_registry_pool(wallet_handle, ISSUER_DID, &anoncreds::issuer_1_gvt_cred_def_id(), &config_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_revocation_registry_pool_works_for_not_found_credential_definition() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_create_revocation_registry_pool(wallet_handle,
+                                                                        ISSUER_DID,
+                                                                        &anoncreds::issuer_1_xyz_tag2_cred_def_id(),
+                                                                        &anoncreds::revocation_registry_pool_config(5));
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_credential_from_revocation_registry_pool_works_for_not_created_pool() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_create_credential_from_revocation_registry_pool(wallet_handle,
+                                                                                        &credential_offer,
+                                                                                        &credential_req,
+                                                                                        &anoncreds::gvt_credential_values_json());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_add_revocation_registry_to_pool_works_for_not_created_pool() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_add_revocation_registry_to_pool(wallet_handle, &anoncreds::issuer_1_gvt_cred_def_id());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_confirm_revocation_registry_published_works_for_invalid_rev_reg_id() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_confirm_revocation_registry_published(wallet_handle, "invalid_rev_reg_id");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod issuer_revoke_credentials {
+        use super::*;
+
+        #[test]
+        fn issuer_revoke_credentials_works_for_empty_list() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_revoke_credentials(wallet_handle, 1, &anoncreds::gvt_rev_reg_id(), "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_revoke_credentials_works_for_invalid_ids() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_revoke_credentials(wallet_handle, 1, &anoncreds::gvt_rev_reg_id(), "[1, 2]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_get_revocation_status_list_works_for_not_found_registry() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_get_revocation_status_list(wallet_handle, &anoncreds::gvt_rev_reg_id());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod encode_credential_attributes {
+        use super::*;
+
+        #[test]
+        fn encode_credential_attributes_works_for_not_string_value() {
+            let res = anoncreds::encode_credential_attributes(r#"{"age": 28}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn encode_credential_attributes_works_for_empty_attributes() {
+            let res = anoncreds::encode_credential_attributes("{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod prover_store_credential {
         use super::*;
 
@@ -4405,6 +4715,83 @@ mod medium_cases {
         }
     }
 
+    mod prover_refresh_revocation_states {
+        use super::*;
+
+        #[test]
+        fn prover_refresh_revocation_states_works_for_empty_list() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::prover_refresh_revocation_states(1, wallet_handle, 1, "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_refresh_revocation_states_works_for_not_revocable_credential() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::prover_refresh_revocation_states(1, wallet_handle, 1, &json!([CREDENTIAL1_ID]).to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_refresh_revocation_states_works_for_not_found_credential() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::prover_refresh_revocation_states(1, wallet_handle, 1, r#"["other_cred_id"]"#);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_refresh_revocation_states_works_for_duplicated_credential() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::prover_refresh_revocation_states(1, wallet_handle, 1, r#"["other_cred_id", "other_cred_id"]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_create_proof_with_cached_revocation_states_works_for_not_cached_state() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": 100, "revealed":true })
+                 }),
+                 "requested_predicates": json!({})
+            }).to_string();
+
+            let res = anoncreds::prover_create_proof_with_cached_revocation_states(wallet_handle,
+                                                                                   &anoncreds::proof_request_attr(),
+                                                                                   &requested_credentials_json,
+                                                                                   COMMON_MASTER_SECRET,
+                                                                                   &anoncreds::schemas_for_proof(),
+                                                                                   &anoncreds::cred_defs_for_proof());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
     mod prover_create_proof_works {
         use super::*;
 
@@ -4085,4 +4085,307 @@ mod demos {
         wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
         wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
     }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_credentials_batch_issuance_on_demand() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credentials_batch_issuance_on_demand").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credentials_batch_issuance_on_demand").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuer creates Credential Offers and Prover creates Credential Requests
+        let mut cred_requests = Vec::new();
+        let mut cred_req_metadatas = Vec::new();
+
+        for cred_values_json in &[anoncreds::gvt_credential_values_json(), anoncreds::gvt2_credential_values_json()] {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+            let (cred_req_json, cred_req_metadata_json) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                                                  DID_MY1,
+                                                                                                  &cred_offer_json,
+                                                                                                  &cred_def_json,
+                                                                                                  COMMON_MASTER_SECRET).unwrap();
+
+            cred_requests.push(json!({
+                "cred_offer": serde_json::from_str::<serde_json::Value>(&cred_offer_json).unwrap(),
+                "cred_req": serde_json::from_str::<serde_json::Value>(&cred_req_json).unwrap(),
+                "cred_values": serde_json::from_str::<serde_json::Value>(cred_values_json).unwrap(),
+            }));
+            cred_req_metadatas.push(cred_req_metadata_json);
+        }
+
+        //6. Issuer creates Credentials in a batch
+        let (credentials_json, revoc_reg_delta_json) =
+            anoncreds::issuer_create_credentials_batch(issuer_wallet_handle,
+                                                       &serde_json::to_string(&cred_requests).unwrap(),
+                                                       Some(&rev_reg_id),
+                                                       Some(blob_storage_reader_handle)).unwrap();
+
+        let revoc_reg_delta: serde_json::Value = serde_json::from_str(&revoc_reg_delta_json.unwrap()).unwrap();
+        let mut issued: Vec<u64> = revoc_reg_delta["value"]["issued"].as_array().unwrap().iter().map(|idx| idx.as_u64().unwrap()).collect();
+        issued.sort();
+        assert_eq!(vec![1, 2], issued);
+
+        let credentials: Vec<serde_json::Value> = serde_json::from_str(&credentials_json).unwrap();
+        assert_eq!("1", credentials[0]["cred_revoc_id"]);
+        assert_eq!("2", credentials[1]["cred_revoc_id"]);
+
+        //7. Prover stores Credentials. Witness of each credential is checked against the final registry
+        for (i, (credential, cred_req_metadata_json)) in credentials.iter().zip(cred_req_metadatas.iter()).enumerate() {
+            anoncreds::prover_store_credential(prover_wallet_handle,
+                                               &format!("batch_credential_{}", i),
+                                               cred_req_metadata_json,
+                                               &credential["cred"].to_string(),
+                                               &cred_def_json,
+                                               Some(&revoc_reg_def_json)).unwrap();
+        }
+
+        //8. Issuer continues numbering after the batch
+        let (cred_rev_id, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        assert_eq!("3", cred_rev_id);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_registry_pool_rollover() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_pool_rollover").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_pool_rollover").unwrap();
+
+        //3. Issuer creates Schema and Credential Definition supporting revocation
+        let (schema_id, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                                      GVT_SCHEMA_NAME,
+                                                                      SCHEMA_VERSION,
+                                                                      GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+        let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                                          ISSUER_DID,
+                                                                                          &schema_json,
+                                                                                          TAG_1,
+                                                                                          None,
+                                                                                          Some(&anoncreds::revocation_cred_def_config())).unwrap();
+        assert!(schema_id.contains(GVT_SCHEMA_NAME));
+
+        //4. Issuer creates Revocation Registry Pool with the active and the standby registries
+        let pool_json = anoncreds::issuer_create_revocation_registry_pool(issuer_wallet_handle,
+                                                                          ISSUER_DID,
+                                                                          &cred_def_id,
+                                                                          &anoncreds::revocation_registry_pool_config(2)).unwrap();
+        let pool: serde_json::Value = serde_json::from_str(&pool_json).unwrap();
+        let registries = pool["registries"].as_array().unwrap();
+        assert_eq!(2, registries.len());
+        assert_eq!("active", registries[0]["state"]);
+        assert_eq!("standby", registries[1]["state"]);
+
+        let first_rev_reg_id = registries[0]["rev_reg_id"].as_str().unwrap().to_string();
+        let second_rev_reg_id = registries[1]["rev_reg_id"].as_str().unwrap().to_string();
+
+        //5. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //6. Issuer issues credentials until the first registry is exhausted
+        let issue_credential = || {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+            let (cred_req_json, _) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                             DID_MY1,
+                                                                             &cred_offer_json,
+                                                                             &cred_def_json,
+                                                                             COMMON_MASTER_SECRET).unwrap();
+
+            anoncreds::issuer_create_credential_from_revocation_registry_pool(issuer_wallet_handle,
+                                                                              &cred_offer_json,
+                                                                              &cred_req_json,
+                                                                              &anoncreds::gvt_credential_values_json())
+                .map(|(_, rev_reg_id, cred_revoc_id, _)| (rev_reg_id, cred_revoc_id))
+        };
+
+        assert_eq!((first_rev_reg_id.clone(), "1".to_string()), issue_credential().unwrap());
+        assert_eq!((first_rev_reg_id.clone(), "2".to_string()), issue_credential().unwrap());
+
+        //7. Standby registry isn't activated until it's published
+        let res = issue_credential();
+        assert_code!(ErrorCode::AnoncredsRevocationRegistryFullError, res);
+
+        //8. Issuer gets requests for the registries which aren't published yet
+        let requests_json = anoncreds::issuer_get_revocation_registry_pool_pending_requests(issuer_wallet_handle, &cred_def_id).unwrap();
+        let requests: Vec<serde_json::Value> = serde_json::from_str(&requests_json).unwrap();
+        assert_eq!(2, requests.len());
+        assert_eq!(first_rev_reg_id, requests[0]["rev_reg_id"]);
+
+        let rev_reg_def_request: serde_json::Value = serde_json::from_str(requests[0]["rev_reg_def_request"].as_str().unwrap()).unwrap();
+        assert_eq!("113", rev_reg_def_request["operation"]["type"]);
+
+        let rev_reg_entry_request: serde_json::Value = serde_json::from_str(requests[0]["rev_reg_entry_request"].as_str().unwrap()).unwrap();
+        assert_eq!("114", rev_reg_entry_request["operation"]["type"]);
+        assert_eq!(2, rev_reg_entry_request["operation"]["value"]["issued"].as_array().unwrap().len());
+
+        //9. Issuer confirms publication of the registries
+        anoncreds::issuer_confirm_revocation_registry_published(issuer_wallet_handle, &first_rev_reg_id).unwrap();
+        anoncreds::issuer_confirm_revocation_registry_published(issuer_wallet_handle, &second_rev_reg_id).unwrap();
+
+        let requests_json = anoncreds::issuer_get_revocation_registry_pool_pending_requests(issuer_wallet_handle, &cred_def_id).unwrap();
+        let requests: Vec<serde_json::Value> = serde_json::from_str(&requests_json).unwrap();
+        assert_eq!(0, requests.len());
+
+        //10. Published standby registry replaces the full one
+        assert_eq!((second_rev_reg_id.clone(), "1".to_string()), issue_credential().unwrap());
+
+        //11. Issuer generates the next standby registry outside issuance
+        let third_rev_reg_id = anoncreds::issuer_add_revocation_registry_to_pool(issuer_wallet_handle, &cred_def_id).unwrap();
+
+        let requests_json = anoncreds::issuer_get_revocation_registry_pool_pending_requests(issuer_wallet_handle, &cred_def_id).unwrap();
+        let requests: Vec<serde_json::Value> = serde_json::from_str(&requests_json).unwrap();
+        assert_eq!(1, requests.len());
+        assert_eq!(third_rev_reg_id, requests[0]["rev_reg_id"]);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revoking_several_credentials_issuance_on_demand() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revoking_several_credentials_issuance_on_demand").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revoking_several_credentials_issuance_on_demand").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuer creates three Credentials
+        let mut cred_requests = Vec::new();
+
+        for cred_values_json in &[anoncreds::gvt_credential_values_json(), anoncreds::gvt2_credential_values_json(), anoncreds::gvt_credential_values_json()] {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+            let (cred_req_json, _) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                             DID_MY1,
+                                                                             &cred_offer_json,
+                                                                             &cred_def_json,
+                                                                             COMMON_MASTER_SECRET).unwrap();
+
+            cred_requests.push(json!({
+                "cred_offer": serde_json::from_str::<serde_json::Value>(&cred_offer_json).unwrap(),
+                "cred_req": serde_json::from_str::<serde_json::Value>(&cred_req_json).unwrap(),
+                "cred_values": serde_json::from_str::<serde_json::Value>(cred_values_json).unwrap(),
+            }));
+        }
+
+        anoncreds::issuer_create_credentials_batch(issuer_wallet_handle,
+                                                   &serde_json::to_string(&cred_requests).unwrap(),
+                                                   Some(&rev_reg_id),
+                                                   Some(blob_storage_reader_handle)).unwrap();
+
+        //6. Issuer revokes the first and the third Credentials at once
+        let revoc_reg_delta_json = anoncreds::issuer_revoke_credentials(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, r#"["1", "3"]"#).unwrap();
+
+        let revoc_reg_delta: serde_json::Value = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+        let mut revoked: Vec<u64> = revoc_reg_delta["value"]["revoked"].as_array().unwrap().iter().map(|idx| idx.as_u64().unwrap()).collect();
+        revoked.sort();
+        assert_eq!(vec![1, 3], revoked);
+
+        //7. Issuer gets status of the Credentials
+        let status_list_json = anoncreds::issuer_get_revocation_status_list(issuer_wallet_handle, &rev_reg_id).unwrap();
+        let status_list: serde_json::Value = serde_json::from_str(&status_list_json).unwrap();
+        assert_eq!(json!([2]), status_list["issued"]);
+        assert_eq!(json!([1, 3]), status_list["revoked"]);
+        assert_eq!(3, status_list["issued_count"]);
+        assert_eq!("oA==", status_list["status_list"]);
+
+        //8. Revocation fails as whole if one of Credentials is already revoked
+        let res = anoncreds::issuer_revoke_credentials(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, r#"["2", "3"]"#);
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        let status_list_json = anoncreds::issuer_get_revocation_status_list(issuer_wallet_handle, &rev_reg_id).unwrap();
+        let status_list: serde_json::Value = serde_json::from_str(&status_list_json).unwrap();
+        assert_eq!(json!([2]), status_list["issued"]);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revoking_several_credentials_issuance_by_default() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revoking_several_credentials_issuance_by_default").unwrap();
+
+        //2. Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _, _, _,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //3. Issuer revokes two Credentials at once
+        let revoc_reg_delta_json = anoncreds::issuer_revoke_credentials(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, r#"["1", "4"]"#).unwrap();
+
+        let revoc_reg_delta: serde_json::Value = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+        let mut revoked: Vec<u64> = revoc_reg_delta["value"]["revoked"].as_array().unwrap().iter().map(|idx| idx.as_u64().unwrap()).collect();
+        revoked.sort();
+        assert_eq!(vec![1, 4], revoked);
+
+        //4. Issuer gets status of the Credentials
+        let status_list_json = anoncreds::issuer_get_revocation_status_list(issuer_wallet_handle, &rev_reg_id).unwrap();
+        let status_list: serde_json::Value = serde_json::from_str(&status_list_json).unwrap();
+        assert_eq!("ISSUANCE_BY_DEFAULT", status_list["issuance_type"]);
+        assert_eq!(json!([1, 4]), status_list["revoked"]);
+        assert_eq!("kA==", status_list["status_list"]);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+    }
 }
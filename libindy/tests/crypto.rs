@@ -10,7 +10,7 @@ use crate::utils::crypto;
 use crate::utils::constants::*;
 use crate::utils::Setup;
 
-use self::indy::ErrorCode;
+use self::indy::{ErrorCode, WalletHandle};
 
 pub const ENCRYPTED_MESSAGE: &'static [u8; 45] = &[187, 227, 10, 29, 46, 178, 12, 179, 197, 69, 171, 70, 228, 204, 52, 22, 199, 54, 62, 13, 115, 5, 216, 66, 20, 131, 121, 29, 251, 224, 253, 201, 75, 73, 225, 237, 219, 133, 35, 217, 131, 135, 232, 129, 32];
 pub const SIGNATURE: &'static [u8; 64] = &[169, 215, 8, 225, 7, 107, 110, 9, 193, 162, 202, 214, 162, 66, 238, 211, 63, 209, 12, 196, 8, 211, 55, 27, 120, 94, 204, 147, 53, 104, 103, 61, 60, 249, 237, 127, 103, 46, 220, 223, 10, 95, 75, 53, 245, 210, 241, 151, 191, 41, 48, 30, 9, 16, 78, 252, 157, 206, 210, 145, 125, 133, 109, 11];
@@ -127,6 +127,116 @@ mod high_cases {
         }
     }
 
+    mod crypto_sign_jws {
+        use super::*;
+
+        const PROTECTED_WITH_DID_KID: &str = "eyJhbGciOiJFZERTQSIsImtpZCI6ImRpZDpzb3Y6VnNLVjdnclIxQlVFMjltRzJGbTJrWCNrZXktMSJ9";
+        const PROTECTED_WITHOUT_KID: &str = "eyJhbGciOiJFZERTQSJ9";
+        const ENCODED_MESSAGE: &str = "eyJyZXFJZCI6MTQ5NjgyMjIxMTM2MjAxNzc2NH0";
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_compact_serialization() {
+            let setup = Setup::wallet();
+
+            let my_vk = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let options = json!({"did": DID_MY1}).to_string();
+            let jws = crypto::sign_jws(setup.wallet_handle, &my_vk, MESSAGE.as_bytes(), Some(&options)).unwrap();
+
+            let parts: Vec<&str> = jws.split('.').collect();
+            assert_eq!(3, parts.len());
+            assert_eq!(PROTECTED_WITH_DID_KID, parts[0]);
+            assert_eq!(ENCODED_MESSAGE, parts[1]);
+
+            assert!(crypto::verify_jws(&my_vk, &jws, None).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_did_key_kid() {
+            let setup = Setup::wallet();
+
+            let my_vk = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let jws = crypto::sign_jws(setup.wallet_handle, &my_vk, MESSAGE.as_bytes(), None).unwrap();
+
+            assert_ne!(PROTECTED_WITH_DID_KID, jws.split('.').next().unwrap());
+            assert!(crypto::verify_jws(&my_vk, &jws, None).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_json_serialization() {
+            let setup = Setup::wallet();
+
+            let my_vk = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let options = json!({"serialization": "json", "did": DID_MY1}).to_string();
+            let jws = crypto::sign_jws(setup.wallet_handle, &my_vk, MESSAGE.as_bytes(), Some(&options)).unwrap();
+
+            let jws_value: serde_json::Value = serde_json::from_str(&jws).unwrap();
+            assert_eq!(ENCODED_MESSAGE, jws_value["payload"].as_str().unwrap());
+            assert_eq!(PROTECTED_WITHOUT_KID, jws_value["signatures"][0]["protected"].as_str().unwrap());
+            assert_eq!("did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1", jws_value["signatures"][0]["header"]["kid"].as_str().unwrap());
+
+            assert!(crypto::verify_jws(&my_vk, &jws, None).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_detached_payload() {
+            let setup = Setup::wallet();
+
+            let my_vk = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let options = json!({"detached": true}).to_string();
+            let jws = crypto::sign_jws(setup.wallet_handle, &my_vk, MESSAGE.as_bytes(), Some(&options)).unwrap();
+
+            assert_eq!("", jws.split('.').nth(1).unwrap());
+            assert!(crypto::verify_jws(&my_vk, &jws, Some(MESSAGE.as_bytes())).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_p256_key() {
+            let setup = Setup::wallet();
+
+            let my_vk = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "p256").unwrap();
+
+            let jws = crypto::sign_jws(setup.wallet_handle, &my_vk, MESSAGE.as_bytes(), None).unwrap();
+            assert!(crypto::verify_jws(&my_vk, &jws, None).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_unknown_signer() {
+            let setup = Setup::wallet();
+            let res = crypto::sign_jws(setup.wallet_handle, VERKEY, MESSAGE.as_bytes(), None);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod crypto_verify_jws {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_other_signer() {
+            let setup = Setup::wallet();
+
+            let my_vk = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+            let jws = crypto::sign_jws(setup.wallet_handle, &my_vk, MESSAGE.as_bytes(), None).unwrap();
+
+            assert!(!crypto::verify_jws(VERKEY_MY2, &jws, None).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_other_detached_payload() {
+            let setup = Setup::wallet();
+
+            let my_vk = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let options = json!({"detached": true}).to_string();
+            let jws = crypto::sign_jws(setup.wallet_handle, &my_vk, MESSAGE.as_bytes(), Some(&options)).unwrap();
+
+            assert!(!crypto::verify_jws(&my_vk, &jws, Some("other message".as_bytes())).unwrap());
+        }
+    }
+
     mod auth_crypt {
         use super::*;
 
@@ -326,6 +436,226 @@ mod high_cases {
             assert_code!(ErrorCode::WalletItemNotFound, res);
         }
     }
+
+    mod pack_message_v2 {
+        use super::*;
+
+        #[test]
+        fn indy_pack_message_v2_works_for_authcrypt() {
+            let setup = Setup::key();
+            let receiver_kids = serde_json::to_string(&vec![VERKEY_MY1, VERKEY_MY2]).unwrap();
+
+            let packed = crypto::pack_message_v2(setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, Some(&setup.verkey), None).unwrap();
+            let packed: serde_json::Value = serde_json::from_slice(&packed).unwrap();
+
+            assert_eq!(2, packed["recipients"].as_array().unwrap().len());
+            assert!(packed["recipients"][0]["header"]["kid"].as_str().unwrap().starts_with("did:key:z6Mk"));
+        }
+
+        #[test]
+        fn indy_pack_message_v2_works_for_signed_only() {
+            let setup = Setup::key();
+
+            let packed = crypto::pack_message_v2(setup.wallet_handle, AGENT_MESSAGE.as_bytes(), "[]", None, Some(&setup.verkey)).unwrap();
+            let packed: serde_json::Value = serde_json::from_slice(&packed).unwrap();
+
+            assert_eq!(1, packed["signatures"].as_array().unwrap().len());
+        }
+    }
+
+    mod unpack_message_v2 {
+        use super::*;
+
+        fn _unpack(wallet_handle: WalletHandle, packed: &[u8]) -> serde_json::Value {
+            let res = crypto::unpack_message_v2(wallet_handle, packed).unwrap();
+            serde_json::from_slice(&res).unwrap()
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_works_for_authcrypt() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::key();
+
+            let receiver_kids = serde_json::to_string(&vec![VERKEY_TRUSTEE, &receiver_setup.verkey]).unwrap();
+            let packed = crypto::pack_message_v2(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, Some(&sender_setup.verkey), None).unwrap();
+
+            let res = _unpack(receiver_setup.wallet_handle, &packed);
+
+            assert_eq!(AGENT_MESSAGE, res["message"].as_str().unwrap());
+            assert!(res["recipient_kid"].as_str().unwrap().starts_with("did:key:"));
+            assert!(res["sender_kid"].as_str().unwrap().starts_with("did:key:"));
+            assert!(res["signer_kid"].is_null());
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_works_for_anoncrypt() {
+            let sender_setup = Setup::wallet();
+            let receiver_setup = Setup::key();
+
+            let receiver_kids = serde_json::to_string(&vec![&receiver_setup.verkey]).unwrap();
+            let packed = crypto::pack_message_v2(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, None, None).unwrap();
+
+            let res = _unpack(receiver_setup.wallet_handle, &packed);
+
+            assert_eq!(AGENT_MESSAGE, res["message"].as_str().unwrap());
+            assert!(res["sender_kid"].is_null());
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_works_for_signed_and_encrypted() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::key();
+
+            let receiver_kids = serde_json::to_string(&vec![&receiver_setup.verkey]).unwrap();
+            let packed = crypto::pack_message_v2(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, None, Some(&sender_setup.verkey)).unwrap();
+
+            let res = _unpack(receiver_setup.wallet_handle, &packed);
+
+            assert_eq!(AGENT_MESSAGE, res["message"].as_str().unwrap());
+            assert!(res["signer_kid"].as_str().unwrap().starts_with("did:key:z6Mk"));
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_works_for_signed_only_by_p256_key() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "p256").unwrap();
+
+            let packed = crypto::pack_message_v2(setup.wallet_handle, AGENT_MESSAGE.as_bytes(), "[]", None, Some(&verkey)).unwrap();
+
+            let other_setup = Setup::wallet();
+            let res = _unpack(other_setup.wallet_handle, &packed);
+
+            assert_eq!(AGENT_MESSAGE, res["message"].as_str().unwrap());
+            assert!(res["recipient_kid"].is_null());
+            assert!(res["signer_kid"].as_str().unwrap().starts_with("did:key:"));
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_works_for_did_key_kids() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::key();
+
+            // Receiver kid can be taken from a previous message
+            let receiver_kids = serde_json::to_string(&vec![&receiver_setup.verkey]).unwrap();
+            let packed = crypto::pack_message_v2(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, Some(&sender_setup.verkey), None).unwrap();
+            let res = _unpack(receiver_setup.wallet_handle, &packed);
+
+            let receiver_kids = serde_json::to_string(&vec![res["recipient_kid"].as_str().unwrap()]).unwrap();
+            let packed = crypto::pack_message_v2(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, Some(&sender_setup.verkey), None).unwrap();
+            let res = _unpack(receiver_setup.wallet_handle, &packed);
+
+            assert_eq!(AGENT_MESSAGE, res["message"].as_str().unwrap());
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_works_for_v1_message() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::key();
+
+            let receiver_keys = serde_json::to_string(&vec![&receiver_setup.verkey]).unwrap();
+            let packed = crypto::pack_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_keys, Some(&sender_setup.verkey)).unwrap();
+
+            let res = _unpack(receiver_setup.wallet_handle, &packed);
+
+            assert_eq!(AGENT_MESSAGE, res["message"].as_str().unwrap());
+            assert_eq!(receiver_setup.verkey, res["recipient_kid"].as_str().unwrap());
+            assert_eq!(sender_setup.verkey, res["sender_kid"].as_str().unwrap());
+        }
+    }
+
+    mod key_backend {
+        use super::*;
+        use crate::utils::{did, ledger};
+
+        #[test]
+        fn indy_create_key_works_for_key_backend() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_in_mock_backend(setup.wallet_handle).unwrap();
+            assert!(verkey.ends_with(&format!(":{}", crypto::MOCK_KEY_BACKEND_CRYPTO_TYPE)));
+        }
+
+        #[test]
+        fn indy_crypto_sign_works_for_key_backend() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_in_mock_backend(setup.wallet_handle).unwrap();
+
+            let signature = crypto::sign(setup.wallet_handle, &verkey, MESSAGE.as_bytes()).unwrap();
+
+            assert!(crypto::verify(&verkey, MESSAGE.as_bytes(), &signature).unwrap());
+
+            // Mock backend keeps ed25519 keys, so signature is valid for built-in crypto too
+            let unqualified_verkey = verkey.split(':').next().unwrap();
+            assert!(crypto::verify(unqualified_verkey, MESSAGE.as_bytes(), &signature).unwrap());
+        }
+
+        #[test]
+        fn indy_sign_request_works_for_key_backend_did() {
+            let setup = Setup::wallet();
+            crypto::register_mock_key_backend();
+
+            let did_json = json!({"crypto_type": crypto::MOCK_KEY_BACKEND_CRYPTO_TYPE}).to_string();
+            let (did, _) = did::create_my_did(setup.wallet_handle, &did_json).unwrap();
+
+            let request = json!({
+                "reqId": 1496822211362017764u64,
+                "identifier": did,
+                "operation": {"type": "105", "dest": did}
+            }).to_string();
+
+            let signed_request = ledger::sign_request(setup.wallet_handle, &did, &request).unwrap();
+            let signed_request: serde_json::Value = serde_json::from_str(&signed_request).unwrap();
+            assert!(signed_request["signature"].is_string());
+        }
+    }
+
+    mod signature_crypto_types {
+        use super::*;
+        use crate::utils::did;
+
+        #[test]
+        fn indy_create_key_works_for_secp256k1() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "secp256k1").unwrap();
+            assert!(verkey.ends_with(":secp256k1"));
+        }
+
+        #[test]
+        fn indy_create_key_works_for_p256_and_seed() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, Some(MY1_SEED), "p256").unwrap();
+            assert!(verkey.ends_with(":p256"));
+
+            let other_setup = Setup::wallet();
+            let other_verkey = crypto::create_key_with_crypto_type(other_setup.wallet_handle, Some(MY1_SEED), "p256").unwrap();
+            assert_eq!(verkey, other_verkey);
+        }
+
+        #[test]
+        fn indy_crypto_sign_verify_works_for_secp256k1_and_p256() {
+            let setup = Setup::wallet();
+
+            for crypto_type in &["secp256k1", "p256"] {
+                let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, crypto_type).unwrap();
+
+                let signature = crypto::sign(setup.wallet_handle, &verkey, MESSAGE.as_bytes()).unwrap();
+                assert_eq!(64, signature.len());
+
+                assert!(crypto::verify(&verkey, MESSAGE.as_bytes(), &signature).unwrap());
+                assert!(!crypto::verify(&verkey, "other message".as_bytes(), &signature).unwrap());
+            }
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_secp256k1() {
+            let setup = Setup::wallet();
+
+            let (_, verkey) = did::create_my_did(setup.wallet_handle, &json!({"crypto_type": "secp256k1"}).to_string()).unwrap();
+            assert!(verkey.ends_with(":secp256k1"));
+
+            let signature = crypto::sign(setup.wallet_handle, &verkey, MESSAGE.as_bytes()).unwrap();
+            assert!(crypto::verify(&verkey, MESSAGE.as_bytes(), &signature).unwrap());
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -423,6 +753,75 @@ mod medium_cases {
         }
     }
 
+    mod crypto_sign_jws {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_invalid_wallet_handle() {
+            let setup = Setup::did();
+            let res = crypto::sign_jws(INVALID_WALLET_HANDLE, &setup.verkey, MESSAGE.as_bytes(), None);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_invalid_did() {
+            let setup = Setup::key();
+            let options = json!({"did": "invalid_base58_did"}).to_string();
+            let res = crypto::sign_jws(setup.wallet_handle, &setup.verkey, MESSAGE.as_bytes(), Some(&options));
+            assert_code!(ErrorCode::CommonInvalidParam6, res);
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_invalid_serialization() {
+            let setup = Setup::key();
+            let options = json!({"serialization": "xml"}).to_string();
+            let res = crypto::sign_jws(setup.wallet_handle, &setup.verkey, MESSAGE.as_bytes(), Some(&options));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod crypto_verify_jws {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_missed_detached_payload() {
+            let setup = Setup::key();
+
+            let options = json!({"detached": true}).to_string();
+            let jws = crypto::sign_jws(setup.wallet_handle, &setup.verkey, MESSAGE.as_bytes(), Some(&options)).unwrap();
+
+            let res = crypto::verify_jws(&setup.verkey, &jws, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_attached_and_detached_payload() {
+            let setup = Setup::key();
+
+            let jws = crypto::sign_jws(setup.wallet_handle, &setup.verkey, MESSAGE.as_bytes(), None).unwrap();
+
+            let res = crypto::verify_jws(&setup.verkey, &jws, Some(MESSAGE.as_bytes()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_algorithm_of_other_crypto_type() {
+            let setup = Setup::key();
+
+            let jws = crypto::sign_jws(setup.wallet_handle, &setup.verkey, MESSAGE.as_bytes(), None).unwrap();
+            let other_vk = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "secp256k1").unwrap();
+
+            let res = crypto::verify_jws(&other_vk, &jws, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_invalid_compact_serialization() {
+            let res = crypto::verify_jws(VERKEY_MY1, "eyJhbGciOiJFZERTQSJ9.payload", None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod auth_crypt {
         use super::*;
 
@@ -588,6 +987,155 @@ mod medium_cases {
             assert!(res.is_ok());
         }
     }
+
+    mod pack_message_v2 {
+        use super::*;
+
+        #[test]
+        fn indy_pack_message_v2_fails_no_receivers_and_signer() {
+            let setup = Setup::key();
+            let res = crypto::pack_message_v2(setup.wallet_handle, AGENT_MESSAGE.as_bytes(), "[]", Some(&setup.verkey), None);
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+        }
+
+        #[test]
+        fn indy_pack_message_v2_fails_for_secp256k1_receiver() {
+            let setup = Setup::key();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "secp256k1").unwrap();
+            let receiver_kids = serde_json::to_string(&vec![verkey]).unwrap();
+
+            let res = crypto::pack_message_v2(setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, None, None);
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
+
+        #[test]
+        fn indy_pack_message_v2_fails_for_unsupported_did_method() {
+            let setup = Setup::key();
+            let receiver_kids = serde_json::to_string(&vec!["did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1"]).unwrap();
+
+            let res = crypto::pack_message_v2(setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, None, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod unpack_message_v2 {
+        use super::*;
+
+        #[test]
+        fn indy_unpack_message_v2_fails_no_matching_key() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::key();
+
+            let receiver_kids = serde_json::to_string(&vec![VERKEY_TRUSTEE]).unwrap();
+            let packed = crypto::pack_message_v2(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_kids, Some(&sender_setup.verkey), None).unwrap();
+
+            let res = crypto::unpack_message_v2(receiver_setup.wallet_handle, &packed);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_fails_for_tampered_signature() {
+            let setup = Setup::key();
+
+            let packed = crypto::pack_message_v2(setup.wallet_handle, AGENT_MESSAGE.as_bytes(), "[]", None, Some(&setup.verkey)).unwrap();
+            let mut packed: serde_json::Value = serde_json::from_slice(&packed).unwrap();
+            packed["payload"] = json!("dGFtcGVyZWQ");
+
+            let res = crypto::unpack_message_v2(setup.wallet_handle, packed.to_string().as_bytes());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_unpack_message_v2_fails_for_unknown_format() {
+            let setup = Setup::key();
+            let res = crypto::unpack_message_v2(setup.wallet_handle, "{\"some\": \"json\"}".as_bytes());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod key_backend {
+        use super::*;
+
+        #[test]
+        fn indy_register_key_backend_works_for_builtin_crypto_type() {
+            Setup::empty();
+            let res = crypto::register_key_backend("ed25519");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_register_key_backend_works_for_twice() {
+            Setup::empty();
+            crypto::register_key_backend("mock_backend_twice").unwrap();
+            let res = crypto::register_key_backend("mock_backend_twice");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_auth_crypt_works_for_key_backend_without_crypto_box() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_in_mock_backend(setup.wallet_handle).unwrap();
+
+            let res = crypto::auth_crypt(setup.wallet_handle, &verkey, VERKEY_MY2, MESSAGE.as_bytes());
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
+
+        #[test]
+        fn indy_anon_decrypt_works_for_key_backend() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_in_mock_backend(setup.wallet_handle).unwrap();
+
+            let res = crypto::anon_decrypt(setup.wallet_handle, &verkey, ENCRYPTED_MESSAGE);
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
+    }
+
+    mod signature_crypto_types {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_verify_works_for_invalid_signature_len() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "p256").unwrap();
+
+            let res = crypto::verify(&verkey, MESSAGE.as_bytes(), &[1, 2, 3]);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_register_key_backend_works_for_signature_crypto_type() {
+            Setup::empty();
+            let res = crypto::register_key_backend("secp256k1");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_auth_crypt_works_for_secp256k1() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "secp256k1").unwrap();
+
+            let res = crypto::auth_crypt(setup.wallet_handle, &verkey, VERKEY_MY2, MESSAGE.as_bytes());
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
+
+        #[test]
+        fn indy_anon_crypt_works_for_p256() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "p256").unwrap();
+
+            let res = crypto::anon_crypt(&verkey, MESSAGE.as_bytes());
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
+
+        #[test]
+        fn indy_anon_decrypt_works_for_p256() {
+            let setup = Setup::wallet();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "p256").unwrap();
+
+            let res = crypto::anon_decrypt(setup.wallet_handle, &verkey, ENCRYPTED_MESSAGE);
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
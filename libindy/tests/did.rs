@@ -123,6 +123,92 @@ mod high_cases {
         }
     }
 
+    mod key_for_did_at {
+        use super::*;
+
+        #[test]
+        fn indy_key_for_did_at_works_for_my_did() {
+            let setup = Setup::did();
+
+            let received_verkey = did::key_for_did_at(-1, setup.wallet_handle, &setup.did, time::get_time().sec as u64).unwrap();
+            assert_eq!(setup.verkey, received_verkey);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_replaced_keys() {
+            let setup = Setup::did();
+
+            let timestamp_before = time::get_time().sec as u64;
+
+            // Keys rotated in the next second become effective later
+            ::std::thread::sleep(::std::time::Duration::from_millis(1100));
+
+            let new_verkey = did::replace_keys_start(setup.wallet_handle, &setup.did, "{}").unwrap();
+            did::replace_keys_apply(setup.wallet_handle, &setup.did).unwrap();
+
+            let timestamp_after = time::get_time().sec as u64;
+
+            let received_verkey = did::key_for_did_at(-1, setup.wallet_handle, &setup.did, timestamp_before).unwrap();
+            assert_eq!(setup.verkey, received_verkey);
+
+            let received_verkey = did::key_for_did_at(-1, setup.wallet_handle, &setup.did, timestamp_after).unwrap();
+            assert_eq!(new_verkey, received_verkey);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_their_did() {
+            let setup = Setup::wallet();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID, VERKEY).unwrap();
+
+            let received_verkey = did::key_for_did_at(-1, setup.wallet_handle, DID, time::get_time().sec as u64).unwrap();
+            assert_eq!(VERKEY, received_verkey);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_their_did_key_changed() {
+            let setup = Setup::wallet();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID, VERKEY).unwrap();
+            let timestamp_before = time::get_time().sec as u64;
+
+            ::std::thread::sleep(::std::time::Duration::from_millis(1100));
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID, VERKEY_MY1).unwrap();
+            let timestamp_after = time::get_time().sec as u64;
+
+            let received_verkey = did::key_for_did_at(-1, setup.wallet_handle, DID, timestamp_before).unwrap();
+            assert_eq!(VERKEY, received_verkey);
+
+            let received_verkey = did::key_for_did_at(-1, setup.wallet_handle, DID, timestamp_after).unwrap();
+            assert_eq!(VERKEY_MY1, received_verkey);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_fully_qualified_my_did() {
+            let setup = Setup::did_fully_qualified();
+
+            let received_verkey = did::key_for_did_at(-1, setup.wallet_handle, &setup.did, time::get_time().sec as u64).unwrap();
+            assert_eq!(setup.verkey, received_verkey);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_get_key_from_ledger() {
+            let setup = Setup::wallet_and_pool();
+
+            let received_verkey = did::key_for_did_at(setup.pool_handle, setup.wallet_handle, DID_TRUSTEE, time::get_time().sec as u64).unwrap();
+            assert_eq!(VERKEY_TRUSTEE.to_string(), received_verkey);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_unknown_did() {
+            let setup = Setup::wallet_and_pool();
+
+            let res = did::key_for_did_at(setup.pool_handle, setup.wallet_handle, DID, time::get_time().sec as u64);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
     mod set_endpoint_for_did {
         use super::*;
 
@@ -217,6 +303,62 @@ mod high_cases {
         }
     }
 
+    mod resolve_did {
+        use super::*;
+
+        #[test]
+        fn indy_resolve_did_works() {
+            let setup = Setup::simulated_trustee();
+
+            let res = did::resolve_did(setup.pool_handle, DID_TRUSTEE).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            let did_document = &res["didDocument"];
+            assert_eq!(format!("did:sov:{}", DID_TRUSTEE), did_document["id"]);
+            assert_eq!(VERKEY_TRUSTEE, did_document["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!([format!("did:sov:{}#key-1", DID_TRUSTEE)]), did_document["authentication"]);
+            assert!(did_document["service"].is_null());
+            assert_eq!(1, res["didResolutionMetadata"]["seqNo"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_endpoint() {
+            let setup = Setup::simulated_trustee();
+
+            let attrib_data = json!({"endpoint": {"endpoint": ENDPOINT, "routingKeys": [VERKEY_TRUSTEE]}}).to_string();
+            let attrib_request = ledger::build_attrib_request(&setup.did, &setup.did, None, Some(&attrib_data), None).unwrap();
+            ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &attrib_request).unwrap();
+
+            let res = did::resolve_did(setup.pool_handle, &setup.did).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            let services = res["didDocument"]["service"].as_array().unwrap();
+            assert_eq!(2, services.len());
+            assert_eq!(ENDPOINT, services[0]["serviceEndpoint"]);
+            assert_eq!(json!([VERKEY_TRUSTEE]), services[1]["routingKeys"]);
+            assert_eq!(2, res["didResolutionMetadata"]["endpoint"]["seqNo"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_fully_qualified_did() {
+            let setup = Setup::simulated_trustee();
+
+            let did = format!("did:indy:sovrin:{}", DID_TRUSTEE);
+            let res = did::resolve_did(setup.pool_handle, &did).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(did, res["didDocument"]["id"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_unknown_did() {
+            let setup = Setup::simulated_trustee();
+
+            let res = did::resolve_did(setup.pool_handle, DID);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
     mod set_did_metadata {
         use super::*;
 
@@ -774,6 +916,26 @@ mod medium_cases {
         }
     }
 
+    mod resolve_did {
+        use super::*;
+
+        #[test]
+        fn indy_resolve_did_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = did::resolve_did(INVALID_POOL_HANDLE, DID_TRUSTEE);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_unsupported_did_method() {
+            Setup::empty();
+
+            let res = did::resolve_did(-1, &format!("did:peer:{}", DID_TRUSTEE));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod key_for_local_did {
         use super::*;
 
@@ -786,6 +948,34 @@ mod medium_cases {
         }
     }
 
+    mod key_for_did_at {
+        use super::*;
+
+        #[test]
+        fn indy_key_for_did_at_works_for_invalid_pool_handle() {
+            let setup = Setup::wallet();
+
+            let res = did::key_for_did_at(INVALID_POOL_HANDLE, setup.wallet_handle, DID_TRUSTEE, time::get_time().sec as u64);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = did::key_for_did_at(-1, INVALID_WALLET_HANDLE, DID, time::get_time().sec as u64);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_key_for_did_at_works_for_invalid_did() {
+            let setup = Setup::wallet();
+
+            let res = did::key_for_did_at(-1, setup.wallet_handle, INVALID_BASE58_DID, time::get_time().sec as u64);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod set_endpoint_for_did {
         use super::*;
 
@@ -434,6 +434,52 @@ impl Prover
         proof_json
     }
 
+    pub fn make_proof_with_cached_revocation_states(&self, pool: &Pool, proof_request: &str, cred_id: &str) -> String
+    {
+        let credential_json = anoncreds::prover_get_credential(self.wallet_handle, cred_id).unwrap();
+        let cred_info: CredentialInfo = serde_json::from_str(&credential_json).unwrap();
+
+        // Prover refreshes RevocationState cached in the wallet
+        let prover_blob_storage_reader_handle = blob_storage::open_reader(TYPE, &anoncreds::tails_writer_config()).unwrap();
+        let refreshed_json = anoncreds::prover_refresh_revocation_states(pool.pool_handle,
+                                                                         self.wallet_handle,
+                                                                         prover_blob_storage_reader_handle,
+                                                                         &json!([cred_id]).to_string()).unwrap();
+        let refreshed: Value = serde_json::from_str(&refreshed_json).unwrap();
+        assert_eq!(cred_info.rev_reg_id.clone().unwrap().0, refreshed[cred_id]["rev_reg_id"]);
+        let timestamp = refreshed[cred_id]["timestamp"].as_u64().unwrap();
+
+        // Prover gets Schema from Ledger
+        let (_, schema_json) = pool.get_schema(None, &cred_info.schema_id.0);
+
+        // Prover gets CredentialDefinition from Ledger
+        let (_, cred_def_json) = pool.get_cred_def(Some(&self.did), &cred_info.cred_def_id.0);
+
+        let requested_credentials_json = json!({
+            "self_attested_attributes": json!({}),
+            "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": cred_id, "timestamp": timestamp,  "revealed":true })
+            }),
+            "requested_predicates": json!({})
+        }).to_string();
+
+        let schemas_json = json!({
+            cred_info.schema_id.0: serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let cred_defs_json = json!({
+            cred_info.cred_def_id.0: serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        // Prover creates Proof using cached RevocationState
+        anoncreds::prover_create_proof_with_cached_revocation_states(self.wallet_handle,
+                                                                     &proof_request,
+                                                                     &requested_credentials_json,
+                                                                     &self.master_secret_id,
+                                                                     &schemas_json,
+                                                                     &cred_defs_json).unwrap()
+    }
+
 
     pub fn close(&self)
     {
@@ -621,6 +667,70 @@ fn anoncreds_revocation_interaction_test_one_prover(revocation_registry_config:
     pool.close();
 }
 
+#[cfg(feature = "revocation_tests")]
+#[cfg(any(feature = "force_full_interaction_tests", not(target_os = "android")))]
+#[cfg(not(feature = "only_high_cases"))]
+#[test]
+fn anoncreds_revocation_interaction_test_issuance_by_demand_cached_revocation_states() {
+    let setup = Setup::empty();
+
+    let pool = Pool::new(&setup.name);
+
+    let mut issuer = Issuer::new(&pool);
+
+    let mut prover = Prover::new(None);
+
+    // Issuer publish Prover DID
+    pool.submit_nym(&issuer.issuer_did, issuer.issuer_wallet_handle, &prover.did, Some(&prover.verkey));
+
+    // ISSUER post to Ledger Schema, CredentialDefinition, RevocationRegistry
+    issuer.create_initial_ledger_state(&pool, r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+    // Issuer issues Credential and Prover stores it
+    let cred_offer_json = issuer.make_credential_offer();
+    let cred_req_json = prover.make_credential_request(&pool, &cred_offer_json);
+    let (cred_json, cred_rev_id, _) = issuer.issue_credential(&pool, &cred_offer_json, &cred_req_json, &anoncreds::gvt_credential_values_json());
+    prover.store_credentials(&pool, &cred_json, CREDENTIAL1_ID);
+
+    thread::sleep(std::time::Duration::from_secs(1));
+
+    let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({}),
+           "non_revoked": json!({ "to": time::get_time().sec as u64 })
+        }).to_string();
+
+    let verifier = Verifier::new(&proof_request);
+
+    // Prover creates RevocationState in the wallet and proves with it
+    let proof_json = prover.make_proof_with_cached_revocation_states(&pool, &proof_request, CREDENTIAL1_ID);
+
+    verifier.verify_revealed(&proof_json, "attr1_referent", "Alex");
+    assert!(verifier.verify(&pool, &proof_json));
+
+    // Issuer revokes Credential
+    issuer.revoke_credential(&pool, &cred_rev_id);
+
+    thread::sleep(std::time::Duration::from_secs(1));
+
+    // Prover updates cached RevocationState with the delta since the previous refresh
+    let proof_json = prover.make_proof_with_cached_revocation_states(&pool, &proof_request, CREDENTIAL1_ID);
+
+    assert!(!verifier.verify(&pool, &proof_json));
+
+    issuer.close();
+    prover.close();
+
+    pool.close();
+}
+
 #[cfg(not(feature = "only_high_cases"))]
 fn multi_steps_create_revocation_credential(pool: &Pool, issuer: &Issuer, prover: &mut Prover, cred_values_json: &str, cred_id: &str) -> (String, Option<String>)
 {
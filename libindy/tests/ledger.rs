@@ -128,6 +128,153 @@ mod high_cases {
         }
     }
 
+    mod submit_request_with_details {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_details_works_for_write_request() {
+            let setup = Setup::trustee();
+
+            let (did, verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+            let nym_request = ledger::build_nym_request(&setup.did, &did, Some(&verkey), None, None).unwrap();
+            let nym_request = ledger::sign_request(setup.wallet_handle, &setup.did, &nym_request).unwrap();
+
+            let response = ledger::submit_request_with_details(setup.pool_handle, &nym_request).unwrap();
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+            assert_eq!("REPLY", response["reply"]["op"].as_str().unwrap());
+            assert!(response["consensus"]["reached"].as_bool().unwrap());
+            assert_eq!(1, response["consensus"]["f"].as_u64().unwrap());
+
+            let nodes = response["consensus"]["nodes"].as_object().unwrap();
+            assert_eq!(4, nodes.len());
+            assert!(nodes.values().filter(|node| node["status"] == "REPLY").count() >= 2);
+
+            let response_metadata = ledger::get_response_metadata(&response.to_string()).unwrap();
+            let response_metadata: serde_json::Value = serde_json::from_str(&response_metadata).unwrap();
+            assert!(response_metadata["seqNo"].as_u64().is_some());
+            assert!(response_metadata["consensus"]["reached"].as_bool().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_details_works_for_rejected_request() {
+            let setup = Setup::new_identity();
+
+            let (did, verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+            let nym_request = ledger::build_nym_request(&setup.did, &did, Some(&verkey), None, Some("TRUSTEE")).unwrap();
+            let nym_request = ledger::sign_request(setup.wallet_handle, &setup.did, &nym_request).unwrap();
+
+            let response = ledger::submit_request_with_details(setup.pool_handle, &nym_request).unwrap();
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+            assert_eq!("REJECT", response["reply"]["op"].as_str().unwrap());
+
+            let nodes = response["consensus"]["nodes"].as_object().unwrap();
+            let rejected: Vec<&serde_json::Value> = nodes.values().filter(|node| node["status"] == "REJECT").collect();
+            assert!(rejected.len() >= 2);
+            assert!(rejected.iter().all(|node| node["reason"].as_str().is_some()));
+            assert!(rejected.iter().all(|node| node["errorKind"] == "AuthRule"));
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_details_works_for_read_request() {
+            let setup = Setup::pool();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            let response = ledger::submit_request_with_details(setup.pool_handle, &get_nym_request).unwrap();
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+            assert_eq!("REPLY", response["reply"]["op"].as_str().unwrap());
+            assert!(response["consensus"].is_null());
+        }
+    }
+
+    mod submit_requests_batch {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works() {
+            let setup = Setup::trustee();
+
+            let requests: Vec<serde_json::Value> = (0..5)
+                .map(|_| {
+                    let (did, verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+                    let nym_request = ledger::build_nym_request(&setup.did, &did, Some(&verkey), None, None).unwrap();
+                    let nym_request = ledger::sign_request(setup.wallet_handle, &setup.did, &nym_request).unwrap();
+                    serde_json::from_str(&nym_request).unwrap()
+                })
+                .collect();
+
+            let results = ledger::submit_requests_batch(setup.pool_handle, &json!(requests).to_string(), Some(r#"{"max_in_flight": 2}"#)).unwrap();
+            let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+
+            assert_eq!(requests.len(), results.len());
+
+            for (request, result) in requests.iter().zip(results.iter()) {
+                assert_eq!("REPLY", result["reply"]["op"].as_str().unwrap());
+                assert_eq!(request["reqId"], result["reply"]["result"]["txn"]["metadata"]["reqId"]);
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works_for_max_in_flight_above_conn_limit() {
+            let setup = Setup::trustee();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+            let get_nym_request: serde_json::Value = serde_json::from_str(&get_nym_request).unwrap();
+
+            let requests: Vec<serde_json::Value> = (0..12)
+                .map(|i| {
+                    let mut request = get_nym_request.clone();
+                    request["reqId"] = json!(request["reqId"].as_u64().unwrap() + i);
+                    request
+                })
+                .collect();
+
+            let results = ledger::submit_requests_batch(setup.pool_handle, &json!(requests).to_string(), Some(r#"{"max_in_flight": 12}"#)).unwrap();
+            let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+
+            assert_eq!(requests.len(), results.len());
+
+            for result in results.iter() {
+                assert_eq!("REPLY", result["reply"]["op"].as_str().unwrap());
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works_for_invalid_request() {
+            let setup = Setup::trustee();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+            let get_nym_request: serde_json::Value = serde_json::from_str(&get_nym_request).unwrap();
+
+            let requests = json!([get_nym_request, "request"]).to_string();
+
+            let results = ledger::submit_requests_batch(setup.pool_handle, &requests, None).unwrap();
+            let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+
+            assert_eq!("REPLY", results[0]["reply"]["op"].as_str().unwrap());
+            assert_eq!(ErrorCode::CommonInvalidStructure as i64, results[1]["error"]["code"].as_i64().unwrap());
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let requests = format!("[{}, {}]", REQUEST, REQUEST);
+
+            let res = ledger::submit_requests_batch(INVALID_POOL_HANDLE, &requests, None);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
     mod submit_action {
         use super::*;
 
@@ -652,6 +799,86 @@ mod high_cases {
         }
     }
 
+    mod rich_schema_requests {
+        use super::*;
+
+        const RS_ID: &str = "did:sov:2sEEi28LsekEUg6Pbpx3c1";
+        const RS_NAME: &str = "rich_schema_name";
+        const RS_VERSION: &str = "1.0";
+
+        fn rs_content() -> String {
+            json!({"@id": RS_ID, "@type": "rdfs:Class", "name": "rdfs:Literal"}).to_string()
+        }
+
+        #[test]
+        fn indy_build_rich_schema_request_works() {
+            let expected_result = json!({
+                "type": constants::RICH_SCHEMA,
+                "id": RS_ID,
+                "content": rs_content(),
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch",
+                "ver": "1"
+            });
+
+            let request = ledger::build_rich_schema_request(IDENTIFIER, RS_ID, &rs_content(), RS_NAME, RS_VERSION, "sch", "1").unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_id_request_works() {
+            let expected_result = json!({
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+                "id": RS_ID
+            });
+
+            let request = ledger::build_get_rich_schema_object_by_id_request(Some(IDENTIFIER), RS_ID).unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_metadata_request_works() {
+            let expected_result = json!({
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "sch",
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION
+            });
+
+            let request = ledger::build_get_rich_schema_object_by_metadata_request(None, "sch", RS_NAME, RS_VERSION).unwrap();
+            check_request_operation(&request, expected_result);
+            check_default_identifier(&request);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_rich_schema_requests_works() {
+            let setup = Setup::trustee();
+
+            let rs_id = format!("did:sov:{}", setup.did);
+            let rs_content = json!({"@id": rs_id, "@type": "rdfs:Class"}).to_string();
+
+            let rich_schema_request = ledger::build_rich_schema_request(&setup.did, &rs_id, &rs_content, RS_NAME, RS_VERSION, "sch", "1").unwrap();
+            let rich_schema_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &rich_schema_request).unwrap();
+            pool::check_response_type(&rich_schema_response, ResponseType::REPLY);
+
+            let get_by_id_request = ledger::build_get_rich_schema_object_by_id_request(Some(&setup.did), &rs_id).unwrap();
+            let get_by_id_response = ledger::submit_request_with_retries(setup.pool_handle, &get_by_id_request, &rich_schema_response).unwrap();
+            let rich_schema_by_id = ledger::parse_get_rich_schema_object_response(&get_by_id_response).unwrap();
+
+            let get_by_metadata_request = ledger::build_get_rich_schema_object_by_metadata_request(Some(&setup.did), "sch", RS_NAME, RS_VERSION).unwrap();
+            let get_by_metadata_response = ledger::submit_request(setup.pool_handle, &get_by_metadata_request).unwrap();
+            let rich_schema_by_metadata = ledger::parse_get_rich_schema_object_response(&get_by_metadata_response).unwrap();
+
+            let rich_schema_by_id: serde_json::Value = serde_json::from_str(&rich_schema_by_id).unwrap();
+            let rich_schema_by_metadata: serde_json::Value = serde_json::from_str(&rich_schema_by_metadata).unwrap();
+            assert_eq!(rs_id, rich_schema_by_id["id"]);
+            assert_eq!(rs_content, rich_schema_by_id["content"]);
+            assert_eq!(rich_schema_by_id, rich_schema_by_metadata);
+        }
+    }
+
     mod node_request {
         use super::*;
 
@@ -1504,6 +1731,25 @@ mod high_cases {
         }
     }
 
+    mod verify_txn_inclusion {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn verify_txn_inclusion_works_for_get_txn_response() {
+            let setup = Setup::pool();
+
+            let get_txn_request = ledger::build_get_txn_request(None, 1, None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let inclusion = ledger::verify_txn_inclusion(setup.pool_handle, &get_txn_response).unwrap();
+            let inclusion: serde_json::Value = serde_json::from_str(&inclusion).unwrap();
+            assert_eq!(1, inclusion["seqNo"].as_u64().unwrap());
+            assert!(inclusion["ledgerSize"].as_u64().unwrap() >= 1);
+            assert!(inclusion["rootHash"].as_str().is_some());
+        }
+    }
+
     mod auth_rule {
         use super::*;
 
@@ -1961,6 +2207,76 @@ mod high_cases {
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
+        fn _auth_rules_json() -> String {
+            json!([
+                {
+                    "auth_type": constants::NYM,
+                    "auth_action": ADD_AUTH_ACTION,
+                    "field": FIELD,
+                    "old_value": null,
+                    "new_value": VALUE,
+                    "constraint": serde_json::from_str::<serde_json::Value>(ROLE_CONSTRAINT).unwrap()
+                }
+            ]).to_string()
+        }
+
+        #[test]
+        fn indy_check_auth_rule_works() {
+            Setup::empty();
+
+            let result = ledger::check_auth_rule(&_auth_rules_json(), constants::NYM, ADD_AUTH_ACTION, FIELD,
+                                                 None, Some(VALUE), r#"[{"role": "TRUSTEE"}]"#).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(true, result["allowed"]);
+
+            let result = ledger::check_auth_rule(&_auth_rules_json(), constants::NYM, ADD_AUTH_ACTION, FIELD,
+                                                 None, Some(VALUE), r#"[{"role": "101"}]"#).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(false, result["allowed"]);
+            assert_eq!("1 signature(s) of TRUSTEE required, 0 provided", result["explanation"]);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_check_auth_rule_works_for_ledger_auth_rules() {
+            let setup = Setup::pool();
+
+            let get_auth_rule_request = ledger::build_get_auth_rule_request(None, None, None, None, None, None).unwrap();
+            let response = ledger::submit_request(setup.pool_handle, &get_auth_rule_request).unwrap();
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            let auth_rules_json = response["result"]["data"].to_string();
+
+            let result = ledger::check_auth_rule(&auth_rules_json, constants::NYM, ADD_AUTH_ACTION, FIELD,
+                                                 None, Some(VALUE), r#"[{"role": "TRUSTEE"}]"#).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(true, result["allowed"]);
+
+            let result = ledger::check_auth_rule(&auth_rules_json, constants::NYM, ADD_AUTH_ACTION, FIELD,
+                                                 None, Some(VALUE), r#"[{"role": null}]"#).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(false, result["allowed"]);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_check_auth_rule_works_for_not_found_rule() {
+            Setup::empty();
+
+            let res = ledger::check_auth_rule(&_auth_rules_json(), constants::SCHEMA, ADD_AUTH_ACTION, "*",
+                                              None, Some("*"), r#"[{"role": "TRUSTEE"}]"#);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_check_auth_rule_works_for_invalid_signers() {
+            Setup::empty();
+
+            let res = ledger::check_auth_rule(&_auth_rules_json(), constants::NYM, ADD_AUTH_ACTION, FIELD,
+                                              None, Some(VALUE), r#"{"role": "TRUSTEE"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_auth_rule_requests_works_for_adding_new_trustee() {
@@ -2702,6 +3018,80 @@ mod high_cases {
             _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
         }
 
+        #[test]
+        fn indy_set_txn_author_agreement_acceptance_for_pool_works() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+            let (_, _, taa_digest, _) = _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            ledger::set_txn_author_agreement_acceptance_for_pool(setup.pool_handle, None, None, Some(&taa_digest),
+                                                                 &aml_label, time::get_time().sec as u64).unwrap();
+
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req = ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+            let nym_resp = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_req).unwrap();
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            let response: serde_json::Value = serde_json::from_str(&nym_resp).unwrap();
+            assert_eq!(taa_digest, response["result"]["txn"]["metadata"]["taaAcceptance"]["taaDigest"].as_str().unwrap());
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_set_txn_author_agreement_acceptance_for_pool_works_for_retired_taa() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+            let (taa_text, taa_version, _, _) = _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            ledger::set_txn_author_agreement_acceptance_for_pool(setup.pool_handle, Some(&taa_text), Some(&taa_version), None,
+                                                                 &aml_label, time::get_time().sec as u64).unwrap();
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req = ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+            let res = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_req);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_set_txn_author_agreement_acceptance_for_pool_works_for_not_latest_taa() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+            let (_, _, taa_digest, _) = _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            ledger::set_txn_author_agreement_acceptance_for_pool(setup.pool_handle, None, None, Some(&taa_digest),
+                                                                 &aml_label, time::get_time().sec as u64).unwrap();
+
+            _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req = ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+            let res = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_req);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_set_txn_author_agreement_acceptance_for_pool_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::set_txn_author_agreement_acceptance_for_pool(INVALID_POOL_HANDLE, None, None, Some(HASH),
+                                                                           ACCEPTANCE_MECH_TYPE, TIME_OF_ACCEPTANCE);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
         #[test]
         #[cfg(not(feature = "only_high_cases"))]
         fn indy_reset_author_agreement_works() {
@@ -3015,6 +3405,60 @@ mod high_cases {
             pool::check_response_type(&response, ResponseType::REQNACK);
         }
     }
+
+    mod endorse_pending_transaction {
+        use super::*;
+
+        fn _setup_new_identity(wallet_handle: WalletHandle, pool_handle: PoolHandle) -> String {
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let nym = ledger::build_nym_request(DID_TRUSTEE, &my_did, Some(&my_vk), None, None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, DID_TRUSTEE, &nym).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+            my_did
+        }
+
+        #[test]
+        fn indy_prepare_request_for_endorser_works() {
+            let setup = Setup::did();
+
+            let request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+            let pending_txn = ledger::prepare_request_for_endorser(setup.wallet_handle, &setup.did, &request, DID_TRUSTEE).unwrap();
+            let pending_txn: serde_json::Value = serde_json::from_str(&pending_txn).unwrap();
+
+            assert_eq!("1.0", pending_txn["ver"].as_str().unwrap());
+            assert_eq!(constants::SCHEMA, pending_txn["txnType"].as_str().unwrap());
+            assert_eq!(setup.did, pending_txn["author"].as_str().unwrap());
+            assert_eq!(DID_TRUSTEE, pending_txn["endorser"].as_str().unwrap());
+            assert_eq!(DID_TRUSTEE, pending_txn["request"]["endorser"].as_str().unwrap());
+            assert!(pending_txn["request"]["signatures"][&setup.did].is_string());
+        }
+
+        #[test]
+        fn indy_endorse_pending_transaction_works() {
+            let setup = Setup::endorser();
+            let my_did = _setup_new_identity(setup.wallet_handle, setup.pool_handle);
+
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let pending_txn = ledger::prepare_request_for_endorser(setup.wallet_handle, &my_did, &request, &setup.did).unwrap();
+
+            let response = ledger::endorse_pending_transaction(setup.pool_handle, setup.wallet_handle, &setup.did, &pending_txn).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_endorse_pending_transaction_works_for_endorser_without_role() {
+            let setup = Setup::endorser();
+            let my_did = _setup_new_identity(setup.wallet_handle, setup.pool_handle);
+            let other_did = _setup_new_identity(setup.wallet_handle, setup.pool_handle);
+
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let pending_txn = ledger::prepare_request_for_endorser(setup.wallet_handle, &my_did, &request, &other_did).unwrap();
+
+            let res = ledger::endorse_pending_transaction(setup.pool_handle, setup.wallet_handle, &other_did, &pending_txn);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -3057,6 +3501,56 @@ mod medium_cases {
         }
     }
 
+    mod submit_request_with_details {
+        use super::*;
+
+        #[test]
+        fn indy_submit_request_with_details_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::submit_request_with_details(INVALID_POOL_HANDLE, REQUEST);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_submit_request_with_details_works_for_invalid_message() {
+            Setup::empty();
+
+            let res = ledger::submit_request_with_details(INVALID_POOL_HANDLE, "request");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod submit_requests_batch {
+        use super::*;
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_empty_batch() {
+            Setup::empty();
+
+            let res = ledger::submit_requests_batch(INVALID_POOL_HANDLE, "[]", None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_invalid_requests_json() {
+            Setup::empty();
+
+            let res = ledger::submit_requests_batch(INVALID_POOL_HANDLE, REQUEST, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_invalid_options() {
+            Setup::empty();
+
+            let requests = format!("[{}]", REQUEST);
+
+            let res = ledger::submit_requests_batch(INVALID_POOL_HANDLE, &requests, Some(r#"{"max_in_flight": 0}"#));
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+        }
+    }
+
     mod submit_action {
         use super::*;
 
@@ -3609,6 +4103,51 @@ mod medium_cases {
         }
     }
 
+    mod rich_schema_requests {
+        use super::*;
+
+        const RS_ID: &str = "did:sov:2sEEi28LsekEUg6Pbpx3c1";
+
+        #[test]
+        fn indy_build_rich_schema_request_works_for_unknown_type() {
+            let rs_content = json!({"@id": RS_ID}).to_string();
+            let res = ledger::build_rich_schema_request(IDENTIFIER, RS_ID, &rs_content, "name", "1.0", "unknown", "1");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_rich_schema_request_works_for_id_mismatch() {
+            let rs_content = json!({"@id": "did:sov:other"}).to_string();
+            let res = ledger::build_rich_schema_request(IDENTIFIER, RS_ID, &rs_content, "name", "1.0", "sch", "1");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_rich_schema_request_works_for_context_without_context_field() {
+            let rs_content = json!({"@id": RS_ID}).to_string();
+            let res = ledger::build_rich_schema_request(IDENTIFIER, RS_ID, &rs_content, "name", "1.0", "ctx", "1");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_parse_get_rich_schema_object_response_works_for_not_found() {
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+                    "reqId": 1,
+                    "identifier": IDENTIFIER,
+                    "seqNo": null,
+                    "txnTime": null,
+                    "data": null
+                }
+            }).to_string();
+
+            let res = ledger::parse_get_rich_schema_object_response(&response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
     mod node_requests {
         use super::*;
 
@@ -3940,6 +4479,49 @@ mod medium_cases {
             assert!(response_metadata["lastSeqNo"].as_u64().is_none());
         }
     }
+
+    mod verify_txn_inclusion {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn verify_txn_inclusion_works_for_invalid_pool_handle() {
+            let setup = Setup::pool();
+
+            let get_txn_request = ledger::build_get_txn_request(None, 1, None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let res = ledger::verify_txn_inclusion(setup.pool_handle + 1, &get_txn_response);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn verify_txn_inclusion_works_for_not_found_txn() {
+            let setup = Setup::pool();
+
+            let get_txn_request = ledger::build_get_txn_request(None, i32::max_value(), None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let res = ledger::verify_txn_inclusion(setup.pool_handle, &get_txn_response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn verify_txn_inclusion_works_for_tampered_txn() {
+            let setup = Setup::pool();
+
+            let get_txn_request = ledger::build_get_txn_request(None, 1, None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let mut get_txn_response: serde_json::Value = serde_json::from_str(&get_txn_response).unwrap();
+            get_txn_response["result"]["data"]["txn"]["data"]["verkey"] = json!("~NcYxiDXkpYi6ov5FcYDi1e");
+
+            let res = ledger::verify_txn_inclusion(setup.pool_handle, &get_txn_response.to_string());
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
+    }
 }
 
 fn check_request(request: &str, expected_operation: serde_json::Value, expected_identifier: &str) {
@@ -73,6 +73,25 @@ mod collect {
         assert!(threadpool_threads_count.contains(&json!({"tags":{"label":"panic"},"value":0})));
     }
 
+    #[test]
+    fn collect_metrics_contains_pool_read_cache_statistics() {
+        let result_metrics = metrics::collect_metrics().unwrap();
+        let metrics_map = serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+
+        assert!(metrics_map.contains_key("pool_read_cache_count"));
+
+        let pool_read_cache_count = metrics_map
+            .get("pool_read_cache_count")
+            .unwrap()
+            .as_array()
+            .unwrap();
+
+        let labels: Vec<&str> = pool_read_cache_count.iter()
+            .map(|metric| metric["tags"]["label"].as_str().unwrap())
+            .collect();
+        assert_eq!(vec!["hit", "miss"], labels);
+    }
+
     #[test]
     fn collect_metrics_includes_commands_count() {
         let setup = Setup::empty();
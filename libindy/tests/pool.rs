@@ -110,6 +110,62 @@ mod high_cases {
         }
     }
 
+    mod open_simulated {
+        use super::*;
+
+        #[test]
+        fn open_pool_ledger_works_for_simulated_transport() {
+            let setup = Setup::empty();
+
+            let pool_handle = pool::create_and_open_simulated_pool_ledger(&setup.name).unwrap();
+
+            pool::refresh(pool_handle).unwrap();
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn open_pool_ledger_works_for_simulated_transport_reopen() {
+            let setup = Setup::empty();
+
+            let pool_handle = pool::create_and_open_simulated_pool_ledger(&setup.name).unwrap();
+            pool::close(pool_handle).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(pool::SIMULATED_POOL_CONFIG)).unwrap();
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
+    mod register_transport {
+        use super::*;
+
+        #[test]
+        fn register_pool_transport_works() {
+            Setup::empty();
+
+            pool::register_mock_pool_transport("register_pool_transport_works").unwrap();
+        }
+
+        #[test]
+        fn register_pool_transport_works_for_built_in_transport() {
+            Setup::empty();
+
+            let res = pool::register_mock_pool_transport("simulated");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn open_pool_ledger_works_for_unknown_transport() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_simulated_pool(&setup.name, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(&setup.name, Some(r#"{"transport":"unknown"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod refresh {
         use super::*;
 
@@ -173,6 +229,66 @@ mod high_cases {
             pool::set_protocol_version(2).unwrap();
         }
     }
+
+    mod get_local_txn {
+        use super::*;
+
+        #[test]
+        fn indy_get_local_txn_works_for_pool_ledger() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let txn = pool::get_local_txn(&setup.name, 0, 1).unwrap();
+            let txn: serde_json::Value = serde_json::from_str(&txn).unwrap();
+            assert_eq!("Node1", txn["txn"]["data"]["data"]["alias"]);
+            assert_eq!(1, txn["txnMetadata"]["seqNo"]);
+        }
+
+        #[test]
+        fn indy_get_local_txns_by_type_works_for_pool_ledger() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let txns = pool::get_local_txns_by_type(&setup.name, 0, "0", 3, 10).unwrap();
+            let txns: Vec<serde_json::Value> = serde_json::from_str(&txns).unwrap();
+            assert_eq!(2, txns.len());
+            assert_eq!("Node3", txns[0]["txn"]["data"]["data"]["alias"]);
+            assert_eq!("Node4", txns[1]["txn"]["data"]["data"]["alias"]);
+        }
+    }
+
+    mod diagnose {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_diagnose_pool_works() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let diagnostics = pool::diagnose_pool(&setup.name, None).unwrap();
+            let diagnostics: serde_json::Value = serde_json::from_str(&diagnostics).unwrap();
+            assert_eq!(1, diagnostics["f"]);
+            assert_eq!("synced", diagnostics["consensus"]);
+
+            let nodes = diagnostics["nodes"].as_array().unwrap();
+            assert_eq!(4, nodes.len());
+            for node in nodes {
+                assert_eq!("reachable", node["status"]);
+                assert_eq!("valid", node["blsKey"]);
+                assert_eq!(4, node["ledgerSize"]);
+            }
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -486,4 +602,64 @@ mod medium_cases {
             assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
         }
     }
+
+    mod get_local_txn {
+        use super::*;
+
+        #[test]
+        fn indy_get_local_txn_works_for_not_created() {
+            let setup = Setup::empty();
+
+            let res = pool::get_local_txn(&setup.name, 0, 1);
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+
+        #[test]
+        fn indy_get_local_txn_works_for_unknown_seq_no() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::get_local_txn(&setup.name, 0, 100);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_local_txn_works_for_not_mirrored_ledger() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::get_local_txn(&setup.name, 1, 1);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
+    mod diagnose {
+        use super::*;
+
+        #[test]
+        fn indy_diagnose_pool_works_for_not_created() {
+            let setup = Setup::empty();
+
+            let res = pool::diagnose_pool(&setup.name, None);
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+
+        #[test]
+        fn indy_diagnose_pool_works_for_simulated_transport() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::diagnose_pool(&setup.name, Some(r#"{"transport": "simulated"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 }
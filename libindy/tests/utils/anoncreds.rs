@@ -21,7 +21,7 @@ use crate::utils::domain::anoncreds::credential::{AttributeValues, CredentialInf
 use crate::utils::domain::anoncreds::credential_for_proof_request::CredentialsForProofRequest;
 use crate::utils::domain::crypto::did::DidValue;
 
-use indy::WalletHandle;
+use indy::{WalletHandle, PoolHandle};
 
 pub static mut CREDENTIAL_DEF_JSON: &'static str = "";
 pub static mut CREDENTIAL_OFFER_JSON: &'static str = "";
@@ -80,10 +80,52 @@ pub fn issuer_create_credential(wallet_handle: WalletHandle, cred_offer_json: &s
     anoncreds::issuer_create_credential(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle.unwrap_or(-1)).wait() // TODO OPTIONAL blob_storage_reader_handle
 }
 
+pub fn issuer_create_credentials_batch(wallet_handle: WalletHandle, cred_requests_json: &str, rev_reg_id: Option<&str>,
+                                       blob_storage_reader_handle: Option<i32>) -> Result<(String, Option<String>), IndyError> {
+    anoncreds::issuer_create_credentials_batch(wallet_handle, cred_requests_json, rev_reg_id, blob_storage_reader_handle.unwrap_or(-1)).wait()
+}
+
+pub fn issuer_create_revocation_registry_pool(wallet_handle: WalletHandle, issuer_did: &str, cred_def_id: &str, config_json: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_create_revocation_registry_pool(wallet_handle, issuer_did, cred_def_id, config_json).wait()
+}
+
+pub fn issuer_create_credential_from_revocation_registry_pool(wallet_handle: WalletHandle, cred_offer_json: &str, cred_req_json: &str,
+                                                              cred_values_json: &str) -> Result<(String, String, String, Option<String>), IndyError> {
+    anoncreds::issuer_create_credential_from_revocation_registry_pool(wallet_handle, cred_offer_json, cred_req_json, cred_values_json).wait()
+}
+
+pub fn issuer_get_revocation_registry_pool_pending_requests(wallet_handle: WalletHandle, cred_def_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_get_revocation_registry_pool_pending_requests(wallet_handle, cred_def_id).wait()
+}
+
+pub fn issuer_add_revocation_registry_to_pool(wallet_handle: WalletHandle, cred_def_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_add_revocation_registry_to_pool(wallet_handle, cred_def_id).wait()
+}
+
+pub fn issuer_confirm_revocation_registry_published(wallet_handle: WalletHandle, rev_reg_id: &str) -> Result<(), IndyError> {
+    anoncreds::issuer_confirm_revocation_registry_published(wallet_handle, rev_reg_id).wait()
+}
+
+pub fn revocation_registry_pool_config(max_cred_num: u32) -> String {
+    json!({
+        "max_cred_num": max_cred_num,
+        "issuance_type": "ISSUANCE_ON_DEMAND",
+        "tails_writer_config": serde_json::from_str::<serde_json::Value>(&tails_writer_config()).unwrap()
+    }).to_string()
+}
+
 pub fn issuer_revoke_credential(wallet_handle: WalletHandle, blob_storage_reader_handle: i32, rev_reg_id: &str, cred_revoc_id: &str) -> Result<String, IndyError> {
     anoncreds::issuer_revoke_credential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id).wait()
 }
 
+pub fn issuer_revoke_credentials(wallet_handle: WalletHandle, blob_storage_reader_handle: i32, rev_reg_id: &str, cred_revoc_ids_json: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_revoke_credentials(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_ids_json).wait()
+}
+
+pub fn issuer_get_revocation_status_list(wallet_handle: WalletHandle, rev_reg_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_get_revocation_status_list(wallet_handle, rev_reg_id).wait()
+}
+
 pub fn issuer_merge_revocation_registry_deltas(rev_reg_delta: &str, other_rev_reg_delta: &str) -> Result<String, IndyError> {
     anoncreds::issuer_merge_revocation_registry_deltas(rev_reg_delta, other_rev_reg_delta).wait()
 }
@@ -160,11 +202,22 @@ pub fn prover_create_proof(wallet_handle: WalletHandle, proof_req_json: &str, re
                                    master_secret_name, schemas_json, cred_defs_json, rev_states_json).wait()
 }
 
+pub fn prover_create_proof_with_cached_revocation_states(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str,
+                                                         master_secret_name: &str, schemas_json: &str, cred_defs_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_create_proof_with_cached_revocation_states(wallet_handle, proof_req_json, requested_credentials_json,
+                                                                 master_secret_name, schemas_json, cred_defs_json).wait()
+}
+
 pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas_json: &str,
                              cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<bool, IndyError> {
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_with_details(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                          cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_verify_proof_with_details(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -175,6 +228,10 @@ pub fn update_revocation_state(tails_reader_handle: i32, rev_state_json: &str, r
     anoncreds::update_revocation_state(tails_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
 }
 
+pub fn prover_refresh_revocation_states(pool_handle: PoolHandle, wallet_handle: WalletHandle, tails_reader_handle: i32, cred_ids_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_refresh_revocation_states(pool_handle, wallet_handle, tails_reader_handle, cred_ids_json).wait()
+}
+
 pub fn generate_nonce() -> Result<String, IndyError> {
     anoncreds::generate_nonce().wait()
 }
@@ -183,6 +240,10 @@ pub fn to_unqualified(entity: &str) -> Result<String, IndyError> {
     anoncreds::to_unqualified(entity).wait()
 }
 
+pub fn encode_credential_attributes(attributes_json: &str) -> Result<String, IndyError> {
+    anoncreds::encode_credential_attributes(attributes_json).wait()
+}
+
 pub fn default_cred_def_config() -> String {
     serde_json::to_string(&CredentialDefinitionConfig { support_revocation: false }).unwrap()
 }
@@ -1,16 +1,49 @@
 extern crate futures;
+extern crate indy_sys;
 
-use indy::IndyError;
+use indy::{IndyError, ErrorCode};
 use indy::crypto;
 use self::futures::Future;
 
+use std::sync::Once;
+
 use indy::WalletHandle;
 
+pub const MOCK_KEY_BACKEND_CRYPTO_TYPE: &str = "mock_backend";
+
+pub fn register_key_backend(crypto_type: &str) -> Result<(), IndyError> {
+    crypto::register_key_backend(crypto_type,
+                                 Some(mock_key_backend::create_key),
+                                 Some(mock_key_backend::sign),
+                                 Some(mock_key_backend::verify),
+                                 None,
+                                 Some(mock_key_backend::free_buffer)).wait()
+}
+
+pub fn register_mock_key_backend() {
+    static REGISTER_MOCK_KEY_BACKEND: Once = Once::new();
+
+    REGISTER_MOCK_KEY_BACKEND.call_once(|| {
+        register_key_backend(MOCK_KEY_BACKEND_CRYPTO_TYPE).unwrap();
+    })
+}
+
+pub fn create_key_in_mock_backend(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    register_mock_key_backend();
+    let key_json = json!({"crypto_type": MOCK_KEY_BACKEND_CRYPTO_TYPE}).to_string();
+    crypto::create_key(wallet_handle, Some(&key_json)).wait()
+}
+
 pub fn create_key(wallet_handle: WalletHandle, seed: Option<&str>) -> Result<String, IndyError> {
     let key_json = json!({"seed": seed}).to_string();
     crypto::create_key(wallet_handle, Some(&key_json)).wait()
 }
 
+pub fn create_key_with_crypto_type(wallet_handle: WalletHandle, seed: Option<&str>, crypto_type: &str) -> Result<String, IndyError> {
+    let key_json = json!({"seed": seed, "crypto_type": crypto_type}).to_string();
+    crypto::create_key(wallet_handle, Some(&key_json)).wait()
+}
+
 pub fn set_key_metadata(wallet_handle: WalletHandle, verkey: &str, metadata: &str) -> Result<(), IndyError> {
     crypto::set_key_metadata(wallet_handle, verkey, metadata).wait()
 }
@@ -27,6 +60,14 @@ pub fn verify(their_vk: &str, msg: &[u8], signature: &[u8]) -> Result<bool, Indy
     crypto::verify(their_vk, msg, signature).wait()
 }
 
+pub fn sign_jws(wallet_handle: WalletHandle, my_vk: &str, payload: &[u8], options_json: Option<&str>) -> Result<String, IndyError> {
+    crypto::sign_jws(wallet_handle, my_vk, payload, options_json).wait()
+}
+
+pub fn verify_jws(their_vk: &str, jws: &str, detached_payload: Option<&[u8]>) -> Result<bool, IndyError> {
+    crypto::verify_jws(their_vk, jws, detached_payload).wait()
+}
+
 pub fn auth_crypt(wallet_handle: WalletHandle, my_vk: &str, their_vk: &str, msg: &[u8]) -> Result<Vec<u8>, IndyError> {
     crypto::auth_crypt(wallet_handle, my_vk, their_vk, msg).wait()
 }
@@ -49,4 +90,106 @@ pub fn pack_message(wallet_handle: WalletHandle, message: &[u8], receiver_keys:
 
 pub fn unpack_message(wallet_handle: WalletHandle, jwe: &[u8]) -> Result<Vec<u8>, IndyError> {
     crypto::unpack_message(wallet_handle, jwe).wait()
-}
\ No newline at end of file
+}
+
+pub fn pack_message_v2(wallet_handle: WalletHandle, message: &[u8], receiver_kids: &str, sender_kid: Option<&str>, signer_kid: Option<&str>) -> Result<Vec<u8>, IndyError> {
+    crypto::pack_message_v2(wallet_handle, message, receiver_kids, sender_kid, signer_kid).wait()
+}
+
+pub fn unpack_message_v2(wallet_handle: WalletHandle, packed_message: &[u8]) -> Result<Vec<u8>, IndyError> {
+    crypto::unpack_message_v2(wallet_handle, packed_message).wait()
+}
+
+pub mod mock_key_backend {
+    use super::*;
+
+    use std::collections::HashMap;
+    use std::ffi::{CStr, CString};
+    use std::slice;
+    use std::sync::Mutex;
+
+    use super::indy_sys;
+    use self::indy_sys::{BString, Error, IndyHandle};
+    use crate::utils::sequence;
+    use rust_base58::{FromBase58, ToBase58};
+    use sodiumoxide::crypto::sign;
+
+    lazy_static! {
+        static ref KEYS: Mutex<HashMap<String, sign::SecretKey>> = Default::default();
+        static ref BUFFERS: Mutex<HashMap<IndyHandle, Vec<u8>>> = Default::default();
+    }
+
+    fn _store_buffer(buffer: Vec<u8>, buffer_handle_p: *mut IndyHandle) -> (BString, u32) {
+        let handle = sequence::get_next_id();
+        let (ptr, len) = (buffer.as_ptr(), buffer.len() as u32);
+        BUFFERS.lock().unwrap().insert(handle, buffer);
+        unsafe { *buffer_handle_p = handle; }
+        (ptr, len)
+    }
+
+    fn _unqualified_verkey(verkey: indy_sys::CString) -> String {
+        let verkey = unsafe { CStr::from_ptr(verkey) }.to_str().unwrap();
+        verkey.split(':').next().unwrap().to_string()
+    }
+
+    pub extern fn create_key(_key_info_json: indy_sys::CString,
+                             verkey_p: *mut indy_sys::CString,
+                             verkey_handle_p: *mut IndyHandle) -> Error {
+        let (vk, sk) = sign::gen_keypair();
+        let vk = vk[..].to_base58();
+        KEYS.lock().unwrap().insert(vk.clone(), sk);
+
+        let (ptr, _) = _store_buffer(CString::new(vk).unwrap().into_bytes_with_nul(), verkey_handle_p);
+        unsafe { *verkey_p = ptr as indy_sys::CString; }
+        ErrorCode::Success as Error
+    }
+
+    pub extern fn sign(verkey: indy_sys::CString,
+                       message_raw: BString,
+                       message_len: u32,
+                       signature_raw_p: *mut BString,
+                       signature_len_p: *mut u32,
+                       signature_handle_p: *mut IndyHandle) -> Error {
+        let message = unsafe { slice::from_raw_parts(message_raw, message_len as usize) };
+
+        let keys = KEYS.lock().unwrap();
+        let sk = match keys.get(&_unqualified_verkey(verkey)) {
+            Some(sk) => sk,
+            None => return ErrorCode::WalletItemNotFound as Error
+        };
+
+        let signature = sign::sign_detached(message, sk);
+        let (ptr, len) = _store_buffer(signature[..].to_vec(), signature_handle_p);
+        unsafe {
+            *signature_raw_p = ptr;
+            *signature_len_p = len;
+        }
+        ErrorCode::Success as Error
+    }
+
+    pub extern fn verify(verkey: indy_sys::CString,
+                         message_raw: BString,
+                         message_len: u32,
+                         signature_raw: BString,
+                         signature_len: u32,
+                         valid_p: *mut bool) -> Error {
+        let message = unsafe { slice::from_raw_parts(message_raw, message_len as usize) };
+        let signature = unsafe { slice::from_raw_parts(signature_raw, signature_len as usize) };
+
+        let vk = sign::PublicKey::from_slice(&_unqualified_verkey(verkey).from_base58().unwrap()).unwrap();
+        let valid = match sign::Signature::from_slice(signature) {
+            Some(signature) => sign::verify_detached(&signature, message, &vk),
+            None => false
+        };
+
+        unsafe { *valid_p = valid; }
+        ErrorCode::Success as Error
+    }
+
+    pub extern fn free_buffer(buffer_handle: IndyHandle) -> Error {
+        match BUFFERS.lock().unwrap().remove(&buffer_handle) {
+            Some(_) => ErrorCode::Success as Error,
+            None => ErrorCode::CommonInvalidState as Error
+        }
+    }
+}
@@ -82,6 +82,10 @@ pub fn key_for_local_did(wallet_handle: WalletHandle, did: &str) -> Result<Strin
     did::key_for_local_did(wallet_handle, did).wait()
 }
 
+pub fn key_for_did_at(pool_handle: PoolHandle, wallet_handle: WalletHandle, did: &str, timestamp: u64) -> Result<String, IndyError> {
+    did::key_for_did_at(pool_handle, wallet_handle, did, timestamp).wait()
+}
+
 pub fn set_endpoint_for_did(wallet_handle: WalletHandle, did: &str, address: &str, transport_key: &str) -> Result<(), IndyError> {
     did::set_endpoint_for_did(wallet_handle, did, address, transport_key).wait()
 }
@@ -90,6 +94,10 @@ pub fn get_endpoint_for_did(wallet_handle: WalletHandle, pool_handle: PoolHandle
     did::get_endpoint_for_did(wallet_handle, pool_handle, did).wait()
 }
 
+pub fn resolve_did(pool_handle: PoolHandle, did: &str) -> Result<String, IndyError> {
+    did::resolve_did(pool_handle, did).wait()
+}
+
 pub fn set_did_metadata(wallet_handle: WalletHandle, did: &str, metadata: &str) -> Result<(), IndyError> {
     did::set_did_metadata(wallet_handle, did, metadata).wait()
 }
@@ -39,6 +39,14 @@ pub fn submit_request(pool_handle: PoolHandle, request_json: &str) -> Result<Str
     ledger::submit_request(pool_handle, request_json).wait()
 }
 
+pub fn submit_request_with_details(pool_handle: PoolHandle, request_json: &str) -> Result<String, IndyError> {
+    ledger::submit_request_with_details(pool_handle, request_json).wait()
+}
+
+pub fn submit_requests_batch(pool_handle: PoolHandle, requests_json: &str, options_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::submit_requests_batch(pool_handle, requests_json, options_json).wait()
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<String, IndyError> {
     ledger::submit_action(pool_handle, request_json, nodes, timeout).wait()
 }
@@ -215,6 +223,10 @@ pub fn get_response_metadata(response: &str) -> Result<String, IndyError> {
     ledger::get_response_metadata(response).wait()
 }
 
+pub fn verify_txn_inclusion(pool_handle: PoolHandle, get_txn_response: &str) -> Result<String, IndyError> {
+    ledger::verify_txn_inclusion(pool_handle, get_txn_response).wait()
+}
+
 pub fn build_auth_rule_request(submitter_did: &str,
                                txn_type: &str,
                                action: &str,
@@ -239,6 +251,33 @@ pub fn build_get_auth_rule_request(submitter_did: Option<&str>,
     ledger::build_get_auth_rule_request(submitter_did, auth_type, auth_action, field, old_value, new_value).wait()
 }
 
+pub fn check_auth_rule(auth_rules_json: &str,
+                       auth_type: &str,
+                       auth_action: &str,
+                       field: &str,
+                       old_value: Option<&str>,
+                       new_value: Option<&str>,
+                       signers_json: &str) -> Result<String, IndyError> {
+    ledger::check_auth_rule(auth_rules_json, auth_type, auth_action, field, old_value, new_value, signers_json).wait()
+}
+
+pub fn build_rich_schema_request(submitter_did: &str, rs_id: &str, rs_content: &str, rs_name: &str,
+                                 rs_version: &str, rs_type: &str, ver: &str) -> Result<String, IndyError> {
+    ledger::build_rich_schema_request(submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver).wait()
+}
+
+pub fn build_get_rich_schema_object_by_id_request(submitter_did: Option<&str>, rs_id: &str) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_id_request(submitter_did, rs_id).wait()
+}
+
+pub fn build_get_rich_schema_object_by_metadata_request(submitter_did: Option<&str>, rs_type: &str, rs_name: &str, rs_version: &str) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_metadata_request(submitter_did, rs_type, rs_name, rs_version).wait()
+}
+
+pub fn parse_get_rich_schema_object_response(get_rich_schema_object_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_rich_schema_object_response(get_rich_schema_object_response).wait()
+}
+
 pub fn build_txn_author_agreement_request(submitter_did: &str,
                                           text: Option<&str>,
                                           version: &str,
@@ -278,11 +317,28 @@ pub fn append_txn_author_agreement_acceptance_to_request(request_json: &str,
     ledger::append_txn_author_agreement_acceptance_to_request(request_json, text, version, taa_digest, acc_mech_type, time_of_acceptance).wait()
 }
 
+pub fn set_txn_author_agreement_acceptance_for_pool(pool_handle: PoolHandle,
+                                                    text: Option<&str>,
+                                                    version: Option<&str>,
+                                                    taa_digest: Option<&str>,
+                                                    acc_mech_type: &str,
+                                                    time_of_acceptance: u64) -> Result<(), IndyError> {
+    ledger::set_txn_author_agreement_acceptance_for_pool(pool_handle, text, version, taa_digest, acc_mech_type, time_of_acceptance).wait()
+}
+
 pub fn append_request_endorser(request_json: &str,
                                endorser_did: &str) -> Result<String, IndyError> {
     ledger::append_request_endorser(request_json, endorser_did).wait()
 }
 
+pub fn prepare_request_for_endorser(wallet_handle: WalletHandle, submitter_did: &str, request_json: &str, endorser_did: &str) -> Result<String, IndyError> {
+    ledger::prepare_request_for_endorser(wallet_handle, submitter_did, request_json, endorser_did).wait()
+}
+
+pub fn endorse_pending_transaction(pool_handle: PoolHandle, wallet_handle: WalletHandle, endorser_did: &str, pending_txn_json: &str) -> Result<String, IndyError> {
+    ledger::endorse_pending_transaction(pool_handle, wallet_handle, endorser_did, pending_txn_json).wait()
+}
+
 pub fn post_entities() -> (&'static str, &'static str, &'static str) {
     lazy_static! {
                     static ref COMMON_ENTITIES_INIT: Once = Once::new();
@@ -138,6 +138,19 @@ impl Setup {
         Setup { name, wallet_config: String::new(), wallet_handle: INVALID_WALLET_HANDLE, pool_handle, did: String::new(), verkey: String::new() }
     }
 
+    pub fn simulated_trustee() -> Setup {
+        let name = setup();
+        let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet(&name).unwrap();
+        let pool_handle = pool::create_and_open_simulated_pool_ledger(&name).unwrap();
+        let (did, verkey) = did::create_and_store_my_did(wallet_handle, Some(constants::TRUSTEE_SEED)).unwrap();
+
+        // Simulated ledger doesn't contain genesis domain transactions, so trustee publishes own NYM
+        let nym_request = ledger::build_nym_request(&did, &did, Some(&verkey), None, Some("TRUSTEE")).unwrap();
+        ledger::sign_and_submit_request(pool_handle, wallet_handle, &did, &nym_request).unwrap();
+
+        Setup { name, wallet_config, wallet_handle, pool_handle, did, verkey }
+    }
+
     pub fn wallet_and_pool() -> Setup {
         let name = setup();
         let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet(&name).unwrap();
@@ -1,8 +1,12 @@
 extern crate byteorder;
 extern crate futures;
+extern crate indy_sys;
 extern crate rmp_serde;
 
 use self::byteorder::{LittleEndian, WriteBytesExt};
+use openssl::sha::sha256;
+use rust_base58::{FromBase58, ToBase58};
+use ursa::bls::{Generator, SignKey, VerKey};
 
 use std::fs;
 use std::io::Write;
@@ -17,6 +21,11 @@ use crate::utils::types::{Response, ResponseType};
 use crate::utils::{environment, test};
 use crate::api::PoolHandle;
 
+pub const SIMULATED_POOL_CONFIG: &str = r#"{"transport":"simulated"}"#;
+
+// BLS generator used by libindy to verify multi-signatures of the pool nodes
+const DEFAULT_GENERATOR: &str = "3LHpUjiyFC2q2hD7MnwwNmVXiuaFbQx2XkAFJWzswCjgN1utjsCeLzHsKk1nJvFEaS4fcrUmVAkdhtPCYbrVyATZcmzwJReTcJqwqBCPTmTQ9uWPwz6rEncKb2pYYYFcdHa8N17HzVyTqKfgPi4X9pMetfT3A5xCHq54R2pDNYWVLDX";
+
 #[derive(Serialize, Deserialize)]
 struct PoolConfig {
     pub genesis_txn: String
@@ -56,6 +65,29 @@ pub fn create_genesis_txn_file_for_test_pool(pool_name: &str,
     create_genesis_txn_file(pool_name, txn_file_data.as_str(), txn_file_path)
 }
 
+// Nodes of the simulated pool sign state proofs with BLS keys derived from their aliases,
+// so genesis transactions of the test pool get corresponded `blskey` values
+pub fn create_genesis_txn_file_for_simulated_pool(pool_name: &str,
+                                                  txn_file_path: Option<&Path>) -> PathBuf {
+    let node_txns: Vec<String> = test::gen_txns().iter()
+        .map(|txn| {
+            let mut txn: serde_json::Value = serde_json::from_str(txn).unwrap();
+            let alias = txn["txn"]["data"]["data"]["alias"].as_str().unwrap().to_string();
+            txn["txn"]["data"]["data"]["blskey"] = json!(simulated_node_bls_key(&alias));
+            txn["txn"]["data"]["data"].as_object_mut().unwrap().remove("blskey_pop");
+            txn.to_string()
+        })
+        .collect();
+
+    create_genesis_txn_file(pool_name, node_txns.join("\n").as_str(), txn_file_path)
+}
+
+pub fn simulated_node_bls_key(alias: &str) -> String {
+    let generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+    let sign_key = SignKey::new(Some(&sha256(alias.as_bytes()))).unwrap();
+    VerKey::new(&generator, &sign_key).unwrap().as_bytes().to_base58()
+}
+
 pub fn create_genesis_txn_file_for_test_pool_with_invalid_nodes(pool_name: &str,
                                                                 txn_file_path: Option<&Path>) -> PathBuf {
     let test_pool_ip = environment::test_pool_ip();
@@ -114,7 +146,6 @@ pub fn create_pool_ledger_config(pool_name: &str, pool_config: Option<&str>) ->
     pool::create_pool_ledger_config(pool_name, pool_config).wait()
 }
 
-#[cfg(feature = "local_nodes_pool")]
 pub fn open_pool_ledger(pool_name: &str, config: Option<&str>) -> Result<PoolHandle, IndyError> {
     pool::open_pool_ledger(pool_name, config).wait()
 }
@@ -170,6 +201,13 @@ pub fn create_and_open_pool_ledger(pool_name: &str) -> Result<PoolHandle, IndyEr
     open_pool_ledger(pool_name, None)
 }
 
+pub fn create_and_open_simulated_pool_ledger(pool_name: &str) -> Result<PoolHandle, IndyError> {
+    let txn_file_path = create_genesis_txn_file_for_simulated_pool(pool_name, None);
+    let pool_config = pool_config_json(txn_file_path.as_path());
+    create_pool_ledger_config(pool_name, Some(pool_config.as_str()))?;
+    open_pool_ledger(pool_name, Some(SIMULATED_POOL_CONFIG))
+}
+
 pub fn refresh(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::refresh_pool_ledger(pool_handle).wait()
 }
@@ -186,7 +224,60 @@ pub fn set_protocol_version(protocol_version: usize) -> Result<(), IndyError> {
     pool::set_protocol_version(protocol_version).wait()
 }
 
+pub fn get_local_txn(pool_name: &str, ledger_id: usize, seq_no: usize) -> Result<String, IndyError> {
+    pool::get_local_txn(pool_name, ledger_id, seq_no).wait()
+}
+
+pub fn get_local_txns_by_type(pool_name: &str, ledger_id: usize, txn_type: &str, from_seq_no: usize, count: usize) -> Result<String, IndyError> {
+    pool::get_local_txns_by_type(pool_name, ledger_id, txn_type, from_seq_no, count).wait()
+}
+
+pub fn diagnose_pool(pool_name: &str, config: Option<&str>) -> Result<String, IndyError> {
+    pool::diagnose_pool(pool_name, config).wait()
+}
+
 pub fn check_response_type(response: &str, _type: ResponseType) {
     let response: Response = serde_json::from_str(&response).unwrap();
     assert_eq!(response.op, _type);
-}
\ No newline at end of file
+}
+pub fn register_mock_pool_transport(transport_name: &str) -> Result<(), IndyError> {
+    pool::register_pool_transport(transport_name,
+                                  Some(mock_transport::open),
+                                  Some(mock_transport::send),
+                                  Some(mock_transport::recv),
+                                  Some(mock_transport::free_buffer),
+                                  Some(mock_transport::close)).wait()
+}
+
+// Transport that drops all sent messages and never receives anything
+pub mod mock_transport {
+    use super::indy_sys;
+    use self::indy_sys::{CString, Error, IndyHandle};
+    use indy::ErrorCode;
+    use std::ptr;
+
+    pub extern fn open(_pool_name: CString, transport_handle_p: *mut IndyHandle) -> Error {
+        unsafe { *transport_handle_p = 1; }
+        ErrorCode::Success as Error
+    }
+
+    pub extern fn send(_transport_handle: IndyHandle, _node_alias: CString, _node_address: CString, _msg: CString) -> Error {
+        ErrorCode::Success as Error
+    }
+
+    pub extern fn recv(_transport_handle: IndyHandle, node_alias_p: *mut CString, msg_p: *mut CString, _buffer_handle_p: *mut IndyHandle) -> Error {
+        unsafe {
+            *node_alias_p = ptr::null();
+            *msg_p = ptr::null();
+        }
+        ErrorCode::Success as Error
+    }
+
+    pub extern fn free_buffer(_buffer_handle: IndyHandle) -> Error {
+        ErrorCode::Success as Error
+    }
+
+    pub extern fn close(_transport_handle: IndyHandle) -> Error {
+        ErrorCode::Success as Error
+    }
+}
@@ -117,6 +117,41 @@ pub fn close_and_delete_wallet(wallet_handle: WalletHandle, wallet_config: &str)
     delete_wallet(wallet_config, WALLET_CREDENTIALS)
 }
 
+pub fn register_wallet_storage_transactions(xtype: &str) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let xxtype = CString::new(xtype).unwrap();
+
+    let err = unsafe {
+        indy_register_wallet_storage_transactions(
+            command_handle,
+            xxtype.as_ptr(),
+            Some(InmemWallet::begin_transaction),
+            Some(InmemWallet::commit_transaction),
+            Some(InmemWallet::rollback_transaction),
+            cb
+        )
+    };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
+pub fn transaction_begin(wallet_handle: WalletHandle) -> Result<(), IndyError> {
+    wallet::wallet_transaction_begin(wallet_handle).wait()
+}
+
+pub fn transaction_commit(wallet_handle: WalletHandle) -> Result<(), IndyError> {
+    wallet::wallet_transaction_commit(wallet_handle).wait()
+}
+
+pub fn transaction_rollback(wallet_handle: WalletHandle) -> Result<(), IndyError> {
+    wallet::wallet_transaction_rollback(wallet_handle).wait()
+}
+
+pub fn rotate_wallet_key(wallet_handle: WalletHandle, rotate_key_config: &str) -> Result<(), IndyError> {
+    wallet::rotate_wallet_key(wallet_handle, rotate_key_config).wait()
+}
+
 pub fn export_wallet(wallet_handle: WalletHandle, export_config_json: &str) -> Result<(), IndyError> {
     wallet::export_wallet(wallet_handle, export_config_json).wait()
 }
@@ -137,6 +172,15 @@ pub fn prepare_export_wallet_config(path: &Path) -> String {
     serde_json::to_string(&json).unwrap()
 }
 
+pub fn prepare_plaintext_export_wallet_config(path: &Path) -> String {
+    let json = json!({
+            "path": path.to_str().unwrap(),
+            "format": "plaintext_jsonl",
+            "acknowledge_plaintext_export": true,
+        });
+    serde_json::to_string(&json).unwrap()
+}
+
 pub fn generate_wallet_key(config: Option<&str>) -> Result<String, IndyError> {
     wallet::generate_wallet_key(config).wait()
 }
@@ -170,6 +214,14 @@ extern {
                                         fetch_search_next_record: Option<WalletFetchSearchNextRecord>,
                                         free_search: Option<WalletFreeSearch>,
                                         cb: Option<ResponseEmptyCB>) -> ErrorCode;
+
+    #[no_mangle]
+    pub fn indy_register_wallet_storage_transactions(command_handle: CommandHandle,
+                                                     type_: *const c_char,
+                                                     begin_transaction: Option<WalletBeginTransaction>,
+                                                     commit_transaction: Option<WalletCommitTransaction>,
+                                                     rollback_transaction: Option<WalletRollbackTransaction>,
+                                                     cb: Option<ResponseEmptyCB>) -> ErrorCode;
 }
 
 pub type WalletCreate = extern fn(name: *const c_char,
@@ -252,5 +304,8 @@ pub type WalletFetchSearchNextRecord = extern fn(storage_handle: i32,
                                                  record_handle_p: *mut i32) -> ErrorCode;
 pub type WalletFreeSearch = extern fn(storage_handle: i32,
                                       search_handle: i32) -> ErrorCode;
+pub type WalletBeginTransaction = extern fn(storage_handle: i32) -> ErrorCode;
+pub type WalletCommitTransaction = extern fn(storage_handle: i32) -> ErrorCode;
+pub type WalletRollbackTransaction = extern fn(storage_handle: i32) -> ErrorCode;
 
 pub type ResponseEmptyCB = extern fn(xcommand_handle: i32, err: i32);
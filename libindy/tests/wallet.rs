@@ -7,7 +7,7 @@ extern crate indyrs as indy;
 extern crate indyrs as api;
 
 use crate::utils::inmem_wallet::InmemWallet;
-use crate::utils::{environment, wallet, test, did};
+use crate::utils::{environment, wallet, test, did, non_secrets};
 use crate::utils::constants::*;
 use crate::utils::Setup;
 
@@ -244,6 +244,75 @@ mod high_cases {
 
             test::cleanup_files(&path, &setup.name);
         }
+
+        #[test]
+        fn indy_export_wallet_works_for_plaintext_jsonl() {
+            let setup = Setup::wallet();
+
+            let path = wallet::export_wallet_path(&setup.name);
+            let config_json = json!({
+                "path": path.to_str().unwrap(),
+                "format": "plaintext_jsonl",
+                "acknowledge_plaintext_export": true,
+                "record_type": "TestType",
+                "query": {"tagName": "tagValue"},
+            }).to_string();
+
+            non_secrets::add_wallet_record(setup.wallet_handle, "TestType", "RecordId1", "RecordValue1", Some(r#"{"tagName": "tagValue"}"#)).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, "TestType", "RecordId2", "RecordValue2", None).unwrap();
+            did::create_my_did(setup.wallet_handle, "{}").unwrap();
+
+            cleanup_file(&path);
+            wallet::export_wallet(setup.wallet_handle, &config_json).unwrap();
+
+            let exported = fs::read_to_string(&path).unwrap();
+            let lines: Vec<&str> = exported.lines().collect();
+            assert_eq!(1, lines.len());
+
+            let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+            assert_eq!(json!({"type": "TestType", "id": "RecordId1", "value": "RecordValue1", "tags": {"tagName": "tagValue"}}), record);
+
+            test::cleanup_files(&path, &setup.name);
+        }
+
+        #[test]
+        fn indy_export_wallet_works_for_plaintext_jsonl_with_offset_and_limit() {
+            let setup = Setup::wallet();
+
+            let path = wallet::export_wallet_path(&setup.name);
+
+            non_secrets::add_wallet_record(setup.wallet_handle, "TestType", "RecordId1", "RecordValue1", None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, "TestType", "RecordId2", "RecordValue2", None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, "TestType", "RecordId3", "RecordValue3", None).unwrap();
+
+            let mut ids = Vec::new();
+
+            for offset in 0..3 {
+                let config_json = json!({
+                    "path": path.to_str().unwrap(),
+                    "format": "plaintext_jsonl",
+                    "acknowledge_plaintext_export": true,
+                    "record_type": "TestType",
+                    "offset": offset,
+                    "limit": 1,
+                }).to_string();
+
+                cleanup_file(&path);
+                wallet::export_wallet(setup.wallet_handle, &config_json).unwrap();
+
+                let exported = fs::read_to_string(&path).unwrap();
+                let lines: Vec<&str> = exported.lines().collect();
+                assert_eq!(1, lines.len());
+
+                let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+                ids.push(record["id"].as_str().unwrap().to_string());
+            }
+
+            ids.sort();
+            assert_eq!(vec!["RecordId1", "RecordId2", "RecordId3"], ids);
+
+            test::cleanup_files(&path, &setup.name);
+        }
     }
 
     mod import_wallet {
@@ -281,6 +350,39 @@ mod high_cases {
             wallet::close_and_delete_wallet(wallet_handle, &config).unwrap();
             cleanup_file(&path);
         }
+
+        #[test]
+        fn indy_import_wallet_works_for_plaintext_jsonl() {
+            let setup = Setup::empty();
+            let config = config(&setup.name);
+
+            let path = wallet::export_wallet_path(&setup.name);
+            let config_json = wallet::prepare_plaintext_export_wallet_config(&path);
+
+            let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet(&setup.name).unwrap();
+
+            let (did, _) = did::create_my_did(wallet_handle, "{}").unwrap();
+            did::set_did_metadata(wallet_handle, &did, METADATA).unwrap();
+
+            let did_with_meta = did::get_my_did_with_metadata(wallet_handle, &did).unwrap();
+
+            cleanup_file(&path);
+            wallet::export_wallet(wallet_handle, &config_json).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::delete_wallet(&wallet_config, WALLET_CREDENTIALS).unwrap();
+
+            wallet::import_wallet(&config, WALLET_CREDENTIALS, &config_json).unwrap();
+
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            let did_with_meta_after_import = did::get_my_did_with_metadata(wallet_handle, &did).unwrap();
+
+            assert_eq!(did_with_meta, did_with_meta_after_import);
+
+            wallet::close_and_delete_wallet(wallet_handle, &config).unwrap();
+            cleanup_file(&path);
+        }
     }
 
     mod generate_wallet_key {
@@ -319,6 +421,140 @@ mod high_cases {
             wallet::delete_wallet(&wallet_config, &credentials).unwrap();
         }
     }
+
+    mod rotate_wallet_key {
+        use super::*;
+
+        #[test]
+        fn indy_rotate_wallet_key_works() {
+            let setup = Setup::empty();
+            let config = config(&setup.name);
+
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            did::create_my_did(wallet_handle, "{}").unwrap();
+
+            wallet::rotate_wallet_key(wallet_handle, r#"{"key":"new_key", "key_derivation_method":"ARGON2I_INT"}"#).unwrap();
+
+            // Opened handle keeps working after rotation
+            did::create_my_did(wallet_handle, "{}").unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            let res = wallet::open_wallet(&config, WALLET_CREDENTIALS);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+
+            let credentials = r#"{"key":"new_key", "key_derivation_method":"ARGON2I_INT"}"#;
+            let wallet_handle = wallet::open_wallet(&config, credentials).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::delete_wallet(&config, credentials).unwrap();
+        }
+
+        #[test]
+        fn indy_rotate_wallet_key_works_for_raw_key() {
+            let setup = Setup::empty();
+            let config = config(&setup.name);
+
+            wallet::create_wallet(&config, r#"{"key":"key"}"#).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, r#"{"key":"key"}"#).unwrap();
+
+            wallet::rotate_wallet_key(wallet_handle, WALLET_CREDENTIALS).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::delete_wallet(&config, WALLET_CREDENTIALS).unwrap();
+        }
+    }
+
+    mod wallet_transaction {
+        use super::*;
+
+        const RECORD_TYPE: &str = "TestType";
+
+        #[test]
+        fn indy_wallet_transaction_commit_works() {
+            let setup = Setup::wallet();
+
+            wallet::transaction_begin(setup.wallet_handle).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId1", "RecordValue", None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId2", "RecordValue", None).unwrap();
+            wallet::transaction_commit(setup.wallet_handle).unwrap();
+
+            non_secrets::get_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId1", "{}").unwrap();
+            non_secrets::get_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId2", "{}").unwrap();
+        }
+
+        #[test]
+        fn indy_wallet_transaction_rollback_works() {
+            let setup = Setup::wallet();
+
+            non_secrets::add_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId1", "RecordValue", None).unwrap();
+
+            wallet::transaction_begin(setup.wallet_handle).unwrap();
+            non_secrets::update_wallet_record_value(setup.wallet_handle, RECORD_TYPE, "RecordId1", "NewRecordValue").unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId2", "RecordValue", None).unwrap();
+            did::create_my_did(setup.wallet_handle, "{}").unwrap();
+            wallet::transaction_rollback(setup.wallet_handle).unwrap();
+
+            let record = non_secrets::get_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId1", "{}").unwrap();
+            assert!(record.contains("\"RecordValue\""));
+
+            let res = non_secrets::get_wallet_record(setup.wallet_handle, RECORD_TYPE, "RecordId2", "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let dids = did::list_my_dids_with_meta(setup.wallet_handle).unwrap();
+            assert_eq!("[]", dids);
+        }
+
+        #[test]
+        fn indy_wallet_transaction_works_for_close_wallet() {
+            let setup = Setup::empty();
+            let config = config(&setup.name);
+
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            wallet::transaction_begin(wallet_handle).unwrap();
+            non_secrets::add_wallet_record(wallet_handle, RECORD_TYPE, "RecordId1", "RecordValue", None).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            let res = non_secrets::get_wallet_record(wallet_handle, RECORD_TYPE, "RecordId1", "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_and_delete_wallet(wallet_handle, &config).unwrap();
+        }
+
+        #[test]
+        fn indy_wallet_transaction_works_for_plugged() {
+            Setup::empty();
+            InmemWallet::cleanup();
+
+            let xtype = "inmem_transactional";
+            let config = json!({"id": "inmem_transactional_wallet", "storage_type": xtype}).to_string();
+
+            wallet::register_wallet_storage(xtype, false).unwrap();
+            wallet::register_wallet_storage_transactions(xtype).unwrap();
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            wallet::transaction_begin(wallet_handle).unwrap();
+            non_secrets::add_wallet_record(wallet_handle, RECORD_TYPE, "RecordId1", "RecordValue", None).unwrap();
+            wallet::transaction_commit(wallet_handle).unwrap();
+
+            wallet::transaction_begin(wallet_handle).unwrap();
+            non_secrets::add_wallet_record(wallet_handle, RECORD_TYPE, "RecordId2", "RecordValue", None).unwrap();
+            wallet::transaction_rollback(wallet_handle).unwrap();
+
+            non_secrets::get_wallet_record(wallet_handle, RECORD_TYPE, "RecordId1", "{}").unwrap();
+            let res = non_secrets::get_wallet_record(wallet_handle, RECORD_TYPE, "RecordId2", "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            InmemWallet::cleanup();
+        }
+    }
 }
 
 #[cfg(not(feature="only_high_cases"))]
@@ -594,6 +830,86 @@ mod medium_cases {
         }
     }
 
+    mod rotate_wallet_key {
+        use super::*;
+
+        #[test]
+        fn indy_rotate_wallet_key_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = wallet::rotate_wallet_key(INVALID_WALLET_HANDLE, r#"{"key":"new_key"}"#);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_rotate_wallet_key_works_for_invalid_config() {
+            let setup = Setup::wallet();
+
+            let res = wallet::rotate_wallet_key(setup.wallet_handle, r#"{"field":"value"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_rotate_wallet_key_works_for_started_transaction() {
+            let setup = Setup::wallet();
+
+            wallet::transaction_begin(setup.wallet_handle).unwrap();
+
+            let res = wallet::rotate_wallet_key(setup.wallet_handle, r#"{"key":"new_key"}"#);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            wallet::transaction_rollback(setup.wallet_handle).unwrap();
+        }
+    }
+
+    mod wallet_transaction {
+        use super::*;
+
+        #[test]
+        fn indy_wallet_transaction_begin_works_for_twice() {
+            let setup = Setup::wallet();
+
+            wallet::transaction_begin(setup.wallet_handle).unwrap();
+
+            let res = wallet::transaction_begin(setup.wallet_handle);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            wallet::transaction_rollback(setup.wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_wallet_transaction_commit_works_for_not_started() {
+            let setup = Setup::wallet();
+
+            let res = wallet::transaction_commit(setup.wallet_handle);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        fn indy_wallet_transaction_rollback_works_for_not_started() {
+            let setup = Setup::wallet();
+
+            let res = wallet::transaction_rollback(setup.wallet_handle);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        fn indy_wallet_transaction_begin_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = wallet::transaction_begin(INVALID_WALLET_HANDLE);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_wallet_transaction_begin_works_for_plugged_without_transactions() {
+            let setup = Setup::plugged_wallet();
+
+            let res = wallet::transaction_begin(setup.wallet_handle);
+            assert_code!(ErrorCode::WalletStorageError, res);
+        }
+    }
+
     mod export_wallet {
         use super::*;
         use std::fs;
@@ -623,6 +939,22 @@ mod medium_cases {
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
+        #[test]
+        fn indy_export_wallet_returns_error_for_not_acknowledged_plaintext() {
+            let setup = Setup::wallet();
+
+            let path = wallet::export_wallet_path(&setup.name);
+            let config_json = json!({
+                "path": path.to_str().unwrap(),
+                "format": "plaintext_jsonl",
+            }).to_string();
+
+            cleanup_file(&path);
+            let res = wallet::export_wallet(setup.wallet_handle, &config_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+            assert!(!path.exists());
+        }
+
         #[test]
         fn indy_export_wallet_returns_error_if_invalid_handle() {
             let setup= Setup::empty();
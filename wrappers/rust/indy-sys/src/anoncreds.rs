@@ -56,6 +56,42 @@ extern {
                                          blob_storage_reader_handle: BlobStorageReaderHandle,
                                          cb: Option<ResponseStringStringStringCB>) -> Error;
 
+    pub fn indy_issuer_create_credentials_batch(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                cred_requests_json: CString,
+                                                rev_reg_id: CString,
+                                                blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                cb: Option<ResponseStringStringCB>) -> Error;
+
+    pub fn indy_issuer_create_revocation_registry_pool(command_handle: CommandHandle,
+                                                       wallet_handle: WalletHandle,
+                                                       issuer_did: CString,
+                                                       cred_def_id: CString,
+                                                       config_json: CString,
+                                                       cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_create_credential_from_revocation_registry_pool(command_handle: CommandHandle,
+                                                                       wallet_handle: WalletHandle,
+                                                                       cred_offer_json: CString,
+                                                                       cred_req_json: CString,
+                                                                       cred_values_json: CString,
+                                                                       cb: Option<ResponseStringStringStringStringCB>) -> Error;
+
+    pub fn indy_issuer_get_revocation_registry_pool_pending_requests(command_handle: CommandHandle,
+                                                                     wallet_handle: WalletHandle,
+                                                                     cred_def_id: CString,
+                                                                     cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_add_revocation_registry_to_pool(command_handle: CommandHandle,
+                                                       wallet_handle: WalletHandle,
+                                                       cred_def_id: CString,
+                                                       cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_confirm_revocation_registry_published(command_handle: CommandHandle,
+                                                             wallet_handle: WalletHandle,
+                                                             rev_reg_id: CString,
+                                                             cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_issuer_revoke_credential(command_handle: CommandHandle,
                                          wallet_handle: WalletHandle,
                                          blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
@@ -63,6 +99,18 @@ extern {
                                          cred_revoc_id: CString,
                                          cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_issuer_revoke_credentials(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                                          rev_reg_id: CString,
+                                          cred_revoc_ids_json: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_get_revocation_status_list(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  rev_reg_id: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
                                                         rev_reg_delta_json: CString,
                                                         other_rev_reg_delta_json: CString,
@@ -171,6 +219,15 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    pub fn indy_verifier_verify_proof_with_details(command_handle: CommandHandle,
+                                                   proof_request_json: CString,
+                                                   proof_json: CString,
+                                                   schemas_json: CString,
+                                                   credential_defs_json: CString,
+                                                   rev_reg_defs_json: CString,
+                                                   rev_regs_json: CString,
+                                                   cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_create_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
                                         rev_reg_def_json: CString,
@@ -188,10 +245,21 @@ extern {
                                         cred_rev_id: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_refresh_revocation_states(command_handle: CommandHandle,
+                                                 pool_handle: PoolHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                 cred_ids_json: CString,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_generate_nonce(command_handle: CommandHandle,
                                cb: Option<ResponseStringCB>) -> Error;
     pub fn indy_to_unqualified(command_handle: CommandHandle,
                                entity: CString,
                                cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_encode_credential_attributes(command_handle: CommandHandle,
+                                             attributes_json: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
 }
 
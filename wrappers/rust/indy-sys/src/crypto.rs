@@ -1,9 +1,18 @@
 use super::*;
 
-use {BString, CString, Error, CommandHandle, WalletHandle};
+use {BString, CString, Error, CommandHandle, IndyHandle, WalletHandle};
 
 extern {
 
+    pub fn indy_register_key_backend(command_handle: CommandHandle,
+                                     crypto_type: CString,
+                                     create_key: Option<KeyBackendCreateKey>,
+                                     sign: Option<KeyBackendSign>,
+                                     verify: Option<KeyBackendVerify>,
+                                     crypto_box: Option<KeyBackendCryptoBox>,
+                                     free_buffer: Option<KeyBackendFreeBuffer>,
+                                     cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_create_key(command_handle: CommandHandle,
                            wallet_handle: WalletHandle,
                            key_json: CString,
@@ -35,6 +44,21 @@ extern {
                               signature_len: u32,
                               cb: Option<ResponseBoolCB>) -> Error;
 
+    pub fn indy_crypto_sign_jws(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                signer_vk: CString,
+                                payload_raw: BString,
+                                payload_len: u32,
+                                options_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_crypto_verify_jws(command_handle: CommandHandle,
+                                  signer_vk: CString,
+                                  jws: CString,
+                                  detached_payload_raw: BString,
+                                  detached_payload_len: u32,
+                                  cb: Option<ResponseBoolCB>) -> Error;
+
     pub fn indy_crypto_auth_crypt(command_handle: CommandHandle,
                                   wallet_handle: WalletHandle,
                                   sender_vk: CString,
@@ -76,5 +100,45 @@ extern {
                                jwe_msg: BString,
                                jwe_len: u32,
                                cb: Option<ResponseSliceCB>) -> Error;
+
+    pub fn indy_pack_message_v2(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                message: BString,
+                                message_len: u32,
+                                receiver_kids: CString,
+                                sender_kid: CString,
+                                signer_kid: CString,
+                                cb: Option<ResponseSliceCB>) -> Error;
+
+    pub fn indy_unpack_message_v2(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  packed_msg: BString,
+                                  packed_len: u32,
+                                  cb: Option<ResponseSliceCB>) -> Error;
 }
 
+pub type KeyBackendCreateKey = extern fn(key_info_json: CString,
+                                         verkey_p: *mut CString,
+                                         verkey_handle_p: *mut IndyHandle) -> Error;
+pub type KeyBackendSign = extern fn(verkey: CString,
+                                    message_raw: BString,
+                                    message_len: u32,
+                                    signature_raw_p: *mut BString,
+                                    signature_len_p: *mut u32,
+                                    signature_handle_p: *mut IndyHandle) -> Error;
+pub type KeyBackendVerify = extern fn(verkey: CString,
+                                      message_raw: BString,
+                                      message_len: u32,
+                                      signature_raw: BString,
+                                      signature_len: u32,
+                                      valid_p: *mut bool) -> Error;
+pub type KeyBackendCryptoBox = extern fn(my_verkey: CString,
+                                         their_verkey: CString,
+                                         message_raw: BString,
+                                         message_len: u32,
+                                         nonce_raw: BString,
+                                         nonce_len: u32,
+                                         encrypted_raw_p: *mut BString,
+                                         encrypted_len_p: *mut u32,
+                                         encrypted_handle_p: *mut IndyHandle) -> Error;
+pub type KeyBackendFreeBuffer = extern fn(buffer_handle: IndyHandle) -> Error;
@@ -8,6 +8,11 @@ extern {
                                         did_info: CString,
                                         cb: Option<ResponseStringStringCB>) -> Error;
 
+    pub fn indy_resolve_did(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            did: CString,
+                            cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_replace_keys_start(command_handle: CommandHandle,
                                    wallet_handle: WalletHandle,
                                    did: CString,
@@ -35,6 +40,13 @@ extern {
                                   did: CString,
                                   cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_key_for_did_at(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               wallet_handle: WalletHandle,
+                               did: CString,
+                               timestamp: u64,
+                               cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_set_endpoint_for_did(command_handle: CommandHandle,
                                      wallet_handle: WalletHandle,
                                      did: CString,
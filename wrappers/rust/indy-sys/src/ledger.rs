@@ -10,11 +10,22 @@ extern {
                                         request_json: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_submit_request_with_details(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            request_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_submit_request(command_handle: CommandHandle,
                                pool_handle: PoolHandle,
                                request_json: CString,
                                cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_submit_requests_batch(command_handle: CommandHandle,
+                                      pool_handle: PoolHandle,
+                                      requests_json: CString,
+                                      options_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_submit_action(command_handle: CommandHandle,
                               pool_handle: PoolHandle,
                               request_json: CString,
@@ -194,6 +205,11 @@ extern {
                                       response: CString,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_verify_txn_inclusion(command_handle: CommandHandle,
+                                     pool_handle: PoolHandle,
+                                     get_txn_response: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_auth_rule_request(command_handle: CommandHandle,
                                         submitter_did: CString,
                                         txn_type: CString,
@@ -218,6 +234,42 @@ extern {
                                             new_value: CString,
                                             cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_check_auth_rule(command_handle: CommandHandle,
+                                auth_rules_json: CString,
+                                txn_type: CString,
+                                action: CString,
+                                field: CString,
+                                old_value: CString,
+                                new_value: CString,
+                                signers_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_rich_schema_request(command_handle: CommandHandle,
+                                          submitter_did: CString,
+                                          rs_id: CString,
+                                          rs_content: CString,
+                                          rs_name: CString,
+                                          rs_version: CString,
+                                          rs_type: CString,
+                                          ver: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_get_rich_schema_object_by_id_request(command_handle: CommandHandle,
+                                                           submitter_did: CString,
+                                                           rs_id: CString,
+                                                           cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle,
+                                                                 submitter_did: CString,
+                                                                 rs_type: CString,
+                                                                 rs_name: CString,
+                                                                 rs_version: CString,
+                                                                 cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_rich_schema_object_response(command_handle: CommandHandle,
+                                                      get_rich_schema_object_response: CString,
+                                                      cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_txn_author_agreement_request(command_handle: CommandHandle,
                                                    submitter_did: CString,
                                                    text: CString,
@@ -258,11 +310,34 @@ extern {
                                                                   time_of_acceptance: u64,
                                                                   cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_set_txn_author_agreement_acceptance_for_pool(command_handle: CommandHandle,
+                                                             pool_handle: PoolHandle,
+                                                             text: CString,
+                                                             version: CString,
+                                                             hash: CString,
+                                                             acc_mech_type: CString,
+                                                             time_of_acceptance: u64,
+                                                             cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_append_request_endorser(command_handle: CommandHandle,
                                         request_json: CString,
                                         endorser_did: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prepare_request_for_endorser(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             submitter_did: CString,
+                                             request_json: CString,
+                                             endorser_did: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_endorse_pending_transaction(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            wallet_handle: WalletHandle,
+                                            endorser_did: CString,
+                                            pending_txn_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_ledgers_freeze_request(command_handle: CommandHandle,
                                        submitter_did: CString,
                                        ledgers_ids: CString,
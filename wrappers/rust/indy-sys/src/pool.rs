@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle, PoolHandle};
+use {CString, Error, CommandHandle, IndyHandle, PoolHandle};
 
 extern {
 
@@ -32,5 +32,46 @@ extern {
     pub fn indy_set_protocol_version(command_handle: CommandHandle,
                                      protocol_version: usize,
                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_get_local_txn(command_handle: CommandHandle,
+                              config_name: CString,
+                              ledger_id: usize,
+                              seq_no: usize,
+                              cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_get_local_txns_by_type(command_handle: CommandHandle,
+                                       config_name: CString,
+                                       ledger_id: usize,
+                                       txn_type: CString,
+                                       from_seq_no: usize,
+                                       count: usize,
+                                       cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_diagnose_pool(command_handle: CommandHandle,
+                              config_name: CString,
+                              config: CString,
+                              cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_register_pool_transport(command_handle: CommandHandle,
+                                        transport_name: CString,
+                                        open: Option<PoolTransportOpen>,
+                                        send: Option<PoolTransportSend>,
+                                        recv: Option<PoolTransportRecv>,
+                                        free_buffer: Option<PoolTransportFreeBuffer>,
+                                        close: Option<PoolTransportClose>,
+                                        cb: Option<ResponseEmptyCB>) -> Error;
 }
 
+pub type PoolTransportOpen = extern fn(pool_name: CString,
+                                       transport_handle_p: *mut IndyHandle) -> Error;
+pub type PoolTransportSend = extern fn(transport_handle: IndyHandle,
+                                       node_alias: CString,
+                                       node_address: CString,
+                                       msg: CString) -> Error;
+pub type PoolTransportRecv = extern fn(transport_handle: IndyHandle,
+                                       node_alias_p: *mut CString,
+                                       msg_p: *mut CString,
+                                       buffer_handle_p: *mut IndyHandle) -> Error;
+pub type PoolTransportFreeBuffer = extern fn(buffer_handle: IndyHandle) -> Error;
+pub type PoolTransportClose = extern fn(transport_handle: IndyHandle) -> Error;
+
@@ -32,6 +32,13 @@ extern {
                                         free_search: Option<WalletFreeSearch>,
                                         cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_register_wallet_storage_transactions(command_handle: CommandHandle,
+                                                     type_: CString,
+                                                     begin_transaction: Option<WalletBeginTransaction>,
+                                                     commit_transaction: Option<WalletCommitTransaction>,
+                                                     rollback_transaction: Option<WalletRollbackTransaction>,
+                                                     cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_create_wallet(command_handle: CommandHandle,
                               config: CString,
                               credentials: CString,
@@ -42,6 +49,11 @@ extern {
                             credentials: CString,
                             cb: Option<ResponseWalletHandleCB>) -> Error;
 
+    pub fn indy_rotate_wallet_key(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  rotate_key_config: CString,
+                                  cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_export_wallet(command_handle: CommandHandle,
                               wallet_handle: WalletHandle,
                               export_config: CString,
@@ -57,6 +69,18 @@ extern {
                              wallet_handle: WalletHandle,
                              cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_wallet_transaction_begin(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_wallet_transaction_commit(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_wallet_transaction_rollback(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_delete_wallet(command_handle: CommandHandle,
                               config: CString,
                               credentials: CString,
@@ -147,3 +171,6 @@ pub type WalletFetchSearchNextRecord = extern fn(storage_handle: StorageHandle,
                                                  record_handle_p: *mut RecordHandle) -> Error;
 pub type WalletFreeSearch = extern fn(storage_handle: StorageHandle,
                                       search_handle: SearchHandle) -> Error;
+pub type WalletBeginTransaction = extern fn(storage_handle: StorageHandle) -> Error;
+pub type WalletCommitTransaction = extern fn(storage_handle: StorageHandle) -> Error;
+pub type WalletRollbackTransaction = extern fn(storage_handle: StorageHandle) -> Error;
@@ -11,11 +11,12 @@ use ffi::anoncreds;
 use ffi::{ResponseStringStringCB,
           ResponseI32UsizeCB,
           ResponseStringStringStringCB,
+          ResponseStringStringStringStringCB,
           ResponseStringCB,
           ResponseI32CB,
           ResponseEmptyCB,
           ResponseBoolCB};
-use {CommandHandle, WalletHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
+use {CommandHandle, WalletHandle, PoolHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
 use ffi::BlobStorageReaderCfgHandle;
 
 /*
@@ -406,6 +407,201 @@ fn _issuer_create_credential(
     })
 }
 
+/// Issue a batch of Credentials for the given pairs of Cred Offer and Cred Request.
+///
+/// All Cred Offers must reference the same credential definition. If revocation registry is set
+/// it is updated only once for the whole batch. Either all credentials are issued or none of them.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `cred_requests_json` - a list of credentials to issue
+///     [{"cred_offer": <cred offer>, "cred_req": <cred request>, "cred_values": <cred values>}]
+/// * `rev_reg_id` - (Optional) id of revocation registry stored in the wallet
+/// * `blob_storage_reader_handle` - pre-configured blob storage reader instance handle that
+/// will allow to read revocation tails or -1 if revocation registry isn't used
+///
+/// # Returns
+/// * `creds_json` - list of issued credentials: [{"cred": <credential json>, "cred_revoc_id": Optional<string>}]
+/// * `revoc_reg_delta_json` - (Optional) Revocation registry delta json with all newly issued credentials
+pub fn issuer_create_credentials_batch(wallet_handle: WalletHandle,
+                                       cred_requests_json: &str,
+                                       rev_reg_id: Option<&str>,
+                                       blob_storage_reader_handle: BlobStorageReaderHandle) -> Box<dyn Future<Item=(String, Option<String>), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_opt_string();
+
+    let err = _issuer_create_credentials_batch(command_handle, wallet_handle, cred_requests_json, rev_reg_id, blob_storage_reader_handle, cb);
+
+    ResultHandler::str_optstr(command_handle, err, receiver)
+}
+
+fn _issuer_create_credentials_batch(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    cred_requests_json: &str,
+    rev_reg_id: Option<&str>,
+    blob_storage_reader_handle: BlobStorageReaderHandle,
+    cb: Option<ResponseStringStringCB>
+) -> ErrorCode {
+    let cred_requests_json = c_str!(cred_requests_json);
+    let rev_reg_id_str = opt_c_str!(rev_reg_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_credentials_batch(command_handle, wallet_handle, cred_requests_json.as_ptr(), opt_c_ptr!(rev_reg_id, rev_reg_id_str), blob_storage_reader_handle, cb)
+    })
+}
+
+/// Create a pool of revocation registries for the given credential definition managed by the wallet.
+///
+/// The pool consists of the active registry used for issuance and the pre-generated standby registry
+/// which replaces the active one when it is full once it's published to the ledger.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `issuer_did` - a DID of the issuer signing transactions to the Ledger
+/// * `cred_def_id` - id of stored in ledger credential definition
+/// * `config_json` - pool configuration json
+///     {
+///         "tag_prefix": string - (optional, default "pool_") prefix of registry tags,
+///         "revoc_def_type": string - (optional, default "CL_ACCUM") type of revocation registries,
+///         "issuance_type": string - (optional, default "ISSUANCE_ON_DEMAND") type of issuance,
+///         "max_cred_num": int - (optional, default 100000) maximum number of credentials in each registry,
+///         "tails_writer_type": string - (optional, default "default") type of blob storage to write tails,
+///         "tails_writer_config": object - blob storage writer config,
+///         "tails_reader_type": string - (optional, default "tails_writer_type") type of blob storage to read tails,
+///         "tails_reader_config": object - (optional, default "tails_writer_config") blob storage reader config
+///     }
+///
+/// # Returns
+/// * `pool_json` - state of the pool:
+///     {"issuer_did", "cred_def_id", "config", "registries": [{"rev_reg_id", "state": "active"|"standby"|"full", "published"}]}
+pub fn issuer_create_revocation_registry_pool(wallet_handle: WalletHandle, issuer_did: &str, cred_def_id: &str, config_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_create_revocation_registry_pool(command_handle, wallet_handle, issuer_did, cred_def_id, config_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_create_revocation_registry_pool(command_handle: CommandHandle, wallet_handle: WalletHandle, issuer_did: &str, cred_def_id: &str, config_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let issuer_did = c_str!(issuer_did);
+    let cred_def_id = c_str!(cred_def_id);
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_revocation_registry_pool(command_handle, wallet_handle, issuer_did.as_ptr(), cred_def_id.as_ptr(), config_json.as_ptr(), cb)
+    })
+}
+
+/// Issue Credential for the given Cred Request using the active registry of the revocation registry pool
+/// of the credential definition of Cred Offer. Full registry is replaced by the published standby one.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `cred_offer_json` - a cred offer created by issuer_create_credential_offer
+/// * `cred_req_json` - a credential request created by prover_create_credential_req
+/// * `cred_values_json` - a credential containing attribute values for each of requested attribute names
+///
+/// # Returns
+/// * `cred_json` - Credential json containing signed credential values
+/// * `rev_reg_id` - id of revocation registry the credential was issued in
+/// * `cred_revoc_id` - local id for revocation info (Can be used for revocation of this credential)
+/// * `revoc_reg_delta_json` - Revocation registry delta json with a newly issued credential
+pub fn issuer_create_credential_from_revocation_registry_pool(wallet_handle: WalletHandle,
+                                                              cred_offer_json: &str,
+                                                              cred_req_json: &str,
+                                                              cred_values_json: &str) -> Box<dyn Future<Item=(String, String, String, Option<String>), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string_string_opt_string();
+
+    let err = _issuer_create_credential_from_revocation_registry_pool(command_handle, wallet_handle, cred_offer_json, cred_req_json, cred_values_json, cb);
+
+    ResultHandler::str_str_str_optstr(command_handle, err, receiver)
+}
+
+fn _issuer_create_credential_from_revocation_registry_pool(command_handle: CommandHandle,
+                                                           wallet_handle: WalletHandle,
+                                                           cred_offer_json: &str,
+                                                           cred_req_json: &str,
+                                                           cred_values_json: &str,
+                                                           cb: Option<ResponseStringStringStringStringCB>) -> ErrorCode {
+    let cred_offer_json = c_str!(cred_offer_json);
+    let cred_req_json = c_str!(cred_req_json);
+    let cred_values_json = c_str!(cred_values_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_credential_from_revocation_registry_pool(command_handle, wallet_handle, cred_offer_json.as_ptr(), cred_req_json.as_ptr(), cred_values_json.as_ptr(), cb)
+    })
+}
+
+/// Build ledger requests for registries of the revocation registry pool which aren't published yet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `cred_def_id` - id of credential definition of the pool
+///
+/// # Returns
+/// * `requests_json` - [{"rev_reg_id": string, "rev_reg_def_request": string, "rev_reg_entry_request": string}]
+pub fn issuer_get_revocation_registry_pool_pending_requests(wallet_handle: WalletHandle, cred_def_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_get_revocation_registry_pool_pending_requests(command_handle, wallet_handle, cred_def_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_get_revocation_registry_pool_pending_requests(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_get_revocation_registry_pool_pending_requests(command_handle, wallet_handle, cred_def_id.as_ptr(), cb)
+    })
+}
+
+/// Generate the standby registry of the revocation registry pool in advance.
+/// It replaces the active registry when the active one is full, but only after it's published to the ledger.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `cred_def_id` - id of credential definition of the pool
+///
+/// # Returns
+/// * `rev_reg_id` - id of the generated revocation registry
+pub fn issuer_add_revocation_registry_to_pool(wallet_handle: WalletHandle, cred_def_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_add_revocation_registry_to_pool(command_handle, wallet_handle, cred_def_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_add_revocation_registry_to_pool(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_add_revocation_registry_to_pool(command_handle, wallet_handle, cred_def_id.as_ptr(), cb)
+    })
+}
+
+/// Mark the registry of the revocation registry pool as published to the ledger.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `rev_reg_id` - id of revocation registry of the pool
+pub fn issuer_confirm_revocation_registry_published(wallet_handle: WalletHandle, rev_reg_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _issuer_confirm_revocation_registry_published(command_handle, wallet_handle, rev_reg_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _issuer_confirm_revocation_registry_published(command_handle: CommandHandle, wallet_handle: WalletHandle, rev_reg_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_confirm_revocation_registry_published(command_handle, wallet_handle, rev_reg_id.as_ptr(), cb)
+    })
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -444,6 +640,64 @@ fn _issuer_revoke_credential(command_handle: CommandHandle,
     })
 }
 
+/// Revoke several credentials of the same revocation registry at once.
+///
+/// If any of ids can't be revoked the registry stays unchanged.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `blob_storage_reader_cfg_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `rev_reg_id`: id of revocation registry stored in wallet
+/// * `cred_revoc_ids_json`: list of local ids for revocation info
+///
+/// # Returns
+/// * `revoc_reg_delta_json`: Revocation registry delta json with all revoked credentials
+pub fn issuer_revoke_credentials(wallet_handle: WalletHandle, blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle, rev_reg_id: &str, cred_revoc_ids_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_revoke_credentials(command_handle, wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_ids_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_revoke_credentials(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                              rev_reg_id: &str,
+                              cred_revoc_ids_json: &str,
+                              cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+    let cred_revoc_ids_json = c_str!(cred_revoc_ids_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_revoke_credentials(command_handle, wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id.as_ptr(), cred_revoc_ids_json.as_ptr(), cb)
+    })
+}
+
+/// Get status of credentials issued by the revocation registry stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `rev_reg_id`: id of revocation registry stored in wallet
+///
+/// # Returns
+/// * `status_list_json`: issued and revoked indices of the registry and base64 encoded revocation bitmap
+pub fn issuer_get_revocation_status_list(wallet_handle: WalletHandle, rev_reg_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_get_revocation_status_list(command_handle, wallet_handle, rev_reg_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_get_revocation_status_list(command_handle: CommandHandle, wallet_handle: WalletHandle, rev_reg_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_get_revocation_status_list(command_handle, wallet_handle, rev_reg_id.as_ptr(), cb)
+    })
+}
+
 /// Merge two revocation registry deltas (returned by create_credential or revoke_credential) to accumulate common delta.
 /// Send common delta to ledger to reduce the load.
 ///
@@ -1217,21 +1471,37 @@ fn _prover_close_credentials_search_for_proof_req(command_handle: CommandHandle,
 pub fn prover_create_proof(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _prover_create_proof(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, cb);
+    let err = _prover_create_proof(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, Some(rev_states_json), cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+/// Creates a proof according to the given proof request
+/// using revocation states cached in the wallet by `prover_refresh_revocation_states`.
+///
+/// # Arguments
+/// Same as for `prover_create_proof` except `rev_states_json`.
+///
+/// # Returns
+/// * `proof_json`: Proof json
+pub fn prover_create_proof_with_cached_revocation_states(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_create_proof(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, None, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
 
-fn _prover_create_proof(command_handle: CommandHandle, wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+fn _prover_create_proof(command_handle: CommandHandle, wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
     let proof_req_json = c_str!(proof_req_json);
     let requested_credentials_json = c_str!(requested_credentials_json);
     let master_secret_id = c_str!(master_secret_id);
     let schemas_json = c_str!(schemas_json);
     let credential_defs_json = c_str!(credential_defs_json);
-    let rev_states_json = c_str!(rev_states_json);
+    let rev_states_json_str = opt_c_str!(rev_states_json);
 
     ErrorCode::from(unsafe {
-        anoncreds::indy_prover_create_proof(command_handle, wallet_handle, proof_req_json.as_ptr(), requested_credentials_json.as_ptr(), master_secret_id.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_states_json.as_ptr(), cb)
+        anoncreds::indy_prover_create_proof(command_handle, wallet_handle, proof_req_json.as_ptr(), requested_credentials_json.as_ptr(), master_secret_id.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), opt_c_ptr!(rev_states_json, rev_states_json_str), cb)
     })
 }
 
@@ -1345,6 +1615,42 @@ fn _verifier_verify_proof(command_handle: CommandHandle, proof_request_json: &st
     })
 }
 
+/// Verifies a proof the same way as `verifier_verify_proof` and returns revealed attributes
+/// which `encoded` value doesn't match canonical encoding of their `raw` value.
+///
+/// # Arguments
+/// Same as for `verifier_verify_proof`.
+///
+/// # Returns
+/// * `details_json`: verification details json
+///     {
+///         "valid": true - if signature is valid, false - otherwise
+///         "non_canonical_attrs": [
+///             {"referent": <attr_referent>, "name": <attribute name>, "raw": <raw value>, "encoded": <encoded value>},
+///             ...
+///         ]
+///     }
+pub fn verifier_verify_proof_with_details(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_verify_proof_with_details(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_details(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_details(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), cb)
+    })
+}
+
 
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
@@ -1431,6 +1737,39 @@ fn _update_revocation_state(command_handle: CommandHandle, blob_storage_reader_h
     })
 }
 
+/// Refresh revocation states of credentials stored in the wallet and cache them in the wallet.
+///
+/// Latest cached state of each credential is updated with the revocation registry delta received from the ledger.
+/// Cached states are used by `prover_create_proof_with_cached_revocation_states`.
+/// Only 10 latest states are kept for each credential.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `cred_ids_json`: ids of credentials in the wallet, each id listed once
+///
+/// # Returns
+/// * `refreshed_json`: registry id and timestamp of the latest cached revocation state for each credential
+/// {
+///     "<cred_id>": {"rev_reg_id": string, "timestamp": integer},
+/// }
+pub fn prover_refresh_revocation_states(pool_handle: PoolHandle, wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle, cred_ids_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_refresh_revocation_states(command_handle, pool_handle, wallet_handle, blob_storage_reader_handle, cred_ids_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_refresh_revocation_states(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle, cred_ids_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_ids_json = c_str!(cred_ids_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_refresh_revocation_states(command_handle, pool_handle, wallet_handle, blob_storage_reader_handle, cred_ids_json.as_ptr(), cb)
+    })
+}
+
 /// Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
 /// # Arguments
@@ -1494,3 +1833,27 @@ fn _to_unqualified(command_handle: CommandHandle, entity: &str, cb: Option<Respo
         anoncreds::indy_to_unqualified(command_handle, entity.as_ptr(), cb)
     })
 }
+
+/// Encodes raw credential attribute values: 32-bit integers are encoded as themselves,
+/// other values as decimal representation of SHA-256 hash.
+///
+/// # Arguments
+/// * `attributes_json`: raw values of the credential attributes: {"attr1": "value1", ...}
+///
+/// # Returns
+/// * `cred_values_json`: credential values with raw and encoded values: {"attr1": {"raw": "value1", "encoded": "value1_as_int"}, ...}
+pub fn encode_credential_attributes(attributes_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _encode_credential_attributes(command_handle, attributes_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _encode_credential_attributes(command_handle: CommandHandle, attributes_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let attributes_json = c_str!(attributes_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_encode_credential_attributes(command_handle, attributes_json.as_ptr(), cb)
+    })
+}
@@ -14,6 +14,46 @@ use {ErrorCode, IndyError};
 use utils::callbacks::{ClosureHandler, ResultHandler};
 use {WalletHandle, CommandHandle};
 
+/// Registers external key backend that keeps non-exportable keys.
+///
+/// Keys created with `crypto_type` equal to registered type are stored in the wallet without secret part
+/// and all sign/verify operations for verkeys with `:<crypto_type>` suffix are delegated to the backend.
+///
+/// # Arguments
+/// * `crypto_type` - crypto type name that identifies keys of the backend
+/// * `create_key` - "create_key" operation handler
+/// * `sign` - "sign" operation handler
+/// * `verify` - "verify" operation handler
+/// * `crypto_box` - optional "crypto_box" operation handler used for authenticated encryption
+/// * `free_buffer` - handler that allows to de-allocate buffers allocated in backend code
+pub fn register_key_backend(crypto_type: &str,
+                            create_key: Option<crypto::KeyBackendCreateKey>,
+                            sign: Option<crypto::KeyBackendSign>,
+                            verify: Option<crypto::KeyBackendVerify>,
+                            crypto_box: Option<crypto::KeyBackendCryptoBox>,
+                            free_buffer: Option<crypto::KeyBackendFreeBuffer>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_key_backend(command_handle, crypto_type, create_key, sign, verify, crypto_box, free_buffer, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_key_backend(command_handle: CommandHandle,
+                         crypto_type: &str,
+                         create_key: Option<crypto::KeyBackendCreateKey>,
+                         sign: Option<crypto::KeyBackendSign>,
+                         verify: Option<crypto::KeyBackendVerify>,
+                         crypto_box: Option<crypto::KeyBackendCryptoBox>,
+                         free_buffer: Option<crypto::KeyBackendFreeBuffer>,
+                         cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let crypto_type = c_str!(crypto_type);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_register_key_backend(command_handle, crypto_type.as_ptr(), create_key, sign, verify, crypto_box, free_buffer, cb)
+    })
+}
+
 /// Creates key pair in wallet
 /// # Arguments
 /// * `wallet_handle` - wallet handle (created by Wallet::open)
@@ -24,7 +64,8 @@ use {WalletHandle, CommandHandle};
 /// {
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
-///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently 'ed25519', 'secp256k1' and 'p256' values are supported for this field.
+///                            // 'secp256k1' and 'p256' keys can be used only for signing and verification.
 /// }
 /// # Returns
 /// verkey of generated key pair, also used as key identifier
@@ -133,6 +174,62 @@ fn _verify(command_handle: CommandHandle, signer_vk: &str, message: &[u8], signa
     })
 }
 
+/// Signs a payload with a key and produces JSON Web Signature (RFC 7515)
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `signer_vk` - key id or verkey of my key. The key must be created by calling create_key or Did::new
+/// * `payload` - the data to be signed
+/// * `options_json` - (optional) signing options as json:
+///   {"serialization": "compact" or "json", "detached": bool, "did": DID used to build "kid" header}
+/// # Returns
+/// JWS in compact or json serialization
+pub fn sign_jws(wallet_handle: WalletHandle, signer_vk: &str, payload: &[u8], options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_jws(command_handle, wallet_handle, signer_vk, payload, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_jws(command_handle: CommandHandle, wallet_handle: WalletHandle, signer_vk: &str, payload: &[u8], options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let signer_vk = c_str!(signer_vk);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_crypto_sign_jws(command_handle, wallet_handle, signer_vk.as_ptr(),
+                                     payload.as_ptr() as *const u8, payload.len() as u32,
+                                     opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+/// Verify JSON Web Signature (RFC 7515) with a verkey
+/// # Arguments
+/// * `signer_vk` - verkey of the payload signer
+/// * `jws` - JWS in compact or json serialization
+/// * `detached_payload` - (optional) payload of JWS produced with detached signature
+/// # Returns
+/// true if signature is valid, false otherwise
+pub fn verify_jws(signer_vk: &str, jws: &str, detached_payload: Option<&[u8]>) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verify_jws(command_handle, signer_vk, jws, detached_payload, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verify_jws(command_handle: CommandHandle, signer_vk: &str, jws: &str, detached_payload: Option<&[u8]>, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let signer_vk = c_str!(signer_vk);
+    let jws = c_str!(jws);
+    let (detached_payload_raw, detached_payload_len) = detached_payload
+        .map(|payload| (payload.as_ptr() as *const u8, payload.len() as u32))
+        .unwrap_or((null(), 0));
+
+    ErrorCode::from(unsafe {
+        crypto::indy_crypto_verify_jws(command_handle, signer_vk.as_ptr(), jws.as_ptr(),
+                                       detached_payload_raw, detached_payload_len, cb)
+    })
+}
+
 /// **** THIS FUNCTION WILL BE DEPRECATED USE pack_message INSTEAD ****
 /// Encrypt a message by authenticated-encryption scheme.
 ///
@@ -344,3 +441,66 @@ fn _unpack_message(command_handle: CommandHandle, wallet_handle: WalletHandle, j
     })
 }
 
+/// Packs a message into DIDComm v2 format (Experimental)
+///
+/// Anoncrypt uses X25519 ECDH-ES+A256KW, authcrypt uses X25519 ECDH-1PU+A256KW and content is encrypted with A256CBC-HS512.
+/// Key ids can be either verkeys or did:key DID URLs.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open).
+/// * `message`: the message to be packed
+/// * `receiver_kids`: a JSON array as a string containing a list of the receivers key ids. Can be empty for signed only message
+/// * `sender_kid`: the sender's key id. When None is used in this parameter, anoncrypt is used
+/// * `signer_kid`: the signer's key id. When set the message is signed as JWS before encryption
+/// # Returns
+/// DIDComm v2 encrypted message (JWE) or signed message (JWS) if no receivers passed
+pub fn pack_message_v2(wallet_handle: WalletHandle, message: &[u8], receiver_kids: &str, sender_kid: Option<&str>, signer_kid: Option<&str>) -> Box<dyn Future<Item=Vec<u8>, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _pack_message_v2(command_handle, wallet_handle, message, receiver_kids, sender_kid, signer_kid, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _pack_message_v2(command_handle: CommandHandle, wallet_handle: WalletHandle, message: &[u8], receiver_kids: &str, sender_kid: Option<&str>, signer_kid: Option<&str>, cb: Option<ResponseSliceCB>) -> ErrorCode {
+    let receiver_kids = c_str!(receiver_kids);
+    let sender_kid_str = opt_c_str!(sender_kid);
+    let signer_kid_str = opt_c_str!(signer_kid);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_pack_message_v2(command_handle,
+                                     wallet_handle,
+                                     message.as_ptr() as *const u8,
+                                     message.len() as u32,
+                                     receiver_kids.as_ptr(),
+                                     opt_c_ptr!(sender_kid, sender_kid_str),
+                                     opt_c_ptr!(signer_kid, signer_kid_str),
+                                     cb)
+    })
+}
+
+/// Unpacks a message packed by pack_message_v2 or pack_message (Experimental)
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open).
+/// * `packed_message`: DIDComm v2 JWE or JWS, or JWE produced by pack_message
+/// # Returns
+/// a json structure that contains a decrypted message and recipient_kid, sender_kid, signer_kid if applicable
+pub fn unpack_message_v2(wallet_handle: WalletHandle, packed_message: &[u8]) -> Box<dyn Future<Item=Vec<u8>, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _unpack_message_v2(command_handle, wallet_handle, packed_message, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _unpack_message_v2(command_handle: CommandHandle, wallet_handle: WalletHandle, packed_message: &[u8], cb: Option<ResponseSliceCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        crypto::indy_unpack_message_v2(command_handle,
+                                       wallet_handle,
+                                       packed_message.as_ptr() as *const u8,
+                                       packed_message.len() as u32,
+                                       cb)
+    })
+}
+
@@ -32,7 +32,8 @@ use {CommandHandle, WalletHandle, PoolHandle};
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               currently 'ed25519', 'secp256k1' and 'p256' values are supported for this field;
+///               'secp256k1' and 'p256' keys can be used only for signing and verification)
 ///     "cid": bool, (optional; if not set then false is used;)
 ///     "method_name": string, (optional) method name to create fully qualified did.
 /// }
@@ -200,6 +201,33 @@ fn _key_for_local_did(command_handle: CommandHandle, wallet_handle: WalletHandle
     ErrorCode::from(unsafe { did::indy_key_for_local_did(command_handle, wallet_handle, did.as_ptr(), cb) })
 }
 
+/// Returns ver key (key id) the given DID had at the given time.
+///
+/// Looks to dated key history stored in the wallet first and falls back to
+/// GET_NYM request with timestamp sent to the ledger if history doesn't cover requested time.
+///
+/// # Arguments
+/// * `pool_handle` - Pool handle (created by Pool::open_ledger).
+/// * `wallet_handle` - Wallet handle (created by Wallet::open).
+/// * `did` - The DID to resolve key.
+/// * `timestamp` - Time (POSIX timestamp in seconds) to get the key for.
+///
+/// # Returns
+/// * `key` - The DIDs ver key (key id) at the given time.
+pub fn key_for_did_at(pool_handle: PoolHandle, wallet_handle: WalletHandle, did: &str, timestamp: u64) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _key_for_did_at(command_handle, pool_handle, wallet_handle, did, timestamp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _key_for_did_at(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, did: &str, timestamp: u64, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_key_for_did_at(command_handle, pool_handle, wallet_handle, did.as_ptr(), timestamp, cb) })
+}
+
 /// Set/replaces endpoint information for the given DID.
 ///
 /// # Arguments
@@ -246,6 +274,29 @@ fn _get_endpoint_for_did(command_handle: CommandHandle, wallet_handle: WalletHan
     ErrorCode::from(unsafe { did::indy_get_endpoint_for_did(command_handle, wallet_handle, pool_handle, did.as_ptr(), cb) })
 }
 
+/// Resolves DID into W3C DID Document built from NYM and endpoint ATTRIB transactions written to the ledger.
+///
+/// # Arguments
+/// * `pool_handle` - Pool handle (created by Pool::open_ledger).
+/// * `did` - The DID to resolve ("did:sov", "did:indy" or unqualified).
+///
+/// # Returns
+/// * `resolution_result_json` - DID Document with resolution metadata:
+///   {"didDocument": {..}, "didResolutionMetadata": {"contentType", "seqNo", "txnTime", "endpoint"}}
+pub fn resolve_did(pool_handle: PoolHandle, did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did(command_handle, pool_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did(command_handle: CommandHandle, pool_handle: PoolHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_resolve_did(command_handle, pool_handle, did.as_ptr(), cb) })
+}
+
 /// Saves/replaces the meta information for the giving DID in the wallet.
 ///
 /// # Arguments
@@ -7,7 +7,8 @@ use std::ptr::null;
 use futures::Future;
 
 use ffi::ledger;
-use ffi::{ResponseStringCB,
+use ffi::{ResponseEmptyCB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
@@ -74,6 +75,58 @@ fn _submit_request(command_handle: CommandHandle, pool_handle: PoolHandle, reque
     ErrorCode::from(unsafe { ledger::indy_submit_request(command_handle, pool_handle, request_json.as_ptr(), cb) })
 }
 
+/// Publishes request message to validator pool (no signing, like submit_request)
+/// and returns the reply together with per node results of consensus.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Request data json.
+///
+/// # Returns
+/// {"reply": {reply json} or null, "consensus": {"reached": bool, "f": int, "nodes": {alias: {node result}}} or null}
+pub fn submit_request_with_details(pool_handle: PoolHandle, request_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_request_with_details(command_handle, pool_handle, request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_request_with_details(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_request_with_details(command_handle, pool_handle, request_json.as_ptr(), cb) })
+}
+
+/// Publishes batch of request messages to validator pool (no signing, like submit_request).
+///
+/// Requests are pipelined: up to `max_in_flight` (`conn_limit` pool open option by default) of them
+/// are sent without waiting for replies, spread across pool connections by `conn_limit` requests.
+/// Consensus and state proof verification are performed for each request independently.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `requests_json` - Array of request data jsons.
+/// * `options_json` - (Optional) batch options {"max_in_flight": int}.
+///
+/// # Returns
+/// Array of per-request results in the same order as requests:
+/// {"reply": {reply json}} or {"error": {"code": int, "message": string}}.
+pub fn submit_requests_batch(pool_handle: PoolHandle, requests_json: &str, options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_requests_batch(command_handle, pool_handle, requests_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_requests_batch(command_handle: CommandHandle, pool_handle: PoolHandle, requests_json: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let requests_json = c_str!(requests_json);
+    let options_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_requests_batch(command_handle, pool_handle, requests_json.as_ptr(), opt_c_ptr!(options_json, options_str), cb) })
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, wait_timeout: Option<i32>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -1039,6 +1092,39 @@ fn _get_response_metadata(command_handle: CommandHandle, response: &str, cb: Opt
     ErrorCode::from(unsafe { ledger::indy_get_response_metadata(command_handle, response.as_ptr(), cb) })
 }
 
+/// Verifies that transaction returned in GET_TXN response is included into the ledger.
+///
+/// Checks that audit path leads from the transaction to the ledger root hash
+/// multi-signed by nodes of the pool.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `get_txn_response` - response of GET_TXN request.
+///
+/// # Returns
+/// verified inclusion info
+/// {
+///     "seqNo": u64 - sequence number of the transaction,
+///     "txnTime": Option<u64> - transaction ordering time,
+///     "ledgerId": Option<u64> - id of the ledger signed by nodes,
+///     "ledgerSize": u64 - size of the ledger the audit path is built for,
+///     "rootHash": string - verified ledger root hash (base58),
+///     "multiSignatureTimestamp": Option<u64> - time when the root hash was signed by nodes
+/// }
+pub fn verify_txn_inclusion(pool_handle: PoolHandle, get_txn_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verify_txn_inclusion(command_handle, pool_handle, get_txn_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verify_txn_inclusion(command_handle: CommandHandle, pool_handle: PoolHandle, get_txn_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_txn_response = c_str!(get_txn_response);
+
+    ErrorCode::from(unsafe { ledger::indy_verify_txn_inclusion(command_handle, pool_handle, get_txn_response.as_ptr(), cb) })
+}
+
 /// Builds a AUTH_RULE request. Request to change authentication rules for a ledger transaction.
 ///
 /// # Arguments
@@ -1203,6 +1289,208 @@ fn _build_get_auth_rule_request(command_handle: CommandHandle,
     })
 }
 
+/// Checks locally whether a set of signers satisfies the auth rule constraint for a ledger action.
+///
+/// The rule is selected in the same way as the ledger does it:
+/// rules with exact `field`, `old_value` and `new_value` take precedence over rules with `*` values.
+///
+/// # Arguments
+/// * `auth_rules_json`: list of auth rules in the format of `data` field of GET_AUTH_RULE reply.
+/// * `txn_type`: ledger transaction alias or associated value.
+/// * `action`: type of an action. Can be either "ADD" or "EDIT".
+/// * `field`: transaction field.
+/// * `old_value`: (Optional) old value of a field (mandatory for EDIT action).
+/// * `new_value`: (Optional) new value of a field.
+/// * `signers_json`: list of transaction signers:
+///     [{role: (Optional) role of the signer, isOwner: (Optional) bool, offLedger: (Optional) bool}]
+///
+/// # Returns
+/// Check result json: {allowed: bool, explanation: string, fees: [string]}
+pub fn check_auth_rule(auth_rules_json: &str, txn_type: &str, action: &str, field: &str,
+                       old_value: Option<&str>, new_value: Option<&str>, signers_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _check_auth_rule(command_handle, auth_rules_json, txn_type, action, field, old_value, new_value, signers_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _check_auth_rule(command_handle: CommandHandle,
+                    auth_rules_json: &str,
+                    txn_type: &str,
+                    action: &str,
+                    field: &str,
+                    old_value: Option<&str>,
+                    new_value: Option<&str>,
+                    signers_json: &str,
+                    cb: Option<ResponseStringCB>) -> ErrorCode {
+    let auth_rules_json = c_str!(auth_rules_json);
+    let txn_type = c_str!(txn_type);
+    let action = c_str!(action);
+    let field = c_str!(field);
+    let old_value_str = opt_c_str!(old_value);
+    let new_value_str = opt_c_str!(new_value);
+    let signers_json = c_str!(signers_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_check_auth_rule(command_handle,
+                                     auth_rules_json.as_ptr(),
+                                     txn_type.as_ptr(),
+                                     action.as_ptr(),
+                                     field.as_ptr(),
+                                     opt_c_ptr!(old_value, old_value_str),
+                                     opt_c_ptr!(new_value, new_value_str),
+                                     signers_json.as_ptr(),
+                                     cb)
+    })
+}
+
+/// Builds a Rich Schema object request (JSON_LD_CONTEXT, RICH_SCHEMA, RICH_SCHEMA_ENCODING,
+/// RICH_SCHEMA_MAPPING, RICH_SCHEMA_CRED_DEF or RICH_SCHEMA_PRES_DEF depending on `rs_type`).
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
+/// * `rs_id` - identifier of the Rich Schema object.
+/// * `rs_content` - JSON-LD content of the object as a string.
+/// * `rs_name` - name of the Rich Schema object.
+/// * `rs_version` - version of the Rich Schema object.
+/// * `rs_type` - type of the Rich Schema object (one of "ctx", "sch", "enc", "map", "cdf", "pdf").
+/// * `ver` - version of the Rich Schema object json format.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_rich_schema_request(submitter_did: &str, rs_id: &str, rs_content: &str, rs_name: &str,
+                                 rs_version: &str, rs_type: &str, ver: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_rich_schema_request(command_handle, submitter_did, rs_id, rs_content, rs_name, rs_version, rs_type, ver, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_rich_schema_request(command_handle: CommandHandle,
+                              submitter_did: &str,
+                              rs_id: &str,
+                              rs_content: &str,
+                              rs_name: &str,
+                              rs_version: &str,
+                              rs_type: &str,
+                              ver: &str,
+                              cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let rs_id = c_str!(rs_id);
+    let rs_content = c_str!(rs_content);
+    let rs_name = c_str!(rs_name);
+    let rs_version = c_str!(rs_version);
+    let rs_type = c_str!(rs_type);
+    let ver = c_str!(ver);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_rich_schema_request(command_handle,
+                                               submitter_did.as_ptr(),
+                                               rs_id.as_ptr(),
+                                               rs_content.as_ptr(),
+                                               rs_name.as_ptr(),
+                                               rs_version.as_ptr(),
+                                               rs_type.as_ptr(),
+                                               ver.as_ptr(),
+                                               cb)
+    })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get a Rich Schema object by its identifier.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `rs_id` - identifier of the Rich Schema object.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_id_request(submitter_did: Option<&str>, rs_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_id_request(command_handle, submitter_did, rs_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_id_request(command_handle: CommandHandle, submitter_did: Option<&str>, rs_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let rs_id = c_str!(rs_id);
+
+    ErrorCode::from(unsafe { ledger::indy_build_get_rich_schema_object_by_id_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), rs_id.as_ptr(), cb) })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+/// Request to get a Rich Schema object by its type, name and version.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `rs_type` - type of the Rich Schema object (one of "ctx", "sch", "enc", "map", "cdf", "pdf").
+/// * `rs_name` - name of the Rich Schema object.
+/// * `rs_version` - version of the Rich Schema object.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_metadata_request(submitter_did: Option<&str>, rs_type: &str, rs_name: &str, rs_version: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_metadata_request(command_handle, submitter_did, rs_type, rs_name, rs_version, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle,
+                                                     submitter_did: Option<&str>,
+                                                     rs_type: &str,
+                                                     rs_name: &str,
+                                                     rs_version: &str,
+                                                     cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let rs_type = c_str!(rs_type);
+    let rs_name = c_str!(rs_name);
+    let rs_version = c_str!(rs_version);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_rich_schema_object_by_metadata_request(command_handle,
+                                                                      opt_c_ptr!(submitter_did, submitter_did_str),
+                                                                      rs_type.as_ptr(),
+                                                                      rs_name.as_ptr(),
+                                                                      rs_version.as_ptr(),
+                                                                      cb)
+    })
+}
+
+/// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+///
+/// # Arguments
+/// * `get_rich_schema_object_response` - response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+///
+/// # Returns
+/// Rich Schema object json.
+/// {
+///     id: identifier of the object
+///     content: JSON-LD content of the object as a string
+///     rsName: name of the object
+///     rsVersion: version of the object
+///     rsType: type of the object
+///     ver: version of the object json format
+/// }
+pub fn parse_get_rich_schema_object_response(get_rich_schema_object_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_rich_schema_object_response(command_handle, get_rich_schema_object_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_rich_schema_object_response(command_handle: CommandHandle, get_rich_schema_object_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_rich_schema_object_response = c_str!(get_rich_schema_object_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_rich_schema_object_response(command_handle, get_rich_schema_object_response.as_ptr(), cb) })
+}
+
 /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
 ///
 /// # Arguments
@@ -1473,6 +1761,59 @@ fn _append_txn_author_agreement_acceptance_to_request(command_handle: CommandHan
     })
 }
 
+/// Register transaction author agreement acceptance on the pool handle.
+/// `sign_and_submit_request` will append it to writes to DOMAIN ledger which don't contain acceptance data yet.
+///
+/// Before the first write the agreement is read from the ledger and cached for the pool handle.
+/// Writes fail without sending to the pool if the accepted agreement isn't set on the ledger or has been retired.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by Pool::open_ledger).
+/// * `text` and `version`: (optional) raw data about TAA from ledger.
+///     These parameters should be passed together.
+///     These parameters are required if taa_digest parameter is omitted.
+/// * `taa_digest`: (optional) digest on text and version.
+///     This parameter is required if text and version parameters are omitted.
+/// * `mechanism`: mechanism how user has accepted the TAA
+/// * `time`: UTC timestamp when user has accepted the TAA.
+pub fn set_txn_author_agreement_acceptance_for_pool(pool_handle: PoolHandle,
+                                                    text: Option<&str>,
+                                                    version: Option<&str>,
+                                                    taa_digest: Option<&str>,
+                                                    mechanism: &str,
+                                                    time: u64) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_txn_author_agreement_acceptance_for_pool(command_handle, pool_handle, text, version, taa_digest, mechanism, time, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_txn_author_agreement_acceptance_for_pool(command_handle: CommandHandle,
+                                                 pool_handle: PoolHandle,
+                                                 text: Option<&str>,
+                                                 version: Option<&str>,
+                                                 taa_digest: Option<&str>,
+                                                 mechanism: &str,
+                                                 time: u64,
+                                                 cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let text_str = opt_c_str!(text);
+    let version_str = opt_c_str!(version);
+    let taa_digest_str = opt_c_str!(taa_digest);
+    let mechanism = c_str!(mechanism);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_set_txn_author_agreement_acceptance_for_pool(command_handle,
+                                                                  pool_handle,
+                                                                  opt_c_ptr!(text, text_str),
+                                                                  opt_c_ptr!(version, version_str),
+                                                                  opt_c_ptr!(taa_digest, taa_digest_str),
+                                                                  mechanism.as_ptr(),
+                                                                  time,
+                                                                  cb)
+    })
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1512,6 +1853,90 @@ fn _append_request_endorser(command_handle: CommandHandle,
                                              cb)
     })
 }
+
+/// Prepares a request for an Endorser.
+///
+/// Appends Endorser to the request, signs it by the Transaction Author and wraps it
+/// into a pending transaction which can be passed to the Endorser (see `endorse_pending_transaction`).
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `submitter_did`: DID of the Transaction Author. Must be the same as an identifier of the request.
+/// * `request_json`: original request data json.
+/// * `endorser_did`: DID of the Endorser that will submit the transaction.
+/// # Returns
+/// Pending transaction json.
+pub fn prepare_request_for_endorser(wallet_handle: WalletHandle,
+                                    submitter_did: &str,
+                                    request_json: &str,
+                                    endorser_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prepare_request_for_endorser(command_handle, wallet_handle, submitter_did, request_json, endorser_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prepare_request_for_endorser(command_handle: CommandHandle,
+                                 wallet_handle: WalletHandle,
+                                 submitter_did: &str,
+                                 request_json: &str,
+                                 endorser_did: &str,
+                                 cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let request_json = c_str!(request_json);
+    let endorser_did = c_str!(endorser_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_prepare_request_for_endorser(command_handle,
+                                                  wallet_handle,
+                                                  submitter_did.as_ptr(),
+                                                  request_json.as_ptr(),
+                                                  endorser_did.as_ptr(),
+                                                  cb)
+    })
+}
+
+/// Endorses a pending transaction prepared by `prepare_request_for_endorser` and submits it to validator pool.
+///
+/// Endorser's role is checked against auth rules set on the ledger before co-signing the request.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `endorser_did`: DID of the Endorser. Must be the same as the Endorser of the pending transaction.
+/// * `pending_txn_json`: pending transaction json.
+/// # Returns
+/// Request result as json.
+pub fn endorse_pending_transaction(pool_handle: PoolHandle,
+                                   wallet_handle: WalletHandle,
+                                   endorser_did: &str,
+                                   pending_txn_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _endorse_pending_transaction(command_handle, pool_handle, wallet_handle, endorser_did, pending_txn_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _endorse_pending_transaction(command_handle: CommandHandle,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                endorser_did: &str,
+                                pending_txn_json: &str,
+                                cb: Option<ResponseStringCB>) -> ErrorCode {
+    let endorser_did = c_str!(endorser_did);
+    let pending_txn_json = c_str!(pending_txn_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_endorse_pending_transaction(command_handle,
+                                                 pool_handle,
+                                                 wallet_handle,
+                                                 endorser_did.as_ptr(),
+                                                 pending_txn_json.as_ptr(),
+                                                 cb)
+    })
+}
 /// Request to freeze list of ledgers.
 ///
 /// # Arguments
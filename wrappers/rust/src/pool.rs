@@ -58,6 +58,10 @@ fn _create_pool_ledger_config(command_handle: CommandHandle, pool_name: &str, po
 ///     "number_read_nodes": int (optional) - the number of nodes to send read requests (2 by default)
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
+///     "transport": string (optional) - "zmq" (default), "simulated" for in-process simulated pool
+///         or name of the transport registered with `register_pool_transport`
+///     "read_cache_size": int (optional) - the number of state proof verified read replies to cache (0 by default)
+///     "mirror_ledgers": bool (optional) - whether to keep verified local copies of DOMAIN and CONFIG ledgers (false by default)
 /// }
 ///
 /// # Returns
@@ -165,3 +169,117 @@ fn _set_protocol_version(command_handle: CommandHandle, protocol_version: usize,
       pool::indy_set_protocol_version(command_handle, protocol_version, cb)
     })
 }
+
+/// Gets transaction from the local copy of the ledger.
+///
+/// POOL ledger is always stored locally, DOMAIN and CONFIG ledgers only if pool was opened with `mirror_ledgers` option.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+/// * `ledger_id` - Id of the ledger: 0 - POOL, 1 - DOMAIN, 2 - CONFIG.
+/// * `seq_no` - Sequence number of the transaction (starts from 1).
+///
+/// # Returns
+/// Transaction json as it is stored in the ledger.
+pub fn get_local_txn(config_name: &str, ledger_id: usize, seq_no: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_local_txn(command_handle, config_name, ledger_id, seq_no, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_local_txn(command_handle: CommandHandle, config_name: &str, ledger_id: usize, seq_no: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let config_name = c_str!(config_name);
+
+    ErrorCode::from(unsafe { pool::indy_get_local_txn(command_handle, config_name.as_ptr(), ledger_id, seq_no, cb) })
+}
+
+/// Gets transactions of the given type from the local copy of the ledger.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+/// * `ledger_id` - Id of the ledger: 0 - POOL, 1 - DOMAIN, 2 - CONFIG.
+/// * `txn_type` - Type of transactions to return (for example "1" for NYM).
+/// * `from_seq_no` - Sequence number of the transaction to start search from (starts from 1).
+/// * `count` - Maximum number of transactions to return.
+///
+/// # Returns
+/// Json array of transactions ordered by sequence number.
+pub fn get_local_txns_by_type(config_name: &str, ledger_id: usize, txn_type: &str, from_seq_no: usize, count: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_local_txns_by_type(command_handle, config_name, ledger_id, txn_type, from_seq_no, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_local_txns_by_type(command_handle: CommandHandle, config_name: &str, ledger_id: usize, txn_type: &str, from_seq_no: usize, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let config_name = c_str!(config_name);
+    let txn_type = c_str!(txn_type);
+
+    ErrorCode::from(unsafe { pool::indy_get_local_txns_by_type(command_handle, config_name.as_ptr(), ledger_id, txn_type.as_ptr(), from_seq_no, count, cb) })
+}
+
+/// Checks health of the pool without opening it.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+/// * `config` - Runtime pool configuration json (see `open_pool_ledger`).
+///
+/// # Returns
+/// Pool diagnostics json with per-node status, ledger status votes and BLS key validity.
+pub fn diagnose_pool(config_name: &str, config: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _diagnose_pool(command_handle, config_name, config, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _diagnose_pool(command_handle: CommandHandle, config_name: &str, config: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let config_name = c_str!(config_name);
+    let config_str = opt_c_str!(config);
+
+    ErrorCode::from(unsafe { pool::indy_diagnose_pool(command_handle, config_name.as_ptr(), opt_c_ptr!(config, config_str), cb) })
+}
+
+/// Registers custom pool transport that delivers messages to the pool nodes instead of ZMQ connections.
+///
+/// Pools opened with `transport` config value equal to `transport_name` send messages through the handlers.
+/// State proofs, BLS multi-signatures and catchup replies received through the transport are still verified.
+///
+/// # Arguments
+/// * `transport_name` - name of the transport, must not be equal to built-in "zmq" and "simulated" transports
+/// * `open` - "open" operation handler called for each opened pool
+/// * `send` - non-blocking "send" operation handler
+/// * `recv` - non-blocking "recv" operation handler that returns single received message or nulls
+/// * `free_buffer` - handler that allows to de-allocate buffers allocated in transport code
+/// * `close` - "close" operation handler called when the pool is closed
+pub fn register_pool_transport(transport_name: &str,
+                               open: Option<pool::PoolTransportOpen>,
+                               send: Option<pool::PoolTransportSend>,
+                               recv: Option<pool::PoolTransportRecv>,
+                               free_buffer: Option<pool::PoolTransportFreeBuffer>,
+                               close: Option<pool::PoolTransportClose>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_pool_transport(command_handle, transport_name, open, send, recv, free_buffer, close, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_pool_transport(command_handle: CommandHandle,
+                            transport_name: &str,
+                            open: Option<pool::PoolTransportOpen>,
+                            send: Option<pool::PoolTransportSend>,
+                            recv: Option<pool::PoolTransportRecv>,
+                            free_buffer: Option<pool::PoolTransportFreeBuffer>,
+                            close: Option<pool::PoolTransportClose>,
+                            cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let transport_name = c_str!(transport_name);
+
+    ErrorCode::from(unsafe {
+        pool::indy_register_pool_transport(command_handle, transport_name.as_ptr(), open, send, recv, free_buffer, close, cb)
+    })
+}
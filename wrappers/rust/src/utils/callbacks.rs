@@ -27,6 +27,7 @@ lazy_static! {
     static ref CALLBACKS_STR_OPTSTR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, Option<String>), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_STR_STR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String, String), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_OPTSTR_OPTSTR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, Option<String>, Option<String>), IndyError>>>> = Default::default();
+    static ref CALLBACKS_STR_STR_STR_OPTSTR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String, String, Option<String>), IndyError>>>> = Default::default();
 }
 
 macro_rules! cb_ec {
@@ -96,6 +97,10 @@ impl ClosureHandler {
            CALLBACKS_STR_OPTSTR_OPTSTR,
            (rust_str!(str1), opt_rust_str!(str2), opt_rust_str!(str3)));
 
+    cb_ec!(cb_ec_string_string_string_opt_string(str1: *const c_char, str2: *const c_char, str3: *const c_char, str4: *const c_char)->(String, String, String, Option<String>),
+           CALLBACKS_STR_STR_STR_OPTSTR,
+           (rust_str!(str1), rust_str!(str2), rust_str!(str3), opt_rust_str!(str4)));
+
     cb_ec!(cb_ec_string_string_u64(str1:*const c_char, str2:*const c_char, u: u64)->(String, String, u64),
            CALLBACKS_STR_STR_U64,
            (rust_str!(str1), rust_str!(str2), u));
@@ -143,6 +148,7 @@ impl ResultHandler {
     result_handler!(str_optstr((String, Option<String>)), CALLBACKS_STR_OPTSTR);
     result_handler!(str_optstr_optstr((String, Option<String>, Option<String>)), CALLBACKS_STR_OPTSTR_OPTSTR);
     result_handler!(str_str_str((String, String, String)), CALLBACKS_STR_STR_STR);
+    result_handler!(str_str_str_optstr((String, String, String, Option<String>)), CALLBACKS_STR_STR_STR_OPTSTR);
     result_handler!(str_str_u64((String, String, u64)), CALLBACKS_STR_STR_U64);
 }
 
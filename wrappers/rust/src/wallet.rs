@@ -148,6 +148,37 @@ fn _register_storage(command_handle: CommandHandle,
     })
 }
 
+/// Registers transaction handlers for custom wallet storage implementation.
+///
+/// # Arguments
+/// * `xtype` - Storage type name registered with register_wallet_storage before.
+/// * `begin_transaction` - WalletType begin transaction operation handler
+/// * `commit_transaction` - WalletType commit transaction operation handler
+/// * `rollback_transaction` - WalletType rollback transaction operation handler
+pub fn register_wallet_storage_transactions(xtype: &str,
+                                            begin_transaction: Option<wallet::WalletBeginTransaction>,
+                                            commit_transaction: Option<wallet::WalletCommitTransaction>,
+                                            rollback_transaction: Option<wallet::WalletRollbackTransaction>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_storage_transactions(command_handle, xtype, begin_transaction, commit_transaction, rollback_transaction, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_storage_transactions(command_handle: CommandHandle,
+                                  xtype: &str,
+                                  begin_transaction: Option<wallet::WalletBeginTransaction>,
+                                  commit_transaction: Option<wallet::WalletCommitTransaction>,
+                                  rollback_transaction: Option<wallet::WalletRollbackTransaction>,
+                                  cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+
+    ErrorCode::from(unsafe {
+      wallet::indy_register_wallet_storage_transactions(command_handle, xtype.as_ptr(), begin_transaction, commit_transaction, rollback_transaction, cb)
+    })
+}
+
 /// Creates a new secure wallet with the given unique name.
 ///
 /// # Arguments
@@ -205,6 +236,31 @@ fn _open_wallet(command_handle: CommandHandle, config: &str, credentials: &str,
     })
 }
 
+/// Rotates master key of opened wallet without closing it
+///
+/// # Arguments:
+/// * `wallet_handle` - wallet handle returned by indy_open_wallet
+/// * `rotate_key_config` - JSON containing new wallet key settings.
+///   {
+///     "key": new passphrase or raw key used to derive wallet master key
+///     "key_derivation_method": optional<string> ARGON2I_MOD (used by default), ARGON2I_INT or RAW
+///   }
+pub fn rotate_wallet_key(wallet_handle: WalletHandle, rotate_key_config: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _rotate_wallet_key(command_handle, wallet_handle, rotate_key_config, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _rotate_wallet_key(command_handle: CommandHandle, wallet_handle: WalletHandle, rotate_key_config: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let rotate_key_config = c_str!(rotate_key_config);
+
+    ErrorCode::from(unsafe {
+      wallet::indy_rotate_wallet_key(command_handle, wallet_handle, rotate_key_config.as_ptr(), cb)
+    })
+}
+
 /// Exports opened wallet
 ///
 /// Note this endpoint is EXPERIMENTAL. Function signature and behaviour may change
@@ -308,6 +364,57 @@ fn _close_wallet(command_handle: CommandHandle, wallet_handle: WalletHandle, cb:
     ErrorCode::from(unsafe { wallet::indy_close_wallet(command_handle, wallet_handle, cb) })
 }
 
+/// Starts a transaction on opened wallet.
+///
+/// All wallet changes made after this call are applied atomically by `wallet_transaction_commit`
+/// or discarded by `wallet_transaction_rollback`. Nested transactions are not supported.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle returned by open.
+pub fn wallet_transaction_begin(wallet_handle: WalletHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _wallet_transaction_begin(command_handle, wallet_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _wallet_transaction_begin(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { wallet::indy_wallet_transaction_begin(command_handle, wallet_handle, cb) })
+}
+
+/// Commits the transaction started by `wallet_transaction_begin`.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle returned by open.
+pub fn wallet_transaction_commit(wallet_handle: WalletHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _wallet_transaction_commit(command_handle, wallet_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _wallet_transaction_commit(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { wallet::indy_wallet_transaction_commit(command_handle, wallet_handle, cb) })
+}
+
+/// Rolls back the transaction started by `wallet_transaction_begin`.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle returned by open.
+pub fn wallet_transaction_rollback(wallet_handle: WalletHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _wallet_transaction_rollback(command_handle, wallet_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _wallet_transaction_rollback(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { wallet::indy_wallet_transaction_rollback(command_handle, wallet_handle, cb) })
+}
+
 /// Create a new non-secret record in the wallet
 ///
 /// # Arguments